@@ -32,6 +32,268 @@ pub fn get_tgb_api_key() -> String {
     env::var("TGB_API_KEY").expect("TGB_API_KEY must be set in environment or .env file")
 }
 
+/// Gets the TGB API base URL from environment variables.
+///
+/// # Returns
+/// The TGB_BASE_URL value (e.g., a sandbox deployment), or the production API if unset
+///
+/// # Example
+/// ```no_run
+/// let base_url = get_tgb_base_url();
+/// ```
+pub fn get_tgb_base_url() -> String {
+    env::var("TGB_BASE_URL").unwrap_or_else(|_| "https://api.thegivingblock.com/v1".to_string())
+}
+
+/// Gets the TGB connect and request timeout in seconds.
+///
+/// # Returns
+/// The TGB_TIMEOUT_SECS value, or 10 if unset or not a number
+pub fn get_tgb_timeout_secs() -> u64 {
+    env::var("TGB_TIMEOUT_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(10)
+}
+
+/// Gets the maximum number of retries for rate-limited (429), 5xx and timed-out TGB requests.
+///
+/// # Returns
+/// The TGB_MAX_RETRIES value, or 3 if unset or not a number
+pub fn get_tgb_max_retries() -> u32 {
+    env::var("TGB_MAX_RETRIES")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(3)
+}
+
+/// Gets the number of consecutive TGB failures that opens the circuit breaker.
+///
+/// # Returns
+/// The TGB_CIRCUIT_FAILURE_THRESHOLD value (0 disables the breaker), or 5 if unset or not a number
+pub fn get_tgb_circuit_failure_threshold() -> u32 {
+    env::var("TGB_CIRCUIT_FAILURE_THRESHOLD")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(5)
+}
+
+/// Gets how long the TGB circuit breaker stays open, in seconds.
+///
+/// # Returns
+/// The TGB_CIRCUIT_COOLDOWN_SECS value, or 30 if unset or not a number
+pub fn get_tgb_circuit_cooldown_secs() -> u64 {
+    env::var("TGB_CIRCUIT_COOLDOWN_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(30)
+}
+
+/// Gets the Solana RPC endpoint URL from environment variables.
+///
+/// # Returns
+/// The SOLANA_RPC_URL value, or the public devnet endpoint if unset
+///
+/// # Example
+/// ```no_run
+/// let rpc_url = get_solana_rpc_url();
+/// ```
+pub fn get_solana_rpc_url() -> String {
+    env::var("SOLANA_RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".to_string())
+}
+
+/// Gets the Solana RPC endpoints to fail over between, in order.
+///
+/// # Returns
+/// The comma-separated SOLANA_RPC_URLS entries if set, otherwise the single
+/// `get_solana_rpc_url()` endpoint
+///
+/// # Example
+/// ```no_run
+/// // SOLANA_RPC_URLS=https://primary.example.com,https://api.devnet.solana.com
+/// let rpc_urls = get_solana_rpc_urls();
+/// ```
+pub fn get_solana_rpc_urls() -> Vec<String> {
+    let urls: Vec<String> = env::var("SOLANA_RPC_URLS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+        .map(str::to_string)
+        .collect();
+    if urls.is_empty() {
+        vec![get_solana_rpc_url()]
+    } else {
+        urls
+    }
+}
+
+/// Gets the Fundraisely program ID from environment variables.
+///
+/// # Returns
+/// The FUNDRAISELY_PROGRAM_ID value, or the devnet deployment ID if unset
+///
+/// # Example
+/// ```no_run
+/// let program_id = get_program_id();
+/// ```
+pub fn get_program_id() -> String {
+    env::var("FUNDRAISELY_PROGRAM_ID")
+        .unwrap_or_else(|_| "DurTiNFFQK62B5nMimfhuvztJXsFyu8skMz6rNtp2Wmq".to_string())
+}
+
+/// Gets the maximum number of retries for transient Solana RPC failures.
+///
+/// # Returns
+/// The SOLANA_RPC_MAX_RETRIES value, or 3 if unset or not a number
+///
+/// # Example
+/// ```no_run
+/// let max_retries = get_solana_rpc_max_retries();
+/// ```
+pub fn get_solana_rpc_max_retries() -> u32 {
+    env::var("SOLANA_RPC_MAX_RETRIES")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(3)
+}
+
+/// Gets the Solana RPC connect and request timeout in seconds.
+///
+/// # Returns
+/// The SOLANA_RPC_TIMEOUT_SECS value, or 10 if unset or not a number
+pub fn get_solana_rpc_timeout_secs() -> u64 {
+    env::var("SOLANA_RPC_TIMEOUT_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(10)
+}
+
+/// Gets the charity search cache TTL in seconds.
+///
+/// # Returns
+/// The TGB_SEARCH_CACHE_TTL_SECS value, or 600 if unset or not a number
+pub fn get_tgb_search_cache_ttl_secs() -> u64 {
+    env::var("TGB_SEARCH_CACHE_TTL_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(600)
+}
+
+/// Gets the maximum number of cached charity search queries.
+///
+/// # Returns
+/// The TGB_SEARCH_CACHE_CAPACITY value, or 1000 if unset or not a number
+pub fn get_tgb_search_cache_capacity() -> usize {
+    env::var("TGB_SEARCH_CACHE_CAPACITY")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(1000)
+}
+
+/// Gets the Room account cache TTL in milliseconds.
+///
+/// # Returns
+/// The ROOM_CACHE_TTL_MS value, or 5000 if unset or not a number
+pub fn get_room_cache_ttl_ms() -> u64 {
+    env::var("ROOM_CACHE_TTL_MS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(5000)
+}
+
+/// Gets the SOL balance cache TTL in milliseconds.
+///
+/// # Returns
+/// The BALANCE_CACHE_TTL_MS value, or 2000 if unset or not a number
+pub fn get_balance_cache_ttl_ms() -> u64 {
+    env::var("BALANCE_CACHE_TTL_MS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(2000)
+}
+
+/// Gets the GlobalConfig/TokenRegistry cache TTL in seconds.
+///
+/// # Returns
+/// The CONFIG_CACHE_TTL_SECS value, or 300 if unset or not a number
+pub fn get_config_cache_ttl_secs() -> u64 {
+    env::var("CONFIG_CACHE_TTL_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(300)
+}
+
+/// Gets the sustained per-client request rate for `/api/*` endpoints.
+///
+/// # Returns
+/// The RATE_LIMIT_PER_SEC value, or 10 if unset or not a number (0 disables rate limiting)
+pub fn get_rate_limit_per_sec() -> f64 {
+    env::var("RATE_LIMIT_PER_SEC")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(10.0)
+}
+
+/// Gets the number of requests a client may burst above the sustained rate.
+///
+/// # Returns
+/// The RATE_LIMIT_BURST value, or 20 if unset or not a number
+pub fn get_rate_limit_burst() -> u32 {
+    env::var("RATE_LIMIT_BURST")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(20)
+}
+
+/// Gets whether the server runs behind a reverse proxy whose `X-Forwarded-For` entry
+/// identifies the client for rate limiting.
+///
+/// # Returns
+/// The RATE_LIMIT_TRUST_PROXY value, or false if unset or not a boolean
+pub fn get_rate_limit_trust_proxy() -> bool {
+    env::var("RATE_LIMIT_TRUST_PROXY")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(false)
+}
+
+/// Gets whether debug endpoints exposing service internals (`/api/debug/*`) are served.
+///
+/// # Returns
+/// The DEBUG_ENDPOINTS value, or false if unset or not a boolean
+pub fn get_debug_endpoints() -> bool {
+    env::var("DEBUG_ENDPOINTS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(false)
+}
+
+/// Gets the API tokens trusted services use to authenticate on the WebSocket.
+///
+/// # Returns
+/// The comma-separated WS_API_TOKENS entries, or none if unset (token auth disabled)
+pub fn get_ws_api_tokens() -> Vec<String> {
+    env::var("WS_API_TOKENS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Gets whether rooms without an allowlist can be watched without authenticating.
+///
+/// # Returns
+/// The WS_PUBLIC_ROOMS value, or true if unset or not a boolean
+pub fn get_ws_public_rooms() -> bool {
+    env::var("WS_PUBLIC_ROOMS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(true)
+}
+
 /// Validates all required environment variables are present.
 ///
 /// This function checks that all required configuration is available before
@@ -42,5 +304,26 @@ pub fn get_tgb_api_key() -> String {
 pub fn validate_env() {
     // Validate TGB API key exists
     let _ = get_tgb_api_key();
+
+    // Validate a custom TGB base URL is an absolute http(s) URL
+    let tgb_base_url = get_tgb_base_url();
+    match reqwest::Url::parse(&tgb_base_url) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {}
+        _ => panic!("TGB_BASE_URL is not a valid http(s) URL: {}", tgb_base_url),
+    }
+
+    // Validate every Solana RPC endpoint is an absolute http(s) URL
+    for rpc_url in get_solana_rpc_urls() {
+        match reqwest::Url::parse(&rpc_url) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {}
+            _ => panic!("Solana RPC URL is not a valid http(s) URL: {}", rpc_url),
+        }
+    }
+
+    // Validate program ID is a well-formed pubkey
+    let program_id = get_program_id();
+    program_id
+        .parse::<solana_pubkey::Pubkey>()
+        .unwrap_or_else(|_| panic!("FUNDRAISELY_PROGRAM_ID is not a valid pubkey: {}", program_id));
     // Add more validation as needed
 }
@@ -5,4 +5,12 @@
 
 pub mod environment;
 
-pub use environment::{load_env, get_tgb_api_key, validate_env};
+pub use environment::{
+    get_balance_cache_ttl_ms, get_config_cache_ttl_secs, get_debug_endpoints, get_program_id,
+    get_rate_limit_burst, get_rate_limit_per_sec, get_rate_limit_trust_proxy, get_room_cache_ttl_ms,
+    get_solana_rpc_max_retries, get_solana_rpc_timeout_secs, get_solana_rpc_url,
+    get_solana_rpc_urls, get_tgb_api_key, get_tgb_base_url, get_tgb_circuit_cooldown_secs,
+    get_tgb_circuit_failure_threshold, get_tgb_max_retries, get_tgb_search_cache_capacity,
+    get_tgb_search_cache_ttl_secs, get_tgb_timeout_secs, get_ws_api_tokens, get_ws_public_rooms,
+    load_env, validate_env,
+};
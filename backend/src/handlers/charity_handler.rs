@@ -5,54 +5,159 @@
 
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
     response::Json,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
-use crate::models::{Charity, DonationAddress};
+use crate::models::{ApiError, CharityPage, DonationAddress};
 use crate::services::TgbClient;
 
+/// Default number of charities returned when `limit` is not specified.
+const DEFAULT_CHARITY_LIMIT: usize = 20;
+
+/// Maximum number of charities returned in a single page.
+const MAX_CHARITY_LIMIT: usize = 100;
+
 /// Query parameters for charity search endpoint.
 #[derive(Deserialize)]
 pub struct SearchQuery {
     /// Search term to match against charity names
     pub q: String,
+    /// Page size (default 20, max 100)
+    pub limit: Option<usize>,
+    /// Number of matching charities to skip
+    pub offset: Option<usize>,
+}
+
+/// Maximum number of tokens in one multi-token address request.
+const MAX_ADDRESS_TOKENS: usize = 10;
+
+/// Query parameters for the multi-token address endpoint.
+#[derive(Deserialize)]
+pub struct AddressesQuery {
+    /// Comma-separated token symbols (e.g., "SOL,USDC")
+    pub tokens: String,
+}
+
+/// Donation addresses of one charity for several tokens.
+///
+/// # Fields
+/// * `charity_id` - The TGB charity ID
+/// * `addresses` - Address per token that was found
+/// * `errors` - Per-token failures, keyed by token
+#[derive(Serialize)]
+pub struct CharityAddressesResponse {
+    pub charity_id: String,
+    pub addresses: BTreeMap<String, DonationAddress>,
+    pub errors: BTreeMap<String, TokenAddressError>,
+}
+
+/// Why one token's address could not be fetched.
+///
+/// # Fields
+/// * `status` - Status the single-token address endpoint would have answered
+/// * `error` - Error message from the TGB client
+#[derive(Serialize)]
+pub struct TokenAddressError {
+    pub status: u16,
+    pub error: String,
 }
 
 /// Handles charity search requests.
 ///
 /// # Endpoint
-/// GET /api/charities?q=search_term
+/// GET /api/charities?q=search_term&limit=20&offset=0
 ///
 /// # Parameters
-/// * `query` - Search query parameters containing the search term
+/// * `query` - Search term and pagination (a `limit` above 100 is capped at 100)
 /// * `tgb_client` - Shared TGB API client instance
 ///
 /// # Returns
-/// * `200 OK` with JSON array of matching charities
+/// * `200 OK` with JSON `{charities, total, limit, offset}`, where `total` counts every
+///   match so the picker can page through them
+/// * `429 Too Many Requests` if TGB API is still rate limiting after retries
 /// * `502 Bad Gateway` if TGB API returns an error
-/// * `503 Service Unavailable` if TGB API is unreachable
+/// * `503 Service Unavailable` if TGB API is unreachable or its circuit breaker is open
+/// * `504 Gateway Timeout` if TGB API does not respond within the timeout
 ///
 /// # Example
 /// ```
-/// GET /api/charities?q=red%20cross
+/// GET /api/charities?q=red%20cross&limit=20&offset=20
 /// ```
 pub async fn search_charities(
     Query(query): Query<SearchQuery>,
     State(tgb_client): State<Arc<TgbClient>>,
-) -> Result<Json<Vec<Charity>>, StatusCode> {
-    match tgb_client.search_charities(&query.q).await {
-        Ok(charities) => Ok(Json(charities)),
-        Err(err) => {
-            if err.contains("connect") {
-                Err(StatusCode::SERVICE_UNAVAILABLE)
-            } else {
-                Err(StatusCode::BAD_GATEWAY)
+) -> Result<Json<CharityPage>, ApiError> {
+    let limit = query.limit.unwrap_or(DEFAULT_CHARITY_LIMIT).min(MAX_CHARITY_LIMIT);
+    let offset = query.offset.unwrap_or(0);
+
+    match tgb_client.search_charities(&query.q, limit, offset).await {
+        Ok(page) => Ok(Json(page)),
+        Err(err) => Err(ApiError::tgb(err)),
+    }
+}
+
+/// Handles donation address lookups for several tokens at once.
+///
+/// Room creation usually needs both SOL and USDC addresses; the lookups run
+/// concurrently and a failing token is reported in `errors` without failing the
+/// others.
+///
+/// # Endpoint
+/// GET /api/charities/:charity_id/addresses?tokens=SOL,USDC
+///
+/// # Parameters
+/// * `charity_id` - The TGB charity ID
+/// * `query` - Comma-separated token symbols (duplicates ignored, at most 10)
+/// * `tgb_client` - Shared TGB API client instance
+///
+/// # Returns
+/// * `200 OK` with JSON `{charity_id, addresses, errors}`, even if some tokens failed
+/// * `400 Bad Request` if no tokens or more than 10 tokens are given
+///
+/// # Example
+/// ```
+/// GET /api/charities/charity123/addresses?tokens=SOL,USDC
+/// ```
+pub async fn get_charity_addresses(
+    Path(charity_id): Path<String>,
+    Query(query): Query<AddressesQuery>,
+    State(tgb_client): State<Arc<TgbClient>>,
+) -> Result<Json<CharityAddressesResponse>, ApiError> {
+    let mut tokens: Vec<&str> = Vec::new();
+    for token in query.tokens.split(',').map(str::trim).filter(|token| !token.is_empty()) {
+        if !tokens.contains(&token) {
+            tokens.push(token);
+        }
+    }
+    if tokens.is_empty() || tokens.len() > MAX_ADDRESS_TOKENS {
+        return Err(ApiError::bad_request(format!(
+            "Between 1 and {} tokens must be given",
+            MAX_ADDRESS_TOKENS
+        )));
+    }
+
+    let mut addresses = BTreeMap::new();
+    let mut errors = BTreeMap::new();
+    for (token, result) in tgb_client.get_charity_addresses(&charity_id, &tokens).await {
+        match result {
+            Ok(address) => {
+                addresses.insert(token.to_string(), address);
+            }
+            Err(error) => {
+                let status = ApiError::tgb(error.clone()).status.as_u16();
+                errors.insert(token.to_string(), TokenAddressError { status, error });
             }
         }
     }
+
+    Ok(Json(CharityAddressesResponse {
+        charity_id,
+        addresses,
+        errors,
+    }))
 }
 
 /// Handles donation address lookup requests.
@@ -66,8 +171,10 @@ pub async fn search_charities(
 ///
 /// # Returns
 /// * `200 OK` with JSON donation address details
+/// * `429 Too Many Requests` if TGB API is still rate limiting after retries
 /// * `502 Bad Gateway` if TGB API returns an error
-/// * `503 Service Unavailable` if TGB API is unreachable
+/// * `503 Service Unavailable` if TGB API is unreachable or its circuit breaker is open
+/// * `504 Gateway Timeout` if TGB API does not respond within the timeout
 ///
 /// # Example
 /// ```
@@ -76,15 +183,9 @@ pub async fn search_charities(
 pub async fn get_charity_address(
     Path((charity_id, token)): Path<(String, String)>,
     State(tgb_client): State<Arc<TgbClient>>,
-) -> Result<Json<DonationAddress>, StatusCode> {
+) -> Result<Json<DonationAddress>, ApiError> {
     match tgb_client.get_charity_address(&charity_id, &token).await {
         Ok(address) => Ok(Json(address)),
-        Err(err) => {
-            if err.contains("connect") {
-                Err(StatusCode::SERVICE_UNAVAILABLE)
-            } else {
-                Err(StatusCode::BAD_GATEWAY)
-            }
-        }
+        Err(err) => Err(ApiError::tgb(err)),
     }
 }
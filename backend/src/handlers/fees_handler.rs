@@ -0,0 +1,121 @@
+//! Handler for the fee preview endpoint.
+//!
+//! Lets the frontend show exactly how a room's funds will be split before
+//! the room is created or ended, using the same math as the on-chain program.
+
+use axum::response::Json;
+use serde::{Deserialize, Serialize};
+
+use crate::models::program_event::MAX_WINNERS;
+use crate::models::ApiError;
+use crate::utils::fees::{preview_fee_distribution, FeeBreakdown, PLATFORM_FEE_BPS};
+use crate::utils::{to_ui_amount, SOL_DECIMALS};
+
+/// Request body for the fee preview endpoint.
+#[derive(Deserialize)]
+pub struct CalculateFeesRequest {
+    /// Total entry fees collected, in token base units
+    pub total_entry_fees: u64,
+    /// Total extras collected, in token base units (100% to charity)
+    #[serde(default)]
+    pub total_extras_fees: u64,
+    /// Host fee in basis points
+    pub host_fee_bps: u16,
+    /// Prize pool in basis points
+    pub prize_pool_bps: u16,
+    /// Percentage of the prize pool per place (e.g., [60, 30, 10]), at most `MAX_WINNERS` places
+    #[serde(default)]
+    pub prize_distribution: Vec<u16>,
+    /// Host-escrowed minimum prize, in token base units (0 when the room has none)
+    #[serde(default)]
+    pub guaranteed_min_prize: u64,
+    /// Platform fee override (defaults to the program's 20%)
+    pub platform_fee_bps: Option<u16>,
+    /// Decimals of the room's fee token, used for display amounts (defaults to 9, as SOL)
+    pub decimals: Option<u8>,
+}
+
+/// Fee breakdown converted to display units of the fee token.
+#[derive(Serialize)]
+pub struct FeeBreakdownDisplay {
+    pub platform_fee: f64,
+    pub host_fee: f64,
+    pub prize_pool: f64,
+    pub charity: f64,
+    pub winner_amounts: Vec<f64>,
+}
+
+/// Fee preview with raw base-unit amounts and their display equivalents.
+///
+/// # Fields
+/// * `raw` - Amounts in token base units, exactly as `end_room` would transfer them
+/// * `display` - The same amounts divided by `10^decimals`
+/// * `decimals` - Decimals used for `display`
+#[derive(Serialize)]
+pub struct CalculateFeesResponse {
+    pub raw: FeeBreakdown,
+    pub display: FeeBreakdownDisplay,
+    pub decimals: u8,
+}
+
+/// Handles fee preview requests.
+///
+/// # Endpoint
+/// POST /api/calculate-fees
+///
+/// # Parameters
+/// * `request` - JSON body with entry fees, extras, fee percentages and prize distribution
+///
+/// # Returns
+/// * `200 OK` with platform/host/prize/charity amounts and per-place winner amounts,
+///   both raw and in display units
+/// * `400 Bad Request` if the allocation exceeds 100%, the amounts overflow or there are
+///   more than `MAX_WINNERS` places, i.e. wherever the program itself would fail
+///
+/// # Example
+/// ```
+/// POST /api/calculate-fees
+/// {"total_entry_fees": 1000000, "total_extras_fees": 250000, "host_fee_bps": 500, "prize_pool_bps": 3500, "prize_distribution": [60, 30, 10], "decimals": 6}
+/// ```
+pub async fn calculate_fees(
+    Json(request): Json<CalculateFeesRequest>,
+) -> Result<Json<CalculateFeesResponse>, ApiError> {
+    if request.prize_distribution.len() > MAX_WINNERS {
+        return Err(ApiError::bad_request(format!(
+            "At most {} prize places are supported",
+            MAX_WINNERS
+        )));
+    }
+
+    let raw = preview_fee_distribution(
+        request.total_entry_fees,
+        request.total_extras_fees,
+        request.platform_fee_bps.unwrap_or(PLATFORM_FEE_BPS),
+        request.host_fee_bps,
+        request.prize_pool_bps,
+        &request.prize_distribution,
+        request.guaranteed_min_prize,
+    )
+    .ok_or_else(|| {
+        ApiError::bad_request("Fee allocation exceeds 100% or the amounts overflow")
+    })?;
+
+    let decimals = request.decimals.unwrap_or(SOL_DECIMALS);
+    let display = FeeBreakdownDisplay {
+        platform_fee: to_ui_amount(raw.platform_fee, decimals),
+        host_fee: to_ui_amount(raw.host_fee, decimals),
+        prize_pool: to_ui_amount(raw.prize_pool, decimals),
+        charity: to_ui_amount(raw.charity, decimals),
+        winner_amounts: raw
+            .winner_amounts
+            .iter()
+            .map(|&amount| to_ui_amount(amount, decimals))
+            .collect(),
+    };
+
+    Ok(Json(CalculateFeesResponse {
+        raw,
+        display,
+        decimals,
+    }))
+}
@@ -1,6 +1,41 @@
 //! Handler for health check endpoint.
 //!
-//! Provides a simple health check endpoint to verify the server is running.
+//! Reports whether the server can reach its Solana RPC node, along with account
+//! cache statistics. Load balancers get a 503 while the node is unreachable.
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::Serialize;
+use std::sync::Arc;
+use tracing::warn;
+
+use crate::services::cache_service::CacheStats;
+use crate::services::solana_service::RpcEndpointStats;
+use crate::services::tgb_cache::SearchCacheStats;
+use crate::services::tgb_client::TgbReadiness;
+use crate::services::{CacheService, SolanaService, TgbClient};
+
+/// Health check response.
+///
+/// # Fields
+/// * `status` - "OK" when the RPC node is reachable, "degraded" otherwise
+/// * `rpc` - "ok" or "degraded" (unreachable, erroring or slower than the health check timeout)
+/// * `slot` - Current slot reported by the RPC node (omitted when degraded)
+/// * `rpc_latency_ms` - How long the RPC probe took (omitted when degraded)
+/// * `rpc_endpoints` - Failure count and availability of each configured RPC endpoint
+/// * `cache` - Account cache hit/miss counts since startup
+/// * `charity_search_cache` - TGB search cache hit/miss counts since startup
+#[derive(Serialize)]
+pub struct HealthResponse {
+    pub status: &'static str,
+    pub rpc: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slot: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rpc_latency_ms: Option<u64>,
+    pub rpc_endpoints: Vec<RpcEndpointStats>,
+    pub cache: CacheStats,
+    pub charity_search_cache: SearchCacheStats,
+}
 
 /// Health check handler.
 ///
@@ -8,17 +43,128 @@
 /// GET /health
 ///
 /// # Returns
-/// Always returns "OK" with HTTP 200 status.
+/// * `200 OK` with `status: "OK"`, the current slot, the RPC latency and account/charity
+///   cache statistics
+/// * `503 Service Unavailable` with `status: "degraded"` and `rpc: "degraded"` if the
+///   RPC node doesn't answer `getSlot` within the health check timeout
+///
+/// # RPC Cost
+/// At most one `getSlot` per second; probes in between reuse the last result.
 ///
 /// # Usage
 /// This endpoint is used by monitoring tools, load balancers, and deployment
-/// systems to verify the service is healthy and responsive.
+/// systems to verify the service is healthy and responsive. The cache counters
+/// show how much RPC traffic the account cache is absorbing.
 ///
 /// # Example
 /// ```
 /// GET /health
-/// Response: "OK"
+/// Response: {"status": "OK", "rpc": "ok", "slot": 312345678, "rpc_latency_ms": 84,
+///            "rpc_endpoints": [{"endpoint": "https://api.devnet.solana.com", "failures": 0, "available": true}],
+///            "cache": {"hits": 120, "misses": 14},
+///            "charity_search_cache": {"hits": 52, "misses": 9}}
+/// ```
+pub async fn health_check(
+    State(solana_service): State<Arc<SolanaService>>,
+    State(cache_service): State<Arc<CacheService>>,
+    State(tgb_client): State<Arc<TgbClient>>,
+) -> (StatusCode, Json<HealthResponse>) {
+    let (status_code, status, rpc, health) = match solana_service.check_health().await {
+        Ok(health) => (StatusCode::OK, "OK", "ok", Some(health)),
+        Err(err) => {
+            warn!("Health check: Solana RPC unreachable: {}", err);
+            (StatusCode::SERVICE_UNAVAILABLE, "degraded", "degraded", None)
+        }
+    };
+
+    (
+        status_code,
+        Json(HealthResponse {
+            status,
+            rpc,
+            slot: health.map(|health| health.slot),
+            rpc_latency_ms: health.map(|health| health.latency_ms),
+            rpc_endpoints: solana_service.rpc_endpoint_stats(),
+            cache: cache_service.stats(),
+            charity_search_cache: tgb_client.search_cache_stats(),
+        }),
+    )
+}
+
+/// Readiness check response.
+///
+/// # Fields
+/// * `status` - "ready" when TGB answers an authenticated request, "not_ready" otherwise
+/// * `tgb` - Result of the TGB probe, including the upstream HTTP status
+#[derive(Serialize)]
+pub struct ReadinessResponse {
+    pub status: &'static str,
+    pub tgb: TgbReadiness,
+}
+
+/// Readiness check handler.
+///
+/// Unlike `/health`, this confirms the upstream TGB API is reachable and still
+/// accepts the configured API key, so monitoring catches TGB outages and expired keys.
+///
+/// # Endpoint
+/// GET /health/ready
+///
+/// # Returns
+/// * `200 OK` - TGB answered an authenticated charity search
+/// * `503 Service Unavailable` - TGB is down, timed out (2s) or rejected the API key
+///
+/// # Example
+/// ```
+/// GET /health/ready
+/// Response: {"status": "not_ready",
+///            "tgb": {"reachable": false, "upstream_status": 401,
+///                    "error": "TGB API rejected the API key: 401 Unauthorized"}}
 /// ```
-pub async fn health_check() -> &'static str {
-    "OK"
+pub async fn readiness_check(
+    State(tgb_client): State<Arc<TgbClient>>,
+) -> (StatusCode, Json<ReadinessResponse>) {
+    let tgb = tgb_client.check_ready().await;
+    let (status_code, status) = if tgb.reachable {
+        (StatusCode::OK, "ready")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "not_ready")
+    };
+
+    (status_code, Json(ReadinessResponse { status, tgb }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_pubkey::Pubkey;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn reports_503_while_the_rpc_fails() {
+        // An RPC node that accepts connections but never answers
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let rpc_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 4096];
+                    while socket.read(&mut buf).await.is_ok_and(|n| n > 0) {}
+                });
+            }
+        });
+
+        let cache_service = Arc::new(CacheService::default());
+        let solana_service = Arc::new(SolanaService::new(rpc_url, Pubkey::new_unique(), cache_service.clone()));
+        let tgb_client = Arc::new(TgbClient::new("test-key".to_string()));
+
+        let (status_code, Json(health)) =
+            health_check(State(solana_service), State(cache_service), State(tgb_client)).await;
+
+        assert_eq!(status_code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!((health.status, health.rpc), ("degraded", "degraded"));
+        assert_eq!((health.slot, health.rpc_latency_ms), (None, None));
+        assert_eq!(health.rpc_endpoints.len(), 1);
+    }
 }
@@ -0,0 +1,83 @@
+//! Handlers for operational metrics endpoints.
+//!
+//! Exposes counters collected by the backend so operators can see how users
+//! interact with the program (e.g., which errors they hit most).
+
+use axum::{
+    extract::State,
+    http::header,
+    response::{IntoResponse, Json},
+};
+use std::sync::Arc;
+
+use crate::services::cache_service::CacheDebugStats;
+use crate::services::error_metrics::ErrorCount;
+use crate::services::{CacheService, ErrorMetrics, MetricsService};
+
+/// Handles program error frequency requests.
+///
+/// # Endpoint
+/// GET /api/metrics/errors
+///
+/// # Parameters
+/// * `error_metrics` - Shared per-error-code counters
+///
+/// # Returns
+/// * `200 OK` with JSON array of `{code, name, count}` ordered by code
+///
+/// # Example
+/// ```
+/// GET /api/metrics/errors
+/// Response: [{"code": 6025, "name": "MaxPlayersReached", "count": 14}]
+/// ```
+pub async fn get_error_metrics(
+    State(error_metrics): State<Arc<ErrorMetrics>>,
+) -> Json<Vec<ErrorCount>> {
+    Json(error_metrics.snapshot())
+}
+
+/// Handles Prometheus scrapes.
+///
+/// # Endpoint
+/// GET /metrics
+///
+/// # Parameters
+/// * `metrics` - Shared request and RPC metrics
+///
+/// # Returns
+/// * `200 OK` with `http_requests_total`, `http_request_duration_seconds`,
+///   `solana_rpc_duration_seconds` and `solana_rpc_errors_total` in Prometheus text format
+///
+/// # Example
+/// ```
+/// GET /metrics
+/// Response: http_requests_total{method="GET",route="/api/rooms",status="200"} 42
+/// ```
+pub async fn get_prometheus_metrics(State(metrics): State<Arc<MetricsService>>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        metrics.render(),
+    )
+}
+
+/// Handles cache debug requests.
+///
+/// # Endpoint
+/// GET /api/debug/cache
+///
+/// # Parameters
+/// * `cache_service` - Shared account cache
+///
+/// # Returns
+/// * `200 OK` with hit/miss/invalidation counts, current entry counts and TTLs
+///
+/// # Example
+/// ```
+/// GET /api/debug/cache
+/// Response: {"hits": 120, "misses": 14, "invalidations": 3, "room_entries": 6, ...}
+/// ```
+pub async fn get_cache_stats(
+    State(cache_service): State<Arc<CacheService>>,
+) -> Json<CacheDebugStats> {
+    Json(cache_service.debug_stats())
+}
@@ -4,7 +4,21 @@
 //! interact with services, and return appropriate responses.
 
 pub mod charity_handler;
+pub mod fees_handler;
 pub mod health_handler;
+pub mod metrics_handler;
+pub mod query_handler;
+pub mod transaction_handler;
+pub mod ws_handler;
 
-pub use charity_handler::{get_charity_address, search_charities};
-pub use health_handler::health_check;
+pub use charity_handler::{get_charity_address, get_charity_addresses, search_charities};
+pub use fees_handler::calculate_fees;
+pub use health_handler::{health_check, readiness_check};
+pub use metrics_handler::{get_cache_stats, get_error_metrics, get_prometheus_metrics};
+pub use query_handler::{
+    get_approved_tokens, get_balance, get_charity_preview, get_charity_proof, get_player_entries,
+    get_room, get_room_financials, get_room_full, get_room_outcome, get_room_timeline, get_token_balance,
+    get_vault_balance, list_rooms,
+};
+pub use transaction_handler::{build_transaction, simulate_transaction};
+pub use ws_handler::ws_handler;
@@ -0,0 +1,697 @@
+//! Handlers for on-chain query endpoints.
+//!
+//! This module contains HTTP handlers that read Fundraisely program state from
+//! Solana (rooms, player entries) and return it as JSON for the frontend.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+use std::sync::Arc;
+
+use crate::models::{
+    ApiError, ApprovedToken, CharityPreview, CharityProof, PlayerEntryAccount, RoomAccount,
+    RoomFinancials, RoomFull, RoomOutcome, RoomStatus, TimelineEntry,
+};
+use crate::services::{OutcomeIndex, RoomFilter, SolanaService};
+use crate::utils::{format_lamports, format_token_amount};
+
+/// Default number of rooms returned when `limit` is not specified.
+const DEFAULT_ROOM_LIMIT: usize = 50;
+
+/// Maximum number of rooms returned in a single page.
+const MAX_ROOM_LIMIT: usize = 100;
+
+/// Default number of player entries returned when `limit` is not specified.
+const DEFAULT_ENTRY_LIMIT: usize = 50;
+
+/// Maximum number of player entries returned in a single page.
+const MAX_ENTRY_LIMIT: usize = 100;
+
+/// Query parameters for the room listing endpoint.
+#[derive(Deserialize)]
+pub struct ListRoomsQuery {
+    /// Only return rooms in this state (e.g., "Ready", "Active", "Ended")
+    pub status: Option<RoomStatus>,
+    /// Only return rooms created by this host (base58 pubkey)
+    pub host: Option<String>,
+    /// Page size (default 50, max 100)
+    pub limit: Option<usize>,
+    /// Number of rooms to skip
+    pub offset: Option<usize>,
+}
+
+/// Pagination parameters for list endpoints.
+#[derive(Deserialize)]
+pub struct PaginationQuery {
+    /// Page size
+    pub limit: Option<usize>,
+    /// Number of items to skip
+    pub offset: Option<usize>,
+}
+
+/// Query parameters for cached single-account reads.
+#[derive(Deserialize)]
+pub struct FreshQuery {
+    /// Bypass the cache and read straight from RPC (use after sending a transaction)
+    #[serde(default)]
+    pub fresh: bool,
+}
+
+/// SOL balance of an account.
+///
+/// # Fields
+/// * `pubkey` - Account address (base58)
+/// * `lamports` - Balance in lamports
+/// * `sol` - Balance in SOL, formatted exactly (e.g., "1.5")
+#[derive(Serialize)]
+pub struct BalanceResponse {
+    pub pubkey: String,
+    pub lamports: u64,
+    pub sol: String,
+}
+
+/// Real on-chain balance of a room's token vault.
+///
+/// # Fields
+/// * `room` - Room PDA address (base58)
+/// * `vault` - Vault PDA address (base58)
+/// * `exists` - Whether the vault account exists (false before creation or after `cancel_room`)
+/// * `mint` - Mint the vault holds (the room's fee token mint when the vault doesn't exist)
+/// * `amount` - Balance in the mint's base units (0 when the vault doesn't exist)
+/// * `total_collected` - The room's accounting figure, for reconciliation against `amount`
+#[derive(Serialize)]
+pub struct VaultBalanceResponse {
+    pub room: String,
+    pub vault: String,
+    pub exists: bool,
+    pub mint: String,
+    pub amount: u64,
+    pub total_collected: u64,
+}
+
+/// SPL token balance of an owner's associated token account.
+#[derive(Serialize)]
+pub struct TokenBalanceResponse {
+    pub owner: String,
+    pub mint: String,
+    pub token_account: String,
+    /// Balance in the mint's base units
+    pub amount: u64,
+    pub decimals: u8,
+    /// Balance in display units (e.g., "1.5")
+    pub formatted: String,
+}
+
+/// Handles room listing requests.
+///
+/// # Endpoint
+/// GET /api/rooms?status=Active&host=<pubkey>&limit=50&offset=0
+///
+/// # Parameters
+/// * `query` - Optional status/host filters and pagination
+/// * `solana_service` - Shared Solana RPC service instance
+///
+/// # Returns
+/// * `200 OK` with JSON array of rooms
+/// * `400 Bad Request` if `host` is not a valid pubkey or `status` is unknown
+/// * `502 Bad Gateway` if the RPC node returns an error
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # RPC Cost
+/// Every call issues one `getProgramAccounts` request, which is among the most
+/// expensive RPC methods: the node scans every account owned by the program and
+/// returns all matching Room accounts in full. Only the discriminator can be
+/// filtered server-side; `status`, `host` and pagination are applied here after
+/// the whole set has been downloaded, so `limit`/`offset` reduce the response size
+/// but not the RPC cost. Many public RPC providers rate-limit or disable this method,
+/// so production deployments should use a dedicated RPC endpoint.
+///
+/// # Example
+/// ```
+/// GET /api/rooms?status=Active&limit=20
+/// ```
+pub async fn list_rooms(
+    Query(query): Query<ListRoomsQuery>,
+    State(solana_service): State<Arc<SolanaService>>,
+) -> Result<Json<Vec<RoomAccount>>, ApiError> {
+    let host = match query.host {
+        Some(host) => Some(host.parse::<Pubkey>().map_err(|_| ApiError::invalid_pubkey("host"))?),
+        None => None,
+    };
+
+    let filter = RoomFilter {
+        status: query.status,
+        host,
+    };
+
+    let limit = query.limit.unwrap_or(DEFAULT_ROOM_LIMIT).min(MAX_ROOM_LIMIT);
+    let offset = query.offset.unwrap_or(0);
+
+    match solana_service.get_all_rooms(&filter).await {
+        Ok(rooms) => Ok(Json(rooms.into_iter().skip(offset).take(limit).collect())),
+        Err(err) => Err(ApiError::rpc(err)),
+    }
+}
+
+/// Handles player participation history requests.
+///
+/// # Endpoint
+/// GET /api/player/:pubkey/entries?limit=50&offset=0
+///
+/// # Parameters
+/// * `pubkey` - Player wallet address (base58)
+/// * `query` - Pagination (default 50, max 100)
+/// * `solana_service` - Shared Solana RPC service instance
+///
+/// # Returns
+/// * `200 OK` with JSON array of entries, most recent first (empty if the player never joined)
+/// * `400 Bad Request` if `pubkey` is not a valid pubkey
+/// * `502 Bad Gateway` if the RPC node returns an error
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # Example
+/// ```
+/// GET /api/player/7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU/entries?limit=10
+/// ```
+pub async fn get_player_entries(
+    Path(pubkey): Path<String>,
+    Query(query): Query<PaginationQuery>,
+    State(solana_service): State<Arc<SolanaService>>,
+) -> Result<Json<Vec<PlayerEntryAccount>>, ApiError> {
+    let player = pubkey.parse::<Pubkey>().map_err(|_| ApiError::invalid_pubkey("pubkey"))?;
+
+    let limit = query.limit.unwrap_or(DEFAULT_ENTRY_LIMIT).min(MAX_ENTRY_LIMIT);
+    let offset = query.offset.unwrap_or(0);
+
+    match solana_service.get_player_entries(&player).await {
+        Ok(entries) => Ok(Json(entries.into_iter().skip(offset).take(limit).collect())),
+        Err(err) => Err(ApiError::rpc(err)),
+    }
+}
+
+/// Handles single room lookups.
+///
+/// # Endpoint
+/// GET /api/room/:pubkey?fresh=false
+///
+/// # Parameters
+/// * `pubkey` - Room PDA address (base58)
+/// * `query` - `fresh=true` bypasses the cache
+/// * `solana_service` - Shared Solana RPC service instance
+///
+/// # Returns
+/// * `200 OK` with the JSON room
+/// * `400 Bad Request` if `pubkey` is not a valid pubkey
+/// * `404 Not Found` if no account exists at the address
+/// * `502 Bad Gateway` if the RPC node returns an error or the account is not a Room
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # Example
+/// ```
+/// GET /api/room/9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin?fresh=true
+/// ```
+pub async fn get_room(
+    Path(pubkey): Path<String>,
+    Query(query): Query<FreshQuery>,
+    State(solana_service): State<Arc<SolanaService>>,
+) -> Result<Json<RoomAccount>, ApiError> {
+    let room = pubkey.parse::<Pubkey>().map_err(|_| ApiError::invalid_pubkey("pubkey"))?;
+
+    match solana_service.get_room_account(&room, query.fresh).await {
+        Ok(Some(room)) => Ok(Json(room)),
+        Ok(None) => Err(ApiError::not_found(format!("Room not found: {}", pubkey))),
+        Err(err) => Err(ApiError::rpc(err)),
+    }
+}
+
+/// Handles full room lookups for room detail pages.
+///
+/// # Endpoint
+/// GET /api/room/:pubkey/full
+///
+/// # Parameters
+/// * `pubkey` - Room PDA address (base58)
+/// * `solana_service` - Shared Solana RPC service instance
+///
+/// # Returns
+/// * `200 OK` with every field of the room, pubkeys base58-encoded
+/// * `400 Bad Request` if `pubkey` is not a valid pubkey
+/// * `404 Not Found` if no account exists at the address
+/// * `502 Bad Gateway` if the RPC node returns an error or the account is not a Room
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # RPC Cost
+/// One `getAccountInfo` call (never cached; use `GET /api/room/:pubkey` for lists).
+///
+/// # Example
+/// ```
+/// GET /api/room/9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin/full
+/// Response: {"room_id": "quiz-night", "charity_bps": 4000, "winners": [...],
+///            "charity_wallet": "...", "prize_assets": [null, null, null], ...}
+/// ```
+pub async fn get_room_full(
+    Path(pubkey): Path<String>,
+    State(solana_service): State<Arc<SolanaService>>,
+) -> Result<Json<RoomFull>, ApiError> {
+    let room = pubkey.parse::<Pubkey>().map_err(|_| ApiError::invalid_pubkey("pubkey"))?;
+
+    match solana_service.get_room_full(&room).await {
+        Ok(Some(room)) => Ok(Json(room)),
+        Ok(None) => Err(ApiError::not_found(format!("Room not found: {}", pubkey))),
+        Err(err) => Err(ApiError::rpc(err)),
+    }
+}
+
+/// Handles SOL balance lookups.
+///
+/// # Endpoint
+/// GET /api/balance/:pubkey?fresh=false
+///
+/// # Parameters
+/// * `pubkey` - Account address (base58)
+/// * `query` - `fresh=true` bypasses the cache
+/// * `solana_service` - Shared Solana RPC service instance
+///
+/// # Returns
+/// * `200 OK` with JSON `{pubkey, lamports, sol}`
+/// * `400 Bad Request` if `pubkey` is not a valid pubkey
+/// * `502 Bad Gateway` if the RPC node returns an error
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # Example
+/// ```
+/// GET /api/balance/7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU
+/// ```
+pub async fn get_balance(
+    Path(pubkey): Path<String>,
+    Query(query): Query<FreshQuery>,
+    State(solana_service): State<Arc<SolanaService>>,
+) -> Result<Json<BalanceResponse>, ApiError> {
+    let account = pubkey.parse::<Pubkey>().map_err(|_| ApiError::invalid_pubkey("pubkey"))?;
+
+    match solana_service.get_balance(&account, query.fresh).await {
+        Ok(lamports) => Ok(Json(BalanceResponse {
+            pubkey,
+            lamports,
+            sol: format_lamports(lamports),
+        })),
+        Err(err) => Err(ApiError::rpc(err)),
+    }
+}
+
+/// Handles SPL token balance lookups.
+///
+/// Lets the frontend check a player can afford a room's entry fee before building
+/// the join transaction. A wallet without an associated token account for the mint
+/// has a balance of 0.
+///
+/// # Endpoint
+/// GET /api/token-balance/:owner/:mint
+///
+/// # Parameters
+/// * `owner` - Wallet address (base58)
+/// * `mint` - SPL token mint (base58)
+/// * `solana_service` - Shared Solana RPC service instance
+///
+/// # Returns
+/// * `200 OK` with JSON `{owner, mint, token_account, amount, decimals, formatted}`
+/// * `400 Bad Request` if `owner` or `mint` is not a valid pubkey
+/// * `404 Not Found` if the mint does not exist
+/// * `502 Bad Gateway` if the RPC node returns an error or `mint` is not a mint
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # RPC Cost
+/// One `getTokenAccountBalance` call, plus one `getAccountInfo` for the mint when
+/// the associated token account does not exist.
+///
+/// # Example
+/// ```
+/// GET /api/token-balance/7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU/EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v
+/// Response: {"amount": 1500000, "decimals": 6, "formatted": "1.5", ...}
+/// ```
+pub async fn get_token_balance(
+    Path((owner, mint)): Path<(String, String)>,
+    State(solana_service): State<Arc<SolanaService>>,
+) -> Result<Json<TokenBalanceResponse>, ApiError> {
+    let owner_key = owner.parse::<Pubkey>().map_err(|_| ApiError::invalid_pubkey("owner"))?;
+    let mint_key = mint.parse::<Pubkey>().map_err(|_| ApiError::invalid_pubkey("mint"))?;
+
+    match solana_service.get_token_balance(&owner_key, &mint_key).await {
+        Ok(Some(balance)) => Ok(Json(TokenBalanceResponse {
+            owner,
+            mint,
+            token_account: balance.token_account.to_string(),
+            amount: balance.amount,
+            decimals: balance.decimals,
+            formatted: format_token_amount(balance.amount, balance.decimals),
+        })),
+        Ok(None) => Err(ApiError::not_found(format!("Mint not found: {}", mint))),
+        Err(err) => Err(ApiError::rpc(err)),
+    }
+}
+
+/// Handles room vault balance requests.
+///
+/// A trust-but-verify tool for auditors: `total_collected` is what the program has
+/// accounted for, `amount` is what the vault actually holds. Both are read fresh.
+///
+/// # Endpoint
+/// GET /api/room/:pubkey/vault
+///
+/// # Parameters
+/// * `pubkey` - Room PDA address (base58)
+/// * `solana_service` - Shared Solana RPC service instance
+///
+/// # Returns
+/// * `200 OK` with the vault address, mint and balance (`exists: false` and a zero
+///   balance if the vault hasn't been created or was closed)
+/// * `400 Bad Request` if `pubkey` is not a valid pubkey
+/// * `404 Not Found` if no account exists at the address
+/// * `502 Bad Gateway` if the RPC node returns an error, the account is not a Room, or
+///   the vault is not a token account
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # RPC Cost
+/// Two `getAccountInfo` calls: the room and its vault.
+///
+/// # Example
+/// ```
+/// GET /api/room/9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin/vault
+/// Response: {"vault": "...", "exists": true, "mint": "...", "amount": 30000000,
+///            "total_collected": 30000000, ...}
+/// ```
+pub async fn get_vault_balance(
+    Path(pubkey): Path<String>,
+    State(solana_service): State<Arc<SolanaService>>,
+) -> Result<Json<VaultBalanceResponse>, ApiError> {
+    let room_key = pubkey.parse::<Pubkey>().map_err(|_| ApiError::invalid_pubkey("pubkey"))?;
+
+    let room = solana_service
+        .get_room_account(&room_key, true)
+        .await
+        .map_err(ApiError::rpc)?
+        .ok_or_else(|| ApiError::not_found(format!("Room not found: {}", pubkey)))?;
+    let balance = solana_service.get_vault_balance(&room_key).await.map_err(ApiError::rpc)?;
+
+    Ok(Json(VaultBalanceResponse {
+        room: pubkey,
+        vault: solana_service.derive_room_vault_pda(&room_key).to_string(),
+        exists: balance.is_some(),
+        mint: balance.map_or(room.fee_token_mint, |balance| balance.mint.to_string()),
+        amount: balance.map_or(0, |balance| balance.amount),
+        total_collected: room.total_collected,
+    }))
+}
+
+/// Handles approved token lookups.
+///
+/// Lists the mints rooms may charge entry fees in, with the symbol and decimals the
+/// admin registered for each, so the frontend doesn't hardcode token metadata.
+///
+/// # Endpoint
+/// GET /api/approved-tokens?fresh=false
+///
+/// # Parameters
+/// * `query` - `fresh=true` bypasses the cache
+/// * `solana_service` - Shared Solana RPC service instance
+///
+/// # Returns
+/// * `200 OK` with JSON array of `{mint, symbol, decimals}`
+/// * `404 Not Found` if the token registry has not been initialized
+/// * `502 Bad Gateway` if the RPC node returns an error or the account is not a TokenRegistry
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # Example
+/// ```
+/// GET /api/approved-tokens
+/// ```
+pub async fn get_approved_tokens(
+    Query(query): Query<FreshQuery>,
+    State(solana_service): State<Arc<SolanaService>>,
+) -> Result<Json<Vec<ApprovedToken>>, ApiError> {
+    match solana_service.get_token_registry(query.fresh).await {
+        Ok(Some(registry)) => Ok(Json(registry.approved_tokens)),
+        Ok(None) => Err(ApiError::not_found("Token registry is not initialized")),
+        Err(err) => Err(ApiError::rpc(err)),
+    }
+}
+
+/// Handles room timeline requests.
+///
+/// # Endpoint
+/// GET /api/room/:pubkey/timeline
+///
+/// # Parameters
+/// * `pubkey` - Room PDA address (base58)
+/// * `solana_service` - Shared Solana RPC service instance
+///
+/// # Returns
+/// * `200 OK` with JSON array of `{slot, signature, block_time, event}`, oldest first
+/// * `400 Bad Request` if `pubkey` is not a valid pubkey
+/// * `502 Bad Gateway` if the RPC node returns an error
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # RPC Cost
+/// One `getSignaturesForAddress` call per 1000 transactions plus one `getTransaction`
+/// call per transaction that touched the room.
+///
+/// # Example
+/// ```
+/// GET /api/room/9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin/timeline
+/// Response: [{"slot": 100, "signature": "...", "block_time": 1700000000,
+///             "event": {"type": "RoomCreated", "room_id": "quiz-1", ...}}, ...]
+/// ```
+pub async fn get_room_timeline(
+    Path(pubkey): Path<String>,
+    State(solana_service): State<Arc<SolanaService>>,
+) -> Result<Json<Vec<TimelineEntry>>, ApiError> {
+    let room = pubkey.parse::<Pubkey>().map_err(|_| ApiError::invalid_pubkey("pubkey"))?;
+
+    match solana_service.get_room_timeline(&room).await {
+        Ok(timeline) => Ok(Json(timeline)),
+        Err(err) => Err(ApiError::rpc(err)),
+    }
+}
+
+/// Handles charity donation proof requests.
+///
+/// # Endpoint
+/// GET /api/room/:pubkey/charity-proof
+///
+/// # Parameters
+/// * `pubkey` - Room PDA address (base58)
+/// * `solana_service` - Shared Solana RPC service instance
+///
+/// # Returns
+/// * `200 OK` with JSON `{signature, charity_amount, transferred_amount, verified, ...}`
+/// * `400 Bad Request` if `pubkey` is not a valid pubkey
+/// * `404 Not Found` if the room does not exist or has no `end_room` transaction
+///   (e.g., it was recovered instead of ended)
+/// * `409 Conflict` if the room has not ended yet
+/// * `502 Bad Gateway` if the RPC node returns an error
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # RPC Cost
+/// One `getAccountInfo` (cached) plus `getSignaturesForAddress` and one `getTransaction`
+/// per transaction walked back from the newest, which is normally just the end transaction.
+///
+/// # Example
+/// ```
+/// GET /api/room/9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin/charity-proof
+/// Response: {"signature": "...", "charity_amount": 5700000, "transferred_amount": 5700000,
+///            "charity_token_account": "...", "verified": true, ...}
+/// ```
+pub async fn get_charity_proof(
+    Path(pubkey): Path<String>,
+    State(solana_service): State<Arc<SolanaService>>,
+) -> Result<Json<CharityProof>, ApiError> {
+    let room = pubkey.parse::<Pubkey>().map_err(|_| ApiError::invalid_pubkey("pubkey"))?;
+
+    let result = match solana_service.get_room_account(&room, false).await {
+        Ok(Some(room)) if !room.ended => {
+            return Err(ApiError::conflict(format!("Room has not ended: {}", pubkey)))
+        }
+        Ok(Some(room)) => solana_service.get_charity_proof(&room).await,
+        Ok(None) => return Err(ApiError::not_found(format!("Room not found: {}", pubkey))),
+        Err(err) => Err(err),
+    };
+
+    match result {
+        Ok(Some(proof)) => Ok(Json(proof)),
+        Ok(None) => Err(ApiError::not_found(format!(
+            "No end_room transaction found for room: {}",
+            pubkey
+        ))),
+        Err(err) => Err(ApiError::rpc(err)),
+    }
+}
+
+/// Handles ended-room outcome requests.
+///
+/// Outcomes are read from the index the event listener fills from `RoomEnded`
+/// events. A room missing from the index (e.g., it ended before the backend
+/// started) is resolved once by scanning its transactions, then indexed.
+///
+/// # Endpoint
+/// GET /api/room/:pubkey/outcome
+///
+/// # Parameters
+/// * `pubkey` - Room PDA address (base58)
+/// * `solana_service` - Shared Solana RPC service instance
+/// * `outcome_index` - Shared outcome index
+///
+/// # Returns
+/// * `200 OK` with JSON `{room, winners, platform_amount, host_amount, charity_amount,
+///   prize_amount, prize_distribution, total_players, expired_finalization, host_fee_waived,
+///   end_signature, slot, ended_at}`
+/// * `400 Bad Request` if `pubkey` is not a valid pubkey
+/// * `404 Not Found` if the room does not exist or no `RoomEnded` event was found
+/// * `409 Conflict` if the room has not ended yet
+/// * `502 Bad Gateway` if the RPC node returns an error
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # RPC Cost
+/// None for indexed rooms. Otherwise one `getAccountInfo` call (cached) plus
+/// `getSignaturesForAddress` and one `getTransaction` per signature until the end
+/// transaction is found.
+///
+/// # Example
+/// ```
+/// GET /api/room/9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin/outcome
+/// Response: {"charity_amount": 4000000, "end_signature": "5Kd3...", "winners": ["7xKX..."], ...}
+/// ```
+pub async fn get_room_outcome(
+    Path(pubkey): Path<String>,
+    State(solana_service): State<Arc<SolanaService>>,
+    State(outcome_index): State<Arc<OutcomeIndex>>,
+) -> Result<Json<RoomOutcome>, ApiError> {
+    let room = pubkey.parse::<Pubkey>().map_err(|_| ApiError::invalid_pubkey("pubkey"))?;
+
+    let result = outcome_index
+        .get_or_scan(&room, || async {
+            match solana_service.get_room_account(&room, false).await? {
+                Some(account) if account.ended => solana_service.find_room_outcome(&room).await,
+                Some(_) => Err("Room has not ended".to_string()),
+                None => Ok(None),
+            }
+        })
+        .await;
+
+    match result {
+        Ok(Some(outcome)) => Ok(Json(outcome)),
+        Ok(None) => Err(ApiError::not_found(format!("No outcome found for room: {}", pubkey))),
+        Err(err) if err == "Room has not ended" => Err(ApiError::conflict(err)),
+        Err(err) => Err(ApiError::rpc(err)),
+    }
+}
+
+/// Handles live charity preview requests.
+///
+/// # Endpoint
+/// GET /api/room/:pubkey/charity-preview?fresh=false
+///
+/// # Parameters
+/// * `pubkey` - Room PDA address (base58)
+/// * `query` - `fresh=true` bypasses the cache
+/// * `solana_service` - Shared Solana RPC service instance
+///
+/// # Returns
+/// * `200 OK` with JSON `{platform_fee, host_fee, prize_pool, charity, charity_percentage, ...}`
+///   computed from the room's own fee shares (including its platform fee) and
+///   entry/extras counters
+/// * `400 Bad Request` if `pubkey` is not a valid pubkey
+/// * `404 Not Found` if no account exists at the address
+/// * `502 Bad Gateway` if the RPC node returns an error, the account is not a Room,
+///   or `end_room` would fail on the room's counters
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # RPC Cost
+/// One `getAccountInfo` call for the room (cached unless `fresh=true`).
+///
+/// # Example
+/// ```
+/// GET /api/room/9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin/charity-preview
+/// Response: {"platform_fee": 2000000, "host_fee": 500000, "prize_pool": 3500000,
+///            "charity": 14000000, "charity_percentage": 70.0, ...}
+/// ```
+pub async fn get_charity_preview(
+    Path(pubkey): Path<String>,
+    Query(query): Query<FreshQuery>,
+    State(solana_service): State<Arc<SolanaService>>,
+) -> Result<Json<CharityPreview>, ApiError> {
+    let room = pubkey.parse::<Pubkey>().map_err(|_| ApiError::invalid_pubkey("pubkey"))?;
+
+    let room = solana_service
+        .get_room_account(&room, query.fresh)
+        .await
+        .map_err(ApiError::rpc)?
+        .ok_or_else(|| ApiError::not_found(format!("Room not found: {}", pubkey)))?;
+
+    CharityPreview::from_room(&room)
+        .map(Json)
+        .ok_or_else(|| {
+            ApiError::new(
+                StatusCode::BAD_GATEWAY,
+                "invalid_room_state",
+                "Room counters would make end_room fail",
+            )
+        })
+}
+
+/// Handles live room financials requests.
+///
+/// # Endpoint
+/// GET /api/room/:pubkey/financials?fresh=false
+///
+/// # Parameters
+/// * `pubkey` - Room PDA address (base58)
+/// * `query` - `fresh=true` bypasses the cache
+/// * `solana_service` - Shared Solana RPC service instance
+///
+/// # Returns
+/// * `200 OK` with the platform/host/prize amounts, per-place prize estimates and the
+///   charity amount split into its entry-fee and extras portions, computed with
+///   `end_room`'s arithmetic from the room's current counters
+/// * `400 Bad Request` if `pubkey` is not a valid pubkey
+/// * `404 Not Found` if no account exists at the address
+/// * `502 Bad Gateway` if the RPC node returns an error, the account is not a Room,
+///   or `end_room` would fail on the room's counters
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # RPC Cost
+/// One `getAccountInfo` call for the room (cached unless `fresh=true`).
+///
+/// # Example
+/// ```
+/// GET /api/room/9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin/financials
+/// Response: {"platform_fee": 2000000, "host_fee": 500000, "prize_pool": 3500000,
+///            "winner_amounts": [2100000, 1050000, 350000], "charity_from_entry_fees": 4000000,
+///            "charity_from_extras": 10000000, "charity": 14000000, ...}
+/// ```
+pub async fn get_room_financials(
+    Path(pubkey): Path<String>,
+    Query(query): Query<FreshQuery>,
+    State(solana_service): State<Arc<SolanaService>>,
+) -> Result<Json<RoomFinancials>, ApiError> {
+    let room = pubkey.parse::<Pubkey>().map_err(|_| ApiError::invalid_pubkey("pubkey"))?;
+
+    let room = solana_service
+        .get_room_account(&room, query.fresh)
+        .await
+        .map_err(ApiError::rpc)?
+        .ok_or_else(|| ApiError::not_found(format!("Room not found: {}", pubkey)))?;
+
+    RoomFinancials::from_room(&room)
+        .map(Json)
+        .ok_or_else(|| {
+            ApiError::new(
+                StatusCode::BAD_GATEWAY,
+                "invalid_room_state",
+                "Room counters would make end_room fail",
+            )
+        })
+}
@@ -0,0 +1,700 @@
+//! Handlers for building unsigned Fundraisely transactions.
+//!
+//! The frontend sends an instruction type and its parameters; the backend derives
+//! every PDA, resolves token accounts, attaches a recent blockhash and returns the
+//! unsigned transaction as base64 for the user's wallet to sign and submit. Built
+//! transactions can be simulated first so program errors surface before signing.
+
+use axum::{extract::State, http::StatusCode, response::Json};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solana_message::Message;
+use solana_pubkey::Pubkey;
+use solana_transaction::Transaction;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::models::program_error::{program_error_message, program_error_name};
+use crate::models::room_full::PrizeMode;
+use crate::models::ApiError;
+use crate::services::instruction_builder::{
+    self, EndRoomAccounts, InitPoolRoomAccounts, InitPoolRoomArgs, JoinRoomAccounts,
+};
+use crate::services::{ErrorMetrics, SolanaService, TgbClient};
+use crate::utils::fees::validate_room_fees;
+
+/// Request body for the transaction builder endpoint.
+#[derive(Deserialize)]
+pub struct BuildTransactionRequest {
+    /// Program instruction to build (e.g., "join_room")
+    pub instruction_type: String,
+    /// Instruction-specific parameters
+    #[serde(default)]
+    pub params: Value,
+}
+
+/// Unsigned transaction ready for wallet signing.
+///
+/// # Fields
+/// * `transaction` - Bincode-serialized unsigned transaction, base64-encoded
+/// * `instruction_type` - Echo of the requested instruction type
+/// * `recent_blockhash` - Blockhash the transaction was built against (base58)
+/// * `compute_unit_limit` - Compute unit limit the transaction requests, if it sets one
+///   (`end_room`), for debugging compute budget failures
+#[derive(Serialize)]
+pub struct BuildTransactionResponse {
+    pub transaction: String,
+    pub instruction_type: String,
+    pub recent_blockhash: String,
+    pub compute_unit_limit: Option<u32>,
+}
+
+/// Handles transaction build requests.
+///
+/// # Endpoint
+/// POST /api/build-transaction
+///
+/// # Parameters
+/// * `solana_service` - Shared Solana RPC service instance
+/// * `tgb_client` - Shared TGB API client, used to verify charity wallets
+/// * `request` - JSON body with `instruction_type` and `params`
+///
+/// # Supported Instructions
+/// * `join_room` - params: `room_id`, `host`, `player`, optional `extras_amount` (default 0),
+///   optional `allowlist_proof` (base58 merkle proof hashes, leaf level first, for private rooms),
+///   optional `referrer` (base58 wallet credited with the referral)
+/// * `create_room` - builds `init_pool_room`. params: `room_id`, `host`, `charity_wallet`,
+///   `fee_token_mint`, `entry_fee`, `max_players`, `host_fee_bps`, `prize_pool_bps`,
+///   `prize_distribution` (percentage per place), optional `charity_memo` (default ""),
+///   `expiration_slots` (1 to GlobalConfig's `max_expiration_slots`; omitted = the platform
+///   default unless `expiration_unix_ts` is set), `expiration_unix_ts`, `min_players`,
+///   `require_ready`, `allowlist_root` (base58), `early_bird_fee`, `early_bird_count`,
+///   `discount_bps` and `leave_penalty_bps`, plus `charity_id` and `token` (TGB charity and
+///   token symbol) unless `custom_charity` is true. The wallet must be the TGB donation
+///   address for that charity and token. The room vault is created by `init_pool_room`
+///   itself, so no separate account-creation instruction is needed
+/// * `end_room` - params: `room_id`, `host`, `winners` (base58 wallets in place order; only
+///   used when no winners were declared with `declare_winners`), optional
+///   `priority_fee_microlamports`. Prepends a compute unit limit sized by the number of
+///   winners and extra charities, and a compute unit price when a priority fee is given
+///
+/// # Returns
+/// * `200 OK` with the base64 unsigned transaction
+/// * `400 Bad Request` (`charity_wallet_mismatch`) if the charity wallet isn't the charity's
+///   TGB donation address
+/// * `400 Bad Request` (`invalid_fees`) if the fee shares or prize distribution break the
+///   platform limits in GlobalConfig
+/// * `400 Bad Request` (`token_not_approved`) if the fee mint isn't in the token registry
+/// * `400 Bad Request` if a required param is missing or invalid (message names the field)
+/// * `404 Not Found` if the referenced room, or for `create_room` the program's
+///   GlobalConfig or TokenRegistry, does not exist
+/// * `400 Bad Request` (`wrong_prize_mode`) for `end_room` on an asset room
+/// * `409 Conflict` (`room_not_joinable`) for `join_room` when the room is full, ended,
+///   expired or not open to players
+/// * `501 Not Implemented` for instruction types that are not supported yet
+/// * `502 Bad Gateway` if the RPC node returns an error
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # Example
+/// ```
+/// POST /api/build-transaction
+/// {"instruction_type": "join_room", "params": {"room_id": "quiz-1", "host": "...", "player": "...", "extras_amount": 0}}
+/// ```
+pub async fn build_transaction(
+    State(solana_service): State<Arc<SolanaService>>,
+    State(tgb_client): State<Arc<TgbClient>>,
+    Json(request): Json<BuildTransactionRequest>,
+) -> Result<Json<BuildTransactionResponse>, ApiError> {
+    let (transaction, compute_unit_limit) = match request.instruction_type.as_str() {
+        "join_room" => (build_join_room(&solana_service, &request.params).await?, None),
+        "create_room" => (build_create_room(&solana_service, &tgb_client, &request.params).await?, None),
+        "end_room" => {
+            let (transaction, limit) = build_end_room(&solana_service, &request.params).await?;
+            (transaction, Some(limit))
+        }
+        other => {
+            return Err(ApiError::new(
+                StatusCode::NOT_IMPLEMENTED,
+                "not_implemented",
+                format!("Instruction type '{}' is not implemented", other),
+            ))
+        }
+    };
+
+    let serialized = bincode::serialize(&transaction).map_err(|e| {
+        ApiError::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "internal",
+            format!("Failed to serialize transaction: {}", e),
+        )
+    })?;
+
+    Ok(Json(BuildTransactionResponse {
+        transaction: BASE64.encode(serialized),
+        instruction_type: request.instruction_type,
+        recent_blockhash: transaction.message.recent_blockhash.to_string(),
+        compute_unit_limit,
+    }))
+}
+
+/// Request body for the simulation endpoint.
+#[derive(Deserialize)]
+pub struct SimulateTransactionRequest {
+    /// Bincode-serialized transaction, base64-encoded (signed or not)
+    pub transaction: String,
+}
+
+/// Program error a simulation failed with.
+///
+/// # Fields
+/// * `code` - Custom program error code (e.g., 6025)
+/// * `name` - `FundraiselyError` variant name (e.g., "MaxPlayersReached"), or null for
+///   errors from other programs
+/// * `message` - The variant's `#[msg]` string for display (e.g., "Room has reached
+///   maximum players"), or null for errors from other programs
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct SimulatedProgramError {
+    pub code: u32,
+    pub name: Option<&'static str>,
+    pub message: Option<&'static str>,
+}
+
+/// Outcome of simulating a transaction.
+///
+/// # Fields
+/// * `success` - Whether the transaction would succeed
+/// * `err` - Raw transaction error from the RPC node, null on success
+/// * `program_error` - Decoded custom program error, if the failure was one
+/// * `logs` - Simulation log messages
+/// * `units_consumed` - Compute units consumed, if reported
+#[derive(Serialize)]
+pub struct SimulateTransactionResponse {
+    pub success: bool,
+    pub err: Option<Value>,
+    pub program_error: Option<SimulatedProgramError>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+}
+
+/// Handles transaction simulation requests.
+///
+/// Lets the frontend build → simulate → sign → send, surfacing failures such as
+/// `MaxPlayersReached` before the user signs. Signatures aren't checked and the
+/// blockhash is replaced with a recent one, so unsigned transactions from
+/// `/api/build-transaction` can be simulated as-is. Failed simulations are counted in
+/// the per-error-code metrics.
+///
+/// # Endpoint
+/// POST /api/simulate-transaction
+///
+/// # Parameters
+/// * `solana_service` - Shared Solana RPC service instance
+/// * `error_metrics` - Shared per-error-code counters
+/// * `request` - JSON body with the base64 `transaction`
+///
+/// # Returns
+/// * `200 OK` with the simulation result, whether or not the transaction would succeed
+/// * `400 Bad Request` (`invalid_transaction`) if the transaction isn't valid base64 or
+///   the RPC node can't decode it
+/// * `502 Bad Gateway` if the RPC node returns another error
+/// * `503 Service Unavailable` if the RPC node is unreachable
+///
+/// # Example
+/// ```
+/// POST /api/simulate-transaction
+/// {"transaction": "AQAAAA..."}
+/// ```
+pub async fn simulate_transaction(
+    State(solana_service): State<Arc<SolanaService>>,
+    State(error_metrics): State<Arc<ErrorMetrics>>,
+    Json(request): Json<SimulateTransactionRequest>,
+) -> Result<Json<SimulateTransactionResponse>, ApiError> {
+    let invalid = |message: String| ApiError::new(StatusCode::BAD_REQUEST, "invalid_transaction", message);
+    if BASE64.decode(&request.transaction).map_or(true, |bytes| bytes.is_empty()) {
+        return Err(invalid("transaction must be a base64-encoded transaction".to_string()));
+    }
+
+    let simulation = solana_service.simulate(&request.transaction).await.map_err(|e| {
+        // -32602 is the node rejecting the params, i.e. a transaction it can't decode
+        if e.contains("-32602") {
+            invalid(e)
+        } else {
+            ApiError::rpc(e)
+        }
+    })?;
+
+    let program_error = simulation.error_code().map(|code| SimulatedProgramError {
+        code,
+        name: program_error_name(code),
+        message: program_error_message(code),
+    });
+    if let Some(error) = &program_error {
+        error_metrics.increment(error.code);
+    }
+
+    Ok(Json(SimulateTransactionResponse {
+        success: simulation.err.is_none(),
+        err: simulation.err,
+        program_error,
+        logs: simulation.logs.unwrap_or_default(),
+        units_consumed: simulation.units_consumed,
+    }))
+}
+
+/// Builds an unsigned `join_room` transaction with the player as fee payer.
+async fn build_join_room(
+    solana_service: &SolanaService,
+    params: &Value,
+) -> Result<Transaction, ApiError> {
+    let room_id = required_str(params, "room_id")?;
+    let host = required_pubkey(params, "host")?;
+    let player = required_pubkey(params, "player")?;
+    let extras_amount = optional_u64(params, "extras_amount")?.unwrap_or(0);
+    let allowlist_proof = optional_hashes(params, "allowlist_proof")?;
+    let referrer = optional_pubkey(params, "referrer")?;
+
+    let room = solana_service.derive_room_pda(&host, room_id);
+    let room_account = solana_service
+        .get_room_account(&room, false)
+        .await
+        .map_err(ApiError::rpc)?
+        .ok_or_else(|| ApiError::not_found(format!("Room not found: {}", room)))?;
+
+    // Full, ended, expired or unopened rooms would only fail in simulation
+    let current_slot = solana_service.get_slot().await.map_err(ApiError::rpc)?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64);
+    if !room_account.is_joinable(current_slot, now) {
+        return Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "room_not_joinable",
+            format!("Room is not accepting players: {}", room),
+        ));
+    }
+
+    // The player pays with their associated token account for the room's fee mint
+    let fee_token_mint = room_account
+        .fee_token_mint
+        .parse::<Pubkey>()
+        .map_err(|e| ApiError::rpc(format!("Invalid room mint: {}", e)))?;
+
+    // Wallets the host registered with add_discount join at the reduced fee
+    let discount = if room_account.discount_bps > 0 {
+        let discount = solana_service.derive_discount_pda(&room, &player);
+        solana_service
+            .account_exists(&discount)
+            .await
+            .map_err(ApiError::rpc)?
+            .then_some(discount)
+    } else {
+        None
+    };
+
+    let accounts = JoinRoomAccounts {
+        room,
+        player_entry: solana_service.derive_player_pda(&room, &player),
+        room_vault: solana_service.derive_room_vault_pda(&room),
+        player_token_account: instruction_builder::get_associated_token_address(
+            &player,
+            &fee_token_mint,
+        ),
+        global_config: solana_service.derive_global_config_pda(),
+        blacklist: solana_service.derive_blacklist_pda(),
+        discount,
+        player,
+    };
+
+    let instruction = instruction_builder::join_room(
+        solana_service.program_id(),
+        &accounts,
+        room_id,
+        extras_amount,
+        allowlist_proof.as_deref(),
+        referrer.as_ref(),
+    );
+
+    let blockhash = solana_service.get_latest_blockhash().await.map_err(ApiError::rpc)?;
+    let message = Message::new_with_blockhash(&[instruction], Some(&player), &blockhash);
+
+    Ok(Transaction::new_unsigned(message))
+}
+
+/// Builds an unsigned `init_pool_room` transaction with the host as fee payer.
+///
+/// The charity wallet is checked against TGB first so a room can't route its
+/// charity share to an arbitrary wallet while the UI shows a verified charity.
+/// Rooms for charities outside TGB pass `custom_charity: true` to skip the check.
+/// Fee shares and the fee mint are then checked against GlobalConfig and the token
+/// registry so hosts get a precise error instead of a failed simulation.
+async fn build_create_room(
+    solana_service: &SolanaService,
+    tgb_client: &TgbClient,
+    params: &Value,
+) -> Result<Transaction, ApiError> {
+    let room_id = required_str(params, "room_id")?;
+    let host = required_pubkey(params, "host")?;
+    let charity_wallet = required_pubkey(params, "charity_wallet")?;
+    let fee_token_mint = required_pubkey(params, "fee_token_mint")?;
+    let entry_fee = required_u64(params, "entry_fee")?;
+    let max_players = narrow(required_u64(params, "max_players")?, "max_players")?;
+    let host_fee_bps = narrow(required_u64(params, "host_fee_bps")?, "host_fee_bps")?;
+    let prize_pool_bps = narrow(required_u64(params, "prize_pool_bps")?, "prize_pool_bps")?;
+    let prize_distribution = required_u16_list(params, "prize_distribution")?;
+    let charity_memo = optional_str(params, "charity_memo")?.unwrap_or("");
+
+    if !optional_bool(params, "custom_charity")?.unwrap_or(false) {
+        verify_charity_wallet(tgb_client, params, &charity_wallet).await?;
+    }
+
+    let config = solana_service
+        .get_global_config(false)
+        .await
+        .map_err(ApiError::rpc)?
+        .ok_or_else(|| ApiError::not_found("GlobalConfig not initialized"))?;
+    validate_room_fees(&config, host_fee_bps, prize_pool_bps, &prize_distribution)
+        .map_err(|e| ApiError::new(StatusCode::BAD_REQUEST, "invalid_fees", e))?;
+
+    // Omitted = the platform default (when there's no unix deadline either)
+    let expiration_slots = optional_u64(params, "expiration_slots")?;
+    if let Some(slots) = expiration_slots {
+        if slots == 0 || slots > config.max_expiration_slots {
+            return Err(ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "invalid_expiration",
+                format!(
+                    "expiration_slots must be between 1 and {}",
+                    config.max_expiration_slots
+                ),
+            ));
+        }
+    }
+
+    let registry = solana_service
+        .get_token_registry(false)
+        .await
+        .map_err(ApiError::rpc)?
+        .ok_or_else(|| ApiError::not_found("TokenRegistry not initialized"))?;
+    let mint = fee_token_mint.to_string();
+    if !registry.approved_tokens.iter().any(|token| token.mint == mint) {
+        return Err(ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "token_not_approved",
+            format!("Fee token mint {} is not in the token registry", fee_token_mint),
+        ));
+    }
+
+    let args = InitPoolRoomArgs {
+        room_id,
+        charity_wallet,
+        entry_fee,
+        max_players,
+        host_fee_bps,
+        prize_pool_bps,
+        prize_distribution: &prize_distribution,
+        charity_memo,
+        expiration_slots,
+        min_players: optional_u64(params, "min_players")?
+            .map(|value| narrow(value, "min_players"))
+            .transpose()?,
+        require_ready: optional_bool(params, "require_ready")?,
+        expiration_unix_ts: optional_u64(params, "expiration_unix_ts")?
+            .map(|value| narrow(value, "expiration_unix_ts"))
+            .transpose()?,
+        allowlist_root: optional_hash(params, "allowlist_root")?,
+        early_bird_fee: optional_u64(params, "early_bird_fee")?,
+        early_bird_count: optional_u64(params, "early_bird_count")?
+            .map(|value| narrow(value, "early_bird_count"))
+            .transpose()?,
+        discount_bps: optional_u64(params, "discount_bps")?
+            .map(|value| narrow(value, "discount_bps"))
+            .transpose()?,
+        leave_penalty_bps: optional_u64(params, "leave_penalty_bps")?
+            .map(|value| narrow(value, "leave_penalty_bps"))
+            .transpose()?,
+    };
+
+    let room = solana_service.derive_room_pda(&host, room_id);
+    let accounts = InitPoolRoomAccounts {
+        room,
+        room_vault: solana_service.derive_room_vault_pda(&room),
+        fee_token_mint,
+        token_registry: solana_service.derive_token_registry_pda(),
+        global_config: solana_service.derive_global_config_pda(),
+        host,
+    };
+
+    let instruction = instruction_builder::init_pool_room(solana_service.program_id(), &accounts, &args);
+
+    let blockhash = solana_service.get_latest_blockhash().await.map_err(ApiError::rpc)?;
+    let message = Message::new_with_blockhash(&[instruction], Some(&host), &blockhash);
+
+    Ok(Transaction::new_unsigned(message))
+}
+
+/// Builds an unsigned `end_room` transaction with the host as fee payer.
+///
+/// `end_room` pays every winner and extra charity with its own token transfer and can
+/// exceed the default compute budget, so a compute unit limit sized for those transfers
+/// is prepended (and a priority fee, if requested).
+///
+/// # Returns
+/// The transaction and the compute unit limit it requests
+async fn build_end_room(
+    solana_service: &SolanaService,
+    params: &Value,
+) -> Result<(Transaction, u32), ApiError> {
+    let room_id = required_str(params, "room_id")?;
+    let host = required_pubkey(params, "host")?;
+    let priority_fee = optional_u64(params, "priority_fee_microlamports")?;
+
+    let room = solana_service.derive_room_pda(&host, room_id);
+    let room_full = solana_service
+        .get_room_full(&room)
+        .await
+        .map_err(ApiError::rpc)?
+        .ok_or_else(|| ApiError::not_found(format!("Room not found: {}", room)))?;
+    if room_full.prize_mode != PrizeMode::PoolSplit {
+        return Err(ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "wrong_prize_mode",
+            "Asset rooms end with end_asset_room",
+        ));
+    }
+
+    let config = solana_service
+        .get_global_config(false)
+        .await
+        .map_err(ApiError::rpc)?
+        .ok_or_else(|| ApiError::not_found("GlobalConfig not initialized"))?;
+
+    // Declared winners take precedence on-chain, so the winners param is only needed
+    // (and only sent) for rooms that skipped declare_winners
+    let declared = !room_full.winners.is_empty();
+    let winners = if declared {
+        room_full.winners.iter().map(|winner| decoded_pubkey(winner)).collect::<Result<Vec<_>, _>>()?
+    } else {
+        required_pubkey_list(params, "winners")?
+    };
+    let extra_charities = room_full
+        .charity_splits
+        .iter()
+        .skip(1)
+        .map(|split| decoded_pubkey(&split.wallet))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mint = decoded_pubkey(&room_full.fee_token_mint)?;
+    let platform_wallet = decoded_pubkey(&config.platform_wallet)?;
+    let charity_wallet = decoded_pubkey(&room_full.charity_wallet)?;
+    let accounts = EndRoomAccounts {
+        room,
+        room_vault: solana_service.derive_room_vault_pda(&room),
+        global_config: solana_service.derive_global_config_pda(),
+        platform_token_account: instruction_builder::get_associated_token_address(&platform_wallet, &mint),
+        charity_token_account: instruction_builder::get_associated_token_address(&charity_wallet, &mint),
+        host_token_account: instruction_builder::get_associated_token_address(&host, &mint),
+        host,
+        payout_token_accounts: winners
+            .iter()
+            .chain(&extra_charities)
+            .map(|wallet| instruction_builder::get_associated_token_address(wallet, &mint))
+            .collect(),
+    };
+
+    let limit = instruction_builder::end_room_compute_units(winners.len(), extra_charities.len());
+    let mut instructions = vec![instruction_builder::set_compute_unit_limit(limit)];
+    if let Some(micro_lamports) = priority_fee {
+        instructions.push(instruction_builder::set_compute_unit_price(micro_lamports));
+    }
+    let legacy_winners: &[Pubkey] = if declared { &[] } else { &winners };
+    instructions.push(instruction_builder::end_room(
+        solana_service.program_id(),
+        &accounts,
+        room_id,
+        legacy_winners,
+    ));
+
+    let blockhash = solana_service.get_latest_blockhash().await.map_err(ApiError::rpc)?;
+    let message = Message::new_with_blockhash(&instructions, Some(&host), &blockhash);
+
+    Ok((Transaction::new_unsigned(message), limit))
+}
+
+/// Checks that `charity_wallet` is the TGB donation address for the `charity_id`
+/// and `token` params.
+async fn verify_charity_wallet(
+    tgb_client: &TgbClient,
+    params: &Value,
+    charity_wallet: &Pubkey,
+) -> Result<(), ApiError> {
+    let charity_id = required_str(params, "charity_id")?;
+    let token = required_str(params, "token")?;
+
+    let address = tgb_client
+        .get_charity_address(charity_id, token)
+        .await
+        .map_err(ApiError::tgb)?;
+
+    if !address.matches_wallet(charity_wallet) {
+        return Err(ApiError::new(
+            StatusCode::BAD_REQUEST,
+            "charity_wallet_mismatch",
+            format!(
+                "Charity wallet {} is not the TGB {} address for charity {} ({}); \
+                 pass custom_charity: true for charities outside TGB",
+                charity_wallet, token, charity_id, address.address
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Parses a pubkey decoded from an account (always valid base58).
+fn decoded_pubkey(value: &str) -> Result<Pubkey, ApiError> {
+    value.parse().map_err(|_| {
+        ApiError::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "internal",
+            format!("Invalid pubkey in decoded account: {}", value),
+        )
+    })
+}
+
+/// Reads a required array of base58 pubkeys.
+fn required_pubkey_list(params: &Value, field: &str) -> Result<Vec<Pubkey>, ApiError> {
+    params
+        .get(field)
+        .and_then(Value::as_array)
+        .ok_or_else(|| ApiError::bad_request(format!("Missing required field: {}", field)))?
+        .iter()
+        .map(|value| {
+            value
+                .as_str()
+                .and_then(|s| s.parse::<Pubkey>().ok())
+                .ok_or_else(|| ApiError::invalid_pubkey(field))
+        })
+        .collect()
+}
+
+/// Reads a required string param, failing with 400 naming the missing field.
+fn required_str<'a>(params: &'a Value, field: &str) -> Result<&'a str, ApiError> {
+    params
+        .get(field)
+        .and_then(Value::as_str)
+        .ok_or_else(|| ApiError::bad_request(format!("Missing required field: {}", field)))
+}
+
+/// Reads an optional string param.
+fn optional_str<'a>(params: &'a Value, field: &str) -> Result<Option<&'a str>, ApiError> {
+    match params.get(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => value
+            .as_str()
+            .map(Some)
+            .ok_or_else(|| ApiError::bad_request(format!("Invalid string for field: {}", field))),
+    }
+}
+
+/// Reads a required base58 pubkey param.
+fn required_pubkey(params: &Value, field: &str) -> Result<Pubkey, ApiError> {
+    required_str(params, field)?
+        .parse::<Pubkey>()
+        .map_err(|_| ApiError::invalid_pubkey(field))
+}
+
+/// Reads an optional base58 pubkey param.
+fn optional_pubkey(params: &Value, field: &str) -> Result<Option<Pubkey>, ApiError> {
+    match params.get(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => value
+            .as_str()
+            .and_then(|s| s.parse::<Pubkey>().ok())
+            .map(Some)
+            .ok_or_else(|| ApiError::invalid_pubkey(field)),
+    }
+}
+
+/// Reads an optional boolean param.
+fn optional_bool(params: &Value, field: &str) -> Result<Option<bool>, ApiError> {
+    match params.get(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => value
+            .as_bool()
+            .map(Some)
+            .ok_or_else(|| ApiError::bad_request(format!("Invalid boolean for field: {}", field))),
+    }
+}
+
+/// Reads an optional unsigned integer param.
+fn optional_u64(params: &Value, field: &str) -> Result<Option<u64>, ApiError> {
+    match params.get(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => value
+            .as_u64()
+            .map(Some)
+            .ok_or_else(|| ApiError::bad_request(format!("Invalid integer for field: {}", field))),
+    }
+}
+
+/// Reads a required unsigned integer param.
+fn required_u64(params: &Value, field: &str) -> Result<u64, ApiError> {
+    optional_u64(params, field)?
+        .ok_or_else(|| ApiError::bad_request(format!("Missing required field: {}", field)))
+}
+
+/// Converts an integer param to the instruction argument's width.
+fn narrow<T: TryFrom<u64>>(value: u64, field: &str) -> Result<T, ApiError> {
+    T::try_from(value)
+        .map_err(|_| ApiError::bad_request(format!("Integer out of range for field: {}", field)))
+}
+
+/// Reads a required array of small unsigned integers (e.g., percentages).
+fn required_u16_list(params: &Value, field: &str) -> Result<Vec<u16>, ApiError> {
+    params
+        .get(field)
+        .and_then(Value::as_array)
+        .ok_or_else(|| ApiError::bad_request(format!("Missing required field: {}", field)))?
+        .iter()
+        .map(|value| {
+            value
+                .as_u64()
+                .and_then(|n| u16::try_from(n).ok())
+                .ok_or_else(|| ApiError::bad_request(format!("Invalid integer list for field: {}", field)))
+        })
+        .collect()
+}
+
+/// Reads an optional base58-encoded 32-byte hash.
+fn optional_hash(params: &Value, field: &str) -> Result<Option<[u8; 32]>, ApiError> {
+    let invalid = || ApiError::bad_request(format!("Invalid hash for field: {}", field));
+    match params.get(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => {
+            let decoded = bs58::decode(value.as_str().ok_or_else(invalid)?)
+                .into_vec()
+                .map_err(|_| invalid())?;
+            <[u8; 32]>::try_from(decoded.as_slice()).map(Some).map_err(|_| invalid())
+        }
+    }
+}
+
+/// Reads an optional array of base58-encoded 32-byte hashes.
+fn optional_hashes(params: &Value, field: &str) -> Result<Option<Vec<[u8; 32]>>, ApiError> {
+    let invalid = || ApiError::bad_request(format!("Invalid hash list for field: {}", field));
+    match params.get(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Array(values)) => values
+            .iter()
+            .map(|value| {
+                let decoded = bs58::decode(value.as_str().ok_or_else(invalid)?)
+                    .into_vec()
+                    .map_err(|_| invalid())?;
+                <[u8; 32]>::try_from(decoded.as_slice()).map_err(|_| invalid())
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some),
+        Some(_) => Err(invalid()),
+    }
+}
@@ -0,0 +1,270 @@
+//! WebSocket stream of room events.
+//!
+//! Clients connect to `/ws`, receive a challenge nonce, optionally authenticate, then
+//! subscribe to rooms and receive every program event for them as the event listener
+//! decodes it. All messages are JSON text frames tagged by `type`.
+//!
+//! # Protocol
+//! 1. Server: `{"type": "challenge", "nonce": "..."}` right after the upgrade
+//! 2. Client: `{"type": "auth", "wallet": "...", "signature": "..."}` (signature of
+//!    `AUTH_MESSAGE_PREFIX` + nonce) or `{"type": "auth", "token": "..."}`;
+//!    server answers `{"type": "authenticated"}`
+//! 3. Client: `{"type": "subscribe", "room": "<room pubkey>"}`;
+//!    server answers `{"type": "subscribed", "room": "..."}`
+//! 4. Server: `{"type": "event", "event": {...}}` for each event of a subscribed room
+//!
+//! # Access
+//! Rooms with an allowlist are private: only the host, a wallet with a PlayerEntry in
+//! the room, or an API token holder may watch them. Other rooms are public and, while
+//! `WS_PUBLIC_ROOMS` is on, need no authentication. Authentication must come first and
+//! only once (the nonce is single-use) and within `AUTH_TIMEOUT`. A client breaking any
+//! of these rules has its socket closed with code 1008 (policy violation).
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use axum::{
+    extract::{
+        ws::{close_code, CloseFrame, Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    response::Response,
+};
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::middleware::{AuthConfig, Credentials, Principal};
+use crate::models::{ProgramEvent, RoomFull};
+use crate::services::SolanaService;
+use crate::state::AppState;
+
+/// How long a client that hasn't subscribed yet has to authenticate.
+pub const AUTH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Messages a client sends.
+#[derive(Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ClientMessage {
+    Auth {
+        wallet: Option<String>,
+        signature: Option<String>,
+        token: Option<String>,
+    },
+    Subscribe {
+        room: String,
+    },
+}
+
+/// Messages the server sends.
+#[derive(Serialize, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage<'a> {
+    Challenge { nonce: &'a str },
+    Authenticated,
+    Subscribed { room: String },
+    Event { event: &'a ProgramEvent },
+}
+
+/// Handles WebSocket upgrades.
+///
+/// # Endpoint
+/// GET /ws
+///
+/// # Parameters
+/// * `ws` - Upgrade request
+/// * `state` - Application state (event channel, RPC service, authentication settings)
+///
+/// # Returns
+/// `101 Switching Protocols`, after which the protocol in the module docs applies
+pub async fn ws_handler(ws: WebSocketUpgrade, State(state): State<AppState>) -> Response {
+    ws.on_upgrade(move |socket| handle_socket(socket, state))
+}
+
+async fn handle_socket(mut socket: WebSocket, state: AppState) {
+    let nonce = Uuid::new_v4().to_string();
+    if send(&mut socket, &ServerMessage::Challenge { nonce: &nonce }).await.is_err() {
+        return;
+    }
+
+    let mut events = state.events.subscribe();
+    let mut principal: Option<Principal> = None;
+    let mut rooms: HashSet<Pubkey> = HashSet::new();
+    let auth_deadline = tokio::time::sleep(AUTH_TIMEOUT);
+    tokio::pin!(auth_deadline);
+
+    loop {
+        tokio::select! {
+            // Connections that neither authenticate nor watch anything are dropped
+            _ = &mut auth_deadline, if principal.is_none() && rooms.is_empty() => {
+                close(socket, "Authentication timed out").await;
+                return;
+            }
+            message = socket.recv() => {
+                let text = match message {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | None | Some(Err(_)) => return,
+                    Some(Ok(_)) => continue,
+                };
+                let reply = match serde_json::from_str::<ClientMessage>(&text) {
+                    Ok(message) => {
+                        handle_message(message, &state, &nonce, &mut principal, &mut rooms).await
+                    }
+                    Err(_) => Err("Unrecognized message".to_string()),
+                };
+                let sent = match reply {
+                    Ok(reply) => send(&mut socket, &reply).await,
+                    Err(reason) => {
+                        close(socket, &reason).await;
+                        return;
+                    }
+                };
+                if sent.is_err() {
+                    return;
+                }
+            }
+            event = events.recv() => match event {
+                Ok(event) => {
+                    let watched = event.room().is_some_and(|room| rooms.contains(&room));
+                    if watched && send(&mut socket, &ServerMessage::Event { event: &event }).await.is_err() {
+                        return;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => warn!("WebSocket: Client lagged, {} events skipped", skipped),
+                Err(RecvError::Closed) => return,
+            },
+        }
+    }
+}
+
+/// Applies one client message to the connection.
+///
+/// # Returns
+/// * `Ok(ServerMessage)` - Acknowledgement to send
+/// * `Err(String)` - Why the socket must be closed
+async fn handle_message(
+    message: ClientMessage,
+    state: &AppState,
+    nonce: &str,
+    principal: &mut Option<Principal>,
+    rooms: &mut HashSet<Pubkey>,
+) -> Result<ServerMessage<'static>, String> {
+    match message {
+        ClientMessage::Auth { wallet, signature, token } => {
+            // Authentication comes first and only once: the nonce is single-use
+            if principal.is_some() || !rooms.is_empty() {
+                return Err("Authentication must be the first message".to_string());
+            }
+            let credentials = match (wallet, signature, token) {
+                (Some(wallet), Some(signature), None) => Credentials::Wallet { wallet, signature },
+                (None, None, Some(token)) => Credentials::Token(token),
+                _ => return Err("Send either wallet and signature, or token".to_string()),
+            };
+            *principal = Some(state.ws_auth.authenticate(&credentials, nonce)?);
+            Ok(ServerMessage::Authenticated)
+        }
+        ClientMessage::Subscribe { room } => {
+            let room = room.parse::<Pubkey>().map_err(|_| "Invalid room".to_string())?;
+            let full = state
+                .solana_service
+                .get_room_full(&room)
+                .await
+                .map_err(|e| format!("Room lookup failed: {}", e))?
+                .ok_or_else(|| format!("Room not found: {}", room))?;
+
+            let is_player = match principal {
+                Some(Principal::Wallet(wallet)) if full.allowlist_root.is_some() => {
+                    is_player(&state.solana_service, &room, wallet).await?
+                }
+                _ => false,
+            };
+            if !may_subscribe(&state.ws_auth, *principal, &full, is_player) {
+                return Err(format!("Not allowed to watch room {}", room));
+            }
+
+            rooms.insert(room);
+            info!("WebSocket: Client subscribed to room {}", room);
+            Ok(ServerMessage::Subscribed { room: full.pubkey })
+        }
+    }
+}
+
+/// Whether the wallet has a PlayerEntry in the room.
+async fn is_player(solana_service: &SolanaService, room: &Pubkey, wallet: &Pubkey) -> Result<bool, String> {
+    solana_service
+        .account_exists(&solana_service.derive_player_pda(room, wallet))
+        .await
+        .map_err(|e| format!("Player lookup failed: {}", e))
+}
+
+/// Decides whether a client may watch a room (see "Access" in the module docs).
+///
+/// # Arguments
+/// * `principal` - Who the client authenticated as, if anyone
+/// * `room` - The room
+/// * `is_player` - Whether the client's wallet has a PlayerEntry in the room
+fn may_subscribe(config: &AuthConfig, principal: Option<Principal>, room: &RoomFull, is_player: bool) -> bool {
+    let private = room.allowlist_root.is_some();
+    match principal {
+        Some(Principal::Service) => true,
+        Some(Principal::Wallet(wallet)) => !private || is_player || room.host == wallet.to_string(),
+        None => !private && config.public_rooms_open(),
+    }
+}
+
+async fn send(socket: &mut WebSocket, message: &ServerMessage<'_>) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(message).expect("server messages serialize");
+    socket.send(Message::Text(text.into())).await
+}
+
+/// Closes the socket with a policy-violation code and the reason.
+async fn close(mut socket: WebSocket, reason: &str) {
+    info!("WebSocket: Closing connection: {}", reason);
+    let _ = socket
+        .send(Message::Close(Some(CloseFrame {
+            code: close_code::POLICY,
+            reason: reason.to_string().into(),
+        })))
+        .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::room_account::tests::room_data;
+
+    fn room(private: bool) -> RoomFull {
+        let mut room = RoomFull::from_account_data(&Pubkey::new_unique(), &room_data(0)).unwrap();
+        if private {
+            room.allowlist_root = Some(Pubkey::new_unique().to_string());
+        }
+        room
+    }
+
+    #[test]
+    fn public_rooms_follow_the_open_setting() {
+        let room = room(false);
+        let stranger = Some(Principal::Wallet(Pubkey::new_unique()));
+
+        assert!(may_subscribe(&AuthConfig::new(vec![], true), None, &room, false));
+        assert!(!may_subscribe(&AuthConfig::new(vec![], false), None, &room, false));
+        // Any authenticated client may watch a public room
+        assert!(may_subscribe(&AuthConfig::new(vec![], false), stranger, &room, false));
+    }
+
+    #[test]
+    fn private_rooms_admit_only_host_players_and_services() {
+        let config = AuthConfig::new(vec![], true);
+        let room = room(true);
+        let host = Some(Principal::Wallet(room.host.parse().unwrap()));
+        let stranger = Some(Principal::Wallet(Pubkey::new_unique()));
+
+        assert!(!may_subscribe(&config, None, &room, false));
+        assert!(!may_subscribe(&config, stranger, &room, false));
+        assert!(may_subscribe(&config, stranger, &room, true));
+        assert!(may_subscribe(&config, host, &room, false));
+        assert!(may_subscribe(&config, Some(Principal::Service), &room, false));
+    }
+}
@@ -0,0 +1,16 @@
+//! # Fundraisely Backend Library
+//!
+//! Library half of the Fundraisely backend. All modules live here so they can be
+//! shared between the server binary (`main.rs`) and tests; `main.rs` only wires
+//! configuration, state and the router together and starts listening.
+//!
+//! See `main.rs` for an overview of the exposed endpoints and module layout.
+
+pub mod config;
+pub mod handlers;
+pub mod middleware;
+pub mod models;
+pub mod routes;
+pub mod services;
+pub mod state;
+pub mod utils;
@@ -3,15 +3,40 @@
 //! This server acts as a proxy between the Fundraisely frontend and The Giving Block (TGB) API.
 //! It provides two main functions: allowing users to search for charities by name, and fetching
 //! Solana donation addresses for selected charities. By proxying TGB API calls through this backend,
-//! we keep the TGB API key secure (not exposed in frontend) and can add additional business logic
-//! like caching, rate limiting, or logging in the future.
+//! we keep the TGB API key secure (not exposed in frontend) and can add business logic such as
+//! caching, per-client rate limiting and logging.
 //!
-//! The server runs on port 3002 and exposes three endpoints:
-//! - GET /api/charities?q=search_term - Search for charities by name via TGB API
+//! The server runs on port 3002 and exposes the following endpoints:
+//! - GET /api/charities?q=search_term&limit=20&offset=0 - Search for charities by name via TGB API (paginated)
 //! - GET /api/charities/:id/address/:token - Get donation address for charity+token combination
-//! - GET /health - Simple health check endpoint
+//! - GET /api/charities/:id/addresses?tokens=SOL,USDC - Get addresses for several tokens at once
+//! - GET /api/rooms - List fundraising rooms read from the Solana program
+//! - GET /api/room/:pubkey - Get a single room (cached; `?fresh=true` bypasses the cache)
+//! - GET /api/room/:pubkey/full - Every field of a room, for detail pages
+//! - GET /api/room/:pubkey/timeline - Chronological event history of a room
+//! - GET /api/room/:pubkey/charity-proof - Proof the charity transfer of an ended room landed
+//! - GET /api/room/:pubkey/outcome - Outcome of an ended room (indexed, chain-scan fallback)
+//! - GET /api/room/:pubkey/charity-preview - Live charity amount if the room ended now
+//! - GET /api/room/:pubkey/financials - Full live payout breakdown, per winner and charity source
+//! - GET /api/room/:pubkey/vault - Real on-chain vault balance next to the room's total_collected
+//! - GET /api/balance/:pubkey - Get an account's SOL balance (cached; `?fresh=true` bypasses)
+//! - GET /api/token-balance/:owner/:mint - Get an owner's SPL token balance for a mint
+//! - GET /api/approved-tokens - Approved entry fee tokens with symbols and decimals (cached)
+//! - GET /api/player/:pubkey/entries - List every room a player has joined
+//! - POST /api/calculate-fees - Preview the platform/host/prize/charity split of entry fees
+//! - POST /api/build-transaction - Build unsigned Fundraisely transactions for wallet signing
+//! - POST /api/simulate-transaction - Simulate a transaction, decoding FundraiselyError failures
+//! - GET /api/metrics/errors - Frequency of program errors seen in failed transactions
+//! - GET /api/debug/cache - Account cache hit/miss counts, entry counts and TTLs (only
+//!   with `DEBUG_ENDPOINTS=true`)
+//! - GET /ws - WebSocket stream of room events, with a wallet-signature/API-token handshake
+//! - GET /health - Health check with RPC connectivity and cache statistics
+//! - GET /health/ready - Readiness check that pings TGB with the API key
+//! - GET /metrics - Prometheus request counts, latencies and RPC call latency
 //!
 //! This backend is required for the charity selection feature in room creation to work properly.
+//! It also reads Fundraisely program accounts over Solana RPC so the frontend can browse rooms
+//! without knowing their addresses in advance.
 //!
 //! # Architecture
 //! The application is organized into modules following Rust best practices:
@@ -20,22 +45,36 @@
 //! - `services` - Business logic and external API clients
 //! - `handlers` - HTTP request handlers
 //! - `routes` - Router configuration
-//! - `middleware` - HTTP middleware (CORS, etc.)
+//! - `middleware` - HTTP middleware (CORS, request IDs and latency logging, rate limiting)
+//! - `state` - Shared application state injected into handlers
+//! - `utils` - Fee and amount calculations mirroring the on-chain program
+//!
+//! Modules are declared in `lib.rs`; this binary only wires them together.
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
 use tracing::info;
 
-// Module declarations
-mod config;
-mod handlers;
-mod middleware;
-mod models;
-mod routes;
-mod services;
+use fundraisely_tgb_backend::config::{
+    get_balance_cache_ttl_ms, get_config_cache_ttl_secs, get_debug_endpoints, get_program_id,
+    get_rate_limit_burst, get_rate_limit_per_sec, get_rate_limit_trust_proxy, get_room_cache_ttl_ms,
+    get_solana_rpc_max_retries, get_solana_rpc_timeout_secs, get_solana_rpc_urls, get_tgb_api_key,
+    get_tgb_base_url, get_tgb_circuit_cooldown_secs, get_tgb_circuit_failure_threshold,
+    get_tgb_max_retries, get_tgb_search_cache_capacity, get_tgb_search_cache_ttl_secs,
+    get_tgb_timeout_secs, get_ws_api_tokens, get_ws_public_rooms, load_env, validate_env,
+};
+use fundraisely_tgb_backend::middleware::{AuthConfig, RateLimiter};
+use fundraisely_tgb_backend::routes;
+use fundraisely_tgb_backend::services::event_listener::spawn_event_listener;
+use fundraisely_tgb_backend::services::{
+    CacheService, MetricsService, OutcomeIndex, SolanaService, TgbClient,
+};
+use fundraisely_tgb_backend::state::AppState;
 
-use config::{load_env, validate_env, get_tgb_api_key};
-use services::TgbClient;
+/// Program events buffered per WebSocket subscriber before it starts skipping.
+const EVENT_CHANNEL_CAPACITY: usize = 1024;
 
 #[tokio::main]
 async fn main() {
@@ -52,19 +91,111 @@ async fn main() {
 
     // Create TGB API client (shared across all requests)
     let api_key = get_tgb_api_key();
-    let tgb_client = Arc::new(TgbClient::new(api_key));
+    let tgb_client = Arc::new(
+        TgbClient::new(api_key)
+            .with_base_url(get_tgb_base_url())
+            .with_timeout(Duration::from_secs(get_tgb_timeout_secs()))
+            .with_max_retries(get_tgb_max_retries())
+            .with_circuit_breaker(
+                get_tgb_circuit_failure_threshold(),
+                Duration::from_secs(get_tgb_circuit_cooldown_secs()),
+            )
+            .with_search_cache(
+                Duration::from_secs(get_tgb_search_cache_ttl_secs()),
+                get_tgb_search_cache_capacity(),
+            ),
+    );
+
+    // Create Solana RPC service with its account cache (shared across all requests)
+    let cache_service = Arc::new(
+        CacheService::new(
+            Duration::from_millis(get_room_cache_ttl_ms()),
+            Duration::from_millis(get_balance_cache_ttl_ms()),
+        )
+        .with_config_ttl(Duration::from_secs(get_config_cache_ttl_secs())),
+    );
+    let program_id = get_program_id().parse().expect("validated by validate_env");
+    let metrics = Arc::new(MetricsService::new());
+    let mut rpc_urls = get_solana_rpc_urls().into_iter();
+    let primary_rpc_url = rpc_urls.next().expect("at least one RPC URL");
+    let solana_service = Arc::new(
+        SolanaService::new(primary_rpc_url, program_id, cache_service.clone())
+            .with_failover_urls(rpc_urls.collect())
+            .with_max_retries(get_solana_rpc_max_retries())
+            .with_timeout(Duration::from_secs(get_solana_rpc_timeout_secs()))
+            .with_metrics(metrics.clone()),
+    );
+
+    // Evict cached accounts as soon as program events show they changed, index the
+    // outcome of every room that ends and stream events to WebSocket subscribers
+    let outcome_index = Arc::new(OutcomeIndex::new());
+    let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+    spawn_event_listener(
+        solana_service.clone(),
+        cache_service.clone(),
+        outcome_index.clone(),
+        events.clone(),
+        Duration::from_secs(2),
+    );
+
+    // Per-client rate limiting for /api/*, with idle buckets swept every minute
+    let rate_limiter = Arc::new(
+        RateLimiter::new(get_rate_limit_per_sec(), get_rate_limit_burst())
+            .with_trust_proxy(get_rate_limit_trust_proxy()),
+    );
+    rate_limiter.spawn_cleanup(Duration::from_secs(60));
 
     // Build router with all routes and middleware
-    let app = routes::build_router(tgb_client);
+    let debug_endpoints = get_debug_endpoints();
+    let app = routes::build_router(
+        AppState::new(
+            tgb_client,
+            solana_service,
+            cache_service,
+            outcome_index,
+            metrics,
+            events,
+            AuthConfig::new(get_ws_api_tokens(), get_ws_public_rooms()),
+        ),
+        rate_limiter,
+        debug_endpoints,
+    );
 
     // Run server on port 3002 (port 3001 is used by WebSocket server)
     let addr = SocketAddr::from(([127, 0, 0, 1], 3002));
     info!("TGB Backend Server listening on http://{}", addr);
     info!("Health check available at http://{}/health", addr);
+    info!("Readiness check available at http://{}/health/ready", addr);
+    info!("Prometheus metrics available at http://{}/metrics", addr);
+    info!("Room event stream available at ws://{}/ws", addr);
     info!("API endpoints:");
-    info!("  - GET /api/charities?q=<search_term>");
+    info!("  - GET /api/charities?q=<search_term>&limit=<n>&offset=<n>");
     info!("  - GET /api/charities/<id>/address/<token>");
+    info!("  - GET /api/charities/<id>/addresses?tokens=<token>,<token>");
+    info!("  - GET /api/rooms?status=<status>&host=<pubkey>&limit=<n>&offset=<n>");
+    info!("  - GET /api/room/<pubkey>?fresh=<bool>");
+    info!("  - GET /api/room/<pubkey>/full");
+    info!("  - GET /api/room/<pubkey>/timeline");
+    info!("  - GET /api/room/<pubkey>/charity-proof");
+    info!("  - GET /api/room/<pubkey>/outcome");
+    info!("  - GET /api/room/<pubkey>/charity-preview");
+    info!("  - GET /api/room/<pubkey>/financials?fresh=<bool>");
+    info!("  - GET /api/room/<pubkey>/vault");
+    info!("  - GET /api/balance/<pubkey>?fresh=<bool>");
+    info!("  - GET /api/token-balance/<owner>/<mint>");
+    info!("  - GET /api/approved-tokens?fresh=<bool>");
+    info!("  - GET /api/player/<pubkey>/entries?limit=<n>&offset=<n>");
+    info!("  - POST /api/calculate-fees");
+    info!("  - POST /api/build-transaction");
+    info!("  - POST /api/simulate-transaction");
+    info!("  - GET /api/metrics/errors");
+    if debug_endpoints {
+        info!("  - GET /api/debug/cache");
+    }
 
     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+    // Connect info supplies the peer address the rate limiter falls back to
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+        .await
+        .unwrap();
 }
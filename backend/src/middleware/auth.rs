@@ -0,0 +1,151 @@
+//! Client authentication.
+//!
+//! A client proves who it is in one of two ways:
+//!
+//! - **Wallet signature**: the wallet signs `AUTH_MESSAGE_PREFIX` followed by a
+//!   single-use nonce the server issued (with the wallet's `signMessage`), proving it
+//!   holds the key without sending a transaction.
+//! - **API token**: trusted services (e.g. the game server) present one of the tokens
+//!   configured in `WS_API_TOKENS`.
+//!
+//! Header-based middleware doesn't fit the WebSocket lifecycle, where credentials
+//! arrive as the first message after the upgrade, so the checks live in
+//! `AuthConfig::authenticate` for the WebSocket handler to call directly.
+
+use solana_pubkey::Pubkey;
+use solana_signature::Signature;
+
+/// Prefix of the message a wallet signs, so the signature can't double as anything else.
+pub const AUTH_MESSAGE_PREFIX: &str = "Fundraisely room subscription: ";
+
+/// Credentials a client presents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Credentials {
+    /// Base58 wallet and base58 signature of `AUTH_MESSAGE_PREFIX` + the issued nonce
+    Wallet { wallet: String, signature: String },
+    /// One of the configured API tokens
+    Token(String),
+}
+
+/// Who a client authenticated as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Principal {
+    /// The holder of this wallet's key
+    Wallet(Pubkey),
+    /// A trusted service holding an API token; may subscribe to any room
+    Service,
+}
+
+/// Authentication settings.
+///
+/// # Fields
+/// * `api_tokens` - Tokens accepted from trusted services (none = token auth disabled)
+/// * `public_rooms_open` - Whether rooms without an allowlist may be subscribed to
+///   without authenticating
+#[derive(Debug, Clone, Default)]
+pub struct AuthConfig {
+    api_tokens: Vec<String>,
+    public_rooms_open: bool,
+}
+
+impl AuthConfig {
+    /// Creates the settings; empty tokens are ignored.
+    pub fn new(api_tokens: Vec<String>, public_rooms_open: bool) -> Self {
+        Self {
+            api_tokens: api_tokens.into_iter().filter(|token| !token.is_empty()).collect(),
+            public_rooms_open,
+        }
+    }
+
+    /// Whether rooms without an allowlist are open to unauthenticated clients.
+    pub fn public_rooms_open(&self) -> bool {
+        self.public_rooms_open
+    }
+
+    /// Checks credentials against the nonce issued to this client.
+    ///
+    /// # Returns
+    /// * `Ok(Principal)` - Who the client is
+    /// * `Err(&str)` - Why the credentials were rejected
+    pub fn authenticate(&self, credentials: &Credentials, nonce: &str) -> Result<Principal, &'static str> {
+        match credentials {
+            Credentials::Wallet { wallet, signature } => {
+                let wallet = wallet.parse::<Pubkey>().map_err(|_| "Invalid wallet")?;
+                let signature = signature.parse::<Signature>().map_err(|_| "Invalid signature")?;
+                let message = format!("{}{}", AUTH_MESSAGE_PREFIX, nonce);
+                if signature.verify(wallet.as_ref(), message.as_bytes()) {
+                    Ok(Principal::Wallet(wallet))
+                } else {
+                    Err("Signature does not match the wallet and nonce")
+                }
+            }
+            Credentials::Token(token) => {
+                if self.api_tokens.iter().any(|accepted| accepted == token) {
+                    Ok(Principal::Service)
+                } else {
+                    Err("Unknown API token")
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+
+    fn keypair(seed: u8) -> Keypair {
+        let secret = SecretKey::from_bytes(&[seed; 32]).unwrap();
+        let public = PublicKey::from(&secret);
+        Keypair { secret, public }
+    }
+
+    fn wallet_credentials(keypair: &Keypair, nonce: &str) -> Credentials {
+        let message = format!("{}{}", AUTH_MESSAGE_PREFIX, nonce);
+        Credentials::Wallet {
+            wallet: bs58::encode(keypair.public.as_bytes()).into_string(),
+            signature: bs58::encode(keypair.sign(message.as_bytes()).to_bytes()).into_string(),
+        }
+    }
+
+    #[test]
+    fn accepts_a_wallet_signature_of_the_issued_nonce() {
+        let keypair = keypair(7);
+        let config = AuthConfig::default();
+
+        let principal = config.authenticate(&wallet_credentials(&keypair, "nonce-1"), "nonce-1").unwrap();
+        assert_eq!(principal, Principal::Wallet(Pubkey::new_from_array(keypair.public.to_bytes())));
+    }
+
+    #[test]
+    fn rejects_a_signature_of_another_nonce_or_wallet() {
+        let config = AuthConfig::default();
+
+        // Replaying a signature from an earlier connection
+        assert!(config.authenticate(&wallet_credentials(&keypair(7), "nonce-1"), "nonce-2").is_err());
+
+        // Someone else's signature claimed for this wallet
+        let Credentials::Wallet { signature, .. } = wallet_credentials(&keypair(8), "nonce-1") else {
+            unreachable!()
+        };
+        let forged = Credentials::Wallet {
+            wallet: bs58::encode(keypair(7).public.as_bytes()).into_string(),
+            signature,
+        };
+        assert!(config.authenticate(&forged, "nonce-1").is_err());
+    }
+
+    #[test]
+    fn accepts_only_configured_tokens() {
+        let config = AuthConfig::new(vec!["game-server".to_string(), String::new()], false);
+
+        assert_eq!(
+            config.authenticate(&Credentials::Token("game-server".to_string()), "n"),
+            Ok(Principal::Service)
+        );
+        assert!(config.authenticate(&Credentials::Token("guess".to_string()), "n").is_err());
+        // An unset token in the list doesn't let an empty token through
+        assert!(config.authenticate(&Credentials::Token(String::new()), "n").is_err());
+    }
+}
@@ -5,12 +5,15 @@
 
 use tower_http::cors::{Any, CorsLayer};
 
+use super::X_REQUEST_ID;
+
 /// Creates a permissive CORS layer for development.
 ///
 /// This configuration allows:
 /// - Any origin to make requests
 /// - Any HTTP method (GET, POST, PUT, DELETE, etc.)
 /// - Any headers
+/// - Reading the `X-Request-Id` response header, so the frontend can report it with errors
 ///
 /// # Security Note
 /// This is suitable for development but should be restricted in production
@@ -31,4 +34,5 @@ pub fn cors_layer() -> CorsLayer {
         .allow_origin(Any)
         .allow_methods(Any)
         .allow_headers(Any)
+        .expose_headers([X_REQUEST_ID])
 }
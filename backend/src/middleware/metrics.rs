@@ -0,0 +1,68 @@
+//! Request metrics middleware.
+//!
+//! Records the method, matched route, status and latency of every request in the
+//! shared [`MetricsService`]. It is installed as a route layer so the matched
+//! route template is known; `/metrics` is registered after the layer and so is
+//! never counted itself.
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    middleware::Next,
+    response::Response,
+};
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::services::MetricsService;
+
+/// Times the request and records it under its route template.
+///
+/// # Example
+/// ```rust,ignore
+/// Router::new().route_layer(axum::middleware::from_fn_with_state(metrics, track_metrics))
+/// ```
+pub async fn track_metrics(
+    State(metrics): State<Arc<MetricsService>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = request.method().clone();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let start = Instant::now();
+    let response = next.run(request).await;
+    metrics.record_request(method.as_str(), &route, response.status().as_u16(), start.elapsed());
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, routing::get, Router};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn records_requests_by_route_template() {
+        let metrics = Arc::new(MetricsService::new());
+        let app = Router::new()
+            .route("/api/room/{pubkey}", get(|| async { "room" }))
+            .route_layer(axum::middleware::from_fn_with_state(metrics.clone(), track_metrics))
+            .route("/metrics", get(|| async { "metrics" }));
+
+        for uri in ["/api/room/abc", "/api/room/def", "/metrics"] {
+            let request = axum::http::Request::builder().uri(uri).body(Body::empty()).unwrap();
+            app.clone().oneshot(request).await.unwrap();
+        }
+
+        let text = metrics.render();
+        assert!(text.contains(
+            "http_requests_total{method=\"GET\",route=\"/api/room/{pubkey}\",status=\"200\"} 2"
+        ));
+        assert!(!text.contains("route=\"/metrics\""));
+    }
+}
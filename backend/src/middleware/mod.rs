@@ -1,8 +1,17 @@
 //! HTTP middleware for the application.
 //!
 //! This module contains middleware that processes requests before they reach handlers,
-//! such as CORS configuration, authentication, logging, etc.
+//! such as CORS configuration, request IDs, request metrics and per-client rate limiting,
+//! plus the client authentication checks the WebSocket handler applies itself.
 
+pub mod auth;
 pub mod cors;
+pub mod metrics;
+pub mod rate_limit;
+pub mod request_id;
 
+pub use auth::{AuthConfig, Credentials, Principal};
 pub use cors::cors_layer;
+pub use metrics::track_metrics;
+pub use rate_limit::{rate_limit, RateLimiter};
+pub use request_id::{current_request_id, request_id, RequestId, X_REQUEST_ID};
@@ -0,0 +1,318 @@
+//! Per-client rate limiting middleware.
+//!
+//! Each client IP gets a token bucket that refills at `requests_per_sec` and holds up
+//! to `burst` tokens. Every request takes one token; when the bucket is empty the
+//! request is refused with `429 Too Many Requests` and a `Retry-After` header giving
+//! the seconds until the next token. This keeps a single client from exhausting the
+//! RPC and TGB quotas shared by everyone.
+//!
+//! The client IP is the socket peer address. Behind a reverse proxy every peer is the
+//! proxy, so with `trust_proxy` set the client IP is instead the last address in
+//! `X-Forwarded-For`, the one the proxy appended. Earlier entries come from the client
+//! and are ignored: trusting them would let a client pick a fresh bucket per request.
+//! Never enable `trust_proxy` when clients can reach the server directly, since they
+//! could then send the header themselves. Buckets that have been idle long enough
+//! to refill completely are dropped by a periodic cleanup task, so memory stays bounded
+//! by the number of recently active clients.
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+use crate::models::ApiError;
+
+/// Default sustained requests per second allowed per client.
+pub const DEFAULT_REQUESTS_PER_SEC: f64 = 10.0;
+
+/// Default number of requests a client may make in a burst.
+pub const DEFAULT_BURST: u32 = 20;
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Token buckets keyed by client IP.
+pub struct RateLimiter {
+    requests_per_sec: f64,
+    burst: f64,
+    /// Key clients on the proxy-appended `X-Forwarded-For` entry instead of the peer
+    trust_proxy: bool,
+    buckets: Mutex<HashMap<IpAddr, Bucket>>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter; a `requests_per_sec` of 0 disables it.
+    pub fn new(requests_per_sec: f64, burst: u32) -> Self {
+        Self {
+            requests_per_sec,
+            burst: f64::from(burst.max(1)),
+            trust_proxy: false,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets whether the server sits behind a reverse proxy that appends the client
+    /// address to `X-Forwarded-For` (see the module docs).
+    pub fn with_trust_proxy(mut self, trust_proxy: bool) -> Self {
+        self.trust_proxy = trust_proxy;
+        self
+    }
+
+    /// Takes a token from `client`'s bucket.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the request may proceed
+    /// * `Err(Duration)` - Time until the bucket holds a token again
+    pub fn check(&self, client: IpAddr) -> Result<(), Duration> {
+        if self.requests_per_sec <= 0.0 {
+            return Ok(());
+        }
+
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(client).or_insert(Bucket {
+            tokens: self.burst,
+            last_refill: now,
+        });
+
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.requests_per_sec).min(self.burst);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / self.requests_per_sec,
+            ))
+        }
+    }
+
+    /// Drops buckets that have refilled completely, i.e. clients idle for at least
+    /// `burst / requests_per_sec`; a returning client starts from a full bucket anyway.
+    ///
+    /// # Returns
+    /// The number of buckets removed
+    pub fn remove_idle(&self) -> usize {
+        if self.requests_per_sec <= 0.0 {
+            return 0;
+        }
+
+        let refill_time = Duration::from_secs_f64(self.burst / self.requests_per_sec);
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        let before = buckets.len();
+        buckets.retain(|_, bucket| now.duration_since(bucket.last_refill) < refill_time);
+        before - buckets.len()
+    }
+
+    /// Number of clients currently tracked.
+    pub fn tracked_clients(&self) -> usize {
+        self.buckets.lock().unwrap().len()
+    }
+
+    /// Spawns a background task that removes idle buckets every `interval`.
+    pub fn spawn_cleanup(self: &Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let limiter = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let removed = limiter.remove_idle();
+                if removed > 0 {
+                    debug!("Rate limiter: Removed {} idle client buckets", removed);
+                }
+            }
+        })
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_REQUESTS_PER_SEC, DEFAULT_BURST)
+    }
+}
+
+/// Client IP: with `trust_proxy`, the last `X-Forwarded-For` entry (appended by the
+/// proxy); otherwise, or if that entry is missing, the socket peer address.
+///
+/// Requests with neither (e.g. a server started without connect info) share one bucket.
+fn client_ip(headers: &HeaderMap, peer: Option<SocketAddr>, trust_proxy: bool) -> IpAddr {
+    let forwarded = || {
+        headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .next_back()
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.rsplit(',').next())
+            .and_then(|last| last.trim().parse().ok())
+    };
+    trust_proxy
+        .then(forwarded)
+        .flatten()
+        .or_else(|| peer.map(|addr| addr.ip()))
+        .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED))
+}
+
+/// Refuses requests over the client's rate with 429 and `Retry-After`.
+///
+/// # Example
+/// ```rust,ignore
+/// Router::new().route_layer(axum::middleware::from_fn_with_state(limiter, rate_limit))
+/// ```
+pub async fn rate_limit(
+    State(limiter): State<Arc<RateLimiter>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| *addr);
+    let client = client_ip(request.headers(), peer, limiter.trust_proxy);
+
+    match limiter.check(client) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            // Round up so clients never retry before a token is available
+            let retry_after_secs = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+            warn!(
+                "Rate limit: {} exceeded limit, retry after {}s",
+                client, retry_after_secs
+            );
+
+            let mut response = ApiError::new(
+                StatusCode::TOO_MANY_REQUESTS,
+                "rate_limited",
+                format!("Too many requests, retry after {}s", retry_after_secs),
+            )
+            .into_response();
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(retry_after_secs));
+            response
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, routing::get, Router};
+    use tower::ServiceExt;
+
+    /// A request the proxy tagged with client address `ip`
+    fn request_from(ip: &str) -> Request {
+        request_from_with("10.0.0.1", ip)
+    }
+
+    /// A request whose client claimed `claimed` and which the proxy tagged with `ip`
+    fn request_from_with(claimed: &str, ip: &str) -> Request {
+        Request::builder()
+            .uri("/api/ping")
+            .header("x-forwarded-for", format!("{}, {}", claimed, ip))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    /// A request arriving straight from `peer`, with a client-chosen `X-Forwarded-For`
+    fn direct_request(peer: &str, forwarded_for: &str) -> Request {
+        let mut request = Request::builder()
+            .uri("/api/ping")
+            .header("x-forwarded-for", forwarded_for)
+            .body(Body::empty())
+            .unwrap();
+        let peer: SocketAddr = peer.parse().unwrap();
+        request.extensions_mut().insert(ConnectInfo(peer));
+        request
+    }
+
+    #[tokio::test]
+    async fn limits_each_client_to_its_burst() {
+        let limiter = Arc::new(RateLimiter::new(1.0, 2).with_trust_proxy(true));
+        let app = Router::new()
+            .route("/api/ping", get(|| async { "pong" }))
+            .route_layer(axum::middleware::from_fn_with_state(limiter.clone(), rate_limit));
+
+        for _ in 0..2 {
+            let response = app.clone().oneshot(request_from("203.0.113.7")).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let response = app.clone().oneshot(request_from("203.0.113.7")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers().get(header::RETRY_AFTER).unwrap(), "1");
+
+        // Another client has its own bucket
+        let response = app.clone().oneshot(request_from("203.0.113.8")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(limiter.tracked_clients(), 2);
+    }
+
+    #[test]
+    fn refills_over_time_and_drops_idle_buckets() {
+        let limiter = RateLimiter::new(50.0, 1);
+        let client = IpAddr::V4(Ipv4Addr::new(198, 51, 100, 1));
+
+        assert!(limiter.check(client).is_ok());
+        assert!(limiter.check(client).is_err());
+        assert_eq!(limiter.remove_idle(), 0, "bucket is not full yet");
+
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(limiter.remove_idle(), 1);
+        assert_eq!(limiter.tracked_clients(), 0);
+        assert!(limiter.check(client).is_ok());
+    }
+
+    #[tokio::test]
+    async fn spoofed_forwarded_for_does_not_escape_the_limit() {
+        for trust_proxy in [false, true] {
+            let limiter = Arc::new(RateLimiter::new(1.0, 2).with_trust_proxy(trust_proxy));
+            let app = Router::new()
+                .route("/api/ping", get(|| async { "pong" }))
+                .route_layer(axum::middleware::from_fn_with_state(limiter.clone(), rate_limit));
+
+            // Directly connected, or through the proxy: a new forged address every request
+            let request = |i: u32| {
+                let forged = format!("198.51.100.{}", i);
+                if trust_proxy {
+                    request_from_with(&forged, "203.0.113.7")
+                } else {
+                    direct_request("203.0.113.7:4000", &forged)
+                }
+            };
+            for i in 0..2 {
+                let response = app.clone().oneshot(request(i)).await.unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+            }
+            let response = app.clone().oneshot(request(2)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS, "trust_proxy: {}", trust_proxy);
+            assert_eq!(limiter.tracked_clients(), 1);
+        }
+    }
+
+    #[test]
+    fn uses_forwarded_for_only_behind_a_trusted_proxy() {
+        let peer: SocketAddr = "10.0.0.2:5000".parse().unwrap();
+        let mut headers = HeaderMap::new();
+        assert_eq!(client_ip(&headers, Some(peer), true), peer.ip());
+
+        headers.insert("x-forwarded-for", HeaderValue::from_static("192.0.2.4, 198.51.100.9"));
+        assert_eq!(client_ip(&headers, Some(peer), false), peer.ip());
+        assert_eq!(client_ip(&headers, Some(peer), true), "198.51.100.9".parse::<IpAddr>().unwrap());
+
+        // A client-sent header the proxy appended another one after
+        headers.append("x-forwarded-for", HeaderValue::from_static("203.0.113.5"));
+        assert_eq!(client_ip(&headers, Some(peer), true), "203.0.113.5".parse::<IpAddr>().unwrap());
+    }
+}
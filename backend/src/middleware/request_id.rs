@@ -0,0 +1,102 @@
+//! Request ID and latency logging middleware.
+//!
+//! Every request gets a fresh UUID that is returned in the `X-Request-Id` response
+//! header and recorded on a tracing span together with the method and path. When
+//! the response is ready, its status and the elapsed time are logged inside that
+//! span, so a request ID reported by the frontend leads straight to the backend
+//! log lines for that request, and per-endpoint latency is visible in the logs.
+//! The ID is also available to the handler's task through [`current_request_id`],
+//! which is how `ApiError` bodies carry it.
+
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use std::time::Instant;
+use tracing::{info, info_span, Instrument};
+use uuid::Uuid;
+
+/// Response header carrying the request ID.
+pub const X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Request ID stored in request extensions, for handlers that want to log or return it.
+#[derive(Clone, Debug)]
+pub struct RequestId(pub String);
+
+tokio::task_local! {
+    static CURRENT_REQUEST_ID: String;
+}
+
+/// ID of the request being handled by the current task, if it passed through [`request_id`].
+pub fn current_request_id() -> Option<String> {
+    CURRENT_REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Tags the request with a UUID, logs method/path/status/latency and sets `X-Request-Id`.
+///
+/// # Example
+/// ```rust,ignore
+/// Router::new().layer(axum::middleware::from_fn(request_id))
+/// ```
+pub async fn request_id(mut request: Request, next: Next) -> Response {
+    let id = Uuid::new_v4().to_string();
+    let span = info_span!(
+        "request",
+        request_id = %id,
+        method = %request.method(),
+        path = %request.uri().path(),
+    );
+    request.extensions_mut().insert(RequestId(id.clone()));
+
+    let start = Instant::now();
+    let mut response = CURRENT_REQUEST_ID
+        .scope(id.clone(), next.run(request))
+        .instrument(span.clone())
+        .await;
+    let elapsed = start.elapsed();
+
+    span.in_scope(|| {
+        info!(
+            status = response.status().as_u16(),
+            elapsed_ms = elapsed.as_secs_f64() * 1000.0,
+            "Request completed"
+        );
+    });
+
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(X_REQUEST_ID, value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, routing::get, Router};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn sets_well_formed_request_id_header() {
+        let app = Router::new()
+            .route("/ping", get(|| async { "pong" }))
+            .layer(axum::middleware::from_fn(request_id));
+
+        let mut ids = Vec::new();
+        for _ in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri("/ping").body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+
+            let header = response.headers().get(X_REQUEST_ID).expect("X-Request-Id header");
+            let id = Uuid::parse_str(header.to_str().unwrap()).expect("UUID request id");
+            assert_eq!(id.get_version_num(), 4);
+            ids.push(id);
+        }
+
+        assert_ne!(ids[0], ids[1]);
+    }
+}
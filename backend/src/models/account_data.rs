@@ -0,0 +1,124 @@
+//! Raw on-chain account data decoding.
+//!
+//! Anchor accounts (and emitted events) are stored as an 8-byte discriminator
+//! followed by the Borsh encoding of the struct. This module provides a small cursor
+//! for reading that encoding field by field, so models can decode only the fields
+//! they expose.
+
+use solana_pubkey::Pubkey;
+
+/// Length of the Anchor account discriminator prefix.
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+/// Sequential reader over Borsh-encoded account data.
+///
+/// Every read advances the cursor and fails with a descriptive error if the
+/// buffer is too short, rather than panicking on malformed accounts.
+pub struct AccountReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> AccountReader<'a> {
+    /// Creates a reader positioned after the discriminator, verifying it matches.
+    ///
+    /// # Arguments
+    /// * `data` - Full account data including the discriminator
+    /// * `discriminator` - Expected Anchor discriminator for the account type
+    pub fn new(data: &'a [u8], discriminator: &[u8; DISCRIMINATOR_LEN]) -> Result<Self, String> {
+        if data.len() < DISCRIMINATOR_LEN || &data[..DISCRIMINATOR_LEN] != discriminator {
+            return Err("Account discriminator mismatch".to_string());
+        }
+
+        Ok(Self {
+            data,
+            offset: DISCRIMINATOR_LEN,
+        })
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| format!("Account data too short at offset {}", self.offset))?;
+
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, String> {
+        Ok(self.read_u8()? != 0)
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64, String> {
+        Ok(i64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    pub fn read_pubkey(&mut self) -> Result<Pubkey, String> {
+        Ok(Pubkey::new_from_array(self.read_bytes32()?))
+    }
+
+    pub fn read_bytes32(&mut self) -> Result<[u8; 32], String> {
+        Ok(self.take(32)?.try_into().unwrap())
+    }
+
+    pub fn read_option_pubkey(&mut self) -> Result<Option<Pubkey>, String> {
+        match self.read_u8()? {
+            0 => Ok(None),
+            1 => Ok(Some(self.read_pubkey()?)),
+            tag => Err(format!("Invalid Option tag: {}", tag)),
+        }
+    }
+
+    pub fn read_option_u8(&mut self) -> Result<Option<u8>, String> {
+        match self.read_u8()? {
+            0 => Ok(None),
+            1 => Ok(Some(self.read_u8()?)),
+            tag => Err(format!("Invalid Option tag: {}", tag)),
+        }
+    }
+
+    pub fn read_pubkey_vec(&mut self) -> Result<Vec<Pubkey>, String> {
+        let len = self.read_u32()? as usize;
+        (0..len).map(|_| self.read_pubkey()).collect()
+    }
+
+    pub fn read_string(&mut self) -> Result<String, String> {
+        let len = self.read_u32()? as usize;
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|e| format!("Invalid UTF-8 string: {}", e))
+    }
+
+    pub fn read_u8_vec(&mut self) -> Result<Vec<u8>, String> {
+        let len = self.read_u32()? as usize;
+        Ok(self.take(len)?.to_vec())
+    }
+
+    pub fn read_u16_vec(&mut self) -> Result<Vec<u16>, String> {
+        let len = self.read_u32()? as usize;
+        (0..len).map(|_| self.read_u16()).collect()
+    }
+
+    pub fn read_u64_vec(&mut self) -> Result<Vec<u64>, String> {
+        let len = self.read_u32()? as usize;
+        (0..len).map(|_| self.read_u64()).collect()
+    }
+}
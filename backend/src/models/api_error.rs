@@ -0,0 +1,157 @@
+//! Unified error response for API handlers.
+//!
+//! Every failing `/api/*` request answers with the same body shape:
+//! `{"error": {"code": "...", "message": "...", "request_id": "..."}}`. The
+//! `request_id` matches the `X-Request-Id` response header and the tracing span of
+//! the request, so an error reported by the frontend leads straight to its logs.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Json, Response},
+};
+use serde::Serialize;
+
+use crate::middleware::request_id::current_request_id;
+
+/// Error returned by API handlers: HTTP status, stable machine-readable code and message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub code: &'static str,
+    pub message: String,
+}
+
+/// JSON body of an error response.
+#[derive(Serialize, Debug)]
+pub struct ApiErrorBody {
+    pub error: ApiErrorDetail,
+}
+
+/// Error details, including the request ID the error can be correlated by.
+#[derive(Serialize, Debug)]
+pub struct ApiErrorDetail {
+    pub code: &'static str,
+    pub message: String,
+    pub request_id: Option<String>,
+}
+
+impl ApiError {
+    /// Creates an error with an explicit status and code.
+    pub fn new(status: StatusCode, code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            code,
+            message: message.into(),
+        }
+    }
+
+    /// `400 Bad Request` for invalid parameters or bodies.
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "bad_request", message)
+    }
+
+    /// `404 Not Found` when the requested account or record does not exist.
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::NOT_FOUND, "not_found", message)
+    }
+
+    /// `409 Conflict` when the resource is not in the state the request needs.
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::CONFLICT, "conflict", message)
+    }
+
+    /// `400 Bad Request` for a path or query parameter that is not a base58 pubkey.
+    pub fn invalid_pubkey(field: &str) -> Self {
+        Self::bad_request(format!("Invalid pubkey for field: {}", field))
+    }
+
+    /// Maps a SolanaService error to a 503 (RPC unreachable) or 502 (RPC error).
+    pub fn rpc(err: String) -> Self {
+        if err.contains("connect") {
+            Self::new(StatusCode::SERVICE_UNAVAILABLE, "rpc_unavailable", err)
+        } else {
+            Self::new(StatusCode::BAD_GATEWAY, "rpc_error", err)
+        }
+    }
+
+    /// Maps a TgbClient error to 429 (still rate limited), 504 (timed out),
+    /// 503 (unreachable or circuit open) or 502 (TGB error).
+    pub fn tgb(err: String) -> Self {
+        if err.contains("rate limited") {
+            Self::new(StatusCode::TOO_MANY_REQUESTS, "tgb_rate_limited", err)
+        } else if err.contains("timed out") {
+            Self::new(StatusCode::GATEWAY_TIMEOUT, "tgb_timeout", err)
+        } else if err.contains("connect") || err.contains("circuit open") {
+            Self::new(StatusCode::SERVICE_UNAVAILABLE, "tgb_unavailable", err)
+        } else {
+            Self::new(StatusCode::BAD_GATEWAY, "tgb_error", err)
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = ApiErrorBody {
+            error: ApiErrorDetail {
+                code: self.code,
+                message: self.message,
+                request_id: current_request_id(),
+            },
+        };
+        (self.status, Json(body)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::middleware::{request_id, X_REQUEST_ID};
+    use axum::{body::Body, extract::Request, routing::get, Router};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn error_body_carries_the_request_id() {
+        let app = Router::new()
+            .route(
+                "/api/room",
+                get(|| async { Err::<(), _>(ApiError::rpc("RPC error: boom".to_string())) }),
+            )
+            .layer(axum::middleware::from_fn(request_id));
+
+        let response = app
+            .oneshot(Request::builder().uri("/api/room").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+        let header = response.headers().get(X_REQUEST_ID).unwrap().to_str().unwrap().to_string();
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "error": {
+                    "code": "rpc_error",
+                    "message": "RPC error: boom",
+                    "request_id": header,
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn maps_upstream_errors_to_statuses() {
+        assert_eq!(
+            ApiError::rpc("Failed to connect to Solana RPC".to_string()).status,
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+        assert_eq!(
+            ApiError::tgb("TGB API rate limited".to_string()).status,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(
+            ApiError::tgb("TGB API circuit open".to_string()).code,
+            "tgb_unavailable"
+        );
+    }
+}
@@ -22,6 +22,21 @@ pub struct Charity {
     pub categories: Vec<String>,
 }
 
+/// One page of charity search results.
+///
+/// # Fields
+/// * `charities` - Matching charities in this page
+/// * `total` - Number of charities matching the query across all pages
+/// * `limit` - Page size that was applied
+/// * `offset` - Number of matching charities skipped before this page
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CharityPage {
+    pub charities: Vec<Charity>,
+    pub total: usize,
+    pub limit: usize,
+    pub offset: usize,
+}
+
 impl Charity {
     /// Creates a new Charity instance.
     pub fn new(
@@ -0,0 +1,179 @@
+//! Live charity impact of a room before it ends.
+//!
+//! Applies `end_room`'s split to the room's current counters, using the room's own
+//! platform fee (which the admin may have lowered for the room): platform, host and
+//! prize shares are taken from entry fees only, charity gets the remainder plus
+//! 100% of extras, and a host-escrowed minimum prize tops up a short pool with
+//! any unused escrow going to charity, as does the prize split's rounding dust.
+//! A host fee the host waived goes to charity too, as do penalties kept from
+//! players who left the room.
+
+use serde::Serialize;
+
+use super::RoomAccount;
+use crate::utils::fees::preview_fee_distribution;
+
+/// What `end_room` would transfer if the room ended now.
+///
+/// # Fields
+/// * `room` - Room PDA address (base58)
+/// * `total_entry_fees` / `total_extras_fees` - Room counters the preview is based on
+/// * `platform_fee` / `host_fee` - Shares of entry fees (`host_fee` is 0 once waived)
+/// * `prize_pool` - Prize pool, including any guaranteed minimum top-up
+/// * `charity` - Total donation: entry-fee remainder, extras, unused escrow, prize dust
+///   and leave penalties
+/// * `charity_percentage` - Share of all distributed funds going to charity (0-100)
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct CharityPreview {
+    pub room: String,
+    pub total_entry_fees: u64,
+    pub total_extras_fees: u64,
+    pub platform_fee: u64,
+    pub host_fee: u64,
+    pub prize_pool: u64,
+    pub charity: u64,
+    pub charity_percentage: f64,
+}
+
+impl CharityPreview {
+    /// Builds the preview from a room's current state.
+    ///
+    /// # Returns
+    /// * `Some(CharityPreview)` - The preview
+    /// * `None` - Where `end_room` would fail with an arithmetic error
+    pub fn from_room(room: &RoomAccount) -> Option<Self> {
+        let split = preview_fee_distribution(
+            room.total_entry_fees,
+            room.total_extras_fees,
+            room.platform_fee_bps,
+            room.host_fee_bps,
+            room.prize_pool_bps,
+            room.effective_prize_distribution(),
+            room.guaranteed_min_prize,
+        )?;
+
+        // Waived host fee is donated instead of paid out
+        let (host_fee, host_fee_to_charity) = if room.host_fee_waived {
+            (0, split.host_fee)
+        } else {
+            (split.host_fee, 0)
+        };
+
+        let prize_pool = split.prize_pool;
+        let charity = split
+            .charity
+            .checked_add(host_fee_to_charity)?
+            .checked_add(room.total_leave_penalties)?;
+
+        let distributed = split.platform_fee as u128 + host_fee as u128 + prize_pool as u128 + charity as u128;
+        let charity_percentage = if distributed == 0 {
+            0.0
+        } else {
+            charity as f64 * 100.0 / distributed as f64
+        };
+
+        Some(Self {
+            room: room.pubkey.clone(),
+            total_entry_fees: room.total_entry_fees,
+            total_extras_fees: room.total_extras_fees,
+            platform_fee: split.platform_fee,
+            host_fee,
+            prize_pool,
+            charity,
+            charity_percentage,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RoomStatus;
+
+    fn room(entry_fees: u64, extras: u64, guaranteed_min_prize: u64, platform_fee_bps: u16) -> RoomAccount {
+        RoomAccount {
+            pubkey: "Room111".to_string(),
+            room_id: "quiz-night".to_string(),
+            host: "Host111".to_string(),
+            charity_wallet: "Charity111".to_string(),
+            fee_token_mint: "Mint111".to_string(),
+            entry_fee: 10_000_000,
+            host_fee_bps: 500,
+            prize_pool_bps: 3500,
+            prize_distribution: vec![60, 30, 10],
+            max_players: 10,
+            player_count: 1,
+            status: RoomStatus::Active,
+            total_collected: entry_fees + extras,
+            total_entry_fees: entry_fees,
+            total_extras_fees: extras,
+            ended: false,
+            creation_slot: 100,
+            expiration_slot: 0,
+            expiration_unix_ts: 0,
+            guaranteed_min_prize,
+            platform_fee_bps,
+            prize_tiers: Vec::new(),
+            host_fee_waived: false,
+            early_bird_fee: 0,
+            early_bird_count: 0,
+            discount_bps: 0,
+            total_discounts: 0,
+            place_sizes: Vec::new(),
+            room_version: 3,
+            leave_penalty_bps: 0,
+            total_leave_penalties: 0,
+            total_donations: 0,
+        }
+    }
+
+    #[test]
+    fn charity_gets_entry_remainder_plus_extras() {
+        // 10 USDC entry: platform 2, host 0.5, prizes 3.5, charity 4 + 10 extras
+        let preview = CharityPreview::from_room(&room(10_000_000, 10_000_000, 0, 2000)).unwrap();
+
+        assert_eq!(preview.platform_fee, 2_000_000);
+        assert_eq!(preview.host_fee, 500_000);
+        assert_eq!(preview.prize_pool, 3_500_000);
+        assert_eq!(preview.charity, 14_000_000);
+        assert!((preview.charity_percentage - 70.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn guaranteed_prize_tops_up_pool_and_sends_unused_escrow_to_charity() {
+        // Pool 3.5 < guarantee 5: prize 5, escrow to charity 3.5
+        let preview = CharityPreview::from_room(&room(10_000_000, 0, 5_000_000, 2000)).unwrap();
+        assert_eq!(preview.prize_pool, 5_000_000);
+        assert_eq!(preview.charity, 7_500_000);
+
+        // Empty room: nothing distributed yet
+        let empty = CharityPreview::from_room(&room(0, 0, 0, 2000)).unwrap();
+        assert_eq!(empty.charity, 0);
+        assert_eq!(empty.charity_percentage, 0.0);
+    }
+
+    #[test]
+    fn platform_fee_override_goes_to_charity() {
+        // Partner rate of 10%: the waived 1 USDC of platform fee is donated
+        let standard = CharityPreview::from_room(&room(10_000_000, 0, 0, 2000)).unwrap();
+        let partner = CharityPreview::from_room(&room(10_000_000, 0, 0, 1000)).unwrap();
+
+        assert_eq!(partner.platform_fee, 1_000_000);
+        assert_eq!(partner.charity, standard.charity + 1_000_000);
+        assert_eq!(partner.prize_pool, standard.prize_pool);
+    }
+
+    #[test]
+    fn waived_host_fee_goes_to_charity() {
+        let standard = CharityPreview::from_room(&room(10_000_000, 0, 0, 2000)).unwrap();
+        let waived = CharityPreview::from_room(&RoomAccount {
+            host_fee_waived: true,
+            ..room(10_000_000, 0, 0, 2000)
+        })
+        .unwrap();
+
+        assert_eq!(waived.host_fee, 0);
+        assert_eq!(waived.charity, standard.charity + 500_000);
+        assert!((waived.charity_percentage - 45.0).abs() < 1e-9);
+    }
+}
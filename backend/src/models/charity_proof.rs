@@ -0,0 +1,306 @@
+//! Proof that a room's charity donation reached the charity wallet.
+//!
+//! `end_room` emits a `RoomEnded` event with the amount it intends to send to each
+//! charity, and performs the transfers in the same transaction. The token balance
+//! changes recorded in that transaction's metadata show which token account owned
+//! by the charity wallet received funds and how much, so the two can be compared
+//! without trusting the program's own logs alone.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solana_pubkey::Pubkey;
+
+use super::ProgramEvent;
+
+/// Subset of a `getTransaction` result (`json` encoding) needed to verify transfers.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmedTransaction {
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub transaction: TransactionBody,
+    pub meta: Option<TransactionStatusMeta>,
+}
+
+/// Transaction envelope; only the account keys are read.
+#[derive(Deserialize, Debug, Clone)]
+pub struct TransactionBody {
+    pub message: TransactionMessage,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionMessage {
+    pub account_keys: Vec<String>,
+}
+
+/// Execution metadata: error, logs and token balances before and after.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionStatusMeta {
+    pub err: Option<Value>,
+    #[serde(default)]
+    pub log_messages: Option<Vec<String>>,
+    #[serde(default)]
+    pub pre_token_balances: Vec<TokenBalance>,
+    #[serde(default)]
+    pub post_token_balances: Vec<TokenBalance>,
+    #[serde(default)]
+    pub loaded_addresses: Option<LoadedAddresses>,
+}
+
+/// Accounts loaded from address lookup tables (versioned transactions).
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct LoadedAddresses {
+    #[serde(default)]
+    pub writable: Vec<String>,
+    #[serde(default)]
+    pub readonly: Vec<String>,
+}
+
+/// Token account balance at one point of the transaction.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenBalance {
+    pub account_index: usize,
+    pub mint: String,
+    pub owner: Option<String>,
+    pub ui_token_amount: UiTokenAmount,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UiTokenAmount {
+    /// Raw amount in base units, as a decimal string
+    pub amount: String,
+}
+
+impl ConfirmedTransaction {
+    /// Returns the program events emitted by this transaction.
+    pub fn events(&self) -> Vec<ProgramEvent> {
+        self.meta
+            .as_ref()
+            .and_then(|meta| meta.log_messages.as_deref())
+            .map(ProgramEvent::from_logs)
+            .unwrap_or_default()
+    }
+
+    /// Finds the token account owned by `owner` for `mint` whose balance increased,
+    /// returning its address and the amount received.
+    pub fn token_credit(&self, owner: &str, mint: &str) -> Option<(String, u64)> {
+        let meta = self.meta.as_ref()?;
+        let account_keys = self.account_keys();
+
+        meta.post_token_balances
+            .iter()
+            .filter(|post| post.owner.as_deref() == Some(owner) && post.mint == mint)
+            .find_map(|post| {
+                let before = meta
+                    .pre_token_balances
+                    .iter()
+                    .find(|pre| pre.account_index == post.account_index)
+                    .map_or(0, TokenBalance::amount);
+                let received = post.amount().checked_sub(before).filter(|&amount| amount > 0)?;
+                let address = account_keys.get(post.account_index)?.clone();
+                Some((address, received))
+            })
+    }
+
+    /// Static account keys followed by lookup-table addresses, matching `accountIndex`.
+    fn account_keys(&self) -> Vec<String> {
+        let mut keys = self.transaction.message.account_keys.clone();
+        if let Some(loaded) = self.meta.as_ref().and_then(|meta| meta.loaded_addresses.as_ref()) {
+            keys.extend(loaded.writable.iter().cloned());
+            keys.extend(loaded.readonly.iter().cloned());
+        }
+        keys
+    }
+}
+
+impl TokenBalance {
+    fn amount(&self) -> u64 {
+        self.ui_token_amount.amount.parse().unwrap_or(0)
+    }
+}
+
+/// Verifiable record of a room's charity transfer.
+///
+/// # Fields
+/// * `room` - Room PDA address (base58)
+/// * `charity_wallet` - Charity wallet configured on the room
+/// * `signature` - Signature of the `end_room` transaction
+/// * `slot` / `block_time` - When the transaction was confirmed
+/// * `charity_amount` - Amount `end_room` reported for the room's charity wallet (the first
+///   charity of a multi-charity room) in its `RoomEnded` event
+/// * `charity_token_account` - Charity-owned token account that received funds, if any
+/// * `transferred_amount` - Amount that account actually received in the transaction
+/// * `verified` - Whether the transfer matches the reported amount
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CharityProof {
+    pub room: String,
+    pub charity_wallet: String,
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub charity_amount: u64,
+    pub charity_token_account: Option<String>,
+    pub transferred_amount: u64,
+    pub verified: bool,
+}
+
+impl CharityProof {
+    /// Builds the proof from a room's `end_room` transaction.
+    ///
+    /// # Returns
+    /// * `Some(CharityProof)` - If the transaction succeeded and emitted `RoomEnded` for `room`
+    /// * `None` - If this is not the room's end transaction
+    pub fn from_end_transaction(
+        room: &Pubkey,
+        charity_wallet: &str,
+        fee_token_mint: &str,
+        signature: &str,
+        transaction: &ConfirmedTransaction,
+    ) -> Option<Self> {
+        if transaction.meta.as_ref()?.err.is_some() {
+            return None;
+        }
+
+        let charity_amount = transaction.events().into_iter().find_map(|event| match event {
+            ProgramEvent::RoomEnded {
+                room: ended_room,
+                charity_amount,
+                charity_amounts,
+                ..
+            } if ended_room == room.to_string() => {
+                Some(charity_amounts.first().copied().unwrap_or(charity_amount))
+            }
+            _ => None,
+        })?;
+
+        let (charity_token_account, transferred_amount) =
+            match transaction.token_credit(charity_wallet, fee_token_mint) {
+                Some((account, amount)) => (Some(account), amount),
+                None => (None, 0),
+            };
+
+        Some(Self {
+            room: room.to_string(),
+            charity_wallet: charity_wallet.to_string(),
+            signature: signature.to_string(),
+            slot: transaction.slot,
+            block_time: transaction.block_time,
+            charity_amount,
+            charity_token_account,
+            transferred_amount,
+            verified: transferred_amount == charity_amount,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::program_event::ROOM_ENDED_DISCRIMINATOR;
+    use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+    use serde_json::json;
+
+    fn room_ended_log(room: &Pubkey, charity_amount: u64) -> String {
+        let mut payload = ROOM_ENDED_DISCRIMINATOR.to_vec();
+        payload.extend_from_slice(room.as_ref());
+        payload.extend_from_slice(&1u32.to_le_bytes()); // winners: Vec<Pubkey> of 1
+        payload.extend_from_slice(Pubkey::new_unique().as_ref());
+        payload.extend_from_slice(&1u32.to_le_bytes()); // winner_amounts: Vec<u64> of 1
+        payload.extend_from_slice(&2_000_000u64.to_le_bytes());
+        payload.extend_from_slice(&2_000_000u64.to_le_bytes()); // platform_amount
+        payload.extend_from_slice(&300_000u64.to_le_bytes()); // host_amount
+        payload.extend_from_slice(Pubkey::new_unique().as_ref()); // charity_wallet
+        payload.extend_from_slice(&charity_amount.to_le_bytes());
+        payload.extend_from_slice(&1u32.to_le_bytes()); // charity_amounts: Vec<u64> of 1
+        payload.extend_from_slice(&charity_amount.to_le_bytes());
+        payload.extend_from_slice(&2_000_000u64.to_le_bytes()); // prize_amount
+        payload.extend_from_slice(&1u32.to_le_bytes()); // prize_distribution
+        payload.extend_from_slice(&100u16.to_le_bytes());
+        payload.extend_from_slice(&1u32.to_le_bytes()); // total_players
+        payload.push(0); // expired_finalization
+        payload.push(0); // host_fee_waived
+        payload.extend_from_slice(&charity_amount.to_le_bytes()); // charity_from_entry
+        payload.extend_from_slice(&0u64.to_le_bytes()); // charity_from_extras
+        payload.extend_from_slice(&0u64.to_le_bytes()); // charity_from_leave_penalties
+        payload.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        format!("Program data: {}", BASE64.encode(payload))
+    }
+
+    /// `getTransaction` result for an end_room that sends 5.7 tokens to charity.
+    fn end_transaction(room: &Pubkey, charity: &str, mint: &str, reported: u64) -> ConfirmedTransaction {
+        let charity_ata = "CharityAta1111111111111111111111111111111111";
+        let vault = "Vau1t11111111111111111111111111111111111111";
+        let token_balance = |index: usize, owner: &str, amount: u64| {
+            json!({
+                "accountIndex": index,
+                "mint": mint,
+                "owner": owner,
+                "uiTokenAmount": { "amount": amount.to_string(), "decimals": 6 }
+            })
+        };
+
+        serde_json::from_value(json!({
+            "slot": 4242,
+            "blockTime": 1_700_000_000,
+            "transaction": {
+                "message": { "accountKeys": ["Host111", vault, charity_ata, "Token111"] },
+                "signatures": ["sig"]
+            },
+            "meta": {
+                "err": null,
+                "logMessages": [
+                    "Program log: Instruction: EndRoom",
+                    room_ended_log(room, reported),
+                ],
+                "preTokenBalances": [
+                    token_balance(1, &room.to_string(), 10_000_000),
+                    token_balance(2, charity, 1_000_000),
+                ],
+                "postTokenBalances": [
+                    token_balance(1, &room.to_string(), 0),
+                    token_balance(2, charity, 6_700_000),
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn proves_charity_transfer_from_end_transaction() {
+        let room = Pubkey::new_unique();
+        let charity = Pubkey::new_unique().to_string();
+        let mint = Pubkey::new_unique().to_string();
+        let tx = end_transaction(&room, &charity, &mint, 5_700_000);
+
+        let proof = CharityProof::from_end_transaction(&room, &charity, &mint, "sig", &tx).unwrap();
+
+        assert_eq!(proof.charity_amount, 5_700_000);
+        assert_eq!(proof.transferred_amount, 5_700_000);
+        assert_eq!(
+            proof.charity_token_account.as_deref(),
+            Some("CharityAta1111111111111111111111111111111111")
+        );
+        assert_eq!(proof.slot, 4242);
+        assert!(proof.verified);
+    }
+
+    #[test]
+    fn flags_mismatch_and_ignores_other_transactions() {
+        let room = Pubkey::new_unique();
+        let charity = Pubkey::new_unique().to_string();
+        let mint = Pubkey::new_unique().to_string();
+
+        // Event claims more than the charity account received
+        let tx = end_transaction(&room, &charity, &mint, 6_000_000);
+        let proof = CharityProof::from_end_transaction(&room, &charity, &mint, "sig", &tx).unwrap();
+        assert_eq!(proof.transferred_amount, 5_700_000);
+        assert!(!proof.verified);
+
+        // Not this room's end transaction
+        let other_room = Pubkey::new_unique();
+        assert!(CharityProof::from_end_transaction(&other_room, &charity, &mint, "sig", &tx).is_none());
+    }
+}
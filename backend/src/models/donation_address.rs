@@ -4,6 +4,7 @@
 //! for a specific charity and cryptocurrency token.
 
 use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
 
 /// Represents a donation address for a charity on a specific blockchain network.
 ///
@@ -30,4 +31,35 @@ impl DonationAddress {
             network,
         }
     }
+
+    /// Whether this address is the given Solana wallet.
+    ///
+    /// Compares decoded pubkeys, so an address that isn't valid base58 (e.g. an
+    /// address on another network) never matches.
+    pub fn matches_wallet(&self, wallet: &Pubkey) -> bool {
+        self.address.parse::<Pubkey>().is_ok_and(|address| address == *wallet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(address: &str) -> DonationAddress {
+        DonationAddress::new(
+            "charity123".to_string(),
+            "USDC".to_string(),
+            address.to_string(),
+            "solana".to_string(),
+        )
+    }
+
+    #[test]
+    fn matches_only_the_same_wallet() {
+        let wallet = Pubkey::new_unique();
+
+        assert!(address(&wallet.to_string()).matches_wallet(&wallet));
+        assert!(!address(&Pubkey::new_unique().to_string()).matches_wallet(&wallet));
+        assert!(!address("0x742d35Cc6634C0532925a3b844Bc454e4438f44e").matches_wallet(&wallet));
+    }
 }
@@ -0,0 +1,130 @@
+//! Global configuration model mirroring the on-chain GlobalConfig PDA.
+//!
+//! GlobalConfig holds the platform-wide fee limits, wallets and policy flags that
+//! every room is validated against, plus lifetime totals across all ended rooms.
+//! Settings change only when the admin calls `update_config` and the totals only
+//! when a room ends, so it is cached for a long time and evicted on `ConfigUpdated`
+//! and `PlatformStats`.
+
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+
+use super::account_data::AccountReader;
+
+/// Anchor discriminator for the `GlobalConfig` account (`sha256("account:GlobalConfig")[..8]`).
+pub const GLOBAL_CONFIG_DISCRIMINATOR: [u8; 8] = [149, 8, 156, 202, 160, 252, 176, 217];
+
+/// Platform configuration read from the Fundraisely program.
+///
+/// # Fields
+/// * `pubkey` - GlobalConfig PDA address (base58)
+/// * `admin` - Admin allowed to update the configuration
+/// * `platform_wallet` / `charity_wallet` - Default fee and donation recipients
+/// * `platform_fee_bps` - Platform's share of entry fees
+/// * `max_host_fee_bps` / `max_prize_pool_bps` - Upper limits for room fee shares
+/// * `min_charity_bps` - Minimum charity share of entry fees
+/// * `emergency_pause` - Whether room creation and joins are halted
+/// * `require_declaration` - Whether `end_room` requires `declare_winners` first
+/// * `receipt_attestor` - Key allowed to record charity receipts, if any
+/// * `max_approved_tokens` - How many tokens the token registry may hold
+/// * `min_platform_fee_bps` - Lowest platform fee the admin may set on a room
+/// * `lifetime_charity_raised` - Total sent to charity by all ended rooms (base units)
+/// * `lifetime_rooms_ended` / `lifetime_players` - Rooms ended and their players, all time
+/// * `max_expiration_slots` - Longest expiration a room may be created with, in slots
+/// * `default_expiration_slots` - Expiration of rooms created without a deadline, in slots
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct GlobalConfigAccount {
+    pub pubkey: String,
+    pub admin: String,
+    pub platform_wallet: String,
+    pub charity_wallet: String,
+    pub platform_fee_bps: u16,
+    pub max_host_fee_bps: u16,
+    pub max_prize_pool_bps: u16,
+    pub min_charity_bps: u16,
+    pub emergency_pause: bool,
+    pub require_declaration: bool,
+    pub receipt_attestor: Option<String>,
+    pub max_approved_tokens: u16,
+    pub min_platform_fee_bps: u16,
+    pub lifetime_charity_raised: u64,
+    pub lifetime_rooms_ended: u64,
+    pub lifetime_players: u64,
+    pub max_expiration_slots: u64,
+    pub default_expiration_slots: u64,
+}
+
+impl GlobalConfigAccount {
+    /// Decodes a GlobalConfigAccount from raw GlobalConfig account data.
+    ///
+    /// # Returns
+    /// * `Ok(GlobalConfigAccount)` - Decoded configuration
+    /// * `Err(String)` - If the data is not a GlobalConfig account or is truncated
+    pub fn from_account_data(pubkey: &Pubkey, data: &[u8]) -> Result<Self, String> {
+        let mut reader = AccountReader::new(data, &GLOBAL_CONFIG_DISCRIMINATOR)?;
+
+        Ok(Self {
+            pubkey: pubkey.to_string(),
+            admin: reader.read_pubkey()?.to_string(),
+            platform_wallet: reader.read_pubkey()?.to_string(),
+            charity_wallet: reader.read_pubkey()?.to_string(),
+            platform_fee_bps: reader.read_u16()?,
+            max_host_fee_bps: reader.read_u16()?,
+            max_prize_pool_bps: reader.read_u16()?,
+            min_charity_bps: reader.read_u16()?,
+            emergency_pause: reader.read_bool()?,
+            require_declaration: reader.read_bool()?,
+            receipt_attestor: Some(reader.read_pubkey()?)
+                .filter(|attestor| *attestor != Pubkey::default())
+                .map(|attestor| attestor.to_string()),
+            max_approved_tokens: reader.read_u16()?,
+            min_platform_fee_bps: reader.read_u16()?,
+            lifetime_charity_raised: reader.read_u64()?,
+            lifetime_rooms_ended: reader.read_u64()?,
+            lifetime_players: reader.read_u64()?,
+            max_expiration_slots: reader.read_u64()?,
+            default_expiration_slots: reader.read_u64()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_global_config_layout() {
+        let admin = Pubkey::new_unique();
+        let mut data = GLOBAL_CONFIG_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(admin.as_ref());
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        for bps in [2000u16, 500, 3500, 4000] {
+            data.extend_from_slice(&bps.to_le_bytes());
+        }
+        data.extend_from_slice(&[0, 1]); // emergency_pause, require_declaration
+        data.extend_from_slice(Pubkey::default().as_ref()); // no attestor
+        data.extend_from_slice(&20u16.to_le_bytes()); // max_approved_tokens
+        data.extend_from_slice(&1000u16.to_le_bytes()); // min_platform_fee_bps
+        for lifetime in [125_000_000u64, 12, 340] {
+            data.extend_from_slice(&lifetime.to_le_bytes());
+        }
+        data.extend_from_slice(&6_480_000u64.to_le_bytes()); // max_expiration_slots
+        data.extend_from_slice(&1_512_000u64.to_le_bytes()); // default_expiration_slots
+        data.push(254); // bump
+
+        let config = GlobalConfigAccount::from_account_data(&Pubkey::new_unique(), &data).unwrap();
+
+        assert_eq!(config.admin, admin.to_string());
+        assert_eq!(config.platform_fee_bps, 2000);
+        assert_eq!(config.min_charity_bps, 4000);
+        assert!(!config.emergency_pause);
+        assert!(config.require_declaration);
+        assert_eq!(config.receipt_attestor, None);
+        assert_eq!(config.max_approved_tokens, 20);
+        assert_eq!(config.min_platform_fee_bps, 1000);
+        assert_eq!(config.lifetime_charity_raised, 125_000_000);
+        assert_eq!((config.lifetime_rooms_ended, config.lifetime_players), (12, 340));
+        assert_eq!((config.max_expiration_slots, config.default_expiration_slots), (6_480_000, 1_512_000));
+    }
+}
@@ -1,10 +1,38 @@
 //! Data models for the Fundraisely backend.
 //!
 //! This module contains all data structures used for API requests and responses,
-//! including charity information and donation addresses from The Giving Block API.
+//! including charity information and donation addresses from The Giving Block API,
+//! room and player entry accounts decoded from the Fundraisely Solana program,
+//! its events and its error codes, and the error body shared by all API handlers.
 
+pub mod account_data;
+pub mod api_error;
 pub mod charity;
+pub mod charity_preview;
+pub mod charity_proof;
 pub mod donation_address;
+pub mod global_config_account;
+pub mod player_entry_account;
+pub mod program_error;
+pub mod program_event;
+pub mod room_account;
+pub mod room_financials;
+pub mod room_full;
+pub mod room_outcome;
+pub mod timeline;
+pub mod token_registry_account;
 
-pub use charity::Charity;
+pub use api_error::ApiError;
+pub use charity::{Charity, CharityPage};
+pub use charity_preview::CharityPreview;
+pub use charity_proof::CharityProof;
 pub use donation_address::DonationAddress;
+pub use global_config_account::GlobalConfigAccount;
+pub use player_entry_account::PlayerEntryAccount;
+pub use program_event::ProgramEvent;
+pub use room_account::{PrizeTier, RoomAccount, RoomStatus};
+pub use room_financials::RoomFinancials;
+pub use room_full::RoomFull;
+pub use room_outcome::RoomOutcome;
+pub use timeline::TimelineEntry;
+pub use token_registry_account::{ApprovedToken, TokenRegistryAccount};
@@ -0,0 +1,117 @@
+//! Player entry model mirroring the on-chain PlayerEntry PDA.
+//!
+//! One PlayerEntry exists per (room, player) pair and records what the player
+//! paid to join. Listing a player's entries gives their full participation
+//! history across rooms.
+
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+
+use super::account_data::{AccountReader, DISCRIMINATOR_LEN};
+
+/// Anchor discriminator for the `PlayerEntry` account (`sha256("account:PlayerEntry")[..8]`).
+pub const PLAYER_ENTRY_DISCRIMINATOR: [u8; 8] = [158, 6, 39, 104, 234, 4, 153, 255];
+
+/// Byte offset of the `player` field (immediately after the discriminator).
+pub const PLAYER_ENTRY_PLAYER_OFFSET: usize = DISCRIMINATOR_LEN;
+
+/// Represents a player's entry into a room.
+///
+/// # Fields
+/// * `pubkey` - PlayerEntry PDA address (base58)
+/// * `player` - Player's wallet address (base58)
+/// * `room` - Room PDA the player joined (base58)
+/// * `entry_paid` - Entry fee paid, in token base units
+/// * `extras_paid` - Optional extras paid, in token base units
+/// * `total_paid` - Entry fee plus extras
+/// * `join_slot` - Slot at which the player joined
+/// * `ready` - Whether the player has readied up (turn-based rooms)
+/// * `referrer` - Wallet the player credited with referring them, if any (base58)
+/// * `discount_amount` - Entry fee waived by the player's discount, in token base units
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PlayerEntryAccount {
+    pub pubkey: String,
+    pub player: String,
+    pub room: String,
+    pub entry_paid: u64,
+    pub extras_paid: u64,
+    pub total_paid: u64,
+    pub join_slot: u64,
+    pub ready: bool,
+    pub referrer: Option<String>,
+    pub discount_amount: u64,
+}
+
+impl PlayerEntryAccount {
+    /// Decodes a PlayerEntryAccount from raw PlayerEntry account data.
+    ///
+    /// # Arguments
+    /// * `pubkey` - Address of the PlayerEntry PDA
+    /// * `data` - Raw account data including the Anchor discriminator
+    ///
+    /// # Returns
+    /// * `Ok(PlayerEntryAccount)` - Decoded entry
+    /// * `Err(String)` - If the data is not a PlayerEntry account or is truncated
+    pub fn from_account_data(pubkey: &Pubkey, data: &[u8]) -> Result<Self, String> {
+        let mut reader = AccountReader::new(data, &PLAYER_ENTRY_DISCRIMINATOR)?;
+
+        Ok(Self {
+            pubkey: pubkey.to_string(),
+            player: reader.read_pubkey()?.to_string(),
+            room: reader.read_pubkey()?.to_string(),
+            entry_paid: reader.read_u64()?,
+            extras_paid: reader.read_u64()?,
+            total_paid: reader.read_u64()?,
+            join_slot: reader.read_u64()?,
+            ready: reader.read_bool()?,
+            referrer: reader.read_option_pubkey()?.map(|p| p.to_string()),
+            discount_amount: reader.read_u64()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_player_entry_layout() {
+        let pubkey = Pubkey::new_unique();
+        let player = Pubkey::new_unique();
+        let room = Pubkey::new_unique();
+        let referrer = Pubkey::new_unique();
+
+        let mut data = PLAYER_ENTRY_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(player.as_ref());
+        data.extend_from_slice(room.as_ref());
+        data.extend_from_slice(&1_000_000u64.to_le_bytes()); // entry_paid
+        data.extend_from_slice(&250_000u64.to_le_bytes()); // extras_paid
+        data.extend_from_slice(&1_250_000u64.to_le_bytes()); // total_paid
+        data.extend_from_slice(&4_242u64.to_le_bytes()); // join_slot
+        data.push(1); // ready
+        data.push(1); // referrer: Some
+        data.extend_from_slice(referrer.as_ref());
+        data.extend_from_slice(&500_000u64.to_le_bytes()); // discount_amount
+        data.push(254); // bump
+
+        let entry = PlayerEntryAccount::from_account_data(&pubkey, &data).unwrap();
+
+        assert_eq!(entry.player, player.to_string());
+        assert_eq!(entry.room, room.to_string());
+        assert_eq!(entry.entry_paid, 1_000_000);
+        assert_eq!(entry.extras_paid, 250_000);
+        assert_eq!(entry.total_paid, 1_250_000);
+        assert_eq!(entry.join_slot, 4_242);
+        assert!(entry.ready);
+        assert_eq!(entry.referrer, Some(referrer.to_string()));
+        assert_eq!(entry.discount_amount, 500_000);
+        // The memcmp filter in `get_player_entries` relies on this offset
+        assert_eq!(&data[PLAYER_ENTRY_PLAYER_OFFSET..PLAYER_ENTRY_PLAYER_OFFSET + 32], player.as_ref());
+    }
+
+    #[test]
+    fn rejects_other_account_types() {
+        let data = [0u8; 114];
+        assert!(PlayerEntryAccount::from_account_data(&Pubkey::new_unique(), &data).is_err());
+    }
+}
@@ -0,0 +1,325 @@
+//! Fundraisely program error codes.
+//!
+//! Anchor assigns custom error codes sequentially from 6000 in the order the
+//! variants are declared in the program's `FundraiselyError` enum. These tables must
+//! stay in sync with `programs/fundraisely/src/errors.rs`; new variants are only
+//! ever appended there, so existing codes never shift. Besides the variant names,
+//! each variant's `#[msg]` string is kept so an opaque code like `0x1771` can be
+//! shown to users as "Room already exists".
+
+use serde_json::Value;
+
+/// First custom error code assigned by Anchor (`#[error_code]` offset).
+pub const ERROR_CODE_OFFSET: u32 = 6000;
+
+/// `FundraiselyError` variant names in declaration order.
+pub const PROGRAM_ERROR_NAMES: &[&str] = &[
+    "Unauthorized",
+    "RoomAlreadyExists",
+    "RoomNotFound",
+    "RoomNotReady",
+    "InvalidRoomStatus",
+    "RoomAlreadyEnded",
+    "RoomExpired",
+    "PlayerAlreadyJoined",
+    "HostCannotBeWinner",
+    "InvalidWinners",
+    "TokenNotApproved",
+    "TokenAlreadyApproved",
+    "TokenRegistryFull",
+    "InvalidEntryFee",
+    "HostFeeTooHigh",
+    "PrizePoolTooHigh",
+    "CharityBelowMinimum",
+    "TotalAllocationTooHigh",
+    "InvalidPrizeDistribution",
+    "InsufficientBalance",
+    "EmergencyPause",
+    "ArithmeticOverflow",
+    "ArithmeticUnderflow",
+    "InvalidRoomId",
+    "InvalidMemo",
+    "MaxPlayersReached",
+    "InvalidMaxPlayers",
+    "InvalidTokenMint",
+    "InvalidTokenOwner",
+    "WinnersAlreadyDeclared",
+    "InvalidPrizeAmount",
+    "PrizeAlreadyDeposited",
+    "PrizeNotDeposited",
+    "PrizesNotFullyFunded",
+    "RoomNotAbandoned",
+    "InvalidPlayerEntry",
+    "InvalidVaultAccount",
+    "InvalidVaultAuthority",
+    "WinnersNotDeclared",
+    "RoomFrozen",
+    "MinPrizeAlreadyFunded",
+    "HostTokenAccountRequired",
+    "RoomNotEnded",
+    "CharityReceiptAlreadyRecorded",
+    "InvalidReceiptHash",
+    "InvalidWinnerScores",
+    "InvalidCharitySplits",
+    "InvalidTokenSymbol",
+    "InvalidTokenRegistryCap",
+    "InvalidMinPlayers",
+    "InvalidRecoveryReason",
+    "RoomNotExpired",
+    "PlayersNotReady",
+    "PlayerAlreadyReady",
+    "NoPlayersToRefund",
+    "InvalidRefundAccounts",
+    "PlayerNotAllowlisted",
+    "PlayerBlacklisted",
+    "BlacklistFull",
+    "PlayerAlreadyBlacklisted",
+    "PlayerNotBlacklisted",
+    "WrongPrizeMode",
+    "NotAnNft",
+    "InvalidPlatformFee",
+    "UnsupportedMintExtension",
+    "InvalidPrizeTiers",
+    "AlreadyInitialized",
+    "InvalidMint",
+    "RoomHasPlayers",
+    "InvalidReferrer",
+    "InvalidEarlyBirdFee",
+    "InsufficientVaultBalance",
+    "InvalidDiscount",
+    "InvalidCharityWallet",
+    "InvalidTies",
+    "InvalidRemainingAccounts",
+    "VaultBalanceMismatch",
+    "UnsupportedRoomVersion",
+    "RoomAlreadyCurrent",
+    "InvalidLeavePenalty",
+    "InvalidDonationAmount",
+    "InvalidExpiration",
+    "CharityTokenAccountRequired",
+];
+
+/// `#[msg]` strings of the `FundraiselyError` variants, in declaration order.
+pub const PROGRAM_ERROR_MESSAGES: &[&str] = &[
+    "Unauthorized access",
+    "Room already exists",
+    "Room not found",
+    "Room not ready for players",
+    "Invalid room status",
+    "Room already ended",
+    "Room has expired",
+    "Player already joined",
+    "Host cannot be a winner",
+    "Invalid winners list",
+    "Token not approved",
+    "Token is already in the approved registry",
+    "Token registry is full (see GlobalConfig.max_approved_tokens)",
+    "Invalid entry fee",
+    "Host fee exceeds maximum (5%)",
+    "Prize pool exceeds maximum (35%)",
+    "Charity allocation below minimum (40%)",
+    "Total allocation exceeds maximum",
+    "Prize distribution must sum to 100",
+    "Insufficient balance",
+    "Contract is paused",
+    "Arithmetic overflow",
+    "Arithmetic underflow",
+    "Invalid room ID (max 32 characters)",
+    "Invalid memo (max 28 characters)",
+    "Room has reached maximum players",
+    "Invalid max_players (must be between 1 and 1000)",
+    "Token account mint does not match room token mint",
+    "Token account owner does not match expected winner",
+    "Winners have already been declared for this room",
+    "Invalid prize amount (must be > 0)",
+    "Prize already deposited",
+    "Prize not deposited yet",
+    "All prizes must be deposited before players can join",
+    "Room cannot be recovered yet (not abandoned)",
+    "Invalid player entry (winner did not join the room)",
+    "Invalid vault account (must be a valid TokenAccount)",
+    "Invalid vault authority (vault must be owned by room PDA)",
+    "Winners must be declared via declare_winners before ending the room",
+    "Room is frozen by the platform admin",
+    "A guaranteed minimum prize has already been funded for this room",
+    "Host token account is required to refund the escrowed minimum prize",
+    "Room has not ended yet",
+    "A charity receipt has already been recorded for this room",
+    "Receipt hash must not be empty",
+    "Winner scores must match the winners, be non-zero and descend with the prizes",
+    "Charity splits must have 1 to 3 unique wallets starting with the room's charity wallet, with non-zero weights summing to 10000 bps",
+    "Token symbol is too long (max 10 characters)",
+    "Token registry cap must be between 1 and the registry's capacity",
+    "Minimum players must not exceed max players",
+    "Recovery reason does not match the room's turnout",
+    "Room has not expired yet",
+    "Every player must ready up before winners can be declared",
+    "Player is already ready",
+    "Room has no players to refund",
+    "Recovery needs a PlayerEntry and token account for every player, and a prize vault and host token account for every deposited prize",
+    "Player is not on this room's allowlist",
+    "Player is blacklisted from joining rooms",
+    "Blacklist is full (max 200 wallets)",
+    "Wallet is already blacklisted",
+    "Wallet is not blacklisted",
+    "Instruction does not support this room's prize mode (asset rooms end with end_asset_room)",
+    "Prize mint is not a 1-of-1 NFT (supply 1, 0 decimals, no further minting)",
+    "Platform fee must be between GlobalConfig's min_platform_fee_bps and platform_fee_bps",
+    "Fee token mint has an unsupported extension (e.g., a Token-2022 transfer fee)",
+    "Prize tiers must have at most 3 tiers with increasing non-zero player thresholds, each summing to 100",
+    "Account is already initialized by a different admin",
+    "Account is not an initialized SPL Token or Token-2022 mint, or its decimals don't match",
+    "Room can't be cancelled once a player has joined",
+    "Referrer can't be the joining player",
+    "Early-bird fee can't exceed the entry fee",
+    "Room vault balance can't cover the planned payouts",
+    "Discount must leave charity at least its minimum share of the full entry fee",
+    "Charity wallet must be set and differ from the platform wallet",
+    "Tied places must each have at least one winner and together cover every winner",
+    "Remaining accounts must be exactly the payout token accounts, in order",
+    "Room vault holds less than the room has collected",
+    "Room account uses an outdated layout; the admin must run migrate_room",
+    "Room already uses the current layout version",
+    "Leave penalty can't exceed 20% of what the player paid",
+    "Donation amount must be greater than zero",
+    "Expiration must be between 1 slot and GlobalConfig's max_expiration_slots",
+    "Charity token account is required to pass on funds held for charity",
+];
+
+/// Returns the `FundraiselyError` variant name for a custom error code.
+///
+/// # Returns
+/// * `Some(&str)` - Variant name (e.g., "MaxPlayersReached" for 6025)
+/// * `None` - If the code is not a Fundraisely error (e.g., an SPL Token error)
+pub fn program_error_name(code: u32) -> Option<&'static str> {
+    let index = code.checked_sub(ERROR_CODE_OFFSET)? as usize;
+    PROGRAM_ERROR_NAMES.get(index).copied()
+}
+
+/// Returns the `#[msg]` string of the `FundraiselyError` for a custom error code.
+///
+/// # Returns
+/// * `Some(&str)` - Message (e.g., "Room has reached maximum players" for 6025)
+/// * `None` - If the code is not a Fundraisely error
+pub fn program_error_message(code: u32) -> Option<&'static str> {
+    let index = code.checked_sub(ERROR_CODE_OFFSET)? as usize;
+    PROGRAM_ERROR_MESSAGES.get(index).copied()
+}
+
+/// Extracts the custom program error code from an RPC error string.
+///
+/// Recognizes the runtime's `custom program error: 0x1771`, Anchor's
+/// `Error Number: 6001.`, and the transaction error forms `{"Custom":6001}` and
+/// `Custom(6001)`.
+pub fn parse_rpc_error_code(err: &str) -> Option<u32> {
+    parse_error_line(err).or_else(|| {
+        ["\"Custom\":", "Custom("].iter().find_map(|marker| {
+            let (_, rest) = err.split_once(marker)?;
+            let digits: String = rest.trim_start().chars().take_while(|c| c.is_ascii_digit()).collect();
+            digits.parse().ok()
+        })
+    })
+}
+
+/// Returns the user-facing message for a Fundraisely error found in an RPC error string.
+///
+/// # Returns
+/// * `Some(&str)` - The error's `#[msg]` string
+/// * `None` - If the string holds no custom error code, or the code isn't a Fundraisely error
+pub fn friendly_error_message(err: &str) -> Option<&'static str> {
+    parse_rpc_error_code(err).and_then(program_error_message)
+}
+
+/// Extracts the custom error code from a transaction error.
+///
+/// # Arguments
+/// * `err` - Transaction error as returned by the RPC, e.g.
+///   `{"InstructionError": [0, {"Custom": 6025}]}`
+pub fn custom_error_code(err: &Value) -> Option<u32> {
+    err.get("InstructionError")?
+        .get(1)?
+        .get("Custom")?
+        .as_u64()
+        .and_then(|code| u32::try_from(code).ok())
+}
+
+/// Extracts the custom program error code from failed transaction logs.
+///
+/// Recognizes both the runtime's `custom program error: 0x1779` line and Anchor's
+/// `Error Number: 6009.` log line, returning the first code found.
+///
+/// # Arguments
+/// * `logs` - Log messages from a failed simulation or transaction
+pub fn parse_error_code(logs: &[String]) -> Option<u32> {
+    logs.iter().find_map(|line| parse_error_line(line))
+}
+
+/// Extracts the error code from one runtime or Anchor error line.
+fn parse_error_line(line: &str) -> Option<u32> {
+    if let Some((_, hex)) = line.split_once("custom program error: 0x") {
+        let digits: String = hex.chars().take_while(|c| c.is_ascii_hexdigit()).collect();
+        return u32::from_str_radix(&digits, 16).ok();
+    }
+
+    if let Some((_, number)) = line.split_once("Error Number: ") {
+        let digits: String = number.chars().take_while(|c| c.is_ascii_digit()).collect();
+        return digits.parse().ok();
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The program's error enum, the source of truth for both tables
+    const PROGRAM_ERRORS: &str =
+        include_str!("../../../solana-program/fundraisely/programs/fundraisely/src/errors.rs");
+
+    #[test]
+    fn tables_match_the_program_error_enum() {
+        // `#[msg("...")]` followed (after doc comments) by `Variant,`
+        let mut variants = Vec::new();
+        let mut message = None;
+        for line in PROGRAM_ERRORS.lines().map(str::trim) {
+            if let Some(rest) = line.strip_prefix("#[msg(\"") {
+                message = rest.strip_suffix("\")]");
+            } else if let (Some(msg), Some(name)) = (message, line.strip_suffix(',')) {
+                if !line.starts_with("//") {
+                    variants.push((name, msg));
+                    message = None;
+                }
+            }
+        }
+
+        let tables: Vec<_> = PROGRAM_ERROR_NAMES
+            .iter()
+            .copied()
+            .zip(PROGRAM_ERROR_MESSAGES.iter().copied())
+            .collect();
+        assert_eq!(PROGRAM_ERROR_NAMES.len(), PROGRAM_ERROR_MESSAGES.len());
+        assert_eq!(tables, variants);
+    }
+
+    #[test]
+    fn maps_codes_to_messages() {
+        assert_eq!(program_error_message(6025), Some("Room has reached maximum players"));
+        assert_eq!(program_error_message(5999), None);
+        assert_eq!(program_error_message(ERROR_CODE_OFFSET + PROGRAM_ERROR_NAMES.len() as u32), None);
+    }
+
+    #[test]
+    fn decodes_codes_from_rpc_error_strings() {
+        let preflight = "Solana RPC error -32002: Transaction simulation failed: \
+                         Error processing Instruction 0: custom program error: 0x1771";
+        assert_eq!(parse_rpc_error_code(preflight), Some(6001));
+        assert_eq!(friendly_error_message(preflight), Some("Room already exists"));
+
+        assert_eq!(parse_rpc_error_code(r#"{"InstructionError":[0,{"Custom":6025}]}"#), Some(6025));
+        assert_eq!(parse_rpc_error_code("InstructionError(0, Custom(6006))"), Some(6006));
+        // SPL Token's InsufficientFunds is a custom error, but not ours
+        assert_eq!(friendly_error_message("custom program error: 0x1"), None);
+        assert_eq!(parse_rpc_error_code("Solana RPC error: 503 Service Unavailable"), None);
+    }
+}
@@ -0,0 +1,461 @@
+//! Fundraisely program events decoded from transaction logs.
+//!
+//! Anchor's `emit!` writes each event as a `Program data: <base64>` log line, where
+//! the payload is an 8-byte discriminator (`sha256("event:<Name>")[..8]`) followed
+//! by the Borsh-encoded event struct. Fields are decoded in declaration order, so new
+//! fields appended to an event on-chain don't break decoding here.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::Serialize;
+use solana_pubkey::Pubkey;
+
+use super::account_data::AccountReader;
+
+/// Log prefix Anchor uses for emitted events.
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+pub const ROOM_CREATED_DISCRIMINATOR: [u8; 8] = [9, 177, 128, 166, 26, 19, 14, 243];
+pub const PLAYER_JOINED_DISCRIMINATOR: [u8; 8] = [39, 144, 49, 106, 108, 210, 183, 38];
+pub const WINNERS_DECLARED_DISCRIMINATOR: [u8; 8] = [60, 25, 114, 88, 126, 49, 88, 136];
+pub const ROOM_ENDED_DISCRIMINATOR: [u8; 8] = [204, 239, 146, 218, 190, 21, 193, 184];
+pub const CONFIG_UPDATED_DISCRIMINATOR: [u8; 8] = [40, 241, 230, 122, 11, 19, 198, 194];
+pub const ROOM_FROZEN_DISCRIMINATOR: [u8; 8] = [173, 37, 212, 207, 87, 179, 101, 134];
+pub const ROOM_UNFROZEN_DISCRIMINATOR: [u8; 8] = [218, 200, 65, 116, 233, 225, 166, 27];
+pub const MIN_PRIZE_FUNDED_DISCRIMINATOR: [u8; 8] = [239, 206, 221, 182, 116, 162, 22, 118];
+pub const TOKEN_APPROVED_DISCRIMINATOR: [u8; 8] = [160, 161, 41, 109, 11, 25, 143, 153];
+pub const TOKEN_REMOVED_DISCRIMINATOR: [u8; 8] = [94, 118, 141, 122, 52, 69, 100, 239];
+pub const WINNERS_AMENDED_DISCRIMINATOR: [u8; 8] = [181, 122, 92, 134, 125, 245, 199, 43];
+pub const CHARITY_RECEIPT_RECORDED_DISCRIMINATOR: [u8; 8] = [164, 204, 24, 157, 224, 189, 19, 49];
+pub const PLAYER_READY_DISCRIMINATOR: [u8; 8] = [91, 36, 30, 206, 51, 189, 35, 91];
+pub const ROOM_RECOVERED_DISCRIMINATOR: [u8; 8] = [21, 92, 162, 189, 12, 245, 103, 171];
+pub const PLAYER_BANNED_DISCRIMINATOR: [u8; 8] = [164, 0, 117, 147, 4, 138, 149, 196];
+pub const PLAYER_UNBANNED_DISCRIMINATOR: [u8; 8] = [185, 110, 213, 7, 92, 16, 195, 134];
+pub const PLATFORM_STATS_DISCRIMINATOR: [u8; 8] = [207, 5, 166, 220, 91, 178, 89, 159];
+pub const ROOM_PLATFORM_FEE_SET_DISCRIMINATOR: [u8; 8] = [164, 90, 187, 152, 238, 103, 121, 0];
+pub const ROOM_CANCELLED_DISCRIMINATOR: [u8; 8] = [139, 79, 156, 200, 40, 164, 207, 171];
+pub const PLAYER_LEFT_DISCRIMINATOR: [u8; 8] = [7, 106, 62, 150, 175, 170, 96, 84];
+pub const DONATION_RECEIVED_DISCRIMINATOR: [u8; 8] = [160, 135, 32, 7, 241, 105, 91, 158];
+
+/// Maximum number of prize places per room (must match the program's `MAX_WINNERS`)
+pub const MAX_WINNERS: usize = 10;
+
+/// A decoded Fundraisely event. Pubkeys are base58 strings, amounts are token base units.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum ProgramEvent {
+    RoomCreated {
+        room: String,
+        room_id: String,
+        host: String,
+        entry_fee: u64,
+        max_players: u32,
+        expiration_slot: u64,
+        /// Unix time the room stops accepting players (0 = no deadline)
+        expiration_unix_ts: i64,
+        timestamp: i64,
+    },
+    PlayerJoined {
+        room: String,
+        player: String,
+        amount_paid: u64,
+        extras_paid: u64,
+        player_count: u32,
+        /// Wallet credited with the referral, or None
+        referrer: Option<String>,
+        timestamp: i64,
+    },
+    PlayerReady {
+        room: String,
+        player: String,
+        ready_count: u32,
+        player_count: u32,
+        timestamp: i64,
+    },
+    PlayerLeft {
+        room: String,
+        player: String,
+        /// Amount returned to the player
+        refund: u64,
+        /// Amount kept for charity
+        penalty: u64,
+        player_count: u32,
+        timestamp: i64,
+    },
+    DonationReceived {
+        room: String,
+        donor: String,
+        amount: u64,
+        /// Total this donor has given to the room
+        donor_total: u64,
+        /// Total donations the room has received
+        total_donations: u64,
+        timestamp: i64,
+    },
+    WinnersDeclared {
+        room: String,
+        winners: Vec<String>,
+        /// Winner scores in the same order (empty if none were recorded)
+        scores: Vec<u64>,
+        /// Winners sharing each prize place, in place order (empty if there are no ties)
+        place_sizes: Vec<u8>,
+        timestamp: i64,
+    },
+    WinnersAmended {
+        room: String,
+        host: String,
+        old_winners: Vec<String>,
+        new_winners: Vec<String>,
+        timestamp: i64,
+    },
+    RoomEnded {
+        room: String,
+        winners: Vec<String>,
+        /// Prize sent to each winner, parallel to `winners` (in the prize asset's mint for asset rooms)
+        winner_amounts: Vec<u64>,
+        platform_amount: u64,
+        host_amount: u64,
+        charity_wallet: String,
+        charity_amount: u64,
+        /// Amount sent to each charity, in the room's charity split order
+        charity_amounts: Vec<u64>,
+        prize_amount: u64,
+        /// Prize percentages per place applied to `prize_amount` (the room's prize tier
+        /// for `total_players`, or its base distribution)
+        prize_distribution: Vec<u16>,
+        total_players: u32,
+        /// True when settled by `finalize_expired_room` (host fee sent to charity)
+        expired_finalization: bool,
+        /// True when the host waived their fee (host fee sent to charity)
+        host_fee_waived: bool,
+        /// Part of `charity_amount` from entry fees (remainder, unused escrow, prize dust
+        /// and any host fee or prize sent to charity)
+        charity_from_entry: u64,
+        /// Part of `charity_amount` from extras (donations included)
+        charity_from_extras: u64,
+        /// Part of `charity_amount` kept from players who left
+        charity_from_leave_penalties: u64,
+        timestamp: i64,
+    },
+    ConfigUpdated {
+        admin: String,
+        require_declaration: bool,
+        /// Receipt attestor, or None when unset
+        receipt_attestor: Option<String>,
+        max_approved_tokens: u16,
+        max_expiration_slots: u64,
+        default_expiration_slots: u64,
+        timestamp: i64,
+    },
+    TokenApproved {
+        token_mint: String,
+        admin: String,
+        total_approved: u32,
+        timestamp: i64,
+    },
+    TokenRemoved {
+        token_mint: String,
+        admin: String,
+        total_approved: u32,
+        timestamp: i64,
+    },
+    PlayerBanned {
+        wallet: String,
+        admin: String,
+        total_banned: u32,
+        timestamp: i64,
+    },
+    PlayerUnbanned {
+        wallet: String,
+        admin: String,
+        total_banned: u32,
+        timestamp: i64,
+    },
+    RoomFrozen {
+        room: String,
+        admin: String,
+        timestamp: i64,
+    },
+    RoomUnfrozen {
+        room: String,
+        admin: String,
+        timestamp: i64,
+    },
+    PlatformStats {
+        room: String,
+        /// Total sent to charity by all ended rooms (base units of each room's fee token)
+        lifetime_charity_raised: u64,
+        lifetime_rooms_ended: u64,
+        lifetime_players: u64,
+        timestamp: i64,
+    },
+    RoomPlatformFeeSet {
+        room: String,
+        admin: String,
+        platform_fee_bps: u16,
+        charity_bps: u16,
+        timestamp: i64,
+    },
+    /// The host closed a room nobody joined; the Room PDA no longer exists
+    RoomCancelled {
+        room: String,
+        host: String,
+        /// Guaranteed minimum prize escrow returned to the host
+        refunded_escrow: u64,
+        timestamp: i64,
+    },
+    MinPrizeFunded {
+        room: String,
+        host: String,
+        amount: u64,
+        timestamp: i64,
+    },
+    CharityReceiptRecorded {
+        room: String,
+        charity_wallet: String,
+        recorder: String,
+        /// Receipt hash as lowercase hex
+        receipt_hash: String,
+        timestamp: i64,
+    },
+    RoomRecovered {
+        room: String,
+        admin: String,
+        /// "Abandoned" or "LowTurnout"
+        reason: String,
+        fee_taken: bool,
+        platform_fee: u64,
+        refund_per_player: u64,
+        player_count: u32,
+        timestamp: i64,
+    },
+}
+
+impl ProgramEvent {
+    /// Decodes every Fundraisely event found in a transaction's log messages.
+    ///
+    /// Lines that are not `Program data:` entries, or whose payload is not a known
+    /// Fundraisely event, are skipped.
+    pub fn from_logs(logs: &[String]) -> Vec<ProgramEvent> {
+        logs.iter()
+            .filter_map(|line| line.strip_prefix(PROGRAM_DATA_PREFIX))
+            .filter_map(|payload| BASE64.decode(payload.trim()).ok())
+            .filter_map(|data| Self::decode(&data).ok())
+            .collect()
+    }
+
+    /// Decodes a single event payload (discriminator + Borsh data).
+    ///
+    /// # Returns
+    /// * `Ok(ProgramEvent)` - Decoded event
+    /// * `Err(String)` - If the discriminator is unknown or the payload is truncated
+    pub fn decode(data: &[u8]) -> Result<Self, String> {
+        let discriminator: [u8; 8] = data
+            .get(..8)
+            .and_then(|d| d.try_into().ok())
+            .ok_or("Event data too short")?;
+        let mut reader = AccountReader::new(data, &discriminator)?;
+
+        match discriminator {
+            ROOM_CREATED_DISCRIMINATOR => Ok(ProgramEvent::RoomCreated {
+                room: reader.read_pubkey()?.to_string(),
+                room_id: reader.read_string()?,
+                host: reader.read_pubkey()?.to_string(),
+                entry_fee: reader.read_u64()?,
+                max_players: reader.read_u32()?,
+                expiration_slot: reader.read_u64()?,
+                expiration_unix_ts: reader.read_i64()?,
+                timestamp: reader.read_i64()?,
+            }),
+            PLAYER_JOINED_DISCRIMINATOR => Ok(ProgramEvent::PlayerJoined {
+                room: reader.read_pubkey()?.to_string(),
+                player: reader.read_pubkey()?.to_string(),
+                amount_paid: reader.read_u64()?,
+                extras_paid: reader.read_u64()?,
+                player_count: reader.read_u32()?,
+                referrer: reader.read_option_pubkey()?.map(|referrer| referrer.to_string()),
+                timestamp: reader.read_i64()?,
+            }),
+            PLAYER_READY_DISCRIMINATOR => Ok(ProgramEvent::PlayerReady {
+                room: reader.read_pubkey()?.to_string(),
+                player: reader.read_pubkey()?.to_string(),
+                ready_count: reader.read_u32()?,
+                player_count: reader.read_u32()?,
+                timestamp: reader.read_i64()?,
+            }),
+            PLAYER_LEFT_DISCRIMINATOR => Ok(ProgramEvent::PlayerLeft {
+                room: reader.read_pubkey()?.to_string(),
+                player: reader.read_pubkey()?.to_string(),
+                refund: reader.read_u64()?,
+                penalty: reader.read_u64()?,
+                player_count: reader.read_u32()?,
+                timestamp: reader.read_i64()?,
+            }),
+            DONATION_RECEIVED_DISCRIMINATOR => Ok(ProgramEvent::DonationReceived {
+                room: reader.read_pubkey()?.to_string(),
+                donor: reader.read_pubkey()?.to_string(),
+                amount: reader.read_u64()?,
+                donor_total: reader.read_u64()?,
+                total_donations: reader.read_u64()?,
+                timestamp: reader.read_i64()?,
+            }),
+            WINNERS_DECLARED_DISCRIMINATOR => Ok(ProgramEvent::WinnersDeclared {
+                room: reader.read_pubkey()?.to_string(),
+                winners: to_strings(reader.read_pubkey_vec()?),
+                scores: reader.read_u64_vec()?,
+                place_sizes: reader.read_u8_vec()?,
+                timestamp: reader.read_i64()?,
+            }),
+            WINNERS_AMENDED_DISCRIMINATOR => Ok(ProgramEvent::WinnersAmended {
+                room: reader.read_pubkey()?.to_string(),
+                host: reader.read_pubkey()?.to_string(),
+                old_winners: to_strings(reader.read_pubkey_vec()?),
+                new_winners: to_strings(reader.read_pubkey_vec()?),
+                timestamp: reader.read_i64()?,
+            }),
+            ROOM_ENDED_DISCRIMINATOR => Ok(ProgramEvent::RoomEnded {
+                room: reader.read_pubkey()?.to_string(),
+                winners: to_strings(reader.read_pubkey_vec()?),
+                winner_amounts: reader.read_u64_vec()?,
+                platform_amount: reader.read_u64()?,
+                host_amount: reader.read_u64()?,
+                charity_wallet: reader.read_pubkey()?.to_string(),
+                charity_amount: reader.read_u64()?,
+                charity_amounts: reader.read_u64_vec()?,
+                prize_amount: reader.read_u64()?,
+                prize_distribution: reader.read_u16_vec()?,
+                total_players: reader.read_u32()?,
+                expired_finalization: reader.read_bool()?,
+                host_fee_waived: reader.read_bool()?,
+                charity_from_entry: reader.read_u64()?,
+                charity_from_extras: reader.read_u64()?,
+                charity_from_leave_penalties: reader.read_u64()?,
+                timestamp: reader.read_i64()?,
+            }),
+            CONFIG_UPDATED_DISCRIMINATOR => Ok(ProgramEvent::ConfigUpdated {
+                admin: reader.read_pubkey()?.to_string(),
+                require_declaration: reader.read_bool()?,
+                receipt_attestor: Some(reader.read_pubkey()?)
+                    .filter(|attestor| *attestor != Pubkey::default())
+                    .map(|attestor| attestor.to_string()),
+                max_approved_tokens: reader.read_u16()?,
+                max_expiration_slots: reader.read_u64()?,
+                default_expiration_slots: reader.read_u64()?,
+                timestamp: reader.read_i64()?,
+            }),
+            TOKEN_APPROVED_DISCRIMINATOR => Ok(ProgramEvent::TokenApproved {
+                token_mint: reader.read_pubkey()?.to_string(),
+                admin: reader.read_pubkey()?.to_string(),
+                total_approved: reader.read_u32()?,
+                timestamp: reader.read_i64()?,
+            }),
+            TOKEN_REMOVED_DISCRIMINATOR => Ok(ProgramEvent::TokenRemoved {
+                token_mint: reader.read_pubkey()?.to_string(),
+                admin: reader.read_pubkey()?.to_string(),
+                total_approved: reader.read_u32()?,
+                timestamp: reader.read_i64()?,
+            }),
+            PLAYER_BANNED_DISCRIMINATOR => Ok(ProgramEvent::PlayerBanned {
+                wallet: reader.read_pubkey()?.to_string(),
+                admin: reader.read_pubkey()?.to_string(),
+                total_banned: reader.read_u32()?,
+                timestamp: reader.read_i64()?,
+            }),
+            PLAYER_UNBANNED_DISCRIMINATOR => Ok(ProgramEvent::PlayerUnbanned {
+                wallet: reader.read_pubkey()?.to_string(),
+                admin: reader.read_pubkey()?.to_string(),
+                total_banned: reader.read_u32()?,
+                timestamp: reader.read_i64()?,
+            }),
+            ROOM_FROZEN_DISCRIMINATOR => Ok(ProgramEvent::RoomFrozen {
+                room: reader.read_pubkey()?.to_string(),
+                admin: reader.read_pubkey()?.to_string(),
+                timestamp: reader.read_i64()?,
+            }),
+            ROOM_UNFROZEN_DISCRIMINATOR => Ok(ProgramEvent::RoomUnfrozen {
+                room: reader.read_pubkey()?.to_string(),
+                admin: reader.read_pubkey()?.to_string(),
+                timestamp: reader.read_i64()?,
+            }),
+            PLATFORM_STATS_DISCRIMINATOR => Ok(ProgramEvent::PlatformStats {
+                room: reader.read_pubkey()?.to_string(),
+                lifetime_charity_raised: reader.read_u64()?,
+                lifetime_rooms_ended: reader.read_u64()?,
+                lifetime_players: reader.read_u64()?,
+                timestamp: reader.read_i64()?,
+            }),
+            ROOM_PLATFORM_FEE_SET_DISCRIMINATOR => Ok(ProgramEvent::RoomPlatformFeeSet {
+                room: reader.read_pubkey()?.to_string(),
+                admin: reader.read_pubkey()?.to_string(),
+                platform_fee_bps: reader.read_u16()?,
+                charity_bps: reader.read_u16()?,
+                timestamp: reader.read_i64()?,
+            }),
+            ROOM_CANCELLED_DISCRIMINATOR => Ok(ProgramEvent::RoomCancelled {
+                room: reader.read_pubkey()?.to_string(),
+                host: reader.read_pubkey()?.to_string(),
+                refunded_escrow: reader.read_u64()?,
+                timestamp: reader.read_i64()?,
+            }),
+            MIN_PRIZE_FUNDED_DISCRIMINATOR => Ok(ProgramEvent::MinPrizeFunded {
+                room: reader.read_pubkey()?.to_string(),
+                host: reader.read_pubkey()?.to_string(),
+                amount: reader.read_u64()?,
+                timestamp: reader.read_i64()?,
+            }),
+            CHARITY_RECEIPT_RECORDED_DISCRIMINATOR => Ok(ProgramEvent::CharityReceiptRecorded {
+                room: reader.read_pubkey()?.to_string(),
+                charity_wallet: reader.read_pubkey()?.to_string(),
+                recorder: reader.read_pubkey()?.to_string(),
+                receipt_hash: reader.read_bytes32()?.iter().map(|b| format!("{:02x}", b)).collect(),
+                timestamp: reader.read_i64()?,
+            }),
+            ROOM_RECOVERED_DISCRIMINATOR => Ok(ProgramEvent::RoomRecovered {
+                room: reader.read_pubkey()?.to_string(),
+                admin: reader.read_pubkey()?.to_string(),
+                reason: match reader.read_u8()? {
+                    0 => "Abandoned".to_string(),
+                    1 => "LowTurnout".to_string(),
+                    other => return Err(format!("Unknown recovery reason: {}", other)),
+                },
+                fee_taken: reader.read_bool()?,
+                platform_fee: reader.read_u64()?,
+                refund_per_player: reader.read_u64()?,
+                player_count: reader.read_u32()?,
+                timestamp: reader.read_i64()?,
+            }),
+            _ => Err("Unknown event discriminator".to_string()),
+        }
+    }
+
+    /// Returns the room whose on-chain state this event changed, if any.
+    pub fn room(&self) -> Option<Pubkey> {
+        let room = match self {
+            ProgramEvent::RoomCreated { room, .. }
+            | ProgramEvent::PlayerJoined { room, .. }
+            | ProgramEvent::PlayerReady { room, .. }
+            | ProgramEvent::PlayerLeft { room, .. }
+            | ProgramEvent::DonationReceived { room, .. }
+            | ProgramEvent::WinnersDeclared { room, .. }
+            | ProgramEvent::WinnersAmended { room, .. }
+            | ProgramEvent::RoomEnded { room, .. }
+            | ProgramEvent::RoomFrozen { room, .. }
+            | ProgramEvent::RoomUnfrozen { room, .. }
+            | ProgramEvent::RoomPlatformFeeSet { room, .. }
+            | ProgramEvent::PlatformStats { room, .. }
+            | ProgramEvent::RoomCancelled { room, .. }
+            | ProgramEvent::MinPrizeFunded { room, .. }
+            | ProgramEvent::CharityReceiptRecorded { room, .. }
+            | ProgramEvent::RoomRecovered { room, .. } => room,
+            ProgramEvent::ConfigUpdated { .. }
+            | ProgramEvent::TokenApproved { .. }
+            | ProgramEvent::TokenRemoved { .. }
+            | ProgramEvent::PlayerBanned { .. }
+            | ProgramEvent::PlayerUnbanned { .. } => return None,
+        };
+        room.parse().ok()
+    }
+}
+
+fn to_strings(pubkeys: Vec<Pubkey>) -> Vec<String> {
+    pubkeys.iter().map(Pubkey::to_string).collect()
+}
@@ -0,0 +1,404 @@
+//! Room account model mirroring the on-chain Room PDA.
+//!
+//! This model exposes the subset of the Fundraisely program's `Room` account that
+//! the frontend needs for browsing rooms (identity, entry fee, capacity, status and
+//! totals) and for previewing the room's fund split (fee shares, prize distribution,
+//! prize tiers, entry/extras counters, the guaranteed minimum prize, the room's
+//! platform fee and whether the host waived their fee).
+//! It's taken from `RoomFull`, which decodes the complete on-chain layout and carries
+//! the winners, asset prizes and other room settings for detail views.
+
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+
+use super::RoomFull;
+
+/// Anchor discriminator for the `Room` account (`sha256("account:Room")[..8]`).
+pub const ROOM_DISCRIMINATOR: [u8; 8] = [156, 199, 67, 27, 222, 23, 185, 94];
+
+/// Number of asset prize slots in a Room (must match the program's `MAX_ASSET_PRIZES`).
+pub(crate) const MAX_ASSET_PRIZES: usize = 3;
+
+/// Prize distribution that replaces the room's base distribution from a player count.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PrizeTier {
+    pub min_players: u32,
+    pub distribution: Vec<u16>,
+}
+
+/// Room lifecycle state, matching the program's `RoomStatus` enum order.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoomStatus {
+    AwaitingFunding,
+    PartiallyFunded,
+    Ready,
+    Active,
+    Ended,
+    Frozen,
+}
+
+impl RoomStatus {
+    pub(crate) fn from_index(index: u8) -> Result<Self, String> {
+        match index {
+            0 => Ok(RoomStatus::AwaitingFunding),
+            1 => Ok(RoomStatus::PartiallyFunded),
+            2 => Ok(RoomStatus::Ready),
+            3 => Ok(RoomStatus::Active),
+            4 => Ok(RoomStatus::Ended),
+            5 => Ok(RoomStatus::Frozen),
+            other => Err(format!("Unknown room status: {}", other)),
+        }
+    }
+}
+
+/// Represents a fundraising room read from the Fundraisely program.
+///
+/// # Fields
+/// * `pubkey` - Room PDA address (base58)
+/// * `room_id` - Human-readable room identifier
+/// * `host` - Host's wallet address (base58)
+/// * `charity_wallet` - Wallet receiving the charity share (base58)
+/// * `fee_token_mint` - SPL token mint used for entry fees (base58)
+/// * `entry_fee` - Entry fee in token base units
+/// * `host_fee_bps` / `prize_pool_bps` - Room's fee shares of entry fees
+/// * `prize_distribution` - Percentage of the prize pool per place
+/// * `max_players` / `player_count` - Room capacity and current players
+/// * `status` - Lifecycle state of the room
+/// * `total_collected` - Entry fees plus extras collected so far
+/// * `total_entry_fees` / `total_extras_fees` - Split of `total_collected`
+/// * `ended` - Whether funds have been distributed
+/// * `creation_slot` / `expiration_slot` - Timing (expiration 0 = never expires)
+/// * `expiration_unix_ts` - Unix time the room stops accepting players (0 = no deadline)
+/// * `guaranteed_min_prize` - Host-escrowed minimum prize (0 = none)
+/// * `platform_fee_bps` - Platform's share of entry fees for this room (may be an admin override)
+/// * `prize_tiers` - Distributions applying from a player count, by increasing `min_players`
+/// * `host_fee_waived` - Whether the host fee goes to charity instead of the host
+/// * `early_bird_fee` / `early_bird_count` - Discounted entry fee for the first players (count 0 = none)
+/// * `discount_bps` - Entry fee reduction for wallets the host registered (0 = no discounts)
+/// * `total_discounts` - Entry fees waived by those discounts so far
+/// * `place_sizes` - Declared winners sharing each prize place (empty = no ties)
+/// * `room_version` - On-chain layout version (0 = created before versioning, needs `migrate_room`)
+/// * `leave_penalty_bps` - Share of a leaving player's payment kept for charity (basis points)
+/// * `total_leave_penalties` - Leave penalties kept in the vault so far, paid to charity at the end
+/// * `total_donations` - Donations from supporters who didn't play (part of `total_extras_fees`)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RoomAccount {
+    pub pubkey: String,
+    pub room_id: String,
+    pub host: String,
+    pub charity_wallet: String,
+    pub fee_token_mint: String,
+    pub entry_fee: u64,
+    pub host_fee_bps: u16,
+    pub prize_pool_bps: u16,
+    pub prize_distribution: Vec<u16>,
+    pub max_players: u32,
+    pub player_count: u32,
+    pub status: RoomStatus,
+    pub total_collected: u64,
+    pub total_entry_fees: u64,
+    pub total_extras_fees: u64,
+    pub ended: bool,
+    pub creation_slot: u64,
+    pub expiration_slot: u64,
+    pub expiration_unix_ts: i64,
+    pub guaranteed_min_prize: u64,
+    pub platform_fee_bps: u16,
+    pub prize_tiers: Vec<PrizeTier>,
+    pub host_fee_waived: bool,
+    pub early_bird_fee: u64,
+    pub early_bird_count: u32,
+    pub discount_bps: u16,
+    pub total_discounts: u64,
+    pub place_sizes: Vec<u8>,
+    pub room_version: u8,
+    pub leave_penalty_bps: u16,
+    pub total_leave_penalties: u64,
+    pub total_donations: u64,
+}
+
+impl RoomAccount {
+    /// Decodes a RoomAccount from raw Room account data.
+    ///
+    /// # Arguments
+    /// * `pubkey` - Address of the Room PDA
+    /// * `data` - Raw account data including the Anchor discriminator
+    ///
+    /// # Returns
+    /// * `Ok(RoomAccount)` - Decoded room
+    /// * `Err(String)` - If the data is not a Room account or is truncated
+    pub fn from_account_data(pubkey: &Pubkey, data: &[u8]) -> Result<Self, String> {
+        RoomFull::from_account_data(pubkey, data).map(Self::from)
+    }
+
+    /// Whether the room has passed its slot or unix-time deadline, as the program's
+    /// `is_expired` decides (a deadline of 0 never passes).
+    pub fn is_expired(&self, current_slot: u64, now: i64) -> bool {
+        (self.expiration_slot > 0 && current_slot >= self.expiration_slot)
+            || (self.expiration_unix_ts > 0 && now >= self.expiration_unix_ts)
+    }
+
+    /// Whether `join_room` would let another player in: the room is open (Ready or
+    /// Active), not ended, not full and not expired. Allowlists, bans and the emergency
+    /// pause are checked by the program only.
+    pub fn is_joinable(&self, current_slot: u64, now: i64) -> bool {
+        matches!(self.status, RoomStatus::Ready | RoomStatus::Active)
+            && !self.ended
+            && self.player_count < self.max_players
+            && !self.is_expired(current_slot, now)
+    }
+
+    /// Share of entry fees going to charity before prize top-ups and dust, in percent:
+    /// what's left after the platform, host and prize pool shares (extras go to charity
+    /// in full on top of this).
+    pub fn charity_percentage(&self) -> f64 {
+        let other_bps = self.platform_fee_bps as u32 + self.host_fee_bps as u32 + self.prize_pool_bps as u32;
+        10_000u32.saturating_sub(other_bps) as f64 / 100.0
+    }
+
+    /// Entry fee the next player to join would pay: `early_bird_fee` while fewer than
+    /// `early_bird_count` players have joined, `entry_fee` after.
+    pub fn next_entry_fee(&self) -> u64 {
+        if self.player_count < self.early_bird_count {
+            self.early_bird_fee
+        } else {
+            self.entry_fee
+        }
+    }
+
+    /// Prize distribution `end_room` would use at the current player count: the tier
+    /// with the highest `min_players` reached, or `prize_distribution` below every tier.
+    pub fn effective_prize_distribution(&self) -> &[u16] {
+        self.prize_tiers
+            .iter()
+            .rev()
+            .find(|tier| self.player_count >= tier.min_players)
+            .map_or(&self.prize_distribution, |tier| &tier.distribution)
+    }
+}
+
+impl From<RoomFull> for RoomAccount {
+    fn from(room: RoomFull) -> Self {
+        Self {
+            pubkey: room.pubkey,
+            room_id: room.room_id,
+            host: room.host,
+            charity_wallet: room.charity_wallet,
+            fee_token_mint: room.fee_token_mint,
+            entry_fee: room.entry_fee,
+            host_fee_bps: room.host_fee_bps,
+            prize_pool_bps: room.prize_pool_bps,
+            prize_distribution: room.prize_distribution,
+            max_players: room.max_players,
+            player_count: room.player_count,
+            status: room.status,
+            total_collected: room.total_collected,
+            total_entry_fees: room.total_entry_fees,
+            total_extras_fees: room.total_extras_fees,
+            ended: room.ended,
+            creation_slot: room.creation_slot,
+            expiration_slot: room.expiration_slot,
+            expiration_unix_ts: room.expiration_unix_ts,
+            guaranteed_min_prize: room.guaranteed_min_prize,
+            platform_fee_bps: room.platform_fee_bps,
+            prize_tiers: room.prize_tiers,
+            host_fee_waived: room.host_fee_waived,
+            early_bird_fee: room.early_bird_fee,
+            early_bird_count: room.early_bird_count,
+            discount_bps: room.discount_bps,
+            total_discounts: room.total_discounts,
+            place_sizes: room.place_sizes,
+            room_version: room.room_version,
+            leave_penalty_bps: room.leave_penalty_bps,
+            total_leave_penalties: room.total_leave_penalties,
+            total_donations: room.total_donations,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Borsh layout of the program's `Room` account, field by field.
+    pub(crate) fn room_data(guaranteed_min_prize: u64) -> Vec<u8> {
+        let string = |data: &mut Vec<u8>, value: &str| {
+            data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            data.extend_from_slice(value.as_bytes());
+        };
+        let mut data = ROOM_DISCRIMINATOR.to_vec();
+        string(&mut data, "quiz-night");
+        for _ in 0..3 {
+            data.extend_from_slice(Pubkey::new_unique().as_ref()); // host, charity, mint
+        }
+        data.extend_from_slice(&10_000_000u64.to_le_bytes()); // entry_fee
+        for bps in [500u16, 3500, 4000] {
+            data.extend_from_slice(&bps.to_le_bytes());
+        }
+        data.push(0); // prize_mode
+        data.extend_from_slice(&2u32.to_le_bytes());
+        for pct in [70u16, 30] {
+            data.extend_from_slice(&pct.to_le_bytes());
+        }
+        data.push(3); // status: Active
+        data.extend_from_slice(&2u32.to_le_bytes()); // player_count
+        data.extend_from_slice(&10u32.to_le_bytes()); // max_players
+        for total in [25_000_000u64, 20_000_000, 5_000_000] {
+            data.extend_from_slice(&total.to_le_bytes());
+        }
+        data.push(0); // ended
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        string(&mut data, "memo");
+        data.extend_from_slice(&1u32.to_le_bytes()); // winners
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.push(1); // prize_assets[0]
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.push(1); // deposited
+        data.push(0); // is_nft
+        data.extend_from_slice(&[0, 0]); // prize_assets[1..]
+        data.extend_from_slice(&[1, 2]); // pre_freeze_status: Some(Ready)
+        data.extend_from_slice(&guaranteed_min_prize.to_le_bytes());
+        data.push(0); // charity_receipt
+        data.extend_from_slice(&1u32.to_le_bytes()); // winner_scores
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes()); // charity_splits
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(&10_000u16.to_le_bytes());
+        data.extend_from_slice(&2u32.to_le_bytes()); // min_players
+        data.push(1); // require_ready
+        data.extend_from_slice(&1u32.to_le_bytes()); // ready_count
+        data.extend_from_slice(&0i64.to_le_bytes()); // expiration_unix_ts
+        data.extend_from_slice(&[0; 32]); // allowlist_root
+        data.extend_from_slice(&1500u16.to_le_bytes()); // platform_fee_bps
+        data.extend_from_slice(&1u32.to_le_bytes()); // prize_tiers
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&100u16.to_le_bytes());
+        data.push(1); // host_fee_waived
+        data.extend_from_slice(&4_000_000u64.to_le_bytes()); // early_bird_fee
+        data.extend_from_slice(&3u32.to_le_bytes()); // early_bird_count
+        data.extend_from_slice(&2500u16.to_le_bytes()); // discount_bps
+        data.extend_from_slice(&2_500_000u64.to_le_bytes()); // total_discounts
+        data.extend_from_slice(&2u32.to_le_bytes()); // place_sizes: two tied 1st, one 2nd
+        data.extend_from_slice(&[2, 1]);
+        data.push(255); // bump
+        data.push(3); // room_version
+        data.extend_from_slice(&1000u16.to_le_bytes()); // leave_penalty_bps
+        data.extend_from_slice(&500_000u64.to_le_bytes()); // total_leave_penalties
+        data.extend_from_slice(&1_000_000u64.to_le_bytes()); // total_donations
+        data
+    }
+
+    #[test]
+    fn decodes_fee_config_and_fields_past_variable_length_sections() {
+        let room = RoomAccount::from_account_data(&Pubkey::new_unique(), &room_data(7_000_000)).unwrap();
+
+        assert_eq!((room.host_fee_bps, room.prize_pool_bps), (500, 3500));
+        assert_eq!(room.prize_distribution, vec![70, 30]);
+        assert_eq!(room.status, RoomStatus::Active);
+        assert_eq!((room.total_entry_fees, room.total_extras_fees), (20_000_000, 5_000_000));
+        assert_eq!(room.guaranteed_min_prize, 7_000_000);
+        assert_eq!(room.platform_fee_bps, 1500);
+        assert_eq!(room.prize_tiers, vec![PrizeTier { min_players: 2, distribution: vec![100] }]);
+        assert!(room.host_fee_waived);
+        assert_eq!((room.early_bird_fee, room.early_bird_count), (4_000_000, 3));
+        assert_eq!((room.discount_bps, room.total_discounts), (2500, 2_500_000));
+        assert_eq!(room.place_sizes, vec![2, 1]);
+        assert_eq!(room.room_version, 3);
+        assert_eq!((room.leave_penalty_bps, room.total_leave_penalties), (1000, 500_000));
+        assert_eq!(room.total_donations, 1_000_000);
+    }
+
+    #[test]
+    fn rooms_created_before_versioning_read_as_version_0() {
+        let mut data = room_data(0);
+        data.truncate(data.len() - 19); // no room_version, leave penalty or donation bytes
+
+        let room = RoomAccount::from_account_data(&Pubkey::new_unique(), &data).unwrap();
+        assert_eq!(room.room_version, 0);
+        assert_eq!((room.leave_penalty_bps, room.total_leave_penalties), (0, 0));
+        assert_eq!(room.total_donations, 0);
+        assert_eq!(room.place_sizes, vec![2, 1]);
+    }
+
+    #[test]
+    fn effective_prize_distribution_follows_player_count() {
+        let mut room = RoomAccount::from_account_data(&Pubkey::new_unique(), &room_data(0)).unwrap();
+
+        // Two players reach the winner-takes-all tier
+        assert_eq!(room.effective_prize_distribution(), &[100]);
+
+        room.player_count = 1;
+        assert_eq!(room.effective_prize_distribution(), &[70, 30]);
+    }
+
+    #[test]
+    fn next_entry_fee_ends_after_early_birds() {
+        let mut room = RoomAccount::from_account_data(&Pubkey::new_unique(), &room_data(0)).unwrap();
+
+        // Two of three early-bird places taken
+        assert_eq!(room.next_entry_fee(), 4_000_000);
+
+        room.player_count = 3;
+        assert_eq!(room.next_entry_fee(), 10_000_000);
+    }
+
+    #[test]
+    fn joinable_until_full() {
+        // Active, 2 of 10 players, no deadline
+        let mut room = RoomAccount::from_account_data(&Pubkey::new_unique(), &room_data(0)).unwrap();
+        assert!(room.is_joinable(1_000, 1_700_000_000));
+
+        room.player_count = 9;
+        assert!(room.is_joinable(1_000, 1_700_000_000));
+
+        room.player_count = 10;
+        assert!(!room.is_joinable(1_000, 1_700_000_000));
+    }
+
+    #[test]
+    fn expires_at_either_deadline() {
+        let mut room = RoomAccount::from_account_data(&Pubkey::new_unique(), &room_data(0)).unwrap();
+        assert!(!room.is_expired(u64::MAX, i64::MAX), "0 deadlines never pass");
+
+        room.expiration_slot = 500;
+        assert!(!room.is_expired(499, 0));
+        assert!(room.is_expired(500, 0));
+        assert!(!room.is_joinable(500, 0));
+
+        room.expiration_slot = 0;
+        room.expiration_unix_ts = 1_700_000_000;
+        assert!(!room.is_expired(0, 1_699_999_999));
+        assert!(room.is_expired(0, 1_700_000_000));
+        assert!(!room.is_joinable(0, 1_700_000_000));
+    }
+
+    #[test]
+    fn ended_or_unopened_rooms_are_not_joinable() {
+        let mut room = RoomAccount::from_account_data(&Pubkey::new_unique(), &room_data(0)).unwrap();
+
+        room.ended = true;
+        assert!(!room.is_joinable(0, 0));
+
+        room.ended = false;
+        for status in [RoomStatus::AwaitingFunding, RoomStatus::PartiallyFunded, RoomStatus::Ended, RoomStatus::Frozen] {
+            room.status = status;
+            assert!(!room.is_joinable(0, 0), "{:?}", status);
+        }
+
+        room.status = RoomStatus::Ready;
+        assert!(room.is_joinable(0, 0));
+    }
+
+    #[test]
+    fn charity_percentage_is_the_entry_fee_remainder() {
+        // Platform 15%, host 5%, prizes 35%
+        let mut room = RoomAccount::from_account_data(&Pubkey::new_unique(), &room_data(0)).unwrap();
+        assert!((room.charity_percentage() - 45.0).abs() < 1e-9);
+
+        room.platform_fee_bps = 6500;
+        assert_eq!(room.charity_percentage(), 0.0);
+    }
+}
@@ -0,0 +1,199 @@
+//! Full live distribution preview of a room.
+//!
+//! Like `CharityPreview`, applies `end_room`'s split to the room's current counters,
+//! but breaks the result down the way the payout itself does: platform, host and
+//! prize amounts, each place's prize, and the charity amount separated into what it
+//! gets from entry fees, from extras and from players' leave penalties. Built on the same
+//! `preview_fee_distribution` arithmetic, so it never disagrees with the chain.
+
+use serde::Serialize;
+
+use super::RoomAccount;
+use crate::utils::fees::preview_fee_distribution;
+
+/// What `end_room` would transfer if the room ended now, broken down by recipient.
+///
+/// # Fields
+/// * `room` - Room PDA address (base58)
+/// * `ended` - Whether the room has already ended (the preview then reflects its final counters)
+/// * `total_entry_fees` / `total_extras_fees` - Room counters the preview is based on
+/// * `platform_fee` / `host_fee` - Shares of entry fees (`host_fee` is 0 once waived)
+/// * `prize_pool` - Total paid to winners, including any guaranteed minimum top-up
+/// * `prize_distribution` - Prize percentage per place (the tier matching the current player count)
+/// * `winner_amounts` - Estimated prize per place, in place order
+/// * `charity_from_entry_fees` - Entry-fee remainder, unused escrow, prize rounding dust
+///   and any waived host fee
+/// * `charity_from_extras` - Extras, all of which go to charity
+/// * `charity_from_leave_penalties` - Penalties kept from players who left the room
+/// * `charity` - Total donation (the sum of the three `charity_from_*` amounts)
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct RoomFinancials {
+    pub room: String,
+    pub ended: bool,
+    pub total_entry_fees: u64,
+    pub total_extras_fees: u64,
+    pub platform_fee: u64,
+    pub host_fee: u64,
+    pub prize_pool: u64,
+    pub prize_distribution: Vec<u16>,
+    pub winner_amounts: Vec<u64>,
+    pub charity_from_entry_fees: u64,
+    pub charity_from_extras: u64,
+    pub charity_from_leave_penalties: u64,
+    pub charity: u64,
+}
+
+impl RoomFinancials {
+    /// Builds the breakdown from a room's current state.
+    ///
+    /// # Returns
+    /// * `Some(RoomFinancials)` - The breakdown
+    /// * `None` - Where `end_room` would fail with an arithmetic error
+    pub fn from_room(room: &RoomAccount) -> Option<Self> {
+        let prize_distribution = room.effective_prize_distribution().to_vec();
+
+        // Split the entry fees alone so the extras portion stays separate
+        let split = preview_fee_distribution(
+            room.total_entry_fees,
+            0,
+            room.platform_fee_bps,
+            room.host_fee_bps,
+            room.prize_pool_bps,
+            &prize_distribution,
+            room.guaranteed_min_prize,
+        )?;
+
+        // Waived host fee is donated instead of paid out
+        let (host_fee, host_fee_to_charity) = if room.host_fee_waived {
+            (0, split.host_fee)
+        } else {
+            (split.host_fee, 0)
+        };
+
+        let charity_from_entry_fees = split.charity.checked_add(host_fee_to_charity)?;
+        let charity = charity_from_entry_fees
+            .checked_add(room.total_extras_fees)?
+            .checked_add(room.total_leave_penalties)?;
+
+        Some(Self {
+            room: room.pubkey.clone(),
+            ended: room.ended,
+            total_entry_fees: room.total_entry_fees,
+            total_extras_fees: room.total_extras_fees,
+            platform_fee: split.platform_fee,
+            host_fee,
+            prize_pool: split.prize_pool,
+            prize_distribution,
+            winner_amounts: split.winner_amounts,
+            charity_from_entry_fees,
+            charity_from_extras: room.total_extras_fees,
+            charity_from_leave_penalties: room.total_leave_penalties,
+            charity,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CharityPreview, PrizeTier, RoomStatus};
+
+    fn room(entry_fees: u64, extras: u64) -> RoomAccount {
+        RoomAccount {
+            pubkey: "Room111".to_string(),
+            room_id: "quiz-night".to_string(),
+            host: "Host111".to_string(),
+            charity_wallet: "Charity111".to_string(),
+            fee_token_mint: "Mint111".to_string(),
+            entry_fee: 10_000_000,
+            host_fee_bps: 500,
+            prize_pool_bps: 3500,
+            prize_distribution: vec![60, 30, 10],
+            max_players: 10,
+            player_count: 1,
+            status: RoomStatus::Active,
+            total_collected: entry_fees + extras,
+            total_entry_fees: entry_fees,
+            total_extras_fees: extras,
+            ended: false,
+            creation_slot: 100,
+            expiration_slot: 0,
+            expiration_unix_ts: 0,
+            guaranteed_min_prize: 0,
+            platform_fee_bps: 2000,
+            prize_tiers: Vec::new(),
+            host_fee_waived: false,
+            early_bird_fee: 0,
+            early_bird_count: 0,
+            discount_bps: 0,
+            total_discounts: 0,
+            place_sizes: Vec::new(),
+            room_version: 3,
+            leave_penalty_bps: 0,
+            total_leave_penalties: 0,
+            total_donations: 0,
+        }
+    }
+
+    #[test]
+    fn separates_extras_from_entry_fee_charity() {
+        // 10 USDC entry: platform 2, host 0.5, prizes 3.5, charity 4 + 10 extras
+        let financials = RoomFinancials::from_room(&room(10_000_000, 10_000_000)).unwrap();
+
+        assert_eq!(financials.platform_fee, 2_000_000);
+        assert_eq!(financials.host_fee, 500_000);
+        assert_eq!(financials.prize_pool, 3_500_000);
+        assert_eq!(financials.winner_amounts, vec![2_100_000, 1_050_000, 350_000]);
+        assert_eq!(financials.charity_from_entry_fees, 4_000_000);
+        assert_eq!(financials.charity_from_extras, 10_000_000);
+        assert_eq!(financials.charity, 14_000_000);
+    }
+
+    #[test]
+    fn accounts_for_every_collected_unit() {
+        // Odd amounts: prize split dust and the waived host fee land in charity
+        let financials = RoomFinancials::from_room(&RoomAccount {
+            host_fee_waived: true,
+            ..room(999, 7)
+        })
+        .unwrap();
+
+        assert_eq!(financials.host_fee, 0);
+        assert_eq!(financials.winner_amounts, vec![209, 104, 34]);
+        assert_eq!(financials.prize_pool, 347);
+        assert_eq!(
+            financials.platform_fee + financials.prize_pool + financials.charity,
+            999 + 7
+        );
+    }
+
+    #[test]
+    fn leave_penalties_go_to_charity() {
+        let room = RoomAccount {
+            total_leave_penalties: 1_000_000,
+            ..room(10_000_000, 10_000_000)
+        };
+        let financials = RoomFinancials::from_room(&room).unwrap();
+
+        assert_eq!(financials.charity_from_leave_penalties, 1_000_000);
+        assert_eq!(financials.charity, 15_000_000);
+        assert_eq!(financials.charity, CharityPreview::from_room(&room).unwrap().charity);
+    }
+
+    #[test]
+    fn follows_prize_tier_and_matches_charity_preview() {
+        let room = RoomAccount {
+            player_count: 5,
+            prize_tiers: vec![PrizeTier { min_players: 5, distribution: vec![50, 50] }],
+            guaranteed_min_prize: 5_000_000,
+            ..room(10_000_000, 2_500_000)
+        };
+        let financials = RoomFinancials::from_room(&room).unwrap();
+        let preview = CharityPreview::from_room(&room).unwrap();
+
+        assert_eq!(financials.prize_distribution, vec![50, 50]);
+        assert_eq!(financials.winner_amounts, vec![2_500_000, 2_500_000]);
+        assert_eq!(financials.prize_pool, preview.prize_pool);
+        assert_eq!(financials.charity, preview.charity);
+    }
+}
@@ -0,0 +1,314 @@
+//! Complete Room model for detail views.
+//!
+//! `RoomAccount` is the lightweight shape used by room lists and previews. A room
+//! detail page also needs the charity share, winners and their scores, asset prizes,
+//! charity splits, the join rules and the charity receipt, so `RoomFull` decodes
+//! every field of the on-chain `Room` (except the PDA bump), with pubkeys as base58.
+//! It's the one decoder of the Room layout: `RoomAccount` is converted from it.
+
+use serde::Serialize;
+use solana_pubkey::Pubkey;
+
+use super::account_data::AccountReader;
+use super::room_account::{PrizeTier, RoomStatus, MAX_ASSET_PRIZES, ROOM_DISCRIMINATOR};
+
+/// How a room's prizes are funded, matching the program's `PrizeMode` enum order.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrizeMode {
+    PoolSplit,
+    AssetBased,
+}
+
+impl PrizeMode {
+    fn from_index(index: u8) -> Result<Self, String> {
+        match index {
+            0 => Ok(PrizeMode::PoolSplit),
+            1 => Ok(PrizeMode::AssetBased),
+            other => Err(format!("Unknown prize mode: {}", other)),
+        }
+    }
+}
+
+/// A prize the host deposits into an asset room.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PrizeAsset {
+    pub mint: String,
+    pub amount: u64,
+    pub deposited: bool,
+    pub is_nft: bool,
+}
+
+/// An extra charity sharing the room's charity amount.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CharitySplit {
+    pub wallet: String,
+    pub weight_bps: u16,
+}
+
+/// Every field of a fundraising room read from the Fundraisely program.
+///
+/// # Fields
+/// * `pubkey` - Room PDA address (base58)
+/// * `room_id` / `host` / `charity_wallet` / `fee_token_mint` - Identity and wallets (base58)
+/// * `entry_fee` - Entry fee in token base units
+/// * `host_fee_bps` / `prize_pool_bps` / `charity_bps` - Room's shares of entry fees
+/// * `prize_mode` - Whether prizes come from the pool or from deposited assets
+/// * `prize_distribution` - Percentage of the prize pool per place
+/// * `status` / `pre_freeze_status` - Lifecycle state, and the state to restore on unfreeze
+/// * `player_count` / `max_players` / `min_players` / `ready_count` - Turnout
+/// * `total_collected` / `total_entry_fees` / `total_extras_fees` - Fund counters
+/// * `ended` - Whether funds have been distributed
+/// * `creation_slot` / `expiration_slot` / `expiration_unix_ts` - Timing (0 = no deadline)
+/// * `charity_memo` - Memo attached to the charity transfer
+/// * `winners` / `winner_scores` / `place_sizes` - Declared winners, their scores and tied places
+/// * `prize_assets` - Asset prize slots (`None` = unused)
+/// * `guaranteed_min_prize` - Host-escrowed minimum prize (0 = none)
+/// * `charity_receipt` - Hash of the charity's receipt once recorded (hex)
+/// * `charity_splits` - Extra charities sharing the charity amount by weight
+/// * `require_ready` - Whether every player must ready up before winners are declared
+/// * `allowlist_root` - Merkle root of the join allowlist (base58, `None` = open room)
+/// * `platform_fee_bps` - Platform's share of entry fees for this room
+/// * `prize_tiers` - Distributions applying from a player count
+/// * `host_fee_waived` - Whether the host fee goes to charity instead of the host
+/// * `early_bird_fee` / `early_bird_count` - Discounted entry fee for the first players
+/// * `discount_bps` / `total_discounts` - Host-registered discounts and the fees they waived
+/// * `room_version` - On-chain layout version (0 = created before versioning)
+/// * `leave_penalty_bps` / `total_leave_penalties` - Leave penalty and what it has kept for charity
+/// * `total_donations` - Donations from supporters who didn't play (part of `total_extras_fees`)
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct RoomFull {
+    pub pubkey: String,
+    pub room_id: String,
+    pub host: String,
+    pub charity_wallet: String,
+    pub fee_token_mint: String,
+    pub entry_fee: u64,
+    pub host_fee_bps: u16,
+    pub prize_pool_bps: u16,
+    pub charity_bps: u16,
+    pub prize_mode: PrizeMode,
+    pub prize_distribution: Vec<u16>,
+    pub status: RoomStatus,
+    pub player_count: u32,
+    pub max_players: u32,
+    pub total_collected: u64,
+    pub total_entry_fees: u64,
+    pub total_extras_fees: u64,
+    pub ended: bool,
+    pub creation_slot: u64,
+    pub expiration_slot: u64,
+    pub charity_memo: String,
+    pub winners: Vec<String>,
+    pub prize_assets: Vec<Option<PrizeAsset>>,
+    pub pre_freeze_status: Option<RoomStatus>,
+    pub guaranteed_min_prize: u64,
+    pub charity_receipt: Option<String>,
+    pub winner_scores: Vec<u64>,
+    pub charity_splits: Vec<CharitySplit>,
+    pub min_players: u32,
+    pub require_ready: bool,
+    pub ready_count: u32,
+    pub expiration_unix_ts: i64,
+    pub allowlist_root: Option<String>,
+    pub platform_fee_bps: u16,
+    pub prize_tiers: Vec<PrizeTier>,
+    pub host_fee_waived: bool,
+    pub early_bird_fee: u64,
+    pub early_bird_count: u32,
+    pub discount_bps: u16,
+    pub total_discounts: u64,
+    pub place_sizes: Vec<u8>,
+    pub room_version: u8,
+    pub leave_penalty_bps: u16,
+    pub total_leave_penalties: u64,
+    pub total_donations: u64,
+}
+
+impl RoomFull {
+    /// Decodes a RoomFull from raw Room account data.
+    ///
+    /// # Arguments
+    /// * `pubkey` - Address of the Room PDA
+    /// * `data` - Raw account data including the Anchor discriminator
+    ///
+    /// # Returns
+    /// * `Ok(RoomFull)` - Decoded room
+    /// * `Err(String)` - If the data is not a Room account or is truncated
+    pub fn from_account_data(pubkey: &Pubkey, data: &[u8]) -> Result<Self, String> {
+        let mut reader = AccountReader::new(data, &ROOM_DISCRIMINATOR)?;
+
+        let room_id = reader.read_string()?;
+        let host = reader.read_pubkey()?.to_string();
+        let charity_wallet = reader.read_pubkey()?.to_string();
+        let fee_token_mint = reader.read_pubkey()?.to_string();
+        let entry_fee = reader.read_u64()?;
+        let host_fee_bps = reader.read_u16()?;
+        let prize_pool_bps = reader.read_u16()?;
+        let charity_bps = reader.read_u16()?;
+        let prize_mode = PrizeMode::from_index(reader.read_u8()?)?;
+        let prize_distribution = reader.read_u16_vec()?;
+        let status = RoomStatus::from_index(reader.read_u8()?)?;
+        let player_count = reader.read_u32()?;
+        let max_players = reader.read_u32()?;
+        let total_collected = reader.read_u64()?;
+        let total_entry_fees = reader.read_u64()?;
+        let total_extras_fees = reader.read_u64()?;
+        let ended = reader.read_bool()?;
+        let creation_slot = reader.read_u64()?;
+        let expiration_slot = reader.read_u64()?;
+        let charity_memo = reader.read_string()?;
+        let winners = reader.read_pubkey_vec()?.iter().map(Pubkey::to_string).collect();
+        let prize_assets = (0..MAX_ASSET_PRIZES)
+            .map(|_| {
+                if !reader.read_bool()? {
+                    return Ok(None);
+                }
+                Ok(Some(PrizeAsset {
+                    mint: reader.read_pubkey()?.to_string(),
+                    amount: reader.read_u64()?,
+                    deposited: reader.read_bool()?,
+                    is_nft: reader.read_bool()?,
+                }))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let pre_freeze_status = reader.read_option_u8()?.map(RoomStatus::from_index).transpose()?;
+        let guaranteed_min_prize = reader.read_u64()?;
+        let charity_receipt = if reader.read_bool()? {
+            Some(reader.read_bytes32()?.iter().map(|b| format!("{:02x}", b)).collect())
+        } else {
+            None
+        };
+        let winner_scores = reader.read_u64_vec()?;
+        let charity_splits = (0..reader.read_u32()?)
+            .map(|_| {
+                Ok(CharitySplit {
+                    wallet: reader.read_pubkey()?.to_string(),
+                    weight_bps: reader.read_u16()?,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let min_players = reader.read_u32()?;
+        let require_ready = reader.read_bool()?;
+        let ready_count = reader.read_u32()?;
+        let expiration_unix_ts = reader.read_i64()?;
+        let allowlist_root = reader.read_bytes32()?;
+        let platform_fee_bps = reader.read_u16()?;
+        let prize_tiers = (0..reader.read_u32()?)
+            .map(|_| {
+                Ok(PrizeTier {
+                    min_players: reader.read_u32()?,
+                    distribution: reader.read_u16_vec()?,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let host_fee_waived = reader.read_bool()?;
+        let early_bird_fee = reader.read_u64()?;
+        let early_bird_count = reader.read_u32()?;
+        let discount_bps = reader.read_u16()?;
+        let total_discounts = reader.read_u64()?;
+        let place_sizes = reader.read_u8_vec()?;
+        reader.read_u8()?; // bump
+        // Fields past the bump may be missing from rooms written with an older layout
+        let room_version = reader.read_u8().unwrap_or(0);
+        let leave_penalty_bps = reader.read_u16().unwrap_or(0);
+        let total_leave_penalties = reader.read_u64().unwrap_or(0);
+        let total_donations = reader.read_u64().unwrap_or(0);
+
+        Ok(Self {
+            pubkey: pubkey.to_string(),
+            room_id,
+            host,
+            charity_wallet,
+            fee_token_mint,
+            entry_fee,
+            host_fee_bps,
+            prize_pool_bps,
+            charity_bps,
+            prize_mode,
+            prize_distribution,
+            status,
+            player_count,
+            max_players,
+            total_collected,
+            total_entry_fees,
+            total_extras_fees,
+            ended,
+            creation_slot,
+            expiration_slot,
+            charity_memo,
+            winners,
+            prize_assets,
+            pre_freeze_status,
+            guaranteed_min_prize,
+            charity_receipt,
+            winner_scores,
+            charity_splits,
+            min_players,
+            require_ready,
+            ready_count,
+            expiration_unix_ts,
+            // An all-zero root means the room has no allowlist
+            allowlist_root: (allowlist_root != [0; 32]).then(|| bs58::encode(allowlist_root).into_string()),
+            platform_fee_bps,
+            prize_tiers,
+            host_fee_waived,
+            early_bird_fee,
+            early_bird_count,
+            discount_bps,
+            total_discounts,
+            place_sizes,
+            room_version,
+            leave_penalty_bps,
+            total_leave_penalties,
+            total_donations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::room_account::tests::room_data;
+    use crate::models::RoomAccount;
+
+    #[test]
+    fn decodes_fields_the_list_view_skips() {
+        let room = RoomFull::from_account_data(&Pubkey::new_unique(), &room_data(7_000_000)).unwrap();
+
+        assert_eq!(room.charity_bps, 4000);
+        assert_eq!(room.prize_mode, PrizeMode::PoolSplit);
+        assert_eq!(room.charity_memo, "memo");
+        assert_eq!(room.winners.len(), 1);
+        assert_eq!(room.winner_scores, vec![42]);
+        let assets: Vec<_> = room.prize_assets.iter().map(|a| a.as_ref().map(|a| (a.amount, a.deposited))).collect();
+        assert_eq!(assets, vec![Some((1, true)), None, None]);
+        assert_eq!(room.pre_freeze_status, Some(RoomStatus::Ready));
+        assert_eq!(room.charity_receipt, None);
+        assert_eq!(room.charity_splits.len(), 1);
+        assert_eq!(room.charity_splits[0].weight_bps, 10_000);
+        assert_eq!((room.min_players, room.require_ready, room.ready_count), (2, true, 1));
+        assert_eq!(room.allowlist_root, None);
+        assert_eq!((room.leave_penalty_bps, room.total_leave_penalties), (1000, 500_000));
+    }
+
+    #[test]
+    fn agrees_with_the_list_view() {
+        let pubkey = Pubkey::new_unique();
+        let data = room_data(7_000_000);
+        let full = RoomFull::from_account_data(&pubkey, &data).unwrap();
+        let room = RoomAccount::from_account_data(&pubkey, &data).unwrap();
+
+        assert_eq!(full.pubkey, room.pubkey);
+        assert_eq!(
+            (full.host, full.charity_wallet, full.fee_token_mint),
+            (room.host, room.charity_wallet, room.fee_token_mint)
+        );
+        assert_eq!((full.host_fee_bps, full.prize_pool_bps), (room.host_fee_bps, room.prize_pool_bps));
+        assert_eq!(
+            (full.total_collected, full.total_entry_fees, full.total_extras_fees),
+            (room.total_collected, room.total_entry_fees, room.total_extras_fees)
+        );
+        assert_eq!(full.place_sizes, room.place_sizes);
+        assert_eq!(full.room_version, room.room_version);
+    }
+}
@@ -0,0 +1,107 @@
+//! Normalized result of an ended room.
+//!
+//! `end_room` reports everything about a room's payout in its `RoomEnded` event.
+//! The backend keeps that event, together with the transaction that carried it,
+//! as a `RoomOutcome` so outcome lookups don't need to re-scan the room's history.
+
+use serde::{Deserialize, Serialize};
+
+use super::ProgramEvent;
+
+/// Payout summary of an ended room.
+///
+/// # Fields
+/// * `room` - Room PDA address (base58)
+/// * `winners` - Winner wallets in prize order
+/// * `winner_amounts` - Prize paid to each winner, parallel to `winners`
+/// * `platform_amount` / `host_amount` / `charity_amount` / `prize_amount` - Amounts paid out
+/// * `charity_wallet` - Room's charity wallet (first charity of a multi-charity room)
+/// * `charity_amounts` - Amount sent to each charity, in the room's charity split order
+/// * `prize_distribution` - Prize percentage per place the payout used (the prize tier
+///   matching `total_players`, or the room's base distribution)
+/// * `total_players` - Players in the room when it ended
+/// * `expired_finalization` - Whether the room was settled by `finalize_expired_room` after
+///   expiring, in which case `end_signature` is that transaction's and `host_amount` is 0
+/// * `host_fee_waived` - Whether the host waived their fee, so `host_amount` is 0 and the
+///   fee is part of `charity_amount`
+/// * `charity_from_entry` / `charity_from_extras` / `charity_from_leave_penalties` - Where
+///   `charity_amount` came from: entry fees, extras and players' leave penalties
+/// * `end_signature` - Signature of the `end_room` transaction (also carries the charity transfer)
+/// * `slot` - Slot the `end_room` transaction landed in
+/// * `ended_at` - Unix timestamp reported by the event
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RoomOutcome {
+    pub room: String,
+    pub winners: Vec<String>,
+    pub winner_amounts: Vec<u64>,
+    pub platform_amount: u64,
+    pub host_amount: u64,
+    pub charity_wallet: String,
+    pub charity_amount: u64,
+    pub charity_amounts: Vec<u64>,
+    pub prize_amount: u64,
+    pub prize_distribution: Vec<u16>,
+    pub total_players: u32,
+    pub expired_finalization: bool,
+    pub host_fee_waived: bool,
+    pub charity_from_entry: u64,
+    pub charity_from_extras: u64,
+    pub charity_from_leave_penalties: u64,
+    pub end_signature: String,
+    pub slot: u64,
+    pub ended_at: i64,
+}
+
+impl RoomOutcome {
+    /// Builds an outcome from a `RoomEnded` event and the transaction that emitted it.
+    ///
+    /// # Returns
+    /// * `Some(RoomOutcome)` - If `event` is `RoomEnded`
+    /// * `None` - For any other event
+    pub fn from_event(event: &ProgramEvent, signature: &str, slot: u64) -> Option<Self> {
+        let ProgramEvent::RoomEnded {
+            room,
+            winners,
+            winner_amounts,
+            platform_amount,
+            host_amount,
+            charity_wallet,
+            charity_amount,
+            charity_amounts,
+            prize_amount,
+            prize_distribution,
+            total_players,
+            expired_finalization,
+            host_fee_waived,
+            charity_from_entry,
+            charity_from_extras,
+            charity_from_leave_penalties,
+            timestamp,
+        } = event
+        else {
+            return None;
+        };
+
+        Some(Self {
+            room: room.clone(),
+            winners: winners.clone(),
+            winner_amounts: winner_amounts.clone(),
+            platform_amount: *platform_amount,
+            host_amount: *host_amount,
+            charity_wallet: charity_wallet.clone(),
+            charity_amount: *charity_amount,
+            charity_amounts: charity_amounts.clone(),
+            prize_amount: *prize_amount,
+            prize_distribution: prize_distribution.clone(),
+            total_players: *total_players,
+            expired_finalization: *expired_finalization,
+            host_fee_waived: *host_fee_waived,
+            charity_from_entry: *charity_from_entry,
+            charity_from_extras: *charity_from_extras,
+            charity_from_leave_penalties: *charity_from_leave_penalties,
+            end_signature: signature.to_string(),
+            slot,
+            ended_at: *timestamp,
+        })
+    }
+}
@@ -0,0 +1,186 @@
+//! Chronological room history assembled from program events.
+//!
+//! A room's lifecycle (created → joins → winners declared → ended) is spread over
+//! many transactions. This module merges the events decoded from those
+//! transactions into a single ordered timeline for room detail pages.
+
+use serde::Serialize;
+use solana_pubkey::Pubkey;
+
+use super::ProgramEvent;
+
+/// One event in a room's history.
+///
+/// # Fields
+/// * `slot` - Slot of the transaction that emitted the event
+/// * `signature` - Transaction signature (base58)
+/// * `block_time` - Estimated unix time of the block, if the node reports it
+/// * `event` - The decoded event, tagged by `type` (e.g., "PlayerJoined")
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct TimelineEntry {
+    pub slot: u64,
+    pub signature: String,
+    pub block_time: Option<i64>,
+    pub event: ProgramEvent,
+}
+
+/// A transaction that touched the room, with its log messages.
+pub struct RoomTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub logs: Vec<String>,
+}
+
+/// Builds a room's timeline from the transactions that touched it.
+///
+/// Only events about `room` are kept (a transaction may emit events for other
+/// rooms or global config). Entries are ordered by slot; events from the same
+/// transaction keep their emission order.
+pub fn build_timeline(room: &Pubkey, transactions: Vec<RoomTransaction>) -> Vec<TimelineEntry> {
+    let mut entries: Vec<TimelineEntry> = transactions
+        .into_iter()
+        .flat_map(|tx| {
+            ProgramEvent::from_logs(&tx.logs)
+                .into_iter()
+                .filter(|event| event.room().as_ref() == Some(room))
+                .map(move |event| TimelineEntry {
+                    slot: tx.slot,
+                    signature: tx.signature.clone(),
+                    block_time: tx.block_time,
+                    event,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    // Stable sort keeps same-transaction events in emission order
+    entries.sort_by_key(|entry| entry.slot);
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::program_event::{
+        PLAYER_JOINED_DISCRIMINATOR, ROOM_CREATED_DISCRIMINATOR, ROOM_ENDED_DISCRIMINATOR,
+        WINNERS_DECLARED_DISCRIMINATOR,
+    };
+    use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+
+    fn program_data(payload: Vec<u8>) -> String {
+        format!("Program data: {}", BASE64.encode(payload))
+    }
+
+    fn room_created(room: &Pubkey, host: &Pubkey) -> String {
+        let mut data = ROOM_CREATED_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(room.as_ref());
+        data.extend_from_slice(&4u32.to_le_bytes());
+        data.extend_from_slice(b"quiz");
+        data.extend_from_slice(host.as_ref());
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&10u32.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes()); // expiration_slot
+        data.extend_from_slice(&1_700_086_400i64.to_le_bytes()); // expiration_unix_ts
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        program_data(data)
+    }
+
+    fn player_joined(room: &Pubkey, player: &Pubkey, player_count: u32) -> String {
+        let mut data = PLAYER_JOINED_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(room.as_ref());
+        data.extend_from_slice(player.as_ref());
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&player_count.to_le_bytes());
+        data.push(0); // referrer: None
+        data.extend_from_slice(&1_700_000_100i64.to_le_bytes());
+        program_data(data)
+    }
+
+    fn winners_declared(room: &Pubkey, winner: &Pubkey) -> String {
+        let mut data = WINNERS_DECLARED_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(room.as_ref());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(winner.as_ref());
+        data.extend_from_slice(&1u32.to_le_bytes()); // scores: Vec<u64> of 1
+        data.extend_from_slice(&980u64.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes()); // place_sizes: no ties
+        data.extend_from_slice(&1_700_000_200i64.to_le_bytes());
+        program_data(data)
+    }
+
+    fn room_ended(room: &Pubkey, winner: &Pubkey) -> String {
+        let mut data = ROOM_ENDED_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(room.as_ref());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(winner.as_ref());
+        data.extend_from_slice(&1u32.to_le_bytes()); // winner_amounts: Vec<u64> of 1
+        data.extend_from_slice(&700u64.to_le_bytes());
+        data.extend_from_slice(&200u64.to_le_bytes()); // platform_amount
+        data.extend_from_slice(&100u64.to_le_bytes()); // host_amount
+        data.extend_from_slice(Pubkey::new_unique().as_ref()); // charity_wallet
+        data.extend_from_slice(&1_000u64.to_le_bytes()); // charity_amount
+        data.extend_from_slice(&1u32.to_le_bytes()); // charity_amounts: Vec<u64> of 1
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&700u64.to_le_bytes()); // prize_amount
+        data.extend_from_slice(&1u32.to_le_bytes()); // prize_distribution
+        data.extend_from_slice(&100u16.to_le_bytes());
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.push(0); // expired_finalization
+        data.push(0); // host_fee_waived
+        data.extend_from_slice(&1_000u64.to_le_bytes()); // charity_from_entry
+        data.extend_from_slice(&0u64.to_le_bytes()); // charity_from_extras
+        data.extend_from_slice(&0u64.to_le_bytes()); // charity_from_leave_penalties
+        data.extend_from_slice(&1_700_000_300i64.to_le_bytes());
+        program_data(data)
+    }
+
+    fn tx(signature: &str, slot: u64, logs: Vec<String>) -> RoomTransaction {
+        RoomTransaction {
+            signature: signature.to_string(),
+            slot,
+            block_time: None,
+            logs,
+        }
+    }
+
+    #[test]
+    fn orders_events_chronologically_and_ignores_other_rooms() {
+        let room = Pubkey::new_unique();
+        let other_room = Pubkey::new_unique();
+        let host = Pubkey::new_unique();
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+
+        // getSignaturesForAddress returns newest first
+        let transactions = vec![
+            tx("end", 50, vec![room_ended(&room, &alice)]),
+            tx("declare", 40, vec![winners_declared(&room, &alice)]),
+            tx("join-bob", 30, vec![player_joined(&room, &bob, 2)]),
+            tx("join-other", 25, vec![player_joined(&other_room, &bob, 1)]),
+            tx("join-alice", 20, vec!["Program log: Instruction: JoinRoom".to_string(), player_joined(&room, &alice, 1)]),
+            tx("create", 10, vec![room_created(&room, &host)]),
+        ];
+
+        let timeline = build_timeline(&room, transactions);
+
+        let signatures: Vec<&str> = timeline.iter().map(|e| e.signature.as_str()).collect();
+        assert_eq!(signatures, vec!["create", "join-alice", "join-bob", "declare", "end"]);
+        assert!(matches!(timeline[0].event, ProgramEvent::RoomCreated { .. }));
+        assert!(matches!(timeline[2].event, ProgramEvent::PlayerJoined { player_count: 2, .. }));
+        assert!(matches!(
+            &timeline[3].event,
+            ProgramEvent::WinnersDeclared { winners, scores, .. }
+                if winners == &vec![alice.to_string()] && scores == &vec![980]
+        ));
+        assert!(matches!(
+            timeline[4].event,
+            ProgramEvent::RoomEnded { charity_amount: 1_000, charity_from_entry: 1_000, charity_from_extras: 0, .. }
+        ));
+        assert!(matches!(
+            &timeline[4].event,
+            ProgramEvent::RoomEnded { winner_amounts, .. } if winner_amounts == &vec![700]
+        ));
+    }
+}
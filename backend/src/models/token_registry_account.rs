@@ -0,0 +1,111 @@
+//! Token registry model mirroring the on-chain TokenRegistry PDA.
+//!
+//! The registry lists the SPL mints rooms may charge entry fees in. It changes only
+//! through `add_approved_token`/`remove_approved_token`, so it is cached for a long
+//! time and evicted on `TokenApproved`/`TokenRemoved`.
+
+use serde::{Deserialize, Serialize};
+use solana_pubkey::Pubkey;
+
+use super::account_data::AccountReader;
+
+/// Anchor discriminator for the `TokenRegistry` account (`sha256("account:TokenRegistry")[..8]`).
+pub const TOKEN_REGISTRY_DISCRIMINATOR: [u8; 8] = [227, 255, 152, 118, 84, 200, 145, 120];
+
+/// An approved mint with the metadata the admin registered for it.
+///
+/// # Fields
+/// * `mint` - Mint address (base58)
+/// * `symbol` - Display symbol, if one was registered
+/// * `decimals` - Mint decimals, if registered
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ApprovedToken {
+    pub mint: String,
+    pub symbol: Option<String>,
+    pub decimals: Option<u8>,
+}
+
+/// Approved token list read from the Fundraisely program.
+///
+/// # Fields
+/// * `pubkey` - TokenRegistry PDA address (base58)
+/// * `admin` - Admin allowed to change the list
+/// * `approved_tokens` - Approved mints with their metadata
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TokenRegistryAccount {
+    pub pubkey: String,
+    pub admin: String,
+    pub approved_tokens: Vec<ApprovedToken>,
+}
+
+impl TokenRegistryAccount {
+    /// Decodes a TokenRegistryAccount from raw TokenRegistry account data.
+    ///
+    /// # Returns
+    /// * `Ok(TokenRegistryAccount)` - Decoded registry
+    /// * `Err(String)` - If the data is not a TokenRegistry account or is truncated
+    pub fn from_account_data(pubkey: &Pubkey, data: &[u8]) -> Result<Self, String> {
+        let mut reader = AccountReader::new(data, &TOKEN_REGISTRY_DISCRIMINATOR)?;
+        let admin = reader.read_pubkey()?.to_string();
+
+        let count = reader.read_u32()? as usize;
+        let approved_tokens = (0..count)
+            .map(|_| {
+                Ok(ApprovedToken {
+                    mint: reader.read_pubkey()?.to_string(),
+                    symbol: Some(reader.read_string()?).filter(|symbol| !symbol.is_empty()),
+                    decimals: reader.read_option_u8()?,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self {
+            pubkey: pubkey.to_string(),
+            admin,
+            approved_tokens,
+        })
+    }
+
+    /// Returns whether `mint` (base58) is approved for entry fees.
+    pub fn is_token_approved(&self, mint: &str) -> bool {
+        self.approved_tokens.iter().any(|approved| approved.mint == mint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_approved_token_metadata() {
+        let usdc = Pubkey::new_unique();
+        let bare = Pubkey::new_unique();
+        let mut data = TOKEN_REGISTRY_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(Pubkey::new_unique().as_ref()); // admin
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(usdc.as_ref());
+        data.extend_from_slice(&4u32.to_le_bytes());
+        data.extend_from_slice(b"USDC");
+        data.extend_from_slice(&[1, 6]); // decimals: Some(6)
+        data.extend_from_slice(bare.as_ref());
+        data.extend_from_slice(&0u32.to_le_bytes()); // no symbol
+        data.push(0); // decimals: None
+        data.push(255); // bump
+
+        let registry = TokenRegistryAccount::from_account_data(&Pubkey::new_unique(), &data).unwrap();
+
+        assert_eq!(
+            registry.approved_tokens,
+            vec![
+                ApprovedToken {
+                    mint: usdc.to_string(),
+                    symbol: Some("USDC".to_string()),
+                    decimals: Some(6),
+                },
+                ApprovedToken { mint: bare.to_string(), symbol: None, decimals: None },
+            ]
+        );
+        assert!(registry.is_token_approved(&bare.to_string()));
+        assert!(!registry.is_token_approved(&Pubkey::new_unique().to_string()));
+    }
+}
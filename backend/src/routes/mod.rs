@@ -3,17 +3,22 @@
 //! This module defines all HTTP routes and builds the application router,
 //! connecting URL paths to their respective handler functions.
 
-use axum::{routing::get, Router};
+use axum::{
+    routing::{get, post},
+    Router,
+};
 use std::sync::Arc;
 
 use crate::handlers;
-use crate::middleware;
-use crate::services::TgbClient;
+use crate::middleware::{self, RateLimiter};
+use crate::state::AppState;
 
 /// Builds the complete application router with all routes and middleware.
 ///
 /// # Arguments
-/// * `tgb_client` - Shared TGB API client to be injected into handlers
+/// * `state` - Shared application state (TGB client, Solana service) injected into handlers
+/// * `rate_limiter` - Per-client token buckets applied to the `/api/*` routes
+/// * `debug_endpoints` - Whether to mount `/api/debug/*` (`DEBUG_ENDPOINTS`)
 ///
 /// # Returns
 /// A configured Axum Router ready to serve HTTP requests
@@ -21,26 +26,150 @@ use crate::services::TgbClient;
 /// # Route Structure
 /// - GET `/api/charities` - Search for charities by name
 /// - GET `/api/charities/:id/address/:token` - Get donation address for charity
-/// - GET `/health` - Health check endpoint
+/// - GET `/api/charities/:id/addresses?tokens=SOL,USDC` - Get donation addresses for several tokens
+/// - GET `/api/rooms` - List rooms from the Fundraisely program
+/// - GET `/api/room/:pubkey` - Get a single room (cached)
+/// - GET `/api/room/:pubkey/full` - Every field of a room, for detail pages
+/// - GET `/api/room/:pubkey/timeline` - Chronological event history of a room
+/// - GET `/api/room/:pubkey/charity-proof` - Proof the charity transfer of an ended room landed
+/// - GET `/api/room/:pubkey/outcome` - Outcome of an ended room (indexed)
+/// - GET `/api/room/:pubkey/charity-preview` - Live charity amount if the room ended now
+/// - GET `/api/room/:pubkey/financials` - Live payout breakdown per winner and charity source
+/// - GET `/api/room/:pubkey/vault` - Real token balance of a room's vault
+/// - GET `/api/balance/:pubkey` - Get an account's SOL balance (cached)
+/// - GET `/api/token-balance/:owner/:mint` - Get an owner's SPL token balance for a mint
+/// - GET `/api/approved-tokens` - Approved entry fee tokens with their metadata
+/// - GET `/api/player/:pubkey/entries` - List a player's room entries
+/// - POST `/api/calculate-fees` - Preview how entry fees will be split
+/// - POST `/api/build-transaction` - Build an unsigned program transaction
+/// - POST `/api/simulate-transaction` - Simulate a transaction and decode program errors
+/// - GET `/api/metrics/errors` - Program error code frequencies
+/// - GET `/api/debug/cache` - Account cache statistics (only with `debug_endpoints`)
+/// - GET `/ws` - WebSocket stream of room events (challenge/auth handshake, then subscribe)
+/// - GET `/health` - Health check: RPC reachability, current slot and cache statistics
+/// - GET `/health/ready` - Readiness check: TGB reachable and accepting the API key
+/// - GET `/metrics` - Prometheus request, latency and RPC metrics
 ///
 /// # Middleware
+/// - Metrics route layer on every route except `/metrics`: request counts by status and
+///   latency histograms per route template
+/// - Rate limit layer on `/api/*` only: 429 with `Retry-After` once a client IP exceeds
+///   its token bucket; health checks are exempt so probes are never throttled
 /// - CORS layer allowing all origins (suitable for development)
+/// - Request ID layer (outermost): `X-Request-Id` header plus a per-request tracing
+///   span with method, path, status and latency
+///
+/// # Errors
+/// Failing `/api/*` requests answer with `{"error": {"code", "message", "request_id"}}`
+/// (see `models::ApiError`); `request_id` matches the `X-Request-Id` header.
 ///
 /// # State Management
-/// The TgbClient is shared across all handlers using Axum's State extractor,
+/// Services are shared across all handlers using Axum's State extractor,
 /// avoiding the need to create new HTTP clients for each request.
-pub fn build_router(tgb_client: Arc<TgbClient>) -> Router {
-    Router::new()
+pub fn build_router(
+    state: AppState,
+    rate_limiter: Arc<RateLimiter>,
+    debug_endpoints: bool,
+) -> Router {
+    let mut api = Router::new()
         // Charity endpoints
         .route("/api/charities", get(handlers::search_charities))
         .route(
             "/api/charities/{id}/address/{token}",
             get(handlers::get_charity_address),
         )
-        // Health check endpoint
+        .route("/api/charities/{id}/addresses", get(handlers::get_charity_addresses))
+        // On-chain query endpoints
+        .route("/api/rooms", get(handlers::list_rooms))
+        .route("/api/room/{pubkey}", get(handlers::get_room))
+        .route("/api/room/{pubkey}/full", get(handlers::get_room_full))
+        .route("/api/room/{pubkey}/timeline", get(handlers::get_room_timeline))
+        .route("/api/room/{pubkey}/charity-proof", get(handlers::get_charity_proof))
+        .route("/api/room/{pubkey}/outcome", get(handlers::get_room_outcome))
+        .route("/api/room/{pubkey}/charity-preview", get(handlers::get_charity_preview))
+        .route("/api/room/{pubkey}/financials", get(handlers::get_room_financials))
+        .route("/api/room/{pubkey}/vault", get(handlers::get_vault_balance))
+        .route("/api/balance/{pubkey}", get(handlers::get_balance))
+        .route("/api/token-balance/{owner}/{mint}", get(handlers::get_token_balance))
+        .route("/api/approved-tokens", get(handlers::get_approved_tokens))
+        .route(
+            "/api/player/{pubkey}/entries",
+            get(handlers::get_player_entries),
+        )
+        // Fee preview endpoint
+        .route("/api/calculate-fees", post(handlers::calculate_fees))
+        // Transaction builder endpoints
+        .route("/api/build-transaction", post(handlers::build_transaction))
+        .route("/api/simulate-transaction", post(handlers::simulate_transaction))
+        // Operational metrics
+        .route("/api/metrics/errors", get(handlers::get_error_metrics));
+    // Service internals, for development only
+    if debug_endpoints {
+        api = api.route("/api/debug/cache", get(handlers::get_cache_stats));
+    }
+    let api = api.route_layer(axum::middleware::from_fn_with_state(
+        rate_limiter,
+        middleware::rate_limit,
+    ));
+
+    Router::new()
+        .merge(api)
+        // Room event stream (long-lived, so not rate limited per request)
+        .route("/ws", get(handlers::ws_handler))
+        // Health check endpoints (not rate limited)
         .route("/health", get(handlers::health_check))
+        .route("/health/ready", get(handlers::readiness_check))
+        // Request metrics for every route above; scrapes of /metrics aren't counted
+        .route_layer(axum::middleware::from_fn_with_state(
+            state.metrics.clone(),
+            middleware::track_metrics,
+        ))
+        .route("/metrics", get(handlers::get_prometheus_metrics))
         // Add shared state
-        .with_state(tgb_client)
+        .with_state(state)
         // Apply middleware
         .layer(middleware::cors_layer())
+        .layer(axum::middleware::from_fn(middleware::request_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::{Request, StatusCode}};
+    use solana_pubkey::Pubkey;
+    use std::time::Duration;
+    use tokio::sync::broadcast;
+    use tower::ServiceExt;
+
+    use crate::middleware::AuthConfig;
+    use crate::services::{CacheService, MetricsService, OutcomeIndex, SolanaService, TgbClient};
+
+    fn router(debug_endpoints: bool) -> Router {
+        let cache_service = Arc::new(CacheService::new(Duration::from_secs(5), Duration::from_secs(2)));
+        let state = AppState::new(
+            Arc::new(TgbClient::new("test-key".to_string())),
+            Arc::new(SolanaService::new(
+                "http://127.0.0.1:1".to_string(),
+                Pubkey::new_unique(),
+                cache_service.clone(),
+            )),
+            cache_service,
+            Arc::new(OutcomeIndex::new()),
+            Arc::new(MetricsService::new()),
+            broadcast::channel(1).0,
+            AuthConfig::new(Vec::new(), true),
+        );
+        build_router(state, Arc::new(RateLimiter::new(10.0, 20)), debug_endpoints)
+    }
+
+    async fn cache_stats_status(debug_endpoints: bool) -> StatusCode {
+        let request = Request::builder().uri("/api/debug/cache").body(Body::empty()).unwrap();
+        router(debug_endpoints).oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn serves_debug_endpoints_only_when_enabled() {
+        assert_eq!(cache_stats_status(false).await, StatusCode::NOT_FOUND);
+        assert_eq!(cache_stats_status(true).await, StatusCode::OK);
+    }
 }
@@ -0,0 +1,455 @@
+//! In-memory TTL cache for Solana account queries.
+//!
+//! Room and balance lookups are read far more often than they change, and every
+//! uncached read is an RPC round-trip. This cache keeps recent results for a short
+//! time so bursts of identical requests (e.g., a lobby polling a room) are served
+//! locally. Callers that need post-transaction freshness bypass it explicitly, and
+//! program events (see `event_listener`) evict entries as soon as the chain changes.
+//!
+//! GlobalConfig and TokenRegistry change only through admin instructions (and, for
+//! GlobalConfig's lifetime totals, room ends), so they are kept much longer and rely on
+//! `ConfigUpdated`/`PlatformStats`/`TokenApproved`/`TokenRemoved` events for invalidation.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use solana_pubkey::Pubkey;
+
+use crate::models::{GlobalConfigAccount, ProgramEvent, RoomAccount, TokenRegistryAccount};
+
+/// Default time-to-live for cached Room accounts.
+pub const DEFAULT_ROOM_TTL: Duration = Duration::from_secs(5);
+
+/// Default time-to-live for cached SOL balances.
+pub const DEFAULT_BALANCE_TTL: Duration = Duration::from_secs(2);
+
+/// Default time-to-live for the cached GlobalConfig and TokenRegistry accounts.
+pub const DEFAULT_CONFIG_TTL: Duration = Duration::from_secs(300);
+
+/// Map of entries that expire a fixed duration after insertion.
+struct TtlMap<V> {
+    ttl: Duration,
+    entries: RwLock<HashMap<Pubkey, (Instant, V)>>,
+}
+
+impl<V: Clone> TtlMap<V> {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the value if present and not expired; expired entries are evicted.
+    fn get(&self, key: &Pubkey) -> Option<V> {
+        {
+            let entries = self.entries.read().unwrap();
+            match entries.get(key) {
+                Some((inserted, value)) if inserted.elapsed() < self.ttl => return Some(value.clone()),
+                Some(_) => {}
+                None => return None,
+            }
+        }
+
+        self.entries.write().unwrap().remove(key);
+        None
+    }
+
+    fn insert(&self, key: Pubkey, value: V) {
+        let mut entries = self.entries.write().unwrap();
+        // Drop stale entries opportunistically so the map doesn't grow unbounded
+        entries.retain(|_, (inserted, _)| inserted.elapsed() < self.ttl);
+        entries.insert(key, (Instant::now(), value));
+    }
+
+    fn remove(&self, key: &Pubkey) -> bool {
+        self.entries.write().unwrap().remove(key).is_some()
+    }
+
+    /// Removes every entry, returning how many were dropped.
+    fn clear(&self) -> usize {
+        let mut entries = self.entries.write().unwrap();
+        let removed = entries.len();
+        entries.clear();
+        removed
+    }
+
+    fn len(&self) -> usize {
+        self.entries.read().unwrap().len()
+    }
+}
+
+/// Cache hit/miss counters, reported on the health endpoint.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Detailed cache state for the debug endpoint.
+///
+/// # Fields
+/// * `hits` / `misses` - Lookup counts since startup
+/// * `invalidations` - Entries evicted because a program event changed them
+/// * `room_entries` / `balance_entries` - Current entry counts (may include expired entries)
+/// * `config_entries` - Cached GlobalConfig and TokenRegistry accounts
+/// * `room_ttl_ms` / `balance_ttl_ms` / `config_ttl_ms` - Configured TTLs
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheDebugStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub invalidations: u64,
+    pub room_entries: usize,
+    pub balance_entries: usize,
+    pub config_entries: usize,
+    pub room_ttl_ms: u128,
+    pub balance_ttl_ms: u128,
+    pub config_ttl_ms: u128,
+}
+
+/// Cache for Room accounts, SOL balances and platform configuration, keyed by pubkey.
+pub struct CacheService {
+    rooms: TtlMap<RoomAccount>,
+    balances: TtlMap<u64>,
+    config: TtlMap<GlobalConfigAccount>,
+    registry: TtlMap<TokenRegistryAccount>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    invalidations: AtomicU64,
+}
+
+impl CacheService {
+    /// Creates a cache with the given TTLs.
+    ///
+    /// # Arguments
+    /// * `room_ttl` - How long a Room account stays fresh
+    /// * `balance_ttl` - How long a SOL balance stays fresh
+    pub fn new(room_ttl: Duration, balance_ttl: Duration) -> Self {
+        Self {
+            rooms: TtlMap::new(room_ttl),
+            balances: TtlMap::new(balance_ttl),
+            config: TtlMap::new(DEFAULT_CONFIG_TTL),
+            registry: TtlMap::new(DEFAULT_CONFIG_TTL),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            invalidations: AtomicU64::new(0),
+        }
+    }
+
+    /// Sets how long GlobalConfig and TokenRegistry stay fresh (default: 5 minutes).
+    pub fn with_config_ttl(mut self, ttl: Duration) -> Self {
+        self.config = TtlMap::new(ttl);
+        self.registry = TtlMap::new(ttl);
+        self
+    }
+
+    /// Returns a cached room, counting the lookup as a hit or miss.
+    pub fn get_room(&self, pubkey: &Pubkey) -> Option<RoomAccount> {
+        self.record(self.rooms.get(pubkey))
+    }
+
+    /// Stores a room fetched from RPC.
+    pub fn insert_room(&self, pubkey: Pubkey, room: RoomAccount) {
+        self.rooms.insert(pubkey, room);
+    }
+
+    /// Drops a cached room so the next read goes to RPC.
+    pub fn invalidate_room(&self, pubkey: &Pubkey) {
+        if self.rooms.remove(pubkey) {
+            self.invalidations.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Drops a cached balance so the next read goes to RPC.
+    pub fn invalidate_balance(&self, pubkey: &Pubkey) {
+        if self.balances.remove(pubkey) {
+            self.invalidations.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns the cached GlobalConfig, counting the lookup as a hit or miss.
+    pub fn get_global_config(&self, pubkey: &Pubkey) -> Option<GlobalConfigAccount> {
+        self.record(self.config.get(pubkey))
+    }
+
+    /// Stores a GlobalConfig fetched from RPC.
+    pub fn insert_global_config(&self, pubkey: Pubkey, config: GlobalConfigAccount) {
+        self.config.insert(pubkey, config);
+    }
+
+    /// Drops the cached GlobalConfig so the next read goes to RPC.
+    pub fn invalidate_global_config(&self) {
+        let removed = self.config.clear();
+        self.invalidations.fetch_add(removed as u64, Ordering::Relaxed);
+    }
+
+    /// Returns the cached TokenRegistry, counting the lookup as a hit or miss.
+    pub fn get_token_registry(&self, pubkey: &Pubkey) -> Option<TokenRegistryAccount> {
+        self.record(self.registry.get(pubkey))
+    }
+
+    /// Stores a TokenRegistry fetched from RPC.
+    pub fn insert_token_registry(&self, pubkey: Pubkey, registry: TokenRegistryAccount) {
+        self.registry.insert(pubkey, registry);
+    }
+
+    /// Drops the cached TokenRegistry so the next read goes to RPC.
+    pub fn invalidate_token_registry(&self) {
+        let removed = self.registry.clear();
+        self.invalidations.fetch_add(removed as u64, Ordering::Relaxed);
+    }
+
+    /// Evicts every entry a program event has made stale.
+    ///
+    /// Room-scoped events evict the room; a join, leave or donation also evicts the
+    /// wallet's balance, since it paid rent for its PlayerEntry or Donation account or got
+    /// it back. Admin events evict the
+    /// GlobalConfig or TokenRegistry they changed, and a room end's `PlatformStats`
+    /// evicts GlobalConfig, whose lifetime totals it updated.
+    pub fn apply_event(&self, event: &ProgramEvent) {
+        if let Some(room) = event.room() {
+            self.invalidate_room(&room);
+        }
+
+        match event {
+            ProgramEvent::PlayerJoined { player: wallet, .. }
+            | ProgramEvent::PlayerLeft { player: wallet, .. }
+            | ProgramEvent::DonationReceived { donor: wallet, .. } => {
+                if let Ok(wallet) = wallet.parse::<Pubkey>() {
+                    self.invalidate_balance(&wallet);
+                }
+            }
+            ProgramEvent::ConfigUpdated { .. } | ProgramEvent::PlatformStats { .. } => {
+                self.invalidate_global_config()
+            }
+            ProgramEvent::TokenApproved { .. } | ProgramEvent::TokenRemoved { .. } => {
+                self.invalidate_token_registry()
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns a cached balance in lamports, counting the lookup as a hit or miss.
+    pub fn get_balance(&self, pubkey: &Pubkey) -> Option<u64> {
+        self.record(self.balances.get(pubkey))
+    }
+
+    /// Stores a balance fetched from RPC.
+    pub fn insert_balance(&self, pubkey: Pubkey, lamports: u64) {
+        self.balances.insert(pubkey, lamports);
+    }
+
+    /// Returns hit/miss counts since startup.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Returns counters, entry counts and TTLs for debugging.
+    pub fn debug_stats(&self) -> CacheDebugStats {
+        let CacheStats { hits, misses } = self.stats();
+        CacheDebugStats {
+            hits,
+            misses,
+            invalidations: self.invalidations.load(Ordering::Relaxed),
+            room_entries: self.rooms.len(),
+            balance_entries: self.balances.len(),
+            config_entries: self.config.len() + self.registry.len(),
+            room_ttl_ms: self.rooms.ttl.as_millis(),
+            balance_ttl_ms: self.balances.ttl.as_millis(),
+            config_ttl_ms: self.config.ttl.as_millis(),
+        }
+    }
+
+    fn record<V>(&self, value: Option<V>) -> Option<V> {
+        let counter = if value.is_some() { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
+    }
+}
+
+impl Default for CacheService {
+    fn default() -> Self {
+        Self::new(DEFAULT_ROOM_TTL, DEFAULT_BALANCE_TTL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::program_event::{CONFIG_UPDATED_DISCRIMINATOR, PLAYER_JOINED_DISCRIMINATOR};
+    use crate::models::{ApprovedToken, RoomStatus};
+    use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+
+    fn room(pubkey: &Pubkey) -> RoomAccount {
+        RoomAccount {
+            pubkey: pubkey.to_string(),
+            room_id: "quiz-night".to_string(),
+            host: Pubkey::new_unique().to_string(),
+            charity_wallet: Pubkey::new_unique().to_string(),
+            fee_token_mint: Pubkey::new_unique().to_string(),
+            entry_fee: 1_000_000,
+            host_fee_bps: 300,
+            prize_pool_bps: 2000,
+            prize_distribution: vec![100],
+            max_players: 10,
+            player_count: 1,
+            status: RoomStatus::Active,
+            total_collected: 1_000_000,
+            total_entry_fees: 1_000_000,
+            total_extras_fees: 0,
+            ended: false,
+            creation_slot: 100,
+            expiration_slot: 0,
+            expiration_unix_ts: 0,
+            guaranteed_min_prize: 0,
+            platform_fee_bps: 2000,
+            prize_tiers: Vec::new(),
+            host_fee_waived: false,
+            early_bird_fee: 0,
+            early_bird_count: 0,
+            discount_bps: 0,
+            total_discounts: 0,
+            place_sizes: Vec::new(),
+            room_version: 3,
+            leave_penalty_bps: 0,
+            total_leave_penalties: 0,
+            total_donations: 0,
+        }
+    }
+
+    fn global_config(pubkey: &Pubkey) -> GlobalConfigAccount {
+        GlobalConfigAccount {
+            pubkey: pubkey.to_string(),
+            admin: Pubkey::new_unique().to_string(),
+            platform_wallet: Pubkey::new_unique().to_string(),
+            charity_wallet: Pubkey::new_unique().to_string(),
+            platform_fee_bps: 2000,
+            max_host_fee_bps: 500,
+            max_prize_pool_bps: 3500,
+            min_charity_bps: 4000,
+            emergency_pause: false,
+            require_declaration: false,
+            receipt_attestor: None,
+            max_approved_tokens: 50,
+            min_platform_fee_bps: 1000,
+            lifetime_charity_raised: 0,
+            lifetime_rooms_ended: 0,
+            lifetime_players: 0,
+            max_expiration_slots: 6_480_000,
+            default_expiration_slots: 1_512_000,
+        }
+    }
+
+    #[test]
+    fn entries_expire_after_ttl() {
+        let cache = CacheService::new(Duration::from_millis(20), Duration::from_millis(20));
+        let pubkey = Pubkey::new_unique();
+
+        cache.insert_room(pubkey, room(&pubkey));
+        cache.insert_balance(pubkey, 42);
+        assert!(cache.get_room(&pubkey).is_some());
+        assert_eq!(cache.get_balance(&pubkey), Some(42));
+
+        std::thread::sleep(Duration::from_millis(30));
+
+        assert!(cache.get_room(&pubkey).is_none());
+        assert_eq!(cache.get_balance(&pubkey), None);
+        assert_eq!(cache.stats(), CacheStats { hits: 2, misses: 2 });
+        assert_eq!(cache.debug_stats().room_entries, 0);
+    }
+
+    #[test]
+    fn player_joined_event_invalidates_room_and_player_balance() {
+        let cache = CacheService::default();
+        let room_pubkey = Pubkey::new_unique();
+        let player = Pubkey::new_unique();
+        let other_room = Pubkey::new_unique();
+
+        cache.insert_room(room_pubkey, room(&room_pubkey));
+        cache.insert_room(other_room, room(&other_room));
+        cache.insert_balance(player, 5_000_000);
+
+        // `emit!(PlayerJoined { .. })` as it appears in the join_room transaction logs
+        let mut payload = PLAYER_JOINED_DISCRIMINATOR.to_vec();
+        payload.extend_from_slice(room_pubkey.as_ref());
+        payload.extend_from_slice(player.as_ref());
+        payload.extend_from_slice(&1_000_000u64.to_le_bytes());
+        payload.extend_from_slice(&0u64.to_le_bytes());
+        payload.extend_from_slice(&2u32.to_le_bytes());
+        payload.push(0); // referrer: None
+        payload.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        let logs = vec![
+            "Program log: Instruction: JoinRoom".to_string(),
+            format!("Program data: {}", BASE64.encode(&payload)),
+        ];
+
+        let events = ProgramEvent::from_logs(&logs);
+        assert_eq!(events.len(), 1);
+        events.iter().for_each(|event| cache.apply_event(event));
+
+        assert!(cache.get_room(&room_pubkey).is_none());
+        assert!(cache.get_balance(&player).is_none());
+        assert!(cache.get_room(&other_room).is_some());
+        assert_eq!(cache.debug_stats().invalidations, 2);
+    }
+
+    #[test]
+    fn global_config_is_served_from_cache_within_ttl() {
+        let cache = CacheService::default().with_config_ttl(Duration::from_secs(60));
+        let config_pda = Pubkey::new_unique();
+
+        assert!(cache.get_global_config(&config_pda).is_none());
+        cache.insert_global_config(config_pda, global_config(&config_pda));
+
+        let cached = cache.get_global_config(&config_pda).unwrap();
+        assert_eq!(cached.platform_fee_bps, 2000);
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+        assert_eq!(cache.debug_stats().config_ttl_ms, 60_000);
+    }
+
+    #[test]
+    fn config_updated_event_invalidates_global_config_only() {
+        let cache = CacheService::default();
+        let config_pda = Pubkey::new_unique();
+        let registry_pda = Pubkey::new_unique();
+
+        cache.insert_global_config(config_pda, global_config(&config_pda));
+        cache.insert_token_registry(
+            registry_pda,
+            TokenRegistryAccount {
+                pubkey: registry_pda.to_string(),
+                admin: Pubkey::new_unique().to_string(),
+                approved_tokens: vec![ApprovedToken {
+                    mint: Pubkey::new_unique().to_string(),
+                    symbol: Some("USDC".to_string()),
+                    decimals: Some(6),
+                }],
+            },
+        );
+
+        // `emit!(ConfigUpdated { .. })` as it appears in the update_config transaction logs
+        let mut payload = CONFIG_UPDATED_DISCRIMINATOR.to_vec();
+        payload.extend_from_slice(Pubkey::new_unique().as_ref());
+        payload.push(1); // require_declaration
+        payload.extend_from_slice(Pubkey::default().as_ref());
+        payload.extend_from_slice(&50u16.to_le_bytes()); // max_approved_tokens
+        payload.extend_from_slice(&6_480_000u64.to_le_bytes()); // max_expiration_slots
+        payload.extend_from_slice(&1_512_000u64.to_le_bytes()); // default_expiration_slots
+        payload.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        let logs = vec![format!("Program data: {}", BASE64.encode(&payload))];
+
+        ProgramEvent::from_logs(&logs)
+            .iter()
+            .for_each(|event| cache.apply_event(event));
+
+        assert!(cache.get_global_config(&config_pda).is_none());
+        assert!(cache.get_token_registry(&registry_pda).is_some());
+        assert_eq!(cache.debug_stats().invalidations, 1);
+    }
+}
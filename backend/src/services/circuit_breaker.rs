@@ -0,0 +1,112 @@
+//! Consecutive-failure circuit breaker for upstream services.
+//!
+//! After `failure_threshold` failed calls in a row the circuit opens and callers are
+//! refused for the cooldown window instead of waiting on a service that is down.
+//! Once the cooldown has passed calls are let through again (half-open): the first
+//! success closes the circuit, while another failure re-opens it for a fresh cooldown.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Default number of consecutive failures that opens the circuit.
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+
+/// Default time the circuit stays open before calls are retried.
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+#[derive(Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+/// Tracks consecutive upstream failures and refuses calls while the circuit is open.
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    state: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+    /// Creates a closed breaker; a `failure_threshold` of 0 disables it.
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold,
+            cooldown,
+            state: Mutex::new(BreakerState::default()),
+        }
+    }
+
+    /// Checks whether a call may proceed.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the circuit is closed or its cooldown has passed
+    /// * `Err(Duration)` - Time left until calls are let through again
+    pub fn check(&self) -> Result<(), Duration> {
+        let state = self.state.lock().unwrap();
+        match state.open_until {
+            Some(until) => match until.checked_duration_since(Instant::now()) {
+                Some(remaining) if !remaining.is_zero() => Err(remaining),
+                _ => Ok(()),
+            },
+            None => Ok(()),
+        }
+    }
+
+    /// Records a successful call, closing the circuit.
+    pub fn record_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures = 0;
+        state.open_until = None;
+    }
+
+    /// Records a failed call.
+    ///
+    /// # Returns
+    /// `true` if this failure opened (or re-opened) the circuit
+    pub fn record_failure(&self) -> bool {
+        if self.failure_threshold == 0 {
+            return false;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        if state.consecutive_failures >= self.failure_threshold {
+            state.open_until = Some(Instant::now() + self.cooldown);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(DEFAULT_FAILURE_THRESHOLD, DEFAULT_COOLDOWN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opens_after_threshold_and_half_opens_after_cooldown() {
+        let breaker = CircuitBreaker::new(2, Duration::from_millis(50));
+
+        assert!(!breaker.record_failure());
+        assert!(breaker.check().is_ok());
+        assert!(breaker.record_failure());
+        assert!(breaker.check().is_err());
+
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(breaker.check().is_ok(), "cooldown over, calls go through");
+
+        // A failed trial call re-opens immediately; a success closes the circuit
+        assert!(breaker.record_failure());
+        assert!(breaker.check().is_err());
+        breaker.record_success();
+        assert!(breaker.check().is_ok());
+        assert!(!breaker.record_failure());
+    }
+}
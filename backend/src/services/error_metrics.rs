@@ -0,0 +1,128 @@
+//! Per-error-code counters for failed Fundraisely transactions.
+//!
+//! Whenever the backend simulates or relays a transaction that fails, the program
+//! error code is parsed from the transaction logs and counted here, so operators
+//! can see which `FundraiselyError`s users hit most often.
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use tracing::info;
+
+use crate::models::program_error::{parse_error_code, program_error_name};
+
+/// Number of failures observed for one error code.
+///
+/// # Fields
+/// * `code` - Custom program error code (e.g., 6025)
+/// * `name` - `FundraiselyError` variant name, or null for non-Fundraisely codes
+/// * `count` - Failures recorded since the server started
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ErrorCount {
+    pub code: u32,
+    pub name: Option<&'static str>,
+    pub count: u64,
+}
+
+/// Thread-safe counters keyed by program error code.
+#[derive(Default)]
+pub struct ErrorMetrics {
+    counts: Mutex<BTreeMap<u32, u64>>,
+}
+
+impl ErrorMetrics {
+    /// Creates an empty set of counters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the error code from failed transaction logs and increments its counter.
+    ///
+    /// # Returns
+    /// * `Some(code)` - The code that was counted
+    /// * `None` - If the logs contain no custom program error
+    pub fn record_failure_logs(&self, logs: &[String]) -> Option<u32> {
+        let code = parse_error_code(logs)?;
+        self.increment(code);
+        Some(code)
+    }
+
+    /// Increments the counter for a single error code.
+    pub fn increment(&self, code: u32) {
+        let mut counts = self.counts.lock().unwrap();
+        *counts.entry(code).or_insert(0) += 1;
+        info!(
+            "Program error {} ({}) recorded",
+            code,
+            program_error_name(code).unwrap_or("unknown")
+        );
+    }
+
+    /// Returns how many times `code` has been recorded.
+    pub fn count(&self, code: u32) -> u64 {
+        self.counts.lock().unwrap().get(&code).copied().unwrap_or(0)
+    }
+
+    /// Returns all recorded counters, ordered by error code.
+    pub fn snapshot(&self) -> Vec<ErrorCount> {
+        self.counts
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(&code, &count)| ErrorCount {
+                code,
+                name: program_error_name(code),
+                count,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed_join_logs() -> Vec<String> {
+        vec![
+            "Program DurTiNFFQK62B5nMimfhuvztJXsFyu8skMz6rNtp2Wmq invoke [1]".to_string(),
+            "Program log: Instruction: JoinRoom".to_string(),
+            "Program log: AnchorError thrown in programs/fundraisely/src/instructions/player/join_room.rs:60. Error Code: MaxPlayersReached. Error Number: 6025. Error Message: Room has reached maximum players.".to_string(),
+            "Program DurTiNFFQK62B5nMimfhuvztJXsFyu8skMz6rNtp2Wmq consumed 12000 of 200000 compute units".to_string(),
+            "Program DurTiNFFQK62B5nMimfhuvztJXsFyu8skMz6rNtp2Wmq failed: custom program error: 0x1789".to_string(),
+        ]
+    }
+
+    #[test]
+    fn records_known_error_code_from_failed_logs() {
+        let metrics = ErrorMetrics::new();
+
+        assert_eq!(metrics.record_failure_logs(&failed_join_logs()), Some(6025));
+        assert_eq!(metrics.record_failure_logs(&failed_join_logs()), Some(6025));
+
+        assert_eq!(metrics.count(6025), 2);
+        assert_eq!(
+            metrics.snapshot(),
+            vec![ErrorCount {
+                code: 6025,
+                name: Some("MaxPlayersReached"),
+                count: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn parses_runtime_hex_error_line() {
+        let logs = vec!["Program X failed: custom program error: 0x1776".to_string()];
+        assert_eq!(parse_error_code(&logs), Some(6006));
+        assert_eq!(program_error_name(6006), Some("RoomExpired"));
+    }
+
+    #[test]
+    fn ignores_logs_without_program_error() {
+        let metrics = ErrorMetrics::new();
+        let logs = vec!["Program X success".to_string()];
+
+        assert_eq!(metrics.record_failure_logs(&logs), None);
+        assert!(metrics.snapshot().is_empty());
+    }
+}
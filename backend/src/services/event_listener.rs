@@ -0,0 +1,112 @@
+//! Background listener for Fundraisely program events.
+//!
+//! Polls the RPC node for new transactions touching the program, decodes the
+//! events in their logs, evicts the cache entries those events made stale,
+//! records the outcome of every room that ends and forwards every event to the
+//! WebSocket clients watching its room.
+//! Polling `getSignaturesForAddress` stands in for a `logsSubscribe` WebSocket:
+//! it needs no extra connection management and resumes cleanly after RPC errors.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+
+use crate::models::ProgramEvent;
+use crate::services::{CacheService, OutcomeIndex, SolanaService};
+
+/// Maximum signatures fetched per poll.
+const SIGNATURES_PER_POLL: usize = 100;
+
+/// A decoded event with the transaction that emitted it.
+struct PolledEvent {
+    signature: String,
+    slot: u64,
+    event: ProgramEvent,
+}
+
+/// Spawns the event listener as a background task.
+///
+/// The first poll only records the newest signature as a cursor, so historical
+/// transactions are not replayed on startup.
+///
+/// # Arguments
+/// * `solana_service` - RPC client used to fetch signatures and logs
+/// * `cache_service` - Cache to invalidate when events arrive
+/// * `outcome_index` - Index receiving the outcome of every `RoomEnded` event
+/// * `events` - Channel the decoded events are published on (sending with no subscribers is fine)
+/// * `interval` - Delay between polls
+pub fn spawn_event_listener(
+    solana_service: Arc<SolanaService>,
+    cache_service: Arc<CacheService>,
+    outcome_index: Arc<OutcomeIndex>,
+    events: broadcast::Sender<ProgramEvent>,
+    interval: Duration,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        info!("Event listener: Polling program logs every {:?}", interval);
+        let mut cursor: Option<String> = None;
+
+        loop {
+            match poll_events(&solana_service, cursor.as_deref()).await {
+                Ok((polled_events, newest)) => {
+                    for polled in polled_events {
+                        cache_service.apply_event(&polled.event);
+                        outcome_index.record_event(&polled.event, &polled.signature, polled.slot);
+                        let _ = events.send(polled.event);
+                    }
+                    if newest.is_some() {
+                        cursor = newest;
+                    }
+                }
+                Err(e) => warn!("Event listener: Poll failed: {}", e),
+            }
+
+            tokio::time::sleep(interval).await;
+        }
+    })
+}
+
+/// Fetches events from transactions newer than `cursor`, oldest first.
+///
+/// # Returns
+/// * `Ok((events, newest))` - Decoded events with their transactions, and the newest
+///   signature seen (new cursor)
+/// * `Err(String)` - If listing signatures fails
+async fn poll_events(
+    solana_service: &SolanaService,
+    cursor: Option<&str>,
+) -> Result<(Vec<PolledEvent>, Option<String>), String> {
+    let signatures = solana_service
+        .get_signatures_for_address(solana_service.program_id(), None, cursor, SIGNATURES_PER_POLL)
+        .await?;
+
+    let newest = signatures.first().map(|info| info.signature.clone());
+
+    // Without a cursor this is the startup poll: just establish the position
+    if cursor.is_none() {
+        return Ok((Vec::new(), newest));
+    }
+
+    let mut events = Vec::new();
+    for info in signatures.iter().rev().filter(|info| info.err.is_none()) {
+        match solana_service.get_transaction_logs(&info.signature).await {
+            Ok(Some(logs)) => events.extend(ProgramEvent::from_logs(&logs).into_iter().map(|event| {
+                PolledEvent {
+                    signature: info.signature.clone(),
+                    slot: info.slot,
+                    event,
+                }
+            })),
+            Ok(None) => {}
+            Err(e) => warn!("Event listener: Failed to fetch logs for {}: {}", info.signature, e),
+        }
+    }
+
+    if !events.is_empty() {
+        info!("Event listener: Decoded {} events", events.len());
+    }
+
+    Ok((events, newest))
+}
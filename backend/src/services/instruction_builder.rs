@@ -0,0 +1,539 @@
+//! Fundraisely program instruction encoding.
+//!
+//! This module builds raw Solana instructions for the Fundraisely program without
+//! depending on the Anchor client. Instruction data follows Anchor's layout: an
+//! 8-byte discriminator (`sha256("global:<name>")[..8]`) followed by the Borsh
+//! encoding of the instruction arguments, in declaration order.
+
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::{pubkey, Pubkey};
+
+/// SPL Token program ID.
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// SPL Associated Token Account program ID.
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// System program ID.
+pub const SYSTEM_PROGRAM_ID: Pubkey = pubkey!("11111111111111111111111111111111");
+
+/// Rent sysvar ID.
+pub const RENT_SYSVAR_ID: Pubkey = pubkey!("SysvarRent111111111111111111111111111111111");
+
+/// Compute Budget program ID.
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey = pubkey!("ComputeBudget111111111111111111111111111111");
+
+/// Fixed compute units of `end_room` (validation, fee math, platform/host/charity transfers).
+pub const END_ROOM_BASE_COMPUTE_UNITS: u32 = 60_000;
+
+/// Compute units per extra token transfer in `end_room` (one per winner and extra charity).
+pub const END_ROOM_COMPUTE_UNITS_PER_TRANSFER: u32 = 25_000;
+
+/// Anchor discriminator for the `join_room` instruction.
+pub const JOIN_ROOM_DISCRIMINATOR: [u8; 8] = [95, 232, 188, 81, 124, 130, 78, 139];
+
+/// Anchor discriminator for the `init_pool_room` instruction.
+pub const INIT_POOL_ROOM_DISCRIMINATOR: [u8; 8] = [51, 17, 194, 102, 72, 127, 188, 37];
+
+/// Anchor discriminator for the `end_room` instruction.
+pub const END_ROOM_DISCRIMINATOR: [u8; 8] = [102, 106, 181, 155, 61, 17, 40, 78];
+
+/// Derives the associated token account for `owner` and `mint` (SPL Token program).
+pub fn get_associated_token_address(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), TOKEN_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Accounts required by the `join_room` instruction, in `JoinRoom` order.
+///
+/// # Fields
+/// * `room` - Room PDA (`["room", host, room_id]`)
+/// * `player_entry` - PlayerEntry PDA (`["player", room, player]`), created by the instruction
+/// * `room_vault` - Room vault PDA (`["room-vault", room]`)
+/// * `player_token_account` - Player's token account for the room's fee mint
+/// * `global_config` - GlobalConfig PDA (`["global-config"]`)
+/// * `blacklist` - Blacklist PDA (`["blacklist"]`)
+/// * `discount` - The player's Discount PDA (`["discount", room, player]`), if the host added one
+/// * `player` - Joining player (signer and fee payer)
+pub struct JoinRoomAccounts {
+    pub room: Pubkey,
+    pub player_entry: Pubkey,
+    pub room_vault: Pubkey,
+    pub player_token_account: Pubkey,
+    pub global_config: Pubkey,
+    pub blacklist: Pubkey,
+    pub discount: Option<Pubkey>,
+    pub player: Pubkey,
+}
+
+/// Builds a `join_room` instruction.
+///
+/// # Arguments
+/// * `program_id` - Deployed Fundraisely program ID
+/// * `accounts` - Resolved accounts for the instruction
+/// * `room_id` - Room identifier (used by the program to re-derive the room PDA)
+/// * `extras_amount` - Optional extra contribution in token base units (0 for none)
+/// * `allowlist_proof` - Merkle proof of the player's wallet for a private room (None for public rooms)
+/// * `referrer` - Wallet to credit with referring the player (None for no referral)
+pub fn join_room(
+    program_id: &Pubkey,
+    accounts: &JoinRoomAccounts,
+    room_id: &str,
+    extras_amount: u64,
+    allowlist_proof: Option<&[[u8; 32]]>,
+    referrer: Option<&Pubkey>,
+) -> Instruction {
+    let mut data = JOIN_ROOM_DISCRIMINATOR.to_vec();
+    encode_string(&mut data, room_id);
+    data.extend_from_slice(&extras_amount.to_le_bytes());
+    match allowlist_proof {
+        Some(proof) => {
+            data.push(1);
+            data.extend_from_slice(&(proof.len() as u32).to_le_bytes());
+            for hash in proof {
+                data.extend_from_slice(hash);
+            }
+        }
+        None => data.push(0),
+    }
+    match referrer {
+        Some(referrer) => {
+            data.push(1);
+            data.extend_from_slice(referrer.as_ref());
+        }
+        None => data.push(0),
+    }
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(accounts.room, false),
+            AccountMeta::new(accounts.player_entry, false),
+            AccountMeta::new(accounts.room_vault, false),
+            AccountMeta::new(accounts.player_token_account, false),
+            AccountMeta::new_readonly(accounts.global_config, false),
+            AccountMeta::new_readonly(accounts.blacklist, false),
+            // Anchor reads the program ID in an optional account's slot as `None`
+            AccountMeta::new_readonly(accounts.discount.unwrap_or(*program_id), false),
+            AccountMeta::new(accounts.player, true),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+/// Accounts required by the `init_pool_room` instruction, in `InitPoolRoom` order.
+///
+/// # Fields
+/// * `room` - Room PDA (`["room", host, room_id]`), created by the instruction
+/// * `room_vault` - Room vault PDA (`["room-vault", room]`), created by the instruction as a
+///   token account for `fee_token_mint` owned by the room PDA
+/// * `fee_token_mint` - Mint entry fees are paid in (must be in the token registry)
+/// * `token_registry` - TokenRegistry PDA (`["token-registry"]`)
+/// * `global_config` - GlobalConfig PDA (`["global-config"]`)
+/// * `host` - Room host (signer and fee payer)
+pub struct InitPoolRoomAccounts {
+    pub room: Pubkey,
+    pub room_vault: Pubkey,
+    pub fee_token_mint: Pubkey,
+    pub token_registry: Pubkey,
+    pub global_config: Pubkey,
+    pub host: Pubkey,
+}
+
+/// Arguments of the `init_pool_room` instruction, in declaration order.
+///
+/// Charity splits and prize tiers are always encoded as `None`: rooms built here pay a
+/// single charity wallet with one prize distribution.
+///
+/// # Fields
+/// * `room_id` - Room identifier (1-32 characters)
+/// * `charity_wallet` - Wallet receiving the charity share
+/// * `entry_fee` - Entry fee in token base units
+/// * `max_players` - Room capacity
+/// * `host_fee_bps` / `prize_pool_bps` - Host and prize pool shares of entry fees
+/// * `prize_distribution` - Percentage of the prize pool per place
+/// * `charity_memo` - Memo attached to the charity transfer
+/// * `expiration_slots` / `expiration_unix_ts` - Optional expiry, relative in slots or absolute
+/// * `min_players` / `require_ready` - Optional turnout and ready-up requirements
+/// * `allowlist_root` - Merkle root of allowed wallets for a private room
+/// * `early_bird_fee` / `early_bird_count` - Optional reduced fee for the first players
+/// * `discount_bps` - Discount for wallets the host registers with `add_discount`
+/// * `leave_penalty_bps` - Share of a leaving player's payment kept for charity
+pub struct InitPoolRoomArgs<'a> {
+    pub room_id: &'a str,
+    pub charity_wallet: Pubkey,
+    pub entry_fee: u64,
+    pub max_players: u32,
+    pub host_fee_bps: u16,
+    pub prize_pool_bps: u16,
+    pub prize_distribution: &'a [u16],
+    pub charity_memo: &'a str,
+    pub expiration_slots: Option<u64>,
+    pub min_players: Option<u32>,
+    pub require_ready: Option<bool>,
+    pub expiration_unix_ts: Option<i64>,
+    pub allowlist_root: Option<[u8; 32]>,
+    pub early_bird_fee: Option<u64>,
+    pub early_bird_count: Option<u32>,
+    pub discount_bps: Option<u16>,
+    pub leave_penalty_bps: Option<u16>,
+}
+
+/// Builds an `init_pool_room` instruction for an SPL Token fee mint.
+///
+/// # Arguments
+/// * `program_id` - Deployed Fundraisely program ID
+/// * `accounts` - Resolved accounts for the instruction
+/// * `args` - Instruction arguments
+pub fn init_pool_room(
+    program_id: &Pubkey,
+    accounts: &InitPoolRoomAccounts,
+    args: &InitPoolRoomArgs,
+) -> Instruction {
+    let mut data = INIT_POOL_ROOM_DISCRIMINATOR.to_vec();
+    encode_string(&mut data, args.room_id);
+    data.extend_from_slice(args.charity_wallet.as_ref());
+    data.extend_from_slice(&args.entry_fee.to_le_bytes());
+    data.extend_from_slice(&args.max_players.to_le_bytes());
+    data.extend_from_slice(&args.host_fee_bps.to_le_bytes());
+    data.extend_from_slice(&args.prize_pool_bps.to_le_bytes());
+    data.extend_from_slice(&(args.prize_distribution.len() as u32).to_le_bytes());
+    for pct in args.prize_distribution {
+        data.extend_from_slice(&pct.to_le_bytes());
+    }
+    encode_string(&mut data, args.charity_memo);
+    encode_option(&mut data, args.expiration_slots.map(u64::to_le_bytes));
+    data.push(0); // charity_splits
+    encode_option(&mut data, args.min_players.map(u32::to_le_bytes));
+    encode_option(&mut data, args.require_ready.map(|ready| [ready as u8]));
+    encode_option(&mut data, args.expiration_unix_ts.map(i64::to_le_bytes));
+    encode_option(&mut data, args.allowlist_root);
+    data.push(0); // prize_tiers
+    encode_option(&mut data, args.early_bird_fee.map(u64::to_le_bytes));
+    encode_option(&mut data, args.early_bird_count.map(u32::to_le_bytes));
+    encode_option(&mut data, args.discount_bps.map(u16::to_le_bytes));
+    encode_option(&mut data, args.leave_penalty_bps.map(u16::to_le_bytes));
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(accounts.room, false),
+            AccountMeta::new(accounts.room_vault, false),
+            AccountMeta::new_readonly(accounts.fee_token_mint, false),
+            AccountMeta::new_readonly(accounts.token_registry, false),
+            AccountMeta::new_readonly(accounts.global_config, false),
+            AccountMeta::new(accounts.host, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(RENT_SYSVAR_ID, false),
+        ],
+        data,
+    }
+}
+
+/// Accounts required by the `end_room` instruction, in `EndRoom` order.
+///
+/// # Fields
+/// * `room` - Room PDA (`["room", host, room_id]`)
+/// * `room_vault` - Room vault PDA (`["room-vault", room]`)
+/// * `global_config` - GlobalConfig PDA (`["global-config"]`)
+/// * `platform_token_account` - Platform wallet's token account for the room's fee mint
+/// * `charity_token_account` - Room charity wallet's token account for the fee mint
+/// * `host_token_account` - Host's token account for the fee mint
+/// * `host` - Room host (signer and fee payer)
+/// * `payout_token_accounts` - Remaining accounts: winner token accounts in place order,
+///   then the token accounts of charities 2..n
+pub struct EndRoomAccounts {
+    pub room: Pubkey,
+    pub room_vault: Pubkey,
+    pub global_config: Pubkey,
+    pub platform_token_account: Pubkey,
+    pub charity_token_account: Pubkey,
+    pub host_token_account: Pubkey,
+    pub host: Pubkey,
+    pub payout_token_accounts: Vec<Pubkey>,
+}
+
+/// Builds an `end_room` instruction.
+///
+/// # Arguments
+/// * `program_id` - Deployed Fundraisely program ID
+/// * `accounts` - Resolved accounts for the instruction
+/// * `room_id` - Room identifier (used by the program to re-derive the room PDA)
+/// * `winners` - Winners for the legacy flow; empty when winners were declared with
+///   `declare_winners`, which take precedence
+pub fn end_room(program_id: &Pubkey, accounts: &EndRoomAccounts, room_id: &str, winners: &[Pubkey]) -> Instruction {
+    let mut data = END_ROOM_DISCRIMINATOR.to_vec();
+    encode_string(&mut data, room_id);
+    data.extend_from_slice(&(winners.len() as u32).to_le_bytes());
+    for winner in winners {
+        data.extend_from_slice(winner.as_ref());
+    }
+
+    let mut metas = vec![
+        AccountMeta::new(accounts.room, false),
+        AccountMeta::new(accounts.room_vault, false),
+        AccountMeta::new(accounts.global_config, false),
+        AccountMeta::new(accounts.platform_token_account, false),
+        AccountMeta::new(accounts.charity_token_account, false),
+        AccountMeta::new(accounts.host_token_account, false),
+        AccountMeta::new(accounts.host, true),
+        AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+    ];
+    metas.extend(
+        accounts
+            .payout_token_accounts
+            .iter()
+            .map(|account| AccountMeta::new(*account, false)),
+    );
+
+    Instruction {
+        program_id: *program_id,
+        accounts: metas,
+        data,
+    }
+}
+
+/// Compute unit limit for an `end_room` transaction: a fixed cost plus one token transfer
+/// per winner and extra charity (see the Compute Budget section of the program's
+/// `end_room.rs`; the frontend's `endRoomComputeUnits` uses the same figures).
+pub fn end_room_compute_units(winners: usize, extra_charities: usize) -> u32 {
+    let transfers = u32::try_from(winners + extra_charities).unwrap_or(u32::MAX);
+    END_ROOM_BASE_COMPUTE_UNITS.saturating_add(END_ROOM_COMPUTE_UNITS_PER_TRANSFER.saturating_mul(transfers))
+}
+
+/// Builds a Compute Budget `SetComputeUnitLimit` instruction.
+pub fn set_compute_unit_limit(units: u32) -> Instruction {
+    let mut data = vec![2];
+    data.extend_from_slice(&units.to_le_bytes());
+    Instruction {
+        program_id: COMPUTE_BUDGET_PROGRAM_ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Builds a Compute Budget `SetComputeUnitPrice` instruction (priority fee per compute
+/// unit, in micro-lamports).
+pub fn set_compute_unit_price(micro_lamports: u64) -> Instruction {
+    let mut data = vec![3];
+    data.extend_from_slice(&micro_lamports.to_le_bytes());
+    Instruction {
+        program_id: COMPUTE_BUDGET_PROGRAM_ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Appends a Borsh-encoded option (0 for `None`, 1 followed by the value's bytes for `Some`).
+fn encode_option<const N: usize>(data: &mut Vec<u8>, value: Option<[u8; N]>) {
+    match value {
+        Some(bytes) => {
+            data.push(1);
+            data.extend_from_slice(&bytes);
+        }
+        None => data.push(0),
+    }
+}
+
+/// Appends a Borsh-encoded string (u32 little-endian length prefix + UTF-8 bytes).
+fn encode_string(data: &mut Vec<u8>, value: &str) {
+    data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    data.extend_from_slice(value.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accounts() -> JoinRoomAccounts {
+        JoinRoomAccounts {
+            room: Pubkey::new_unique(),
+            player_entry: Pubkey::new_unique(),
+            room_vault: Pubkey::new_unique(),
+            player_token_account: Pubkey::new_unique(),
+            global_config: Pubkey::new_unique(),
+            blacklist: Pubkey::new_unique(),
+            discount: None,
+            player: Pubkey::new_unique(),
+        }
+    }
+
+    #[test]
+    fn join_room_encodes_optional_allowlist_proof() {
+        let program_id = Pubkey::new_unique();
+        let prefix_len = 8 + 4 + 4 + 8; // discriminator, "quiz", extras_amount
+
+        let public = join_room(&program_id, &accounts(), "quiz", 5, None, None);
+        assert_eq!(&public.data[..8], &JOIN_ROOM_DISCRIMINATOR);
+        assert_eq!(&public.data[16..24], &5u64.to_le_bytes());
+        assert_eq!(&public.data[prefix_len..], &[0, 0]);
+
+        let proof = [[7u8; 32], [9u8; 32]];
+        let private = join_room(&program_id, &accounts(), "quiz", 5, Some(&proof), None);
+        let encoded = &private.data[prefix_len..];
+        assert_eq!(encoded[0], 1);
+        assert_eq!(&encoded[1..5], &2u32.to_le_bytes());
+        assert_eq!(&encoded[5..37], &[7u8; 32]);
+        assert_eq!(&encoded[37..69], &[9u8; 32]);
+        assert_eq!(&encoded[69..], &[0]);
+        assert_eq!(private.accounts.len(), 10);
+    }
+
+    #[test]
+    fn join_room_encodes_optional_referrer() {
+        let program_id = Pubkey::new_unique();
+        let prefix_len = 8 + 4 + 4 + 8 + 1; // discriminator, "quiz", extras_amount, no proof
+        let referrer = Pubkey::new_unique();
+
+        let referred = join_room(&program_id, &accounts(), "quiz", 0, None, Some(&referrer));
+        let encoded = &referred.data[prefix_len..];
+        assert_eq!(encoded[0], 1);
+        assert_eq!(&encoded[1..], referrer.as_ref());
+    }
+
+    fn init_pool_room_args() -> InitPoolRoomArgs<'static> {
+        InitPoolRoomArgs {
+            room_id: "quiz",
+            charity_wallet: Pubkey::new_from_array([3; 32]),
+            entry_fee: 10_000_000,
+            max_players: 50,
+            host_fee_bps: 500,
+            prize_pool_bps: 3500,
+            prize_distribution: &[60, 30, 10],
+            charity_memo: "memo",
+            expiration_slots: None,
+            min_players: None,
+            require_ready: None,
+            expiration_unix_ts: None,
+            allowlist_root: None,
+            early_bird_fee: None,
+            early_bird_count: None,
+            discount_bps: None,
+            leave_penalty_bps: None,
+        }
+    }
+
+    fn init_pool_room_accounts() -> InitPoolRoomAccounts {
+        InitPoolRoomAccounts {
+            room: Pubkey::new_unique(),
+            room_vault: Pubkey::new_unique(),
+            fee_token_mint: Pubkey::new_unique(),
+            token_registry: Pubkey::new_unique(),
+            global_config: Pubkey::new_unique(),
+            host: Pubkey::new_unique(),
+        }
+    }
+
+    #[test]
+    fn init_pool_room_encodes_args_in_declaration_order() {
+        let program_id = Pubkey::new_unique();
+        let instruction = init_pool_room(&program_id, &init_pool_room_accounts(), &init_pool_room_args());
+
+        let mut expected = INIT_POOL_ROOM_DISCRIMINATOR.to_vec();
+        expected.extend_from_slice(&[4, 0, 0, 0]);
+        expected.extend_from_slice(b"quiz");
+        expected.extend_from_slice(&[3; 32]);
+        expected.extend_from_slice(&10_000_000u64.to_le_bytes());
+        expected.extend_from_slice(&50u32.to_le_bytes());
+        expected.extend_from_slice(&500u16.to_le_bytes());
+        expected.extend_from_slice(&3500u16.to_le_bytes());
+        expected.extend_from_slice(&[3, 0, 0, 0, 60, 0, 30, 0, 10, 0]);
+        expected.extend_from_slice(&[4, 0, 0, 0]);
+        expected.extend_from_slice(b"memo");
+        expected.extend_from_slice(&[0; 11]); // eleven `None` options
+        assert_eq!(instruction.data, expected);
+        assert_eq!(instruction.accounts.len(), 9);
+        assert!(instruction.accounts[5].is_signer);
+    }
+
+    #[test]
+    fn init_pool_room_encodes_optional_args() {
+        let program_id = Pubkey::new_unique();
+        let args = InitPoolRoomArgs {
+            expiration_slots: Some(7),
+            require_ready: Some(true),
+            discount_bps: Some(2500),
+            leave_penalty_bps: Some(1000),
+            ..init_pool_room_args()
+        };
+        let instruction = init_pool_room(&program_id, &init_pool_room_accounts(), &args);
+
+        let options_start = 8 + 8 + 32 + 8 + 4 + 2 + 2 + 10 + 8;
+        let mut expected = vec![1];
+        expected.extend_from_slice(&7u64.to_le_bytes());
+        expected.extend_from_slice(&[0, 0, 1, 1, 0, 0, 0, 0, 0, 1]);
+        expected.extend_from_slice(&2500u16.to_le_bytes());
+        expected.push(1);
+        expected.extend_from_slice(&1000u16.to_le_bytes());
+        assert_eq!(&instruction.data[options_start..], &expected[..]);
+    }
+
+    #[test]
+    fn end_room_appends_payout_accounts_after_the_fixed_accounts() {
+        let program_id = Pubkey::new_unique();
+        let winner = Pubkey::new_unique();
+        let payouts = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let accounts = EndRoomAccounts {
+            room: Pubkey::new_unique(),
+            room_vault: Pubkey::new_unique(),
+            global_config: Pubkey::new_unique(),
+            platform_token_account: Pubkey::new_unique(),
+            charity_token_account: Pubkey::new_unique(),
+            host_token_account: Pubkey::new_unique(),
+            host: Pubkey::new_unique(),
+            payout_token_accounts: payouts.clone(),
+        };
+
+        let instruction = end_room(&program_id, &accounts, "quiz", &[winner]);
+        let mut expected = END_ROOM_DISCRIMINATOR.to_vec();
+        expected.extend_from_slice(&[4, 0, 0, 0]);
+        expected.extend_from_slice(b"quiz");
+        expected.extend_from_slice(&[1, 0, 0, 0]);
+        expected.extend_from_slice(winner.as_ref());
+        assert_eq!(instruction.data, expected);
+
+        assert_eq!(instruction.accounts.len(), 10);
+        assert!(instruction.accounts[6].is_signer);
+        assert_eq!(instruction.accounts[7].pubkey, TOKEN_PROGRAM_ID);
+        let remaining: Vec<_> = instruction.accounts[8..].iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(remaining, payouts);
+        assert!(instruction.accounts[8..].iter().all(|meta| meta.is_writable));
+    }
+
+    #[test]
+    fn compute_budget_instructions_match_the_program_layout() {
+        assert_eq!(end_room_compute_units(3, 0), 135_000);
+        assert_eq!(end_room_compute_units(2, 1), 135_000);
+
+        let limit = set_compute_unit_limit(135_000);
+        assert_eq!(limit.program_id, COMPUTE_BUDGET_PROGRAM_ID);
+        assert_eq!(limit.data, [2, 0x58, 0x0F, 0x02, 0x00]);
+
+        let price = set_compute_unit_price(10_000);
+        assert_eq!(price.data[0], 3);
+        assert_eq!(&price.data[1..], &10_000u64.to_le_bytes());
+    }
+
+    #[test]
+    fn join_room_passes_program_id_for_missing_discount() {
+        let program_id = Pubkey::new_unique();
+        let mut accounts = accounts();
+
+        let full_price = join_room(&program_id, &accounts, "quiz", 0, None, None);
+        assert_eq!(full_price.accounts[6].pubkey, program_id);
+        assert!(!full_price.accounts[6].is_writable);
+
+        let discount = Pubkey::new_unique();
+        accounts.discount = Some(discount);
+        let discounted = join_room(&program_id, &accounts, "quiz", 0, None, None);
+        assert_eq!(discounted.accounts[6].pubkey, discount);
+        assert_eq!(discounted.accounts[7].pubkey, accounts.player);
+    }
+}
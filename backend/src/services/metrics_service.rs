@@ -0,0 +1,200 @@
+//! Request and RPC metrics in Prometheus text format.
+//!
+//! The metrics middleware records every HTTP request (matched route, method and
+//! status) with its duration, and `SolanaService` records how long each JSON-RPC
+//! call took. `GET /metrics` renders everything in the Prometheus text exposition
+//! format so error rates and slow RPC can be alerted on.
+//!
+//! Each series is a handful of integers behind a short-lived lock; recording is a
+//! map lookup and a few additions, so it adds no measurable latency to requests.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Upper bounds (in seconds) of the latency histogram buckets.
+pub const LATENCY_BUCKETS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Latency histogram with fixed `LATENCY_BUCKETS`.
+#[derive(Default, Debug, Clone)]
+struct Histogram {
+    /// Observations per bucket (not cumulative); the last slot is `+Inf`
+    buckets: [u64; LATENCY_BUCKETS.len() + 1],
+    sum_secs: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, elapsed: Duration) {
+        let secs = elapsed.as_secs_f64();
+        let bucket = LATENCY_BUCKETS
+            .iter()
+            .position(|&bound| secs <= bound)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        self.buckets[bucket] += 1;
+        self.sum_secs += secs;
+        self.count += 1;
+    }
+
+    /// Appends the `_bucket`, `_sum` and `_count` lines for one label set.
+    fn render(&self, out: &mut String, name: &str, labels: &str) {
+        let mut cumulative = 0;
+        for (bound, observed) in LATENCY_BUCKETS.iter().zip(self.buckets) {
+            cumulative += observed;
+            let _ = writeln!(out, "{}_bucket{{{},le=\"{}\"}} {}", name, labels, bound, cumulative);
+        }
+        let _ = writeln!(out, "{}_bucket{{{},le=\"+Inf\"}} {}", name, labels, self.count);
+        let _ = writeln!(out, "{}_sum{{{}}} {}", name, labels, self.sum_secs);
+        let _ = writeln!(out, "{}_count{{{}}} {}", name, labels, self.count);
+    }
+}
+
+/// Per-route request series, keyed by (method, route).
+#[derive(Default)]
+struct RequestMetrics {
+    /// Request count per status code
+    statuses: BTreeMap<(String, String, u16), u64>,
+    durations: BTreeMap<(String, String), Histogram>,
+}
+
+/// Per-method RPC series.
+#[derive(Default)]
+struct RpcMetrics {
+    durations: BTreeMap<String, Histogram>,
+    errors: BTreeMap<String, u64>,
+}
+
+/// Thread-safe request and RPC metrics shared by the middleware, `SolanaService`
+/// and the `/metrics` handler.
+#[derive(Default)]
+pub struct MetricsService {
+    requests: Mutex<RequestMetrics>,
+    rpc: Mutex<RpcMetrics>,
+}
+
+impl MetricsService {
+    /// Creates an empty metrics registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records one HTTP request.
+    ///
+    /// # Arguments
+    /// * `method` - HTTP method (e.g., "GET")
+    /// * `route` - Matched route template (e.g., "/api/room/{pubkey}"), not the raw path,
+    ///   so pubkeys in paths don't create a series each
+    /// * `status` - Response status code
+    /// * `elapsed` - Time taken to produce the response
+    pub fn record_request(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let mut requests = self.requests.lock().unwrap();
+        *requests
+            .statuses
+            .entry((method.to_string(), route.to_string(), status))
+            .or_insert(0) += 1;
+        requests
+            .durations
+            .entry((method.to_string(), route.to_string()))
+            .or_default()
+            .observe(elapsed);
+    }
+
+    /// Records one Solana JSON-RPC call.
+    ///
+    /// # Arguments
+    /// * `method` - RPC method (e.g., "getAccountInfo")
+    /// * `elapsed` - Round-trip time, including failed calls
+    /// * `success` - Whether the call returned a result
+    pub fn record_rpc(&self, method: &str, elapsed: Duration, success: bool) {
+        let mut rpc = self.rpc.lock().unwrap();
+        rpc.durations.entry(method.to_string()).or_default().observe(elapsed);
+        if !success {
+            *rpc.errors.entry(method.to_string()).or_insert(0) += 1;
+        }
+    }
+
+    /// Renders all series in the Prometheus text exposition format (version 0.0.4).
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        {
+            let requests = self.requests.lock().unwrap();
+
+            out.push_str("# HELP http_requests_total HTTP requests by route, method and status.\n");
+            out.push_str("# TYPE http_requests_total counter\n");
+            for ((method, route, status), count) in &requests.statuses {
+                let _ = writeln!(
+                    out,
+                    "http_requests_total{{method=\"{}\",route=\"{}\",status=\"{}\"}} {}",
+                    method, route, status, count
+                );
+            }
+
+            out.push_str("# HELP http_request_duration_seconds HTTP request latency by route and method.\n");
+            out.push_str("# TYPE http_request_duration_seconds histogram\n");
+            for ((method, route), histogram) in &requests.durations {
+                let labels = format!("method=\"{}\",route=\"{}\"", method, route);
+                histogram.render(&mut out, "http_request_duration_seconds", &labels);
+            }
+        }
+
+        let rpc = self.rpc.lock().unwrap();
+
+        out.push_str("# HELP solana_rpc_duration_seconds Solana JSON-RPC call latency by method.\n");
+        out.push_str("# TYPE solana_rpc_duration_seconds histogram\n");
+        for (method, histogram) in &rpc.durations {
+            let labels = format!("method=\"{}\"", method);
+            histogram.render(&mut out, "solana_rpc_duration_seconds", &labels);
+        }
+
+        out.push_str("# HELP solana_rpc_errors_total Failed Solana JSON-RPC calls by method.\n");
+        out.push_str("# TYPE solana_rpc_errors_total counter\n");
+        for (method, count) in &rpc.errors {
+            let _ = writeln!(out, "solana_rpc_errors_total{{method=\"{}\"}} {}", method, count);
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_request_counts_and_cumulative_buckets() {
+        let metrics = MetricsService::new();
+        metrics.record_request("GET", "/api/room/{pubkey}", 200, Duration::from_millis(3));
+        metrics.record_request("GET", "/api/room/{pubkey}", 200, Duration::from_millis(40));
+        metrics.record_request("GET", "/api/room/{pubkey}", 404, Duration::from_secs(30));
+
+        let text = metrics.render();
+
+        assert!(text.contains(
+            "http_requests_total{method=\"GET\",route=\"/api/room/{pubkey}\",status=\"200\"} 2"
+        ));
+        assert!(text.contains(
+            "http_requests_total{method=\"GET\",route=\"/api/room/{pubkey}\",status=\"404\"} 1"
+        ));
+        let labels = "method=\"GET\",route=\"/api/room/{pubkey}\"";
+        assert!(text.contains(&format!("http_request_duration_seconds_bucket{{{},le=\"0.005\"}} 1", labels)));
+        assert!(text.contains(&format!("http_request_duration_seconds_bucket{{{},le=\"0.05\"}} 2", labels)));
+        assert!(text.contains(&format!("http_request_duration_seconds_bucket{{{},le=\"10\"}} 2", labels)));
+        assert!(text.contains(&format!("http_request_duration_seconds_bucket{{{},le=\"+Inf\"}} 3", labels)));
+        assert!(text.contains(&format!("http_request_duration_seconds_count{{{}}} 3", labels)));
+    }
+
+    #[test]
+    fn renders_rpc_latency_and_errors() {
+        let metrics = MetricsService::new();
+        metrics.record_rpc("getAccountInfo", Duration::from_millis(80), true);
+        metrics.record_rpc("getAccountInfo", Duration::from_millis(120), false);
+
+        let text = metrics.render();
+
+        assert!(text.contains("solana_rpc_duration_seconds_count{method=\"getAccountInfo\"} 2"));
+        assert!(text.contains("solana_rpc_duration_seconds_bucket{method=\"getAccountInfo\",le=\"0.1\"} 1"));
+        assert!(text.contains("solana_rpc_errors_total{method=\"getAccountInfo\"} 1"));
+    }
+}
@@ -3,6 +3,21 @@
 //! This module contains service layer implementations that encapsulate
 //! external API integrations and business logic.
 
+pub mod cache_service;
+pub mod circuit_breaker;
+pub mod error_metrics;
+pub mod event_listener;
+pub mod instruction_builder;
+pub mod metrics_service;
+pub mod outcome_index;
+pub mod single_flight;
+pub mod solana_service;
+pub mod tgb_cache;
 pub mod tgb_client;
 
+pub use cache_service::CacheService;
+pub use error_metrics::ErrorMetrics;
+pub use metrics_service::MetricsService;
+pub use outcome_index::OutcomeIndex;
+pub use solana_service::{RoomFilter, SignatureInfo, SimulationResult, SolanaService, TokenBalance, VaultBalance};
 pub use tgb_client::TgbClient;
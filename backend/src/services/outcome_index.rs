@@ -0,0 +1,168 @@
+//! In-memory index of ended room outcomes.
+//!
+//! The event listener records a `RoomOutcome` for every `RoomEnded` event it sees,
+//! so outcome lookups are a map read instead of a walk through the room's
+//! transaction history. Rooms that ended before the backend started (or while the
+//! listener was behind) are missing from the index; those are resolved once by
+//! scanning the chain and then kept here.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::RwLock;
+
+use solana_pubkey::Pubkey;
+
+use crate::models::{ProgramEvent, RoomOutcome};
+
+/// Outcomes of ended rooms, keyed by room PDA.
+#[derive(Default)]
+pub struct OutcomeIndex {
+    outcomes: RwLock<HashMap<Pubkey, RoomOutcome>>,
+}
+
+impl OutcomeIndex {
+    /// Creates an empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the indexed outcome of a room, if any.
+    pub fn get(&self, room: &Pubkey) -> Option<RoomOutcome> {
+        self.outcomes.read().unwrap().get(room).cloned()
+    }
+
+    /// Stores a room's outcome, replacing any previous entry.
+    pub fn record(&self, outcome: RoomOutcome) {
+        if let Ok(room) = outcome.room.parse::<Pubkey>() {
+            self.outcomes.write().unwrap().insert(room, outcome);
+        }
+    }
+
+    /// Records the outcome carried by a `RoomEnded` event; other events are ignored.
+    pub fn record_event(&self, event: &ProgramEvent, signature: &str, slot: u64) {
+        if let Some(outcome) = RoomOutcome::from_event(event, signature, slot) {
+            self.record(outcome);
+        }
+    }
+
+    /// Returns the indexed outcome, falling back to `scan` when the index lacks it.
+    ///
+    /// A scanned outcome is recorded, so each room is scanned at most once.
+    ///
+    /// # Returns
+    /// * `Ok(Some(RoomOutcome))` - Indexed or scanned outcome
+    /// * `Ok(None)` - If the scan found no `RoomEnded` event
+    /// * `Err(String)` - If the scan failed
+    pub async fn get_or_scan<F, Fut>(&self, room: &Pubkey, scan: F) -> Result<Option<RoomOutcome>, String>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Option<RoomOutcome>, String>>,
+    {
+        if let Some(outcome) = self.get(room) {
+            return Ok(Some(outcome));
+        }
+
+        let outcome = scan().await?;
+        if let Some(outcome) = &outcome {
+            self.record(outcome.clone());
+        }
+        Ok(outcome)
+    }
+
+    /// Number of indexed outcomes.
+    pub fn len(&self) -> usize {
+        self.outcomes.read().unwrap().len()
+    }
+
+    /// Whether no outcome has been indexed yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(room: &Pubkey) -> RoomOutcome {
+        RoomOutcome {
+            room: room.to_string(),
+            winners: vec![Pubkey::new_unique().to_string()],
+            winner_amounts: vec![3_500_000],
+            platform_amount: 2_000_000,
+            host_amount: 500_000,
+            charity_wallet: Pubkey::new_unique().to_string(),
+            charity_amount: 4_000_000,
+            charity_amounts: vec![4_000_000],
+            prize_amount: 3_500_000,
+            prize_distribution: vec![100],
+            total_players: 10,
+            expired_finalization: false,
+            host_fee_waived: false,
+            charity_from_entry: 4_000_000,
+            charity_from_extras: 0,
+            charity_from_leave_penalties: 0,
+            end_signature: "5endSig".to_string(),
+            slot: 4242,
+            ended_at: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn records_room_ended_events_and_reads_them_back() {
+        let index = OutcomeIndex::new();
+        let room = Pubkey::new_unique();
+        let expected = outcome(&room);
+
+        let event = ProgramEvent::RoomEnded {
+            room: room.to_string(),
+            winners: expected.winners.clone(),
+            winner_amounts: vec![3_500_000],
+            platform_amount: 2_000_000,
+            host_amount: 500_000,
+            charity_wallet: expected.charity_wallet.clone(),
+            charity_amount: 4_000_000,
+            charity_amounts: vec![4_000_000],
+            prize_amount: 3_500_000,
+            prize_distribution: vec![100],
+            total_players: 10,
+            expired_finalization: false,
+            host_fee_waived: false,
+            charity_from_entry: 4_000_000,
+            charity_from_extras: 0,
+            charity_from_leave_penalties: 0,
+            timestamp: 1_700_000_000,
+        };
+        index.record_event(&event, "5endSig", 4242);
+
+        assert_eq!(index.get(&room), Some(expected));
+        assert!(index.get(&Pubkey::new_unique()).is_none());
+        assert_eq!(index.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_chain_scan_only_when_index_lacks_outcome() {
+        let index = OutcomeIndex::new();
+        let indexed = outcome(&Pubkey::new_unique());
+        let unindexed = outcome(&Pubkey::new_unique());
+        let indexed_room = indexed.room.parse().unwrap();
+        let unindexed_room = unindexed.room.parse().unwrap();
+        index.record(indexed.clone());
+
+        let hit = index
+            .get_or_scan(&indexed_room, || async { panic!("indexed outcome must not trigger a scan") })
+            .await;
+        assert_eq!(hit, Ok(Some(indexed)));
+
+        let scanned = index
+            .get_or_scan(&unindexed_room, || async { Ok(Some(unindexed.clone())) })
+            .await;
+        assert_eq!(scanned, Ok(Some(unindexed.clone())));
+        // The scanned outcome is now served from the index
+        assert_eq!(index.get(&unindexed_room), Some(unindexed));
+
+        let missing = Pubkey::new_unique();
+        assert_eq!(index.get_or_scan(&missing, || async { Ok(None) }).await, Ok(None));
+        assert!(index.get(&missing).is_none());
+    }
+}
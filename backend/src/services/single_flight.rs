@@ -0,0 +1,165 @@
+//! Coalescing of identical in-flight requests.
+//!
+//! When several callers ask for the same key at once, only the first (the leader) runs
+//! the request; the others wait for its result and share it. This covers the
+//! thundering-herd case a cache can't: a burst of requests for an account that isn't
+//! cached yet would otherwise each hit the RPC node.
+//!
+//! Results, errors included, are only shared with callers that arrived while the request
+//! was running: the entry is removed as soon as it completes, so the next caller after a
+//! failure starts a fresh request. If the leader is cancelled (its caller went away), the
+//! waiters start over and one of them becomes the new leader.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::Mutex;
+use tokio::sync::watch;
+
+/// In-flight requests keyed by what they fetch.
+pub struct SingleFlight<K, V> {
+    in_flight: Mutex<HashMap<K, watch::Sender<Option<V>>>>,
+}
+
+impl<K, V> Default for SingleFlight<K, V> {
+    fn default() -> Self {
+        Self {
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> SingleFlight<K, V> {
+    /// Creates an empty set of in-flight requests.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `request` for `key`, or waits for the identical request already running.
+    ///
+    /// # Returns
+    /// The request's result, shared by every caller that asked while it was running
+    pub async fn run<F, Fut>(&self, key: K, request: F) -> V
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V>,
+    {
+        loop {
+            let mut waiting = {
+                let mut in_flight = self.in_flight.lock().unwrap();
+                match in_flight.get(&key) {
+                    Some(sender) => sender.subscribe(),
+                    None => {
+                        in_flight.insert(key.clone(), watch::channel(None).0);
+                        break;
+                    }
+                }
+            };
+
+            // An error means the leader was cancelled without a result: start over
+            let shared = waiting.wait_for(Option::is_some).await.map(|result| result.clone());
+            if let Ok(Some(result)) = shared {
+                return result;
+            }
+        }
+
+        let leader = Leader {
+            flights: self,
+            key,
+            finished: false,
+        };
+        let result = request().await;
+        leader.finish(result.clone());
+        result
+    }
+
+    /// Number of requests currently running.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.lock().unwrap().len()
+    }
+}
+
+/// Removes the leader's entry when it finishes or is dropped mid-request, so a
+/// cancelled request never leaves its waiters hanging.
+struct Leader<'a, K: Eq + Hash, V> {
+    flights: &'a SingleFlight<K, V>,
+    key: K,
+    finished: bool,
+}
+
+impl<K: Eq + Hash, V> Leader<'_, K, V> {
+    /// Hands the result to every waiter.
+    fn finish(mut self, result: V) {
+        self.finished = true;
+        if let Some(sender) = self.flights.in_flight.lock().unwrap().remove(&self.key) {
+            sender.send_replace(Some(result));
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> Drop for Leader<'_, K, V> {
+    fn drop(&mut self) {
+        // After `finish` the entry may already belong to a newer request
+        if !self.finished {
+            // Dropping the sender wakes the waiters, who start over
+            self.flights.in_flight.lock().unwrap().remove(&self.key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn concurrent_callers_share_one_request() {
+        let flights = Arc::new(SingleFlight::<u8, Result<u32, String>>::new());
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let tasks: Vec<_> = (0..10)
+            .map(|_| {
+                let (flights, calls) = (flights.clone(), calls.clone());
+                tokio::spawn(async move {
+                    flights
+                        .run(1, || async {
+                            calls.fetch_add(1, Ordering::SeqCst);
+                            tokio::time::sleep(Duration::from_millis(50)).await;
+                            Err::<u32, _>("node down".to_string())
+                        })
+                        .await
+                })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(task.await.unwrap(), Err("node down".to_string()));
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(flights.in_flight(), 0);
+
+        // The failure isn't remembered: the next caller retries
+        assert_eq!(flights.run(1, || async { Ok(7) }).await, Ok(7));
+    }
+
+    #[tokio::test]
+    async fn waiters_take_over_when_the_leader_is_cancelled() {
+        let flights = Arc::new(SingleFlight::<u8, u32>::new());
+
+        let leader = {
+            let flights = flights.clone();
+            tokio::spawn(async move { flights.run(1, std::future::pending).await })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let waiter = {
+            let flights = flights.clone();
+            tokio::spawn(async move { flights.run(1, || async { 42 }).await })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        leader.abort();
+        assert_eq!(waiter.await.unwrap(), 42);
+        assert_eq!(flights.in_flight(), 0);
+    }
+}
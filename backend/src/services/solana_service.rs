@@ -0,0 +1,1623 @@
+//! Solana RPC client for reading Fundraisely program accounts.
+//!
+//! This module provides a thin JSON-RPC client over the Solana RPC API, used to
+//! discover and decode on-chain state (rooms, player entries) for the frontend
+//! without requiring it to know account addresses in advance.
+//!
+//! Several RPC endpoints can be configured: requests go to the first endpoint whose
+//! circuit breaker is closed and fail over to the next one when an endpoint still
+//! fails after its retries. With a single endpoint it is always used.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use solana_hash::Hash;
+use solana_pubkey::Pubkey;
+use std::cmp::Reverse;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tracing::{error, info, warn};
+
+use crate::models::charity_proof::ConfirmedTransaction;
+use crate::models::program_error::{custom_error_code, parse_error_code};
+use crate::models::player_entry_account::{PLAYER_ENTRY_DISCRIMINATOR, PLAYER_ENTRY_PLAYER_OFFSET};
+use crate::models::room_account::ROOM_DISCRIMINATOR;
+use crate::models::timeline::{build_timeline, RoomTransaction};
+use crate::models::{
+    CharityProof, GlobalConfigAccount, PlayerEntryAccount, ProgramEvent, RoomAccount, RoomFull,
+    RoomOutcome, RoomStatus, TimelineEntry, TokenRegistryAccount,
+};
+use crate::services::instruction_builder::get_associated_token_address;
+use crate::services::circuit_breaker::CircuitBreaker;
+use crate::services::single_flight::SingleFlight;
+use crate::services::{CacheService, MetricsService};
+
+/// JSON-RPC response envelope.
+#[derive(Deserialize)]
+struct RpcResponse<T> {
+    result: Option<T>,
+    error: Option<RpcError>,
+}
+
+/// JSON-RPC error object.
+#[derive(Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+/// Page size for `getSignaturesForAddress` (the RPC maximum).
+const SIGNATURE_PAGE_SIZE: usize = 1000;
+
+/// Default number of retries for transient RPC failures.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Default connect and request timeout for RPC calls, so a hung node fails (and is
+/// retried or failed over) instead of hanging the request.
+pub const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Delay before the first retry; doubles on every subsequent attempt.
+const BASE_RETRY_DELAY: Duration = Duration::from_millis(200);
+
+/// Upper bound on a single retry delay.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Upper bound on the `/health` RPC probe, so the endpoint answers within a second.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_millis(800);
+
+/// How long a health probe result is reused before the node is probed again.
+const HEALTH_CACHE_TTL: Duration = Duration::from_secs(1);
+
+/// Consecutive failed requests (after retries) that take an endpoint out of rotation.
+const ENDPOINT_FAILURE_THRESHOLD: u32 = 3;
+
+/// How long a failing endpoint is skipped before it is tried again.
+const ENDPOINT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Offset of `decimals` in an SPL Mint account (after the COption mint authority and supply).
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+/// Length of the SPL token account fields read here: mint, owner and amount.
+const TOKEN_ACCOUNT_AMOUNT_END: usize = 72;
+
+/// JSON-RPC error codes that indicate a temporarily unhealthy node rather than a bad request.
+const TRANSIENT_RPC_ERROR_CODES: &[i64] = &[
+    -32004, // block not available for slot
+    -32005, // node is behind / unhealthy
+    -32014, // block status not yet available
+];
+
+/// Outcome of a single failed RPC attempt.
+struct RpcFailure {
+    message: String,
+    retryable: bool,
+}
+
+impl RpcFailure {
+    fn transient(message: String) -> Self {
+        Self { message, retryable: true }
+    }
+
+    fn permanent(message: String) -> Self {
+        Self { message, retryable: false }
+    }
+}
+
+/// One JSON-RPC endpoint with its own circuit breaker.
+struct RpcEndpoint {
+    url: String,
+    /// Scheme and host only, so provider API keys in the URL never reach logs or `/health`
+    label: String,
+    breaker: CircuitBreaker,
+    /// Requests that failed on this endpoint after retries, since startup
+    failures: AtomicU64,
+}
+
+impl RpcEndpoint {
+    fn new(url: String) -> Self {
+        let label = reqwest::Url::parse(&url)
+            .ok()
+            .and_then(|parsed| {
+                let host = parsed.host_str()?.to_string();
+                Some(match parsed.port() {
+                    Some(port) => format!("{}://{}:{}", parsed.scheme(), host, port),
+                    None => format!("{}://{}", parsed.scheme(), host),
+                })
+            })
+            .unwrap_or_else(|| "invalid-url".to_string());
+        Self {
+            url,
+            label,
+            breaker: CircuitBreaker::new(ENDPOINT_FAILURE_THRESHOLD, ENDPOINT_COOLDOWN),
+            failures: AtomicU64::new(0),
+        }
+    }
+
+    fn record_failure(&self) {
+        self.failures.fetch_add(1, Ordering::Relaxed);
+        if self.breaker.record_failure() {
+            warn!(
+                "Solana RPC: {} failing repeatedly, skipping it for {:?}",
+                self.label, ENDPOINT_COOLDOWN
+            );
+        }
+    }
+}
+
+/// Failure count and availability of one RPC endpoint, reported by `/health`.
+///
+/// # Fields
+/// * `endpoint` - Scheme and host of the endpoint (path and query are omitted)
+/// * `failures` - Requests that failed on this endpoint after retries, since startup
+/// * `available` - False while the endpoint is skipped after repeated failures
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct RpcEndpointStats {
+    pub endpoint: String,
+    pub failures: u64,
+    pub available: bool,
+}
+
+/// Result of a successful health probe.
+///
+/// # Fields
+/// * `slot` - Current slot at `confirmed` commitment
+/// * `latency_ms` - How long the probe took, failover included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RpcHealth {
+    pub slot: u64,
+    pub latency_ms: u64,
+}
+
+/// Single entry returned by `getProgramAccounts`.
+#[derive(Deserialize)]
+struct ProgramAccount {
+    pubkey: String,
+    account: EncodedAccount,
+}
+
+/// Account payload with base64-encoded data (`[data, "base64"]`).
+#[derive(Deserialize)]
+struct EncodedAccount {
+    data: (String, String),
+}
+
+/// Result of `getAccountInfo` (`value` is null when the account does not exist).
+#[derive(Deserialize)]
+struct AccountInfoResponse {
+    value: Option<EncodedAccount>,
+}
+
+/// Result of `getBalance`.
+#[derive(Deserialize)]
+struct BalanceResponse {
+    value: u64,
+}
+
+/// Result of `getTokenAccountBalance`.
+#[derive(Deserialize)]
+struct TokenAccountBalanceResponse {
+    value: UiTokenAmount,
+}
+
+/// Token amount as returned by the RPC (`amount` is a decimal string of base units).
+#[derive(Deserialize)]
+struct UiTokenAmount {
+    amount: String,
+    decimals: u8,
+}
+
+/// SPL token balance of an owner's associated token account.
+///
+/// # Fields
+/// * `token_account` - Associated token account address
+/// * `amount` - Balance in the mint's base units (0 if the account does not exist)
+/// * `decimals` - Decimals of the mint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenBalance {
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+/// On-chain balance of a room's token vault.
+///
+/// # Fields
+/// * `vault` - Vault PDA (`["room-vault", room]`)
+/// * `mint` - Mint of the tokens the vault holds
+/// * `amount` - Balance in the mint's base units
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VaultBalance {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Entry returned by `getSignaturesForAddress`.
+///
+/// # Fields
+/// * `signature` - Transaction signature (base58)
+/// * `slot` - Slot the transaction landed in
+/// * `err` - Transaction error, or null if it succeeded
+/// * `block_time` - Estimated production time (unix seconds), if available
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureInfo {
+    pub signature: String,
+    pub slot: u64,
+    pub err: Option<Value>,
+    pub block_time: Option<i64>,
+}
+
+/// Subset of `getTransaction` needed to read program logs.
+#[derive(Deserialize)]
+struct TransactionResponse {
+    meta: Option<TransactionMeta>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionMeta {
+    log_messages: Option<Vec<String>>,
+}
+
+/// Result of `simulateTransaction`.
+#[derive(Deserialize)]
+struct SimulateTransactionResponse {
+    value: SimulationResult,
+}
+
+/// Outcome of simulating a transaction.
+///
+/// # Fields
+/// * `err` - Transaction error (e.g. `{"InstructionError": [0, {"Custom": 6025}]}`), or
+///   null if the simulation succeeded
+/// * `logs` - Log messages emitted during the simulation
+/// * `units_consumed` - Compute units consumed, if the node reports them
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationResult {
+    pub err: Option<Value>,
+    pub logs: Option<Vec<String>>,
+    pub units_consumed: Option<u64>,
+}
+
+impl SimulationResult {
+    /// Custom program error code the simulation failed with, from the logs or,
+    /// failing that, from the `Custom` instruction error.
+    pub fn error_code(&self) -> Option<u32> {
+        self.logs
+            .as_deref()
+            .and_then(parse_error_code)
+            .or_else(|| self.err.as_ref().and_then(custom_error_code))
+    }
+}
+
+/// Result of `getLatestBlockhash`.
+#[derive(Deserialize)]
+struct LatestBlockhashResponse {
+    value: LatestBlockhash,
+}
+
+#[derive(Deserialize)]
+struct LatestBlockhash {
+    blockhash: String,
+}
+
+/// Server-side filters applied when listing rooms.
+///
+/// # Fields
+/// * `status` - Only return rooms in this lifecycle state
+/// * `host` - Only return rooms created by this host (base58 pubkey)
+#[derive(Debug, Default, Clone)]
+pub struct RoomFilter {
+    pub status: Option<RoomStatus>,
+    pub host: Option<Pubkey>,
+}
+
+impl RoomFilter {
+    fn matches(&self, room: &RoomAccount) -> bool {
+        let status_ok = self.status.is_none_or(|status| room.status == status);
+        let host_ok = self
+            .host
+            .is_none_or(|host| room.host == host.to_string());
+        status_ok && host_ok
+    }
+}
+
+/// Client for reading Fundraisely program state over Solana JSON-RPC.
+///
+/// All reads use `confirmed` commitment, which is a good balance between
+/// freshness and the risk of reading state from a rolled-back fork. Single-account
+/// reads (rooms, balances) go through a short-lived cache unless `fresh` is requested.
+pub struct SolanaService {
+    client: Client,
+    /// Endpoints in failover order; the first is the primary
+    endpoints: Vec<RpcEndpoint>,
+    program_id: Pubkey,
+    cache: Arc<CacheService>,
+    max_retries: u32,
+    /// Records RPC latency per method, when set
+    metrics: Option<Arc<MetricsService>>,
+    /// Last health probe and when it ran
+    last_health: Mutex<Option<(Instant, Result<RpcHealth, String>)>>,
+    /// Concurrent reads of the same account share one `getAccountInfo` call
+    account_reads: SingleFlight<Pubkey, Result<Option<Vec<u8>>, String>>,
+}
+
+impl SolanaService {
+    /// Creates a new Solana service.
+    ///
+    /// # Arguments
+    /// * `rpc_url` - Primary Solana JSON-RPC endpoint (e.g., devnet or a private provider)
+    /// * `program_id` - Deployed Fundraisely program ID
+    /// * `cache` - Shared cache for room and balance reads
+    pub fn new(rpc_url: String, program_id: Pubkey, cache: Arc<CacheService>) -> Self {
+        Self {
+            client: build_http_client(DEFAULT_RPC_TIMEOUT),
+            endpoints: vec![RpcEndpoint::new(rpc_url)],
+            program_id,
+            cache,
+            max_retries: DEFAULT_MAX_RETRIES,
+            metrics: None,
+            last_health: Mutex::new(None),
+            account_reads: SingleFlight::new(),
+        }
+    }
+
+    /// Sets how many times a transient RPC failure is retried (0 disables retries).
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the connect and request timeout for RPC calls.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.client = build_http_client(timeout);
+        self
+    }
+
+    /// Adds endpoints to fail over to, in order, when the ones before them keep failing.
+    pub fn with_failover_urls(mut self, urls: Vec<String>) -> Self {
+        self.endpoints.extend(urls.into_iter().map(RpcEndpoint::new));
+        self
+    }
+
+    /// Returns the failure count and availability of every configured endpoint.
+    pub fn rpc_endpoint_stats(&self) -> Vec<RpcEndpointStats> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| RpcEndpointStats {
+                endpoint: endpoint.label.clone(),
+                failures: endpoint.failures.load(Ordering::Relaxed),
+                available: endpoint.breaker.check().is_ok(),
+            })
+            .collect()
+    }
+
+    /// Endpoints to try, in order: those not skipped after repeated failures, or
+    /// every endpoint if all of them are (so a lone endpoint is never skipped).
+    fn candidate_endpoints(&self) -> Vec<&RpcEndpoint> {
+        let available: Vec<&RpcEndpoint> = self
+            .endpoints
+            .iter()
+            .filter(|endpoint| endpoint.breaker.check().is_ok())
+            .collect();
+        if available.is_empty() {
+            self.endpoints.iter().collect()
+        } else {
+            available
+        }
+    }
+
+    /// Records the latency of every RPC call in `metrics`.
+    pub fn with_metrics(mut self, metrics: Arc<MetricsService>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Returns the program ID this service reads accounts for.
+    pub fn program_id(&self) -> &Pubkey {
+        &self.program_id
+    }
+
+    /// Derives the Room PDA (`["room", host, room_id]`).
+    pub fn derive_room_pda(&self, host: &Pubkey, room_id: &str) -> Pubkey {
+        Pubkey::find_program_address(&[b"room", host.as_ref(), room_id.as_bytes()], &self.program_id).0
+    }
+
+    /// Derives the PlayerEntry PDA (`["player", room, player]`).
+    pub fn derive_player_pda(&self, room: &Pubkey, player: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"player", room.as_ref(), player.as_ref()], &self.program_id).0
+    }
+
+    /// Derives the room vault PDA (`["room-vault", room]`).
+    pub fn derive_room_vault_pda(&self, room: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"room-vault", room.as_ref()], &self.program_id).0
+    }
+
+    /// Derives the GlobalConfig PDA (`["global-config"]`).
+    pub fn derive_global_config_pda(&self) -> Pubkey {
+        Pubkey::find_program_address(&[b"global-config"], &self.program_id).0
+    }
+
+    /// Derives the TokenRegistry PDA (`["token-registry"]`).
+    pub fn derive_token_registry_pda(&self) -> Pubkey {
+        Pubkey::find_program_address(&[b"token-registry"], &self.program_id).0
+    }
+
+    /// Derives the Blacklist PDA (`["blacklist"]`).
+    pub fn derive_blacklist_pda(&self) -> Pubkey {
+        Pubkey::find_program_address(&[b"blacklist"], &self.program_id).0
+    }
+
+    /// Derives the Discount PDA (`["discount", room, player]`).
+    pub fn derive_discount_pda(&self, room: &Pubkey, player: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"discount", room.as_ref(), player.as_ref()], &self.program_id).0
+    }
+
+    /// Probes the RPC node with `getSlot`, for the health endpoint.
+    ///
+    /// The probe is not retried and gives up after `HEALTH_CHECK_TIMEOUT`. Its result is
+    /// reused for `HEALTH_CACHE_TTL` so frequent load balancer probes don't hammer the node.
+    ///
+    /// # Returns
+    /// * `Ok(RpcHealth)` - Current slot and the probe's latency
+    /// * `Err(String)` - If the node is unreachable, returns an error or is too slow
+    pub async fn check_health(&self) -> Result<RpcHealth, String> {
+        if let Some((checked_at, result)) = self.last_health.lock().unwrap().as_ref() {
+            if checked_at.elapsed() < HEALTH_CACHE_TTL {
+                return result.clone();
+            }
+        }
+
+        // Healthy if any endpoint answers, trying them in failover order
+        let params = json!([{ "commitment": "confirmed" }]);
+        let probe = async {
+            let mut last_error = String::new();
+            for endpoint in self.candidate_endpoints() {
+                match self.send_rpc_request::<u64>(endpoint, "getSlot", &params).await {
+                    Ok(slot) => return Ok(slot),
+                    Err(failure) => last_error = failure.message,
+                }
+            }
+            Err(last_error)
+        };
+        let started = Instant::now();
+        let result = match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, probe).await {
+            Ok(Ok(slot)) => Ok(RpcHealth {
+                slot,
+                latency_ms: started.elapsed().as_millis() as u64,
+            }),
+            Ok(Err(message)) => Err(message),
+            Err(_) => {
+                warn!("Solana RPC: health check timed out after {:?}", HEALTH_CHECK_TIMEOUT);
+                Err("Solana RPC health check timed out".to_string())
+            }
+        };
+
+        *self.last_health.lock().unwrap() = Some((Instant::now(), result.clone()));
+        result
+    }
+
+    /// Sends a JSON-RPC request, failing over between endpoints.
+    ///
+    /// Each endpoint gets the full retry budget; if it still fails with a transient
+    /// error the next endpoint is tried. Permanent errors (invalid params, unknown
+    /// accounts) are returned at once, since another node would answer the same.
+    async fn rpc_request<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T, String> {
+        let mut last_error = String::new();
+
+        for endpoint in self.candidate_endpoints() {
+            match self.rpc_request_with_retries(endpoint, method, &params).await {
+                Ok(result) => {
+                    endpoint.breaker.record_success();
+                    if self.endpoints.len() > 1 {
+                        info!("Solana RPC: {} served by {}", method, endpoint.label);
+                    }
+                    return Ok(result);
+                }
+                Err(failure) if failure.retryable => {
+                    endpoint.record_failure();
+                    warn!("Solana RPC: {} failed on {}: {}", method, endpoint.label, failure.message);
+                    last_error = failure.message;
+                }
+                Err(failure) => {
+                    // The node answered; the request itself was bad
+                    endpoint.breaker.record_success();
+                    return Err(failure.message);
+                }
+            }
+        }
+
+        Err(last_error)
+    }
+
+    /// Sends a JSON-RPC request to one endpoint, retrying transient failures with
+    /// exponential backoff.
+    ///
+    /// Timeouts, connection errors (including a connection reset mid-request or
+    /// mid-body), HTTP 429/5xx and node-health RPC errors are retried up to
+    /// `max_retries` times; anything else (invalid params, decode
+    /// failures, unknown accounts) fails immediately since retrying cannot help.
+    async fn rpc_request_with_retries<T: DeserializeOwned>(
+        &self,
+        endpoint: &RpcEndpoint,
+        method: &str,
+        params: &Value,
+    ) -> Result<T, RpcFailure> {
+        let mut attempt = 0;
+
+        loop {
+            match self.send_rpc_request(endpoint, method, params).await {
+                Ok(result) => return Ok(result),
+                Err(failure) if failure.retryable && attempt < self.max_retries => {
+                    attempt += 1;
+                    let delay = retry_delay(attempt);
+                    warn!(
+                        "Solana RPC: {} failed ({}), retry {}/{} in {:?}",
+                        method, failure.message, attempt, self.max_retries, delay
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(failure) => return Err(failure),
+            }
+        }
+    }
+
+    /// Sends a single JSON-RPC request and deserializes its `result`, recording its latency.
+    async fn send_rpc_request<T: DeserializeOwned>(
+        &self,
+        endpoint: &RpcEndpoint,
+        method: &str,
+        params: &Value,
+    ) -> Result<T, RpcFailure> {
+        let start = Instant::now();
+        let result = self.post_rpc_request(&endpoint.url, method, params).await;
+        if let Some(metrics) = &self.metrics {
+            metrics.record_rpc(method, start.elapsed(), result.is_ok());
+        }
+        result
+    }
+
+    /// Posts one JSON-RPC request and deserializes its `result`.
+    async fn post_rpc_request<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        method: &str,
+        params: &Value,
+    ) -> Result<T, RpcFailure> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| {
+                error!("Solana RPC: {} request failed: {}", method, e);
+                let message = format!("Failed to connect to Solana RPC: {}", e);
+                if is_transient_transport_error(&e) {
+                    RpcFailure::transient(message)
+                } else {
+                    RpcFailure::permanent(message)
+                }
+            })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            error!("Solana RPC: {} returned error status: {}", method, status);
+            let message = format!("Solana RPC error: {}", status);
+            return Err(if status.as_u16() == 429 || status.is_server_error() {
+                RpcFailure::transient(message)
+            } else {
+                RpcFailure::permanent(message)
+            });
+        }
+
+        let rpc_response = response.json::<RpcResponse<T>>().await.map_err(|e| {
+            // The body may be cut off mid-read; only a complete but malformed body is permanent
+            if is_transient_transport_error(&e) {
+                error!("Solana RPC: Failed to read {} response: {}", method, e);
+                RpcFailure::transient(format!("Failed to read Solana RPC response: {}", e))
+            } else {
+                error!("Solana RPC: Failed to parse {} response: {}", method, e);
+                RpcFailure::permanent(format!("Failed to parse Solana RPC response: {}", e))
+            }
+        })?;
+
+        if let Some(err) = rpc_response.error {
+            error!("Solana RPC: {} failed with code {}: {}", method, err.code, err.message);
+            let message = format!("Solana RPC error {}: {}", err.code, err.message);
+            return Err(if TRANSIENT_RPC_ERROR_CODES.contains(&err.code) {
+                RpcFailure::transient(message)
+            } else {
+                RpcFailure::permanent(message)
+            });
+        }
+
+        // A null `result` is valid for lookups like `getTransaction`; it only
+        // deserializes successfully when the caller asked for an `Option<_>`
+        match rpc_response.result {
+            Some(result) => Ok(result),
+            None => serde_json::from_value(Value::Null).map_err(|_| {
+                RpcFailure::permanent(format!("Solana RPC: {} returned no result", method))
+            }),
+        }
+    }
+
+    /// Fetches every Room account owned by the program.
+    ///
+    /// Uses `getProgramAccounts` with a memcmp filter on the Room discriminator so
+    /// only Room accounts are returned. Status and host are filtered after decoding:
+    /// both fields sit behind the variable-length `room_id` string, so they have no
+    /// fixed offset that a memcmp filter could target.
+    ///
+    /// # Arguments
+    /// * `filter` - Optional status/host constraints
+    ///
+    /// # Returns
+    /// * `Ok(Vec<RoomAccount>)` - Matching rooms (accounts that fail to decode are skipped)
+    /// * `Err(String)` - Error message if the RPC request fails
+    pub async fn get_all_rooms(&self, filter: &RoomFilter) -> Result<Vec<RoomAccount>, String> {
+        info!("Solana RPC: Fetching all rooms with filter {:?}", filter);
+
+        let params = json!([
+            self.program_id.to_string(),
+            {
+                "encoding": "base64",
+                "commitment": "confirmed",
+                "filters": [
+                    { "memcmp": { "offset": 0, "bytes": bs58::encode(ROOM_DISCRIMINATOR).into_string() } }
+                ]
+            }
+        ]);
+
+        let accounts: Vec<ProgramAccount> = self.rpc_request("getProgramAccounts", params).await?;
+
+        let rooms: Vec<RoomAccount> = accounts
+            .iter()
+            .filter_map(|account| match decode_room(account) {
+                Ok(room) => Some(room),
+                Err(e) => {
+                    warn!("Solana RPC: Skipping undecodable room {}: {}", account.pubkey, e);
+                    None
+                }
+            })
+            .filter(|room| filter.matches(room))
+            .collect();
+
+        info!("Solana RPC: Found {} matching rooms", rooms.len());
+        Ok(rooms)
+    }
+
+    /// Fetches every PlayerEntry account belonging to a player.
+    ///
+    /// Uses `getProgramAccounts` with two memcmp filters: the PlayerEntry
+    /// discriminator at offset 0 and the player pubkey at offset 8 (the first
+    /// field of the account), so the RPC node does all the filtering.
+    ///
+    /// # Arguments
+    /// * `player` - Player wallet address
+    ///
+    /// # Returns
+    /// * `Ok(Vec<PlayerEntryAccount>)` - The player's entries, most recent (highest `join_slot`) first
+    /// * `Err(String)` - Error message if the RPC request fails
+    pub async fn get_player_entries(&self, player: &Pubkey) -> Result<Vec<PlayerEntryAccount>, String> {
+        info!("Solana RPC: Fetching entries for player {}", player);
+
+        let params = json!([
+            self.program_id.to_string(),
+            {
+                "encoding": "base64",
+                "commitment": "confirmed",
+                "filters": [
+                    { "memcmp": { "offset": 0, "bytes": bs58::encode(PLAYER_ENTRY_DISCRIMINATOR).into_string() } },
+                    { "memcmp": { "offset": PLAYER_ENTRY_PLAYER_OFFSET, "bytes": player.to_string() } }
+                ]
+            }
+        ]);
+
+        let accounts: Vec<ProgramAccount> = self.rpc_request("getProgramAccounts", params).await?;
+
+        let mut entries: Vec<PlayerEntryAccount> = accounts
+            .iter()
+            .filter_map(|account| match decode_player_entry(account) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    warn!("Solana RPC: Skipping undecodable player entry {}: {}", account.pubkey, e);
+                    None
+                }
+            })
+            .collect();
+
+        entries.sort_by_key(|entry| Reverse(entry.join_slot));
+
+        info!("Solana RPC: Found {} entries for player {}", entries.len(), player);
+        Ok(entries)
+    }
+
+    /// Fetches raw account data for a single address.
+    ///
+    /// Concurrent reads of the same address share a single `getAccountInfo` call, so a
+    /// burst of requests for an uncached room costs one RPC round trip; an error is
+    /// returned to every caller that was waiting on it.
+    ///
+    /// # Returns
+    /// * `Ok(Some(Vec<u8>))` - Decoded account data
+    /// * `Ok(None)` - If the account does not exist
+    /// * `Err(String)` - Error message if the RPC request fails
+    async fn get_account_data(&self, pubkey: &Pubkey) -> Result<Option<Vec<u8>>, String> {
+        self.account_reads
+            .run(*pubkey, || async {
+                let params = json!([
+                    pubkey.to_string(),
+                    { "encoding": "base64", "commitment": "confirmed" }
+                ]);
+
+                let response: AccountInfoResponse = self.rpc_request("getAccountInfo", params).await?;
+
+                response
+                    .value
+                    .map(|account| {
+                        BASE64
+                            .decode(&account.data.0)
+                            .map_err(|e| format!("Invalid base64 data: {}", e))
+                    })
+                    .transpose()
+            })
+            .await
+    }
+
+    /// Checks whether an account exists (uncached).
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - Whether the address holds an account
+    /// * `Err(String)` - Error message if the RPC request fails
+    pub async fn account_exists(&self, pubkey: &Pubkey) -> Result<bool, String> {
+        Ok(self.get_account_data(pubkey).await?.is_some())
+    }
+
+    /// Fetches and decodes a single Room account, reading through the cache.
+    ///
+    /// # Arguments
+    /// * `pubkey` - Room PDA address
+    /// * `fresh` - Skip the cache (e.g., right after a transaction changed the room)
+    ///
+    /// # Returns
+    /// * `Ok(Some(RoomAccount))` - Decoded room
+    /// * `Ok(None)` - If no account exists at the address (not cached)
+    /// * `Err(String)` - If the RPC request fails or the account is not a Room
+    pub async fn get_room_account(&self, pubkey: &Pubkey, fresh: bool) -> Result<Option<RoomAccount>, String> {
+        if !fresh {
+            if let Some(room) = self.cache.get_room(pubkey) {
+                return Ok(Some(room));
+            }
+        }
+
+        info!("Solana RPC: Fetching room {}", pubkey);
+
+        let room = match self.get_account_data(pubkey).await? {
+            Some(data) => RoomAccount::from_account_data(pubkey, &data)?,
+            None => return Ok(None),
+        };
+
+        self.cache.insert_room(*pubkey, room.clone());
+        Ok(Some(room))
+    }
+
+    /// Fetches a Room account and decodes every field (uncached).
+    ///
+    /// # Arguments
+    /// * `pubkey` - Room PDA address
+    ///
+    /// # Returns
+    /// * `Ok(Some(RoomFull))` - Decoded room
+    /// * `Ok(None)` - If no account exists at the address
+    /// * `Err(String)` - If the RPC request fails or the account is not a Room
+    pub async fn get_room_full(&self, pubkey: &Pubkey) -> Result<Option<RoomFull>, String> {
+        info!("Solana RPC: Fetching full room {}", pubkey);
+
+        self.get_account_data(pubkey)
+            .await?
+            .map(|data| RoomFull::from_account_data(pubkey, &data))
+            .transpose()
+    }
+
+    /// Fetches and decodes the GlobalConfig account, reading through the cache.
+    ///
+    /// The configuration is cached for the long config TTL and evicted when a
+    /// `ConfigUpdated` or `PlatformStats` event arrives.
+    ///
+    /// # Arguments
+    /// * `fresh` - Skip the cache
+    ///
+    /// # Returns
+    /// * `Ok(Some(GlobalConfigAccount))` - Decoded configuration
+    /// * `Ok(None)` - If the program has not been initialized (not cached)
+    /// * `Err(String)` - If the RPC request fails or the account cannot be decoded
+    pub async fn get_global_config(&self, fresh: bool) -> Result<Option<GlobalConfigAccount>, String> {
+        let pubkey = self.derive_global_config_pda();
+        if !fresh {
+            if let Some(config) = self.cache.get_global_config(&pubkey) {
+                return Ok(Some(config));
+            }
+        }
+
+        info!("Solana RPC: Fetching global config {}", pubkey);
+
+        let config = match self.get_account_data(&pubkey).await? {
+            Some(data) => GlobalConfigAccount::from_account_data(&pubkey, &data)?,
+            None => return Ok(None),
+        };
+
+        self.cache.insert_global_config(pubkey, config.clone());
+        Ok(Some(config))
+    }
+
+    /// Fetches and decodes the TokenRegistry account, reading through the cache.
+    ///
+    /// The registry is cached for the long config TTL and evicted when a
+    /// `TokenApproved` or `TokenRemoved` event arrives.
+    ///
+    /// # Arguments
+    /// * `fresh` - Skip the cache
+    ///
+    /// # Returns
+    /// * `Ok(Some(TokenRegistryAccount))` - Decoded registry
+    /// * `Ok(None)` - If the registry has not been initialized (not cached)
+    /// * `Err(String)` - If the RPC request fails or the account cannot be decoded
+    pub async fn get_token_registry(&self, fresh: bool) -> Result<Option<TokenRegistryAccount>, String> {
+        let pubkey = self.derive_token_registry_pda();
+        if !fresh {
+            if let Some(registry) = self.cache.get_token_registry(&pubkey) {
+                return Ok(Some(registry));
+            }
+        }
+
+        info!("Solana RPC: Fetching token registry {}", pubkey);
+
+        let registry = match self.get_account_data(&pubkey).await? {
+            Some(data) => TokenRegistryAccount::from_account_data(&pubkey, &data)?,
+            None => return Ok(None),
+        };
+
+        self.cache.insert_token_registry(pubkey, registry.clone());
+        Ok(Some(registry))
+    }
+
+    /// Fetches the SOL balance of an account, reading through the cache.
+    ///
+    /// # Arguments
+    /// * `pubkey` - Account address
+    /// * `fresh` - Skip the cache
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - Balance in lamports (0 for accounts that do not exist)
+    /// * `Err(String)` - Error message if the RPC request fails
+    pub async fn get_balance(&self, pubkey: &Pubkey, fresh: bool) -> Result<u64, String> {
+        if !fresh {
+            if let Some(lamports) = self.cache.get_balance(pubkey) {
+                return Ok(lamports);
+            }
+        }
+
+        info!("Solana RPC: Fetching balance for {}", pubkey);
+
+        let params = json!([pubkey.to_string(), { "commitment": "confirmed" }]);
+        let response: BalanceResponse = self.rpc_request("getBalance", params).await?;
+
+        self.cache.insert_balance(*pubkey, response.value);
+        Ok(response.value)
+    }
+
+    /// Fetches the SPL token balance of an owner's associated token account.
+    ///
+    /// A missing associated token account is not an error: the owner simply holds
+    /// none of the mint, so the balance is 0 and the decimals are read from the mint.
+    ///
+    /// # Arguments
+    /// * `owner` - Wallet that owns the token account
+    /// * `mint` - SPL token mint
+    ///
+    /// # Returns
+    /// * `Ok(Some(TokenBalance))` - Balance of the associated token account
+    /// * `Ok(None)` - If neither the token account nor the mint exists
+    /// * `Err(String)` - If the RPC request fails or the mint cannot be decoded
+    pub async fn get_token_balance(&self, owner: &Pubkey, mint: &Pubkey) -> Result<Option<TokenBalance>, String> {
+        let token_account = get_associated_token_address(owner, mint);
+
+        info!("Solana RPC: Fetching token balance of {} ({} / {})", token_account, owner, mint);
+
+        let params = json!([token_account.to_string(), { "commitment": "confirmed" }]);
+        match self
+            .rpc_request::<TokenAccountBalanceResponse>("getTokenAccountBalance", params)
+            .await
+        {
+            Ok(response) => {
+                let amount = response
+                    .value
+                    .amount
+                    .parse()
+                    .map_err(|e| format!("Invalid token amount from Solana RPC: {}", e))?;
+                Ok(Some(TokenBalance {
+                    token_account,
+                    amount,
+                    decimals: response.value.decimals,
+                }))
+            }
+            Err(err) if is_missing_account_error(&err) => {
+                let Some(mint_data) = self.get_account_data(mint).await? else {
+                    return Ok(None);
+                };
+                Ok(Some(TokenBalance {
+                    token_account,
+                    amount: 0,
+                    decimals: mint_decimals(&mint_data)?,
+                }))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Reads the real token balance of a room's vault (uncached).
+    ///
+    /// `room.total_collected` is the program's accounting figure; this is what the
+    /// vault actually holds, so the two can be reconciled.
+    ///
+    /// # Arguments
+    /// * `room` - Room PDA address
+    ///
+    /// # Returns
+    /// * `Ok(Some(VaultBalance))` - Vault address, mint and balance
+    /// * `Ok(None)` - If the vault doesn't exist (never created, or closed by `cancel_room`)
+    /// * `Err(String)` - If the RPC request fails or the account is not a token account
+    pub async fn get_vault_balance(&self, room: &Pubkey) -> Result<Option<VaultBalance>, String> {
+        let vault = self.derive_room_vault_pda(room);
+
+        info!("Solana RPC: Fetching vault {} of room {}", vault, room);
+
+        let Some(data) = self.get_account_data(&vault).await? else {
+            return Ok(None);
+        };
+        let (mint, amount) = token_account_mint_and_amount(&data)?;
+        Ok(Some(VaultBalance { vault, mint, amount }))
+    }
+
+    /// Lists transaction signatures that touched an address, newest first.
+    ///
+    /// # Arguments
+    /// * `address` - Account or program address
+    /// * `before` - Start below this signature (exclusive), used to page backwards
+    /// * `until` - Stop at this signature (exclusive), used to page forward from a cursor
+    /// * `limit` - Maximum signatures to return (RPC caps this at 1000)
+    pub async fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<&str>,
+        until: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SignatureInfo>, String> {
+        let mut config = json!({ "commitment": "confirmed", "limit": limit });
+        if let Some(before) = before {
+            config["before"] = json!(before);
+        }
+        if let Some(until) = until {
+            config["until"] = json!(until);
+        }
+
+        self.rpc_request("getSignaturesForAddress", json!([address.to_string(), config]))
+            .await
+    }
+
+    /// Fetches the log messages of a confirmed transaction.
+    ///
+    /// # Returns
+    /// * `Ok(Some(Vec<String>))` - Log lines emitted while executing the transaction
+    /// * `Ok(None)` - If the transaction is unknown to the node or has no logs
+    /// * `Err(String)` - Error message if the RPC request fails
+    pub async fn get_transaction_logs(&self, signature: &str) -> Result<Option<Vec<String>>, String> {
+        let params = json!([
+            signature,
+            { "encoding": "json", "commitment": "confirmed", "maxSupportedTransactionVersion": 0 }
+        ]);
+
+        let response: Option<TransactionResponse> = self.rpc_request("getTransaction", params).await?;
+
+        Ok(response
+            .and_then(|tx| tx.meta)
+            .and_then(|meta| meta.log_messages))
+    }
+
+    /// Fetches a confirmed transaction with its logs and token balance changes.
+    ///
+    /// # Returns
+    /// * `Ok(Some(ConfirmedTransaction))` - The transaction
+    /// * `Ok(None)` - If the transaction is unknown to the node
+    /// * `Err(String)` - Error message if the RPC request fails
+    pub async fn get_transaction(&self, signature: &str) -> Result<Option<ConfirmedTransaction>, String> {
+        let params = json!([
+            signature,
+            { "encoding": "json", "commitment": "confirmed", "maxSupportedTransactionVersion": 0 }
+        ]);
+
+        self.rpc_request("getTransaction", params).await
+    }
+
+    /// Finds a room's `end_room` transaction and proves the charity transfer in it.
+    ///
+    /// Walks the room's signatures newest first, so an ended room usually needs a single
+    /// `getTransaction` call (the end transaction is the last one to touch the room).
+    ///
+    /// # Arguments
+    /// * `room` - Decoded Room account (provides the charity wallet and fee mint)
+    ///
+    /// # Returns
+    /// * `Ok(Some(CharityProof))` - Proof built from the end transaction
+    /// * `Ok(None)` - If no successful transaction emitted `RoomEnded` for the room
+    /// * `Err(String)` - Error message if an RPC request fails
+    pub async fn get_charity_proof(&self, room: &RoomAccount) -> Result<Option<CharityProof>, String> {
+        let room_pubkey = room
+            .pubkey
+            .parse::<Pubkey>()
+            .map_err(|e| format!("Invalid room pubkey: {}", e))?;
+        info!("Solana RPC: Looking up charity proof for room {}", room_pubkey);
+
+        let mut before: Option<String> = None;
+        loop {
+            let page = self
+                .get_signatures_for_address(&room_pubkey, before.as_deref(), None, SIGNATURE_PAGE_SIZE)
+                .await?;
+            let page_len = page.len();
+            before = page.last().map(|info| info.signature.clone());
+
+            for info in page.into_iter().filter(|info| info.err.is_none()) {
+                let Some(transaction) = self.get_transaction(&info.signature).await? else {
+                    continue;
+                };
+                if let Some(proof) = CharityProof::from_end_transaction(
+                    &room_pubkey,
+                    &room.charity_wallet,
+                    &room.fee_token_mint,
+                    &info.signature,
+                    &transaction,
+                ) {
+                    return Ok(Some(proof));
+                }
+            }
+
+            if page_len < SIGNATURE_PAGE_SIZE {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Finds a room's outcome by scanning its transactions for the `RoomEnded` event.
+    ///
+    /// This is the fallback for rooms missing from the outcome index. Like
+    /// `get_charity_proof` it walks signatures newest first, so an ended room
+    /// usually costs a single `getTransaction` call.
+    ///
+    /// # Arguments
+    /// * `room` - Room PDA address
+    ///
+    /// # Returns
+    /// * `Ok(Some(RoomOutcome))` - Outcome from the `end_room` transaction
+    /// * `Ok(None)` - If no successful transaction emitted `RoomEnded` for the room
+    /// * `Err(String)` - Error message if an RPC request fails
+    pub async fn find_room_outcome(&self, room: &Pubkey) -> Result<Option<RoomOutcome>, String> {
+        info!("Solana RPC: Scanning transactions for outcome of room {}", room);
+
+        let mut before: Option<String> = None;
+        loop {
+            let page = self
+                .get_signatures_for_address(room, before.as_deref(), None, SIGNATURE_PAGE_SIZE)
+                .await?;
+            let page_len = page.len();
+            before = page.last().map(|info| info.signature.clone());
+
+            for info in page.into_iter().filter(|info| info.err.is_none()) {
+                let Some(logs) = self.get_transaction_logs(&info.signature).await? else {
+                    continue;
+                };
+                let outcome = ProgramEvent::from_logs(&logs)
+                    .iter()
+                    .filter(|event| event.room().as_ref() == Some(room))
+                    .find_map(|event| RoomOutcome::from_event(event, &info.signature, info.slot));
+                if outcome.is_some() {
+                    return Ok(outcome);
+                }
+            }
+
+            if page_len < SIGNATURE_PAGE_SIZE {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Builds a room's chronological event timeline.
+    ///
+    /// Pages through every signature that touched the room PDA, fetches the logs
+    /// of each successful transaction and merges the decoded events by slot. This
+    /// costs one `getTransaction` call per transaction, so it is intended for room
+    /// detail pages rather than listings.
+    ///
+    /// # Arguments
+    /// * `room` - Room PDA address
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TimelineEntry>)` - Events oldest first (empty if the room never existed)
+    /// * `Err(String)` - Error message if an RPC request fails
+    pub async fn get_room_timeline(&self, room: &Pubkey) -> Result<Vec<TimelineEntry>, String> {
+        info!("Solana RPC: Building timeline for room {}", room);
+
+        let mut signatures = Vec::new();
+        let mut before: Option<String> = None;
+        loop {
+            let page = self
+                .get_signatures_for_address(room, before.as_deref(), None, SIGNATURE_PAGE_SIZE)
+                .await?;
+            let page_len = page.len();
+            before = page.last().map(|info| info.signature.clone());
+            signatures.extend(page);
+
+            if page_len < SIGNATURE_PAGE_SIZE {
+                break;
+            }
+        }
+
+        let mut transactions = Vec::new();
+        for info in signatures.into_iter().filter(|info| info.err.is_none()) {
+            if let Some(logs) = self.get_transaction_logs(&info.signature).await? {
+                transactions.push(RoomTransaction {
+                    signature: info.signature,
+                    slot: info.slot,
+                    block_time: info.block_time,
+                    logs,
+                });
+            }
+        }
+
+        Ok(build_timeline(room, transactions))
+    }
+
+    /// Fetches the current slot, e.g. to check a room's expiration before building a join.
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - Current slot at `confirmed` commitment
+    /// * `Err(String)` - Error message if the RPC request fails
+    pub async fn get_slot(&self) -> Result<u64, String> {
+        self.rpc_request("getSlot", json!([{ "commitment": "confirmed" }])).await
+    }
+
+    /// Simulates a signed or unsigned transaction without submitting it.
+    ///
+    /// Signatures are not verified and the blockhash is replaced with a recent one, so
+    /// a transaction straight from the transaction builder can be checked before the
+    /// wallet signs it.
+    ///
+    /// # Arguments
+    /// * `transaction` - Bincode-serialized transaction, base64-encoded
+    ///
+    /// # Returns
+    /// * `Ok(SimulationResult)` - Logs, compute units and error of the simulation
+    /// * `Err(String)` - Error message if the RPC request fails or the node rejects the
+    ///   transaction encoding
+    pub async fn simulate(&self, transaction: &str) -> Result<SimulationResult, String> {
+        let params = json!([
+            transaction,
+            {
+                "encoding": "base64",
+                "commitment": "confirmed",
+                "sigVerify": false,
+                "replaceRecentBlockhash": true
+            }
+        ]);
+        let response: SimulateTransactionResponse = self.rpc_request("simulateTransaction", params).await?;
+
+        Ok(response.value)
+    }
+
+    /// Fetches a recent blockhash for building transactions.
+    ///
+    /// # Returns
+    /// * `Ok(Hash)` - Latest blockhash at `confirmed` commitment
+    /// * `Err(String)` - Error message if the RPC request fails
+    pub async fn get_latest_blockhash(&self) -> Result<Hash, String> {
+        let params = json!([{ "commitment": "confirmed" }]);
+        let response: LatestBlockhashResponse = self.rpc_request("getLatestBlockhash", params).await?;
+
+        response
+            .value
+            .blockhash
+            .parse::<Hash>()
+            .map_err(|e| format!("Invalid blockhash from Solana RPC: {}", e))
+    }
+}
+
+fn build_http_client(timeout: Duration) -> Client {
+    Client::builder()
+        .connect_timeout(timeout)
+        .timeout(timeout)
+        .build()
+        .expect("Failed to build Solana RPC HTTP client")
+}
+
+/// Whether a transport error may not happen on the next attempt or another node: a
+/// timeout, a failed connection, or a connection dropped while sending the request or
+/// reading the response.
+fn is_transient_transport_error(e: &reqwest::Error) -> bool {
+    e.is_timeout() || e.is_connect() || e.is_request() || e.is_body()
+}
+
+/// Exponential backoff with jitter: `base * 2^(attempt-1)` capped at the max,
+/// plus up to 50% random jitter so concurrent retries don't hit the node in lockstep.
+fn retry_delay(attempt: u32) -> Duration {
+    let exponential = BASE_RETRY_DELAY
+        .saturating_mul(1 << attempt.saturating_sub(1).min(16))
+        .min(MAX_RETRY_DELAY);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    let jitter = exponential.mul_f64(f64::from(nanos % 1000) / 2000.0);
+    exponential + jitter
+}
+
+/// Whether an RPC error means the queried account does not exist.
+fn is_missing_account_error(err: &str) -> bool {
+    err.contains("could not find account")
+}
+
+/// Reads `decimals` from raw SPL Mint account data.
+fn mint_decimals(data: &[u8]) -> Result<u8, String> {
+    data.get(MINT_DECIMALS_OFFSET)
+        .copied()
+        .ok_or_else(|| "Account is not an SPL token mint".to_string())
+}
+
+/// Reads the mint and amount from raw SPL token account data (Token and Token-2022
+/// share this layout: mint, owner, amount, ...).
+fn token_account_mint_and_amount(data: &[u8]) -> Result<(Pubkey, u64), String> {
+    let fields = data
+        .get(..TOKEN_ACCOUNT_AMOUNT_END)
+        .ok_or_else(|| "Account is not an SPL token account".to_string())?;
+    let mint = Pubkey::try_from(&fields[..32]).map_err(|e| format!("Invalid mint: {}", e))?;
+    let amount = u64::from_le_bytes(fields[64..72].try_into().expect("slice is 8 bytes"));
+    Ok((mint, amount))
+}
+
+/// Decodes a `getProgramAccounts` entry into a RoomAccount.
+fn decode_room(account: &ProgramAccount) -> Result<RoomAccount, String> {
+    let pubkey = account
+        .pubkey
+        .parse::<Pubkey>()
+        .map_err(|e| format!("Invalid pubkey: {}", e))?;
+    let data = BASE64
+        .decode(&account.account.data.0)
+        .map_err(|e| format!("Invalid base64 data: {}", e))?;
+
+    RoomAccount::from_account_data(&pubkey, &data)
+}
+
+/// Decodes a `getProgramAccounts` entry into a PlayerEntryAccount.
+fn decode_player_entry(account: &ProgramAccount) -> Result<PlayerEntryAccount, String> {
+    let pubkey = account
+        .pubkey
+        .parse::<Pubkey>()
+        .map_err(|e| format!("Invalid pubkey: {}", e))?;
+    let data = BASE64
+        .decode(&account.account.data.0)
+        .map_err(|e| format!("Invalid base64 data: {}", e))?;
+
+    PlayerEntryAccount::from_account_data(&pubkey, &data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// PDA test vectors shared with the program's unit tests
+    const PDA_VECTORS: &str =
+        include_str!("../../../solana-program/fundraisely/tests/fixtures/pda_vectors.json");
+
+    fn key(value: &Value) -> Pubkey {
+        value.as_str().unwrap().parse().unwrap()
+    }
+
+    #[test]
+    fn pda_derivation_matches_shared_vectors() {
+        let fixture: Value = serde_json::from_str(PDA_VECTORS).unwrap();
+        let service = SolanaService::new(
+            "http://localhost:8899".to_string(),
+            key(&fixture["program_id"]),
+            Arc::new(CacheService::default()),
+        );
+
+        assert_eq!(service.derive_global_config_pda(), key(&fixture["global_config"]));
+
+        for vector in fixture["vectors"].as_array().unwrap() {
+            let description = vector["description"].as_str().unwrap();
+            let room_id = vector["room_id"].as_str().unwrap();
+            let room = service.derive_room_pda(&key(&vector["host"]), room_id);
+
+            assert_eq!(room, key(&vector["room"]), "room PDA: {}", description);
+            assert_eq!(
+                service.derive_player_pda(&room, &key(&vector["player"])),
+                key(&vector["player_entry"]),
+                "player PDA: {}",
+                description
+            );
+            assert_eq!(
+                service.derive_room_vault_pda(&room),
+                key(&vector["room_vault"]),
+                "vault PDA: {}",
+                description
+            );
+        }
+    }
+
+    /// Answers every request with a `getSlot` result. Returns the URL and a request counter.
+    async fn slot_server(slot: u64) -> (String, Arc<AtomicU32>) {
+        result_server(slot.to_string()).await
+    }
+
+    /// Answers every request with the given JSON `result`. Returns the URL and a request counter.
+    async fn result_server(result: String) -> (String, Arc<AtomicU32>) {
+        delayed_result_server(result, Duration::ZERO).await
+    }
+
+    /// Like `result_server`, but answers each request only after `delay`.
+    async fn delayed_result_server(result: String, delay: Duration) -> (String, Arc<AtomicU32>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(AtomicU32::new(0));
+        let counter = requests.clone();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                counter.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(delay).await;
+                let body = format!(r#"{{"jsonrpc":"2.0","result":{},"id":1}}"#, result);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+
+        (format!("http://{}", addr), requests)
+    }
+
+    /// Answers every request with HTTP 503. Returns the URL and a request counter.
+    async fn unavailable_server() -> (String, Arc<AtomicU32>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(AtomicU32::new(0));
+        let counter = requests.clone();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                counter.fetch_add(1, Ordering::SeqCst);
+                let response = "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+
+        (format!("http://{}", addr), requests)
+    }
+
+    #[tokio::test]
+    async fn fails_over_and_skips_a_repeatedly_failing_endpoint() {
+        let (primary, primary_requests) = unavailable_server().await;
+        let (secondary, _) = slot_server(77).await;
+        let service = SolanaService::new(primary, Pubkey::new_unique(), Arc::new(CacheService::default()))
+            .with_failover_urls(vec![secondary])
+            .with_max_retries(0);
+
+        for _ in 0..ENDPOINT_FAILURE_THRESHOLD {
+            assert_eq!(service.rpc_request::<u64>("getSlot", json!([])).await, Ok(77));
+        }
+        assert_eq!(primary_requests.load(Ordering::SeqCst), ENDPOINT_FAILURE_THRESHOLD);
+
+        let stats = service.rpc_endpoint_stats();
+        assert_eq!(stats[0].failures, u64::from(ENDPOINT_FAILURE_THRESHOLD));
+        assert!(!stats[0].available);
+        assert!(stats[1].available);
+
+        // The primary is now skipped until its cooldown passes
+        assert_eq!(service.rpc_request::<u64>("getSlot", json!([])).await, Ok(77));
+        assert_eq!(primary_requests.load(Ordering::SeqCst), ENDPOINT_FAILURE_THRESHOLD);
+    }
+
+    /// Accepts connections and reads each request, then either never answers (`hang`) or
+    /// drops the connection. Returns the URL and a request counter.
+    async fn silent_server(hang: bool) -> (String, Arc<AtomicU32>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(AtomicU32::new(0));
+        let counter = requests.clone();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                counter.fetch_add(1, Ordering::SeqCst);
+                if hang {
+                    tokio::spawn(async move {
+                        tokio::time::sleep(Duration::from_secs(60)).await;
+                        drop(socket);
+                    });
+                }
+            }
+        });
+
+        (format!("http://{}", addr), requests)
+    }
+
+    #[tokio::test]
+    async fn retries_and_fails_over_from_a_hung_or_resetting_node() {
+        for hang in [true, false] {
+            let (primary, primary_requests) = silent_server(hang).await;
+            let (secondary, _) = slot_server(77).await;
+            let service = SolanaService::new(primary, Pubkey::new_unique(), Arc::new(CacheService::default()))
+                .with_failover_urls(vec![secondary])
+                .with_max_retries(1)
+                .with_timeout(Duration::from_millis(200));
+
+            assert_eq!(service.rpc_request::<u64>("getSlot", json!([])).await, Ok(77), "hang: {}", hang);
+            assert_eq!(primary_requests.load(Ordering::SeqCst), 2, "hang: {}", hang);
+            assert_eq!(service.rpc_endpoint_stats()[0].failures, 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn single_endpoint_is_never_skipped() {
+        let (rpc_url, requests) = unavailable_server().await;
+        let service = SolanaService::new(rpc_url, Pubkey::new_unique(), Arc::new(CacheService::default()))
+            .with_max_retries(0);
+
+        for _ in 0..=ENDPOINT_FAILURE_THRESHOLD {
+            assert!(service.rpc_request::<u64>("getSlot", json!([])).await.is_err());
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), ENDPOINT_FAILURE_THRESHOLD + 1);
+    }
+
+    #[test]
+    fn endpoint_label_hides_path_and_api_key() {
+        let endpoint = RpcEndpoint::new("https://rpc.example.com/v1?api-key=secret".to_string());
+        assert_eq!(endpoint.label, "https://rpc.example.com");
+    }
+
+    #[tokio::test]
+    async fn health_check_reports_slot_and_caches_the_probe() {
+        let (rpc_url, requests) = slot_server(4242).await;
+        let service = SolanaService::new(rpc_url, Pubkey::new_unique(), Arc::new(CacheService::default()));
+
+        let health = service.check_health().await.unwrap();
+        assert_eq!(health.slot, 4242);
+        assert_eq!(service.check_health().await, Ok(health));
+        assert_eq!(requests.load(Ordering::SeqCst), 1, "second probe served from cache");
+
+        // Nothing listening: reported as a failure without retrying
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let closed = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let service = SolanaService::new(closed, Pubkey::new_unique(), Arc::new(CacheService::default()));
+        let err = service.check_health().await.unwrap_err();
+        assert!(err.contains("connect"), "unexpected error: {}", err);
+    }
+
+    #[tokio::test]
+    async fn concurrent_reads_of_one_account_share_an_rpc_call() {
+        let result = json!({ "context": { "slot": 1 }, "value": null });
+        let (rpc_url, requests) = delayed_result_server(result.to_string(), Duration::from_millis(100)).await;
+        let service = Arc::new(SolanaService::new(
+            rpc_url,
+            Pubkey::new_unique(),
+            Arc::new(CacheService::default()),
+        ));
+        let room = Pubkey::new_unique();
+
+        let reads: Vec<_> = (0..10)
+            .map(|_| {
+                let service = service.clone();
+                tokio::spawn(async move { service.get_room_account(&room, false).await })
+            })
+            .collect();
+        for read in reads {
+            assert!(matches!(read.await.unwrap(), Ok(None)));
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // Failures are shared too, then forgotten so the next read retries
+        let (rpc_url, requests) = unavailable_server().await;
+        let service = Arc::new(
+            SolanaService::new(rpc_url, Pubkey::new_unique(), Arc::new(CacheService::default()))
+                .with_max_retries(0),
+        );
+        let reads: Vec<_> = (0..10)
+            .map(|_| {
+                let service = service.clone();
+                tokio::spawn(async move { service.get_room_account(&room, false).await })
+            })
+            .collect();
+        for read in reads {
+            assert!(read.await.unwrap().is_err());
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert!(service.get_room_account(&room, false).await.is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn simulation_decodes_the_program_error() {
+        let result = json!({
+            "context": { "slot": 1 },
+            "value": {
+                "err": { "InstructionError": [0, { "Custom": 6025 }] },
+                "logs": ["Program log: Instruction: JoinRoom"],
+                "unitsConsumed": 12000
+            }
+        });
+        let (rpc_url, _) = result_server(result.to_string()).await;
+        let service = SolanaService::new(rpc_url, Pubkey::new_unique(), Arc::new(CacheService::default()));
+
+        let simulation = service.simulate("AQID").await.unwrap();
+        assert_eq!(simulation.units_consumed, Some(12000));
+        // No Anchor error line in the logs, so the code comes from the instruction error
+        assert_eq!(simulation.error_code(), Some(6025));
+
+        let success = SimulationResult {
+            err: None,
+            logs: Some(vec![]),
+            units_consumed: Some(5000),
+        };
+        assert_eq!(success.error_code(), None);
+    }
+
+    #[test]
+    fn missing_token_account_falls_back_to_mint_decimals() {
+        // Error returned by getTokenAccountBalance for an ATA that was never created
+        assert!(is_missing_account_error(
+            "Solana RPC error -32602: Invalid param: could not find account"
+        ));
+        assert!(!is_missing_account_error("Solana RPC error -32602: Invalid param: not a Token account"));
+
+        // SPL Mint layout: COption<Pubkey> authority, supply, decimals, ...
+        let mut mint = vec![0u8; 82];
+        mint[MINT_DECIMALS_OFFSET] = 6;
+        assert_eq!(mint_decimals(&mint), Ok(6));
+        assert!(mint_decimals(&mint[..MINT_DECIMALS_OFFSET]).is_err());
+    }
+
+    #[test]
+    fn reads_mint_and_amount_of_a_token_account() {
+        // SPL token account layout: mint, owner, amount, then delegate/state/... (165 bytes)
+        let mint = Pubkey::new_unique();
+        let mut account = mint.to_bytes().to_vec();
+        account.extend_from_slice(Pubkey::new_unique().as_ref());
+        account.extend_from_slice(&12_500_000u64.to_le_bytes());
+        account.resize(165, 0);
+
+        assert_eq!(token_account_mint_and_amount(&account), Ok((mint, 12_500_000)));
+        assert!(token_account_mint_and_amount(&account[..TOKEN_ACCOUNT_AMOUNT_END - 1]).is_err());
+    }
+}
@@ -0,0 +1,213 @@
+//! Charity search cache for the TGB client.
+//!
+//! Charity search results change rarely, but the charity picker re-sends the same
+//! queries constantly. Results are cached per lowercased query for a TTL, and the
+//! number of cached queries is capped: when full, the least recently used query is
+//! evicted. Concurrent identical searches are coalesced (single-flight) so a burst
+//! of users typing the same name produces one upstream request, not one per user.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::models::Charity;
+
+/// Default time-to-live for cached search results.
+pub const DEFAULT_SEARCH_CACHE_TTL: Duration = Duration::from_secs(600);
+
+/// Default maximum number of cached queries.
+pub const DEFAULT_SEARCH_CACHE_CAPACITY: usize = 1000;
+
+/// Search cache hit/miss counters, reported on the health endpoint.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Cached results of one query.
+///
+/// `last_used` is a logical clock value, bumped on every hit, that orders entries
+/// for least-recently-used eviction.
+struct CacheEntry {
+    inserted: Instant,
+    last_used: u64,
+    charities: Vec<Charity>,
+}
+
+/// Entries plus the logical clock used to stamp them.
+#[derive(Default)]
+struct LruEntries {
+    entries: HashMap<String, CacheEntry>,
+    clock: u64,
+}
+
+impl LruEntries {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+}
+
+/// Size-capped LRU/TTL cache of charity search results with per-query single-flight locks.
+pub struct SearchCache {
+    ttl: Duration,
+    capacity: usize,
+    entries: Mutex<LruEntries>,
+    in_flight: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl SearchCache {
+    /// Creates an empty cache with the given TTL, holding at most `capacity` queries.
+    pub fn new(ttl: Duration, capacity: usize) -> Self {
+        Self {
+            ttl,
+            capacity,
+            entries: Mutex::new(LruEntries::default()),
+            in_flight: Mutex::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Normalizes a query into its cache key (trimmed, lowercased).
+    pub fn key(query: &str) -> String {
+        query.trim().to_lowercase()
+    }
+
+    /// Returns cached results for `key` if they have not expired, marking them recently used.
+    pub fn get(&self, key: &str) -> Option<Vec<Charity>> {
+        let mut lru = self.entries.lock().unwrap();
+        let now = lru.tick();
+        match lru.entries.get_mut(key) {
+            Some(entry) if entry.inserted.elapsed() < self.ttl => {
+                entry.last_used = now;
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(entry.charities.clone())
+            }
+            Some(_) => {
+                lru.entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Stores results for `key`, dropping expired entries and, when the cache is
+    /// full, the least recently used one.
+    pub fn insert(&self, key: String, charities: Vec<Charity>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut lru = self.entries.lock().unwrap();
+        lru.entries.retain(|_, entry| entry.inserted.elapsed() < self.ttl);
+
+        if !lru.entries.contains_key(&key) && lru.entries.len() >= self.capacity {
+            let oldest = lru
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                lru.entries.remove(&oldest);
+            }
+        }
+
+        let now = lru.tick();
+        lru.entries.insert(
+            key,
+            CacheEntry {
+                inserted: Instant::now(),
+                last_used: now,
+                charities,
+            },
+        );
+    }
+
+    /// Number of cached queries (may include expired entries).
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().entries.len()
+    }
+
+    /// Whether no query is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the single-flight lock for `key`, shared by all concurrent callers.
+    ///
+    /// The first caller to acquire it fetches upstream and fills the cache; callers
+    /// waiting on it re-check the cache once it is released.
+    pub fn flight_lock(&self, key: &str) -> Arc<tokio::sync::Mutex<()>> {
+        self.in_flight
+            .lock()
+            .unwrap()
+            .entry(key.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Removes the single-flight lock once no other caller holds a reference to it.
+    pub fn finish_flight(&self, key: &str) {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if in_flight.get(key).is_some_and(|lock| Arc::strong_count(lock) == 1) {
+            in_flight.remove(key);
+        }
+    }
+
+    /// Records a lookup that had to go upstream.
+    pub fn record_miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns hit/miss counts since startup.
+    pub fn stats(&self) -> SearchCacheStats {
+        SearchCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn charity(id: &str) -> Charity {
+        Charity::new(id.to_string(), "Red Cross".to_string(), None, None, vec![])
+    }
+
+    #[test]
+    fn queries_differing_in_case_share_an_entry_until_expiry() {
+        let cache = SearchCache::new(Duration::from_millis(20), DEFAULT_SEARCH_CACHE_CAPACITY);
+
+        cache.insert(SearchCache::key("Red Cross "), vec![charity("rc")]);
+        assert_eq!(cache.get(&SearchCache::key("red cross")).unwrap()[0].id, "rc");
+
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(cache.get(&SearchCache::key("RED CROSS")).is_none());
+    }
+
+    #[test]
+    fn full_cache_evicts_least_recently_used_query() {
+        let cache = SearchCache::new(Duration::from_secs(60), 2);
+
+        cache.insert("red cross".to_string(), vec![charity("rc")]);
+        cache.insert("unicef".to_string(), vec![charity("un")]);
+        // Touch "red cross" so "unicef" becomes the least recently used
+        assert!(cache.get("red cross").is_some());
+
+        cache.insert("oxfam".to_string(), vec![charity("ox")]);
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("unicef").is_none());
+        assert!(cache.get("red cross").is_some());
+        assert!(cache.get("oxfam").is_some());
+    }
+}
@@ -3,9 +3,52 @@
 //! This module provides a type-safe client for interacting with The Giving Block API,
 //! handling authentication, request construction, and response parsing.
 
-use crate::models::{Charity, DonationAddress};
-use reqwest::Client;
-use tracing::{error, info};
+use crate::models::{Charity, CharityPage, DonationAddress};
+use crate::services::circuit_breaker::CircuitBreaker;
+use crate::services::tgb_cache::{
+    SearchCache, SearchCacheStats, DEFAULT_SEARCH_CACHE_CAPACITY, DEFAULT_SEARCH_CACHE_TTL,
+};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, Response, StatusCode};
+use serde::Serialize;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+/// Production TGB API base URL.
+pub const DEFAULT_TGB_BASE_URL: &str = "https://api.thegivingblock.com/v1";
+
+/// Default connect and request timeout for TGB calls.
+pub const DEFAULT_TGB_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default number of retries after a 429, 5xx or timed-out request.
+pub const DEFAULT_TGB_MAX_RETRIES: u32 = 3;
+
+/// Initial backoff after a 429 without `Retry-After`; doubles on each retry.
+const BASE_RATE_LIMIT_DELAY: Duration = Duration::from_millis(500);
+
+/// Initial backoff after a 5xx or timeout; doubles on each retry.
+const BASE_RETRY_DELAY: Duration = Duration::from_millis(200);
+
+/// Upper bound on any single wait, including server-provided `Retry-After` values.
+const MAX_RATE_LIMIT_DELAY: Duration = Duration::from_secs(10);
+
+/// Timeout for the readiness probe, so a hung TGB doesn't hang `/health/ready`.
+const READINESS_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Outcome of a TGB readiness probe.
+///
+/// # Fields
+/// * `reachable` - Whether TGB answered the authenticated probe with a 2xx
+/// * `upstream_status` - HTTP status TGB answered with (absent if it never answered)
+/// * `error` - Why the probe failed (absent when reachable)
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct TgbReadiness {
+    pub reachable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream_status: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
 
 /// HTTP client for The Giving Block API.
 ///
@@ -15,10 +58,41 @@ use tracing::{error, info};
 ///
 /// # Authentication
 /// All requests include the TGB API key in the Authorization header as a Bearer token.
+///
+/// # Timeouts
+/// Connecting and the whole request are bounded by a timeout (10s by default), so a hung
+/// TGB endpoint fails with a "timed out" error instead of holding the handler open.
+///
+/// # Retries
+/// Both calls are idempotent GETs, so a 429, a 5xx or a timeout is retried with
+/// backoff (honoring `Retry-After` seconds on a 429) up to `max_retries` times. If
+/// TGB is still rate-limiting after that, the error says "rate limited" so handlers
+/// can answer 429.
+///
+/// # Circuit Breaker
+/// A call that still fails with a 5xx, a timeout or a connection error after its
+/// retries counts as an upstream failure. After `failure_threshold` of them in a row
+/// the circuit opens and calls fail immediately with a "circuit open" error (503)
+/// until the cooldown has passed, instead of piling up on a service that is down.
+///
+/// # Caching
+/// Search results are cached per lowercased query in a size-capped LRU (see
+/// `tgb_cache`), so a cache hit makes no upstream call, and concurrent identical
+/// searches share a single upstream request.
 pub struct TgbClient {
     client: Client,
     api_key: String,
     base_url: String,
+    search_cache: SearchCache,
+    max_retries: u32,
+    circuit_breaker: CircuitBreaker,
+}
+
+/// A request that failed after retries; `upstream_down` marks failures that count
+/// towards opening the circuit (5xx, timeouts, connection errors).
+struct RequestFailure {
+    message: String,
+    upstream_down: bool,
 }
 
 impl TgbClient {
@@ -31,50 +105,122 @@ impl TgbClient {
     /// A new TgbClient instance configured to use TGB's production API
     pub fn new(api_key: String) -> Self {
         Self {
-            client: Client::new(),
+            client: build_http_client(DEFAULT_TGB_TIMEOUT),
             api_key,
-            base_url: "https://api.thegivingblock.com/v1".to_string(),
+            base_url: DEFAULT_TGB_BASE_URL.to_string(),
+            search_cache: SearchCache::new(DEFAULT_SEARCH_CACHE_TTL, DEFAULT_SEARCH_CACHE_CAPACITY),
+            max_retries: DEFAULT_TGB_MAX_RETRIES,
+            circuit_breaker: CircuitBreaker::default(),
         }
     }
 
-    /// Searches for charities by name.
+    /// Sets how many times a rate-limited (429), 5xx or timed-out request is retried.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets how many consecutive upstream failures open the circuit (0 disables it)
+    /// and how long it stays open.
+    pub fn with_circuit_breaker(mut self, failure_threshold: u32, cooldown: Duration) -> Self {
+        self.circuit_breaker = CircuitBreaker::new(failure_threshold, cooldown);
+        self
+    }
+
+    /// Points the client at a different TGB deployment (e.g., the sandbox).
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the connect and request timeout for TGB calls.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.client = build_http_client(timeout);
+        self
+    }
+
+    /// Sets how long charity search results are cached and how many queries are kept.
+    pub fn with_search_cache(mut self, ttl: Duration, capacity: usize) -> Self {
+        self.search_cache = SearchCache::new(ttl, capacity);
+        self
+    }
+
+    /// Returns search cache hit/miss counts.
+    pub fn search_cache_stats(&self) -> SearchCacheStats {
+        self.search_cache.stats()
+    }
+
+    /// Searches for charities by name and returns one page of the matches.
+    ///
+    /// TGB's search endpoint returns every match at once, so the full list is fetched
+    /// (and cached per query) and the page is sliced from it; paging through a query
+    /// costs one upstream request.
     ///
     /// # Arguments
     /// * `query` - Search term to match against charity names
+    /// * `limit` - Maximum number of charities in the page
+    /// * `offset` - Number of matching charities to skip
     ///
     /// # Returns
-    /// * `Ok(Vec<Charity>)` - List of matching charities
+    /// * `Ok(CharityPage)` - The page and the total number of matches
     /// * `Err(String)` - Error message if request fails
     ///
     /// # Example
     /// ```no_run
     /// let client = TgbClient::new("api_key".to_string());
-    /// let charities = client.search_charities("red cross").await?;
+    /// let page = client.search_charities("red cross", 20, 0).await?;
     /// ```
-    pub async fn search_charities(&self, query: &str) -> Result<Vec<Charity>, String> {
+    pub async fn search_charities(&self, query: &str, limit: usize, offset: usize) -> Result<CharityPage, String> {
+        let charities = self.search_all_charities(query).await?;
+        Ok(CharityPage {
+            total: charities.len(),
+            charities: charities.into_iter().skip(offset).take(limit).collect(),
+            limit,
+            offset,
+        })
+    }
+
+    /// Returns every charity matching the query, from the cache when possible.
+    async fn search_all_charities(&self, query: &str) -> Result<Vec<Charity>, String> {
+        let key = SearchCache::key(query);
+        if let Some(charities) = self.search_cache.get(&key) {
+            return Ok(charities);
+        }
+
+        // Single-flight: only one caller per query goes upstream at a time
+        let flight = self.search_cache.flight_lock(&key);
+        let result = {
+            let _guard = flight.lock().await;
+            match self.search_cache.get(&key) {
+                Some(charities) => Ok(charities),
+                None => {
+                    self.search_cache.record_miss();
+                    let result = self.fetch_charities(query).await;
+                    if let Ok(charities) = &result {
+                        self.search_cache.insert(key.clone(), charities.clone());
+                    }
+                    result
+                }
+            }
+        };
+        drop(flight);
+        self.search_cache.finish_flight(&key);
+
+        result
+    }
+
+    /// Sends the charity search request to TGB (uncached).
+    async fn fetch_charities(&self, query: &str) -> Result<Vec<Charity>, String> {
         info!("TGB API: Searching charities with query='{}'", query);
 
         let url = format!("{}/charities/search", self.base_url);
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.api_key))
-            .query(&[("q", query)])
-            .send()
-            .await
-            .map_err(|e| {
-                error!("TGB API: Request failed: {}", e);
-                format!("Failed to connect to TGB API: {}", e)
-            })?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            error!("TGB API: Returned error status: {}", status);
-            return Err(format!("TGB API error: {}", status));
-        }
+        let response = self.get(&url, &[("q", query)]).await?;
 
         let charities = response.json::<Vec<Charity>>().await.map_err(|e| {
+            if e.is_timeout() {
+                return request_error(e);
+            }
             error!("TGB API: Failed to parse response: {}", e);
             format!("Failed to parse TGB response: {}", e)
         })?;
@@ -113,24 +259,12 @@ impl TgbClient {
             self.base_url, charity_id, token
         );
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.api_key))
-            .send()
-            .await
-            .map_err(|e| {
-                error!("TGB API: Request failed: {}", e);
-                format!("Failed to connect to TGB API: {}", e)
-            })?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            error!("TGB API: Returned error status: {}", status);
-            return Err(format!("TGB API error: {}", status));
-        }
+        let response = self.get(&url, &[]).await?;
 
         let address = response.json::<DonationAddress>().await.map_err(|e| {
+            if e.is_timeout() {
+                return request_error(e);
+            }
             error!("TGB API: Failed to parse response: {}", e);
             format!("Failed to parse TGB response: {}", e)
         })?;
@@ -138,4 +272,464 @@ impl TgbClient {
         info!("TGB API: Got address: {}", address.address);
         Ok(address)
     }
+
+    /// Fetches donation addresses for several tokens of one charity concurrently.
+    ///
+    /// Each token is looked up with `get_charity_address`; a failing token does not
+    /// affect the others.
+    ///
+    /// # Arguments
+    /// * `charity_id` - The TGB charity ID
+    /// * `tokens` - Token symbols to look up
+    ///
+    /// # Returns
+    /// Each token paired with its address or error message, in the order given
+    ///
+    /// # Example
+    /// ```no_run
+    /// let client = TgbClient::new("api_key".to_string());
+    /// let results = client.get_charity_addresses("charity123", &["SOL", "USDC"]).await;
+    /// ```
+    pub async fn get_charity_addresses<'a>(
+        &self,
+        charity_id: &str,
+        tokens: &[&'a str],
+    ) -> Vec<(&'a str, Result<DonationAddress, String>)> {
+        let lookups = tokens.iter().map(|&token| async move {
+            (token, self.get_charity_address(charity_id, token).await)
+        });
+        futures::future::join_all(lookups).await
+    }
+
+    /// Checks that TGB is reachable and accepts the API key.
+    ///
+    /// Sends one authenticated charity search, bounded by `READINESS_TIMEOUT`. The probe
+    /// skips the cache, retries and circuit breaker so it always reflects TGB right now,
+    /// and a failing probe doesn't open the circuit for real traffic.
+    ///
+    /// # Returns
+    /// A `TgbReadiness`; a 401/403 means TGB is up but rejected the API key.
+    pub async fn check_ready(&self) -> TgbReadiness {
+        let url = format!("{}/charities/search", self.base_url);
+        let sent = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .query(&[("q", "health")])
+            .timeout(READINESS_TIMEOUT)
+            .send()
+            .await;
+
+        match sent {
+            Ok(response) if response.status().is_success() => TgbReadiness {
+                reachable: true,
+                upstream_status: Some(response.status().as_u16()),
+                error: None,
+            },
+            Ok(response) => {
+                let status = response.status();
+                let error = if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+                    format!("TGB API rejected the API key: {}", status)
+                } else {
+                    format!("TGB API error: {}", status)
+                };
+                warn!("TGB API: Readiness probe failed: {}", error);
+                TgbReadiness {
+                    reachable: false,
+                    upstream_status: Some(status.as_u16()),
+                    error: Some(error),
+                }
+            }
+            Err(e) => TgbReadiness {
+                reachable: false,
+                upstream_status: None,
+                error: Some(request_error(e)),
+            },
+        }
+    }
+
+    /// Sends an authenticated GET through the circuit breaker.
+    ///
+    /// # Returns
+    /// * `Ok(Response)` - A successful (2xx) response
+    /// * `Err(String)` - "circuit open" while the circuit is open, "rate limited" once
+    ///   429 retries are exhausted, otherwise the transport or status error
+    async fn get(&self, url: &str, query: &[(&str, &str)]) -> Result<Response, String> {
+        if let Err(remaining) = self.circuit_breaker.check() {
+            warn!("TGB API: Circuit open, skipping request ({:?} left)", remaining);
+            return Err(format!(
+                "TGB API circuit open after repeated failures, retry in {}s",
+                remaining.as_secs().max(1)
+            ));
+        }
+
+        match self.get_with_retries(url, query).await {
+            Ok(response) => {
+                self.circuit_breaker.record_success();
+                Ok(response)
+            }
+            Err(failure) => {
+                if !failure.upstream_down {
+                    self.circuit_breaker.record_success();
+                } else if self.circuit_breaker.record_failure() {
+                    error!("TGB API: Circuit opened after consecutive failures");
+                }
+                Err(failure.message)
+            }
+        }
+    }
+
+    /// Sends an authenticated GET, retrying 429s, 5xx responses and timeouts with backoff.
+    async fn get_with_retries(
+        &self,
+        url: &str,
+        query: &[(&str, &str)],
+    ) -> Result<Response, RequestFailure> {
+        let mut attempt = 0;
+        loop {
+            let sent = self
+                .client
+                .get(url)
+                .header("Authorization", format!("Bearer {}", self.api_key))
+                .query(query)
+                .send()
+                .await;
+
+            let (failure, delay) = match sent {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) if response.status() == StatusCode::TOO_MANY_REQUESTS => {
+                    if attempt >= self.max_retries {
+                        error!("TGB API: Still rate limited after {} retries", attempt);
+                        return Err(RequestFailure {
+                            message: format!("TGB API rate limited after {} retries", attempt),
+                            upstream_down: false,
+                        });
+                    }
+                    ("rate limited".to_string(), rate_limit_delay(&response, attempt + 1))
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    error!("TGB API: Returned error status: {}", status);
+                    let failure = RequestFailure {
+                        message: format!("TGB API error: {}", status),
+                        upstream_down: status.is_server_error(),
+                    };
+                    if !status.is_server_error() || attempt >= self.max_retries {
+                        return Err(failure);
+                    }
+                    (failure.message, retry_delay(attempt + 1))
+                }
+                Err(e) => {
+                    let retryable = e.is_timeout();
+                    let failure = RequestFailure {
+                        message: request_error(e),
+                        upstream_down: true,
+                    };
+                    if !retryable || attempt >= self.max_retries {
+                        return Err(failure);
+                    }
+                    (failure.message, retry_delay(attempt + 1))
+                }
+            };
+
+            attempt += 1;
+            warn!(
+                "TGB API: Request failed ({}), retrying in {:?} (attempt {}/{})",
+                failure, delay, attempt, self.max_retries
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// Wait before retrying a 429: the server's `Retry-After` seconds if given,
+/// otherwise exponential backoff, capped either way.
+fn rate_limit_delay(response: &Response, attempt: u32) -> Duration {
+    let retry_after = response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs);
+
+    retry_after
+        .unwrap_or_else(|| BASE_RATE_LIMIT_DELAY.saturating_mul(1 << (attempt - 1).min(16)))
+        .min(MAX_RATE_LIMIT_DELAY)
+}
+
+/// Exponential backoff after a 5xx or timeout, capped like rate-limit waits.
+fn retry_delay(attempt: u32) -> Duration {
+    BASE_RETRY_DELAY
+        .saturating_mul(1 << (attempt - 1).min(16))
+        .min(MAX_RATE_LIMIT_DELAY)
+}
+
+/// Builds the HTTP client with the given connect and request timeout.
+fn build_http_client(timeout: Duration) -> Client {
+    Client::builder()
+        .connect_timeout(timeout)
+        .timeout(timeout)
+        .build()
+        .expect("Failed to build TGB HTTP client")
+}
+
+/// Maps a transport error to the message handlers inspect: "timed out" (504) or "connect" (503).
+fn request_error(e: reqwest::Error) -> String {
+    if e.is_timeout() {
+        error!("TGB API: Request timed out: {}", e);
+        format!("TGB API request timed out: {}", e)
+    } else {
+        error!("TGB API: Request failed: {}", e);
+        format!("Failed to connect to TGB API: {}", e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    const RATE_LIMITED: &str =
+        "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    const SERVER_ERROR: &str =
+        "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+    /// Serves `rate_limited` 429 responses (with `Retry-After: 0`), then `200 []`.
+    /// Returns the base URL and a counter of requests received.
+    async fn rate_limited_server(rate_limited: u32) -> (String, Arc<AtomicU32>) {
+        flaky_server(rate_limited, RATE_LIMITED).await
+    }
+
+    /// Serves `failing` copies of `failure`, then `200 []`.
+    async fn flaky_server(failing: u32, failure: &'static str) -> (String, Arc<AtomicU32>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(AtomicU32::new(0));
+        let counter = requests.clone();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let seen = counter.fetch_add(1, Ordering::SeqCst);
+                let response = if seen < failing {
+                    failure
+                } else {
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\nConnection: close\r\n\r\n[]"
+                };
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+
+        (format!("http://{}/v1", addr), requests)
+    }
+
+    #[tokio::test]
+    async fn retries_rate_limited_requests_until_success() {
+        let (base_url, requests) = rate_limited_server(2).await;
+        let client = TgbClient::new("test-key".to_string())
+            .with_base_url(base_url)
+            .with_max_retries(3);
+
+        let page = client.search_charities("red cross", 50, 0).await.unwrap();
+        assert!(page.charities.is_empty());
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn repeated_search_is_served_from_cache() {
+        let (base_url, requests) = rate_limited_server(0).await;
+        let client = TgbClient::new("test-key".to_string()).with_base_url(base_url);
+
+        client.search_charities("Red Cross", 50, 0).await.unwrap();
+        client.search_charities("red cross ", 50, 0).await.unwrap();
+
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert_eq!(client.search_cache_stats(), SearchCacheStats { hits: 1, misses: 1 });
+    }
+
+    #[tokio::test]
+    async fn pages_are_sliced_from_one_upstream_search() {
+        // Answers every search with five charities
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(AtomicU32::new(0));
+        let counter = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                counter.fetch_add(1, Ordering::SeqCst);
+                let charities: Vec<String> = (0..5)
+                    .map(|i| format!(r#"{{"id":"c{}","name":"Charity {}","categories":[]}}"#, i, i))
+                    .collect();
+                let body = format!("[{}]", charities.join(","));
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        let client = TgbClient::new("test-key".to_string()).with_base_url(format!("http://{}/v1", addr));
+
+        let first = client.search_charities("cancer", 2, 0).await.unwrap();
+        let last = client.search_charities("cancer", 2, 4).await.unwrap();
+        let past_end = client.search_charities("cancer", 2, 10).await.unwrap();
+
+        let ids = |page: &CharityPage| page.charities.iter().map(|c| c.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&first), ["c0", "c1"]);
+        assert_eq!(ids(&last), ["c4"]);
+        assert!(past_end.charities.is_empty());
+        assert!([&first, &last, &past_end].iter().all(|page| page.total == 5));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn gives_up_with_rate_limited_error() {
+        let (base_url, requests) = rate_limited_server(u32::MAX).await;
+        let client = TgbClient::new("test-key".to_string())
+            .with_base_url(base_url)
+            .with_max_retries(2);
+
+        let err = client.get_charity_address("charity123", "SOL").await.unwrap_err();
+        assert!(err.contains("rate limited"), "unexpected error: {}", err);
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors_until_success() {
+        let (base_url, requests) = flaky_server(2, SERVER_ERROR).await;
+        let client = TgbClient::new("test-key".to_string())
+            .with_base_url(base_url)
+            .with_max_retries(3)
+            .with_circuit_breaker(2, Duration::from_secs(60));
+
+        let page = client.search_charities("red cross", 50, 0).await.unwrap();
+        assert!(page.charities.is_empty());
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+        // Failed attempts of a call that recovered don't count towards the circuit breaker
+        client.search_charities("unicef", 50, 0).await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn down_upstream_opens_circuit_and_short_circuits() {
+        let (base_url, requests) = flaky_server(u32::MAX, SERVER_ERROR).await;
+        let client = TgbClient::new("test-key".to_string())
+            .with_base_url(base_url)
+            .with_max_retries(1)
+            .with_circuit_breaker(2, Duration::from_secs(60));
+
+        for _ in 0..2 {
+            let err = client.get_charity_address("charity123", "SOL").await.unwrap_err();
+            assert!(err.contains("503"), "unexpected error: {}", err);
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+
+        let err = client.search_charities("red cross", 50, 0).await.unwrap_err();
+        assert!(err.contains("circuit open"), "unexpected error: {}", err);
+        assert_eq!(requests.load(Ordering::SeqCst), 4, "open circuit must not reach TGB");
+    }
+
+    #[tokio::test]
+    async fn fetches_addresses_per_token_and_keeps_failures_separate() {
+        // Knows SOL and USDC addresses, 404s anything else
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 4096];
+                let read = socket.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..read]);
+                let path = request.split_whitespace().nth(1).unwrap_or("").to_string();
+                let token = path.rsplit('/').next().unwrap_or("");
+                let response = if token == "SOL" || token == "USDC" {
+                    let body = format!(
+                        r#"{{"charity_id":"charity123","token":"{}","address":"addr-{}","network":"solana"}}"#,
+                        token, token
+                    );
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    )
+                } else {
+                    "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string()
+                };
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+
+        let client = TgbClient::new("test-key".to_string()).with_base_url(format!("http://{}/v1", addr));
+        let results = client
+            .get_charity_addresses("charity123", &["SOL", "DOGE", "USDC"])
+            .await;
+
+        let tokens: Vec<&str> = results.iter().map(|(token, _)| *token).collect();
+        assert_eq!(tokens, vec!["SOL", "DOGE", "USDC"]);
+        assert_eq!(results[0].1.as_ref().unwrap().address, "addr-SOL");
+        assert!(results[1].1.as_ref().unwrap_err().contains("404"));
+        assert_eq!(results[2].1.as_ref().unwrap().address, "addr-USDC");
+    }
+
+    #[tokio::test]
+    async fn readiness_probe_reports_upstream_status_without_retrying() {
+        let (base_url, requests) = flaky_server(0, SERVER_ERROR).await;
+        let client = TgbClient::new("test-key".to_string()).with_base_url(base_url);
+        let ready = client.check_ready().await;
+        assert!(ready.reachable);
+        assert_eq!(ready.upstream_status, Some(200));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        const UNAUTHORIZED: &str =
+            "HTTP/1.1 401 Unauthorized\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+        let (base_url, requests) = flaky_server(u32::MAX, UNAUTHORIZED).await;
+        let client = TgbClient::new("expired-key".to_string()).with_base_url(base_url);
+        let ready = client.check_ready().await;
+        assert!(!ready.reachable);
+        assert_eq!(ready.upstream_status, Some(401));
+        assert!(ready.error.unwrap().contains("API key"));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        let (base_url, requests) = flaky_server(u32::MAX, SERVER_ERROR).await;
+        let client = TgbClient::new("test-key".to_string())
+            .with_base_url(base_url)
+            .with_circuit_breaker(1, Duration::from_secs(60));
+        assert_eq!(client.check_ready().await.upstream_status, Some(503));
+        assert_eq!(requests.load(Ordering::SeqCst), 1, "probe must not retry");
+
+        // A failed probe doesn't open the circuit for real calls
+        let err = client.search_charities("red cross", 50, 0).await.unwrap_err();
+        assert!(!err.contains("circuit open"), "unexpected error: {}", err);
+    }
+
+    #[tokio::test]
+    async fn hung_endpoint_times_out() {
+        // Accepts connections but never responds
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                held.push(socket);
+            }
+        });
+
+        let client = TgbClient::new("test-key".to_string())
+            .with_base_url(format!("http://{}/v1/", addr))
+            .with_timeout(Duration::from_millis(100));
+
+        let err = client.search_charities("red cross", 50, 0).await.unwrap_err();
+        assert!(err.contains("timed out"), "unexpected error: {}", err);
+        assert!(!err.contains("connect"), "timeout must not map to 503: {}", err);
+
+        let err = client.get_charity_address("charity123", "SOL").await.unwrap_err();
+        assert!(err.contains("timed out"), "unexpected error: {}", err);
+    }
 }
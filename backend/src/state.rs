@@ -0,0 +1,99 @@
+//! Shared application state.
+//!
+//! This module defines the state injected into every handler. Each service is
+//! wrapped in an `Arc` so cloning the state per request is cheap, and `FromRef`
+//! implementations let handlers extract only the service they need
+//! (e.g. `State<Arc<TgbClient>>`) instead of the whole struct.
+
+use axum::extract::FromRef;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+use crate::middleware::AuthConfig;
+use crate::models::ProgramEvent;
+use crate::services::{
+    CacheService, ErrorMetrics, MetricsService, OutcomeIndex, SolanaService, TgbClient,
+};
+
+/// State shared across all HTTP handlers.
+///
+/// # Fields
+/// * `tgb_client` - Client for The Giving Block API (charity search, donation addresses)
+/// * `solana_service` - Client for reading Fundraisely program accounts over Solana RPC
+/// * `cache_service` - Account cache shared with `solana_service` (exposed for stats)
+/// * `error_metrics` - Per-error-code counters for failed program transactions
+/// * `outcome_index` - Outcomes of ended rooms, filled by the event listener
+/// * `metrics` - Request and RPC metrics served at `/metrics` (shared with `solana_service`)
+/// * `events` - Program events from the event listener, fanned out to WebSocket clients
+/// * `ws_auth` - Authentication settings for WebSocket room subscriptions
+#[derive(Clone)]
+pub struct AppState {
+    pub tgb_client: Arc<TgbClient>,
+    pub solana_service: Arc<SolanaService>,
+    pub cache_service: Arc<CacheService>,
+    pub error_metrics: Arc<ErrorMetrics>,
+    pub outcome_index: Arc<OutcomeIndex>,
+    pub metrics: Arc<MetricsService>,
+    pub events: broadcast::Sender<ProgramEvent>,
+    pub ws_auth: Arc<AuthConfig>,
+}
+
+impl AppState {
+    /// Creates a new AppState from the shared services.
+    pub fn new(
+        tgb_client: Arc<TgbClient>,
+        solana_service: Arc<SolanaService>,
+        cache_service: Arc<CacheService>,
+        outcome_index: Arc<OutcomeIndex>,
+        metrics: Arc<MetricsService>,
+        events: broadcast::Sender<ProgramEvent>,
+        ws_auth: AuthConfig,
+    ) -> Self {
+        Self {
+            tgb_client,
+            solana_service,
+            cache_service,
+            error_metrics: Arc::new(ErrorMetrics::new()),
+            outcome_index,
+            metrics,
+            events,
+            ws_auth: Arc::new(ws_auth),
+        }
+    }
+}
+
+impl FromRef<AppState> for Arc<TgbClient> {
+    fn from_ref(state: &AppState) -> Self {
+        state.tgb_client.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<SolanaService> {
+    fn from_ref(state: &AppState) -> Self {
+        state.solana_service.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<ErrorMetrics> {
+    fn from_ref(state: &AppState) -> Self {
+        state.error_metrics.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<CacheService> {
+    fn from_ref(state: &AppState) -> Self {
+        state.cache_service.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<OutcomeIndex> {
+    fn from_ref(state: &AppState) -> Self {
+        state.outcome_index.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<MetricsService> {
+    fn from_ref(state: &AppState) -> Self {
+        state.metrics.clone()
+    }
+}
@@ -0,0 +1,364 @@
+//! Fee distribution preview.
+//!
+//! Computes how a room's funds would be split by `end_room`: platform fee, host fee
+//! and prize pool are basis-point shares of the entry fees only, charity gets the
+//! remainder of the entry fees plus 100% of extras, and the prize pool is divided
+//! between winners by percentage.
+//! Every step rounds down exactly like the program, and the dust from splitting the
+//! prize pool between places goes to charity, so the vault ends empty.
+
+use serde::Serialize;
+
+use super::{calculate_bps, calculate_winner_share};
+use crate::models::program_event::MAX_WINNERS;
+use crate::models::GlobalConfigAccount;
+
+/// Platform fee taken by the program (`GlobalConfig::platform_fee_bps`, 20%).
+pub const PLATFORM_FEE_BPS: u16 = 2000;
+
+/// Preview of the token amounts `end_room` would transfer.
+///
+/// # Fields
+/// * `platform_fee` - Platform's share of entry fees
+/// * `host_fee` - Host's share of entry fees
+/// * `prize_pool` - Total paid to winners (the sum of `winner_amounts`)
+/// * `charity` - Remainder of entry fees after platform, host and prizes, plus all extras,
+///   unused minimum-prize escrow and the prize split's rounding dust
+/// * `winner_amounts` - Amount each prize place receives, in place order
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct FeeBreakdown {
+    pub platform_fee: u64,
+    pub host_fee: u64,
+    pub prize_pool: u64,
+    pub charity: u64,
+    pub winner_amounts: Vec<u64>,
+}
+
+/// Computes the fee distribution for a room's entry fees and extras.
+///
+/// # Arguments
+/// * `entry_fees` - Total entry fees collected (`Room::total_entry_fees`), in token base units
+/// * `extras_fees` - Total extras collected (`Room::total_extras_fees`), all of which go to charity
+/// * `platform_fee_bps` - Platform fee in basis points
+/// * `host_fee_bps` - Host fee in basis points
+/// * `prize_pool_bps` - Prize pool in basis points
+/// * `prize_distribution` - Percentage of the prize pool per place (e.g., [60, 30, 10])
+/// * `guaranteed_min_prize` - Host-escrowed minimum prize (`Room::guaranteed_min_prize`);
+///   it tops up a short pool and the unused escrow goes to charity
+///
+/// # Returns
+/// * `Some(FeeBreakdown)` - The preview
+/// * `None` - Where the program would fail with `ArithmeticOverflow`/`ArithmeticUnderflow`
+pub fn preview_fee_distribution(
+    entry_fees: u64,
+    extras_fees: u64,
+    platform_fee_bps: u16,
+    host_fee_bps: u16,
+    prize_pool_bps: u16,
+    prize_distribution: &[u16],
+    guaranteed_min_prize: u64,
+) -> Option<FeeBreakdown> {
+    let platform_fee = calculate_bps(entry_fees, platform_fee_bps)?;
+    let host_fee = calculate_bps(entry_fees, host_fee_bps)?;
+    let pool_from_fees = calculate_bps(entry_fees, prize_pool_bps)?;
+
+    let charity_from_entry_fees = entry_fees
+        .checked_sub(platform_fee)?
+        .checked_sub(host_fee)?
+        .checked_sub(pool_from_fees)?;
+
+    // Guaranteed minimum prize: unused escrow is min(pool, guarantee)
+    let prize_amount = pool_from_fees.max(guaranteed_min_prize);
+    let unused_escrow = pool_from_fees.min(guaranteed_min_prize);
+
+    let winner_amounts: Vec<u64> = prize_distribution
+        .iter()
+        .map(|&pct| calculate_winner_share(prize_amount, pct))
+        .collect();
+    let prize_pool = winner_amounts
+        .iter()
+        .try_fold(0u64, |total, &amount| total.checked_add(amount))?;
+    let prize_dust = prize_amount.checked_sub(prize_pool)?;
+
+    let charity = charity_from_entry_fees
+        .checked_add(extras_fees)?
+        .checked_add(unused_escrow)?
+        .checked_add(prize_dust)?;
+
+    Some(FeeBreakdown {
+        platform_fee,
+        host_fee,
+        prize_pool,
+        charity,
+        winner_amounts,
+    })
+}
+
+/// Checks a new room's fee shares and prize distribution against the platform limits,
+/// as `init_pool_room` does.
+///
+/// # Arguments
+/// * `config` - Current GlobalConfig (fee limits and the platform fee)
+/// * `host_fee_bps` / `prize_pool_bps` - Requested host and prize pool shares
+/// * `prize_distribution` - Requested percentage of the prize pool per place
+///
+/// # Returns
+/// * `Ok(())` - If the program would accept the shares
+/// * `Err(String)` - Message naming the violated limit
+pub fn validate_room_fees(
+    config: &GlobalConfigAccount,
+    host_fee_bps: u16,
+    prize_pool_bps: u16,
+    prize_distribution: &[u16],
+) -> Result<(), String> {
+    if host_fee_bps > config.max_host_fee_bps {
+        return Err(format!(
+            "host_fee_bps {} exceeds the maximum of {}",
+            host_fee_bps, config.max_host_fee_bps
+        ));
+    }
+    if prize_pool_bps > config.max_prize_pool_bps {
+        return Err(format!(
+            "prize_pool_bps {} exceeds the maximum of {}",
+            prize_pool_bps, config.max_prize_pool_bps
+        ));
+    }
+    if prize_distribution.is_empty()
+        || prize_distribution.len() > MAX_WINNERS
+        || prize_distribution.iter().map(|&pct| pct as u32).sum::<u32>() != 100
+    {
+        return Err(format!(
+            "prize_distribution must have 1 to {} places summing to 100",
+            MAX_WINNERS
+        ));
+    }
+
+    let allocated = config.platform_fee_bps as u32 + host_fee_bps as u32 + prize_pool_bps as u32;
+    if allocated > 10000 {
+        return Err(format!("Platform, host and prize shares total {}bps, over 10000", allocated));
+    }
+    let charity_bps = 10000 - allocated;
+    if charity_bps < config.min_charity_bps as u32 {
+        return Err(format!(
+            "Charity share of {}bps is below the minimum of {}bps",
+            charity_bps, config.min_charity_bps
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verbatim copy of the program's arithmetic (`instructions/utils.rs` and the
+    /// fee/prize section of `end_room.rs`), used as the reference implementation.
+    mod onchain {
+        pub fn calculate_bps(amount: u64, bps: u16) -> Option<u64> {
+            const BPS_DENOMINATOR: u64 = 10000;
+            amount
+                .checked_mul(bps as u64)
+                .and_then(|v| v.checked_div(BPS_DENOMINATOR))
+        }
+
+        pub fn end_room(
+            entry_fees_total: u64,
+            extras_total: u64,
+            platform_fee_bps: u16,
+            host_fee_bps: u16,
+            prize_pool_bps: u16,
+            prize_distribution: &[u16],
+            guaranteed_min_prize: u64,
+        ) -> Option<(u64, u64, u64, u64, Vec<u64>)> {
+            let platform_fee = calculate_bps(entry_fees_total, platform_fee_bps)?;
+            let host_fee = calculate_bps(entry_fees_total, host_fee_bps)?;
+            let pool_prize_amount = calculate_bps(entry_fees_total, prize_pool_bps)?;
+            let prize_amount = pool_prize_amount.max(guaranteed_min_prize);
+            let prize_to_charity = pool_prize_amount.min(guaranteed_min_prize);
+            let charity_from_entry_fees = entry_fees_total
+                .checked_sub(platform_fee)
+                .and_then(|v| v.checked_sub(host_fee))
+                .and_then(|v| v.checked_sub(pool_prize_amount))?;
+            let prize_shares: Vec<u64> = prize_distribution
+                .iter()
+                .map(|&pct| (prize_amount as u128 * pct as u128 / 100) as u64)
+                .collect();
+            let prizes_distributed: u64 = prize_shares.iter().sum();
+            let prize_dust = prize_amount.checked_sub(prizes_distributed)?;
+            let charity_amount = charity_from_entry_fees
+                .checked_add(extras_total)
+                .and_then(|v| v.checked_add(prize_to_charity))
+                .and_then(|v| v.checked_add(prize_dust))?;
+            Some((platform_fee, host_fee, prizes_distributed, charity_amount, prize_shares))
+        }
+    }
+
+    /// Deterministic xorshift64 generator so failures are reproducible.
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, bound: u64) -> u64 {
+            self.next() % bound
+        }
+    }
+
+    #[test]
+    fn preview_matches_onchain_math_for_randomized_inputs() {
+        let mut rng = XorShift(0x5eed_f00d_cafe_beef);
+
+        for case in 0..10_000 {
+            // Mix small, token-scale and near-overflow amounts
+            let entry_fees = match case % 3 {
+                0 => rng.below(10_000),
+                1 => rng.below(1_000_000_000_000_000),
+                _ => rng.next(),
+            };
+            let extras_fees = match case % 4 {
+                0 => 0,
+                1 => rng.below(1_000_000_000_000),
+                _ => rng.next(),
+            };
+            let host_fee_bps = rng.below(501) as u16;
+            let prize_pool_bps = rng.below(3501) as u16;
+            let first = rng.below(101) as u16;
+            let second = rng.below(101 - first as u64) as u16;
+            let distribution = [first, second, 100 - first - second];
+            let guaranteed_min_prize = match case % 5 {
+                0 => rng.below(1_000_000_000),
+                _ => 0,
+            };
+
+            let expected = onchain::end_room(
+                entry_fees,
+                extras_fees,
+                PLATFORM_FEE_BPS,
+                host_fee_bps,
+                prize_pool_bps,
+                &distribution,
+                guaranteed_min_prize,
+            );
+            let preview = preview_fee_distribution(
+                entry_fees,
+                extras_fees,
+                PLATFORM_FEE_BPS,
+                host_fee_bps,
+                prize_pool_bps,
+                &distribution,
+                guaranteed_min_prize,
+            )
+            .map(|p| (p.platform_fee, p.host_fee, p.prize_pool, p.charity, p.winner_amounts));
+
+            assert_eq!(
+                preview, expected,
+                "case {}: entry_fees={} extras={} host={} prize={} dist={:?} guarantee={}",
+                case, entry_fees, extras_fees, host_fee_bps, prize_pool_bps, distribution, guaranteed_min_prize
+            );
+        }
+    }
+
+    #[test]
+    fn rounding_truncates_like_the_program() {
+        // 999 * 2000 / 10000 = 199.8 -> 199; remainder dust goes to charity
+        let preview = preview_fee_distribution(999, 0, 2000, 500, 3500, &[60, 30, 10], 0).unwrap();
+        assert_eq!(preview.platform_fee, 199);
+        assert_eq!(preview.host_fee, 49);
+        // 349 * 60 / 100 = 209.4 -> 209, 104.7 -> 104, 34.9 -> 34
+        assert_eq!(preview.winner_amounts, vec![209, 104, 34]);
+        assert_eq!(preview.prize_pool, 347);
+        // Entry-fee remainder 402 plus the 2 units of prize split dust
+        assert_eq!(preview.charity, 404);
+        assert_eq!(
+            preview.platform_fee + preview.host_fee + preview.prize_pool + preview.charity,
+            999
+        );
+    }
+
+    #[test]
+    fn allocation_over_100_percent_fails_like_the_program() {
+        assert!(preview_fee_distribution(1_000, 0, 5000, 3000, 3000, &[100], 0).is_none());
+    }
+
+    #[test]
+    fn extras_go_entirely_to_charity() {
+        // join_room docs example: entry_fee = 10 USDC, host 5%, prizes 35%,
+        // Player B pays 10 entry + 10 extras -> charity 4 + 10 = 14 USDC
+        const USDC: u64 = 1_000_000;
+        let preview =
+            preview_fee_distribution(10 * USDC, 10 * USDC, 2000, 500, 3500, &[100], 0).unwrap();
+        assert_eq!(preview.platform_fee, 2 * USDC);
+        assert_eq!(preview.host_fee, USDC / 2);
+        assert_eq!(preview.prize_pool, 7 * USDC / 2);
+        assert_eq!(preview.charity, 14 * USDC);
+        assert_eq!(preview.winner_amounts, vec![7 * USDC / 2]);
+    }
+
+    #[test]
+    fn extras_do_not_change_entry_fee_splits() {
+        let without = preview_fee_distribution(999, 0, 2000, 500, 3500, &[60, 30, 10], 0).unwrap();
+        let with = preview_fee_distribution(999, 500, 2000, 500, 3500, &[60, 30, 10], 0).unwrap();
+        assert_eq!(with.platform_fee, without.platform_fee);
+        assert_eq!(with.host_fee, without.host_fee);
+        assert_eq!(with.winner_amounts, without.winner_amounts);
+        assert_eq!(with.charity, without.charity + 500);
+    }
+
+    fn config() -> GlobalConfigAccount {
+        GlobalConfigAccount {
+            pubkey: "Config111".to_string(),
+            admin: "Admin111".to_string(),
+            platform_wallet: "Platform111".to_string(),
+            charity_wallet: "Charity111".to_string(),
+            platform_fee_bps: 2000,
+            max_host_fee_bps: 500,
+            max_prize_pool_bps: 3500,
+            min_charity_bps: 4000,
+            emergency_pause: false,
+            require_declaration: false,
+            receipt_attestor: None,
+            max_approved_tokens: 10,
+            min_platform_fee_bps: 1000,
+            lifetime_charity_raised: 0,
+            lifetime_rooms_ended: 0,
+            lifetime_players: 0,
+            max_expiration_slots: 6_480_000,
+            default_expiration_slots: 1_512_000,
+        }
+    }
+
+    #[test]
+    fn room_fees_within_limits_are_accepted() {
+        assert!(validate_room_fees(&config(), 500, 3500, &[60, 30, 10]).is_ok());
+        assert!(validate_room_fees(&config(), 0, 0, &[100]).is_ok());
+    }
+
+    #[test]
+    fn room_fees_over_limits_are_rejected() {
+        let err = validate_room_fees(&config(), 501, 3500, &[100]).unwrap_err();
+        assert!(err.contains("host_fee_bps"), "{}", err);
+        let err = validate_room_fees(&config(), 500, 3501, &[100]).unwrap_err();
+        assert!(err.contains("prize_pool_bps"), "{}", err);
+
+        assert!(validate_room_fees(&config(), 500, 3500, &[60, 30]).is_err());
+        assert!(validate_room_fees(&config(), 500, 3500, &[]).is_err());
+        assert!(validate_room_fees(&config(), 500, 3500, &[10; 11]).is_err());
+
+        // Raised limits: the charity minimum and the 100% cap still apply
+        let loose = GlobalConfigAccount {
+            max_host_fee_bps: 5000,
+            max_prize_pool_bps: 5000,
+            ..config()
+        };
+        let err = validate_room_fees(&loose, 500, 4000, &[100]).unwrap_err();
+        assert!(err.contains("below the minimum"), "{}", err);
+        let err = validate_room_fees(&loose, 5000, 5000, &[100]).unwrap_err();
+        assert!(err.contains("over 10000"), "{}", err);
+    }
+}
@@ -0,0 +1,197 @@
+//! Shared calculation helpers.
+//!
+//! Fee and prize math here mirrors the Fundraisely program exactly (same integer
+//! types, same truncating division, same overflow behaviour), so previews shown
+//! before a transaction match what `end_room` actually transfers. Amount helpers
+//! convert between on-chain base units and display units for a mint's decimals.
+
+pub mod fees;
+
+/// Basis points denominator (10000 bps = 100%).
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Decimals of native SOL (1 SOL = 10^9 lamports).
+pub const SOL_DECIMALS: u8 = 9;
+
+/// Calculates `bps` basis points of `amount`, rounding down.
+///
+/// Mirrors the program's `instructions::utils::calculate_bps`: checked `u64`
+/// multiplication followed by truncating division.
+///
+/// Never panics or wraps, even for `u64::MAX`-scale amounts of high-decimal mints.
+///
+/// # Returns
+/// * `Some(u64)` - The share of `amount`
+/// * `None` - If `amount * bps` overflows `u64` (the program fails with `ArithmeticOverflow`)
+pub fn calculate_bps(amount: u64, bps: u16) -> Option<u64> {
+    amount
+        .checked_mul(bps as u64)
+        .and_then(|v| v.checked_div(BPS_DENOMINATOR))
+}
+
+/// Calculates a winner's share of the prize pool, rounding down.
+///
+/// Mirrors the program's per-winner math in `end_room`, which uses a `u128`
+/// intermediate so the multiplication can never overflow.
+///
+/// # Arguments
+/// * `prize_amount` - Total prize pool in token base units
+/// * `percentage` - Winner's share of the pool (0-100)
+pub fn calculate_winner_share(prize_amount: u64, percentage: u16) -> u64 {
+    (prize_amount as u128 * percentage as u128 / 100) as u64
+}
+
+/// Converts base units to a display amount (e.g., 1_500_000 with 6 decimals → 1.5).
+///
+/// Large values lose precision beyond f64's 53-bit mantissa; use this for display
+/// only and keep raw `u64` amounts for anything sent on-chain.
+pub fn to_ui_amount(raw: u64, decimals: u8) -> f64 {
+    raw as f64 / 10f64.powi(decimals as i32)
+}
+
+/// Converts a display amount to base units, rounding to the nearest unit.
+///
+/// Saturates instead of wrapping: negative and NaN inputs give 0, values above
+/// the `u64` range give `u64::MAX`.
+pub fn to_raw_amount(ui: f64, decimals: u8) -> u64 {
+    let raw = (ui * 10f64.powi(decimals as i32)).round();
+    if raw.is_nan() || raw <= 0.0 {
+        0
+    } else if raw >= u64::MAX as f64 {
+        u64::MAX
+    } else {
+        raw as u64
+    }
+}
+
+/// Converts lamports to SOL for display (e.g., 1_500_000_000 → 1.5).
+pub fn lamports_to_sol(lamports: u64) -> f64 {
+    to_ui_amount(lamports, SOL_DECIMALS)
+}
+
+/// Converts SOL to lamports, rounding to the nearest lamport (saturates like `to_raw_amount`).
+pub fn sol_to_lamports(sol: f64) -> u64 {
+    to_raw_amount(sol, SOL_DECIMALS)
+}
+
+/// Formats lamports as SOL with up to 9 decimals, trailing zeros trimmed
+/// (e.g., 1_500_000_000 → "1.5", 1_000_000_000 → "1").
+pub fn format_lamports(lamports: u64) -> String {
+    format_token_amount(lamports, SOL_DECIMALS)
+}
+
+/// Formats base units of a mint with `decimals` decimals, trailing zeros trimmed
+/// (e.g., 1_500_000 with 6 decimals → "1.5").
+///
+/// Uses integer math, so the result is exact for every `u64` (no float rounding).
+pub fn format_token_amount(raw: u64, decimals: u8) -> String {
+    if decimals == 0 {
+        return raw.to_string();
+    }
+
+    let scale = 10u128.pow(decimals as u32);
+    let whole = raw as u128 / scale;
+    let fraction = raw as u128 % scale;
+
+    if fraction == 0 {
+        return whole.to_string();
+    }
+
+    let fraction = format!("{:0width$}", fraction, width = decimals as usize);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USDC_DECIMALS: u8 = 6;
+
+    #[test]
+    fn calculate_bps_truncates() {
+        assert_eq!(calculate_bps(1000, 2000), Some(200));
+        assert_eq!(calculate_bps(999, 2000), Some(199));
+        assert_eq!(calculate_bps(1, 9999), Some(0));
+        assert_eq!(calculate_bps(1000, 0), Some(0));
+    }
+
+    #[test]
+    fn calculate_bps_near_u64_max_never_wraps() {
+        // Largest amounts whose product still fits: exact truncating division
+        assert_eq!(calculate_bps(u64::MAX, 0), Some(0));
+        assert_eq!(calculate_bps(u64::MAX, 1), Some(u64::MAX / 10_000));
+        assert_eq!(calculate_bps(u64::MAX / 10_000, 10_000), Some(u64::MAX / 10_000));
+        assert_eq!(calculate_bps(u64::MAX / 2000, 2000), Some(u64::MAX / 2000 * 2000 / 10_000));
+
+        // One past the boundary overflows: reported as None, like the program's ArithmeticOverflow
+        assert_eq!(calculate_bps(u64::MAX, 2), None);
+        assert_eq!(calculate_bps(u64::MAX / 2000 + 1, 2000), None);
+        assert_eq!(calculate_bps(u64::MAX / 10_000 + 1, 10_000), None);
+    }
+
+    #[test]
+    fn winner_share_uses_u128_intermediate() {
+        assert_eq!(calculate_winner_share(u64::MAX, 100), u64::MAX);
+        assert_eq!(calculate_winner_share(u64::MAX, 60), (u64::MAX as u128 * 60 / 100) as u64);
+        assert_eq!(calculate_winner_share(u64::MAX, 0), 0);
+        assert_eq!(calculate_winner_share(349, 60), 209);
+    }
+
+    #[test]
+    fn ui_amount_conversions_for_usdc_and_sol() {
+        assert_eq!(to_ui_amount(1_500_000, USDC_DECIMALS), 1.5);
+        assert_eq!(to_ui_amount(1, USDC_DECIMALS), 0.000001);
+        assert_eq!(to_ui_amount(2_500_000_000, SOL_DECIMALS), 2.5);
+
+        assert_eq!(to_raw_amount(1.5, USDC_DECIMALS), 1_500_000);
+        assert_eq!(to_raw_amount(0.000001, USDC_DECIMALS), 1);
+        assert_eq!(to_raw_amount(2.5, SOL_DECIMALS), 2_500_000_000);
+
+        // Round-trips are exact for typical amounts
+        assert_eq!(to_raw_amount(to_ui_amount(123_456_789, USDC_DECIMALS), USDC_DECIMALS), 123_456_789);
+        assert_eq!(to_raw_amount(to_ui_amount(987_654_321, SOL_DECIMALS), SOL_DECIMALS), 987_654_321);
+    }
+
+    #[test]
+    fn raw_amount_rounds_at_the_edge_and_saturates() {
+        // Sub-unit precision rounds to the nearest base unit
+        assert_eq!(to_raw_amount(1.0000004, USDC_DECIMALS), 1_000_000);
+        assert_eq!(to_raw_amount(1.0000006, USDC_DECIMALS), 1_000_001);
+        assert_eq!(to_raw_amount(0.0000000004, SOL_DECIMALS), 0);
+        assert_eq!(to_raw_amount(0.0000000006, SOL_DECIMALS), 1);
+
+        assert_eq!(to_raw_amount(-1.0, USDC_DECIMALS), 0);
+        assert_eq!(to_raw_amount(f64::NAN, USDC_DECIMALS), 0);
+        assert_eq!(to_raw_amount(1e30, SOL_DECIMALS), u64::MAX);
+        assert_eq!(to_raw_amount(f64::INFINITY, SOL_DECIMALS), u64::MAX);
+    }
+
+    #[test]
+    fn format_lamports_trims_trailing_zeros() {
+        assert_eq!(format_lamports(1_500_000_000), "1.5");
+        assert_eq!(format_lamports(1_000_000_000), "1");
+        assert_eq!(format_lamports(1), "0.000000001");
+        assert_eq!(format_lamports(0), "0");
+        assert_eq!(format_lamports(u64::MAX), "18446744073.709551615");
+    }
+
+    #[test]
+    fn sol_conversions_round_to_nearest_lamport() {
+        assert_eq!(lamports_to_sol(1_500_000_000), 1.5);
+        assert_eq!(lamports_to_sol(1), 0.000000001);
+        assert_eq!(sol_to_lamports(1.5), 1_500_000_000);
+        assert_eq!(sol_to_lamports(0.1), 100_000_000);
+        assert_eq!(sol_to_lamports(0.0000000014), 1);
+        assert_eq!(sol_to_lamports(0.0000000016), 2);
+        assert_eq!(sol_to_lamports(-0.5), 0);
+    }
+
+    #[test]
+    fn format_token_amount_respects_decimals() {
+        assert_eq!(format_token_amount(1_500_000, USDC_DECIMALS), "1.5");
+        assert_eq!(format_token_amount(1_000_001, USDC_DECIMALS), "1.000001");
+        assert_eq!(format_token_amount(42, 0), "42");
+        assert_eq!(format_token_amount(5, 2), "0.05");
+        assert_eq!(format_token_amount(1_500_000_000, SOL_DECIMALS), format_lamports(1_500_000_000));
+    }
+}
@@ -110,6 +110,7 @@
       .addApprovedToken(NATIVE_MINT)
       .accounts({
         tokenRegistry: tokenRegistryPDA,
+        mint: NATIVE_MINT,
         admin: wallet,
       })
       .rpc();
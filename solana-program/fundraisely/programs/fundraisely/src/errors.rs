@@ -45,6 +45,7 @@
 //! - `RoomAlreadyExists`, `RoomNotFound`, `RoomNotReady`: Room lifecycle validation
 //! - `RoomAlreadyEnded`, `RoomExpired`: Game completion and timing checks
 //! - `InvalidRoomStatus`: State machine transition validation
+//! - `WinnersNotDeclared`: end_room called without declare_winners when declaration is required
 //!
 //! ### Player Participation Errors
 //! - `PlayerAlreadyJoined`: Prevents duplicate entries
@@ -58,13 +59,21 @@
 //!
 //! ### Input Validation Errors
 //! - `InvalidEntryFee`: Entry fee must be > 0
-//! - `InvalidWinners`: Winner list validation (1-3 winners, valid pubkeys)
+//! - `InvalidWinners`: Winner list validation (1 to MAX_WINNERS winners, valid pubkeys)
 //! - `InvalidRoomId`: Room ID length constraints (1-32 chars)
 //! - `InvalidMemo`: Charity memo length constraints
+//! - `InvalidCharityWallet`: Charity wallet is the default pubkey or the platform wallet
+//! - `InvalidLeavePenalty`: Leave penalty above MAX_LEAVE_PENALTY_BPS
+//! - `InvalidDonationAmount`: Donation must be > 0
+//! - `InvalidExpiration`: Room expiration outside 1..=max_expiration_slots, or bounds misconfigured
+//! - `InvalidRemainingAccounts`: Payout accounts missing, out of order or followed by extras
 //!
 //! ### Safety Errors
 //! - `ArithmeticOverflow`/`ArithmeticUnderflow`: Checked math safety
 //! - `InsufficientBalance`: Token balance validation
+//! - `VaultBalanceMismatch`: Room vault holds less than the room's recorded collections
+//! - `InsufficientVaultBalance`: Room vault can't cover every planned payout (checked before the first transfer)
+//! - `UnsupportedRoomVersion`: Room written with another layout version (see `migrate_room`)
 //! - `EmergencyPause`: Global circuit breaker for security incidents
 //!
 //! ## Economic Model Enforcement
@@ -119,7 +128,7 @@ pub enum FundraiselyError {
     #[msg("Token is already in the approved registry")]
     TokenAlreadyApproved,
 
-    #[msg("Token registry is full (max 50 tokens)")]
+    #[msg("Token registry is full (see GlobalConfig.max_approved_tokens)")]
     TokenRegistryFull,
 
     #[msg("Invalid entry fee")]
@@ -196,4 +205,139 @@ pub enum FundraiselyError {
 
     #[msg("Invalid vault authority (vault must be owned by room PDA)")]
     InvalidVaultAuthority,
+
+    #[msg("Winners must be declared via declare_winners before ending the room")]
+    WinnersNotDeclared,
+
+    #[msg("Room is frozen by the platform admin")]
+    RoomFrozen,
+
+    #[msg("A guaranteed minimum prize has already been funded for this room")]
+    MinPrizeAlreadyFunded,
+
+    #[msg("Host token account is required to refund the escrowed minimum prize")]
+    HostTokenAccountRequired,
+
+    #[msg("Room has not ended yet")]
+    RoomNotEnded,
+
+    #[msg("A charity receipt has already been recorded for this room")]
+    CharityReceiptAlreadyRecorded,
+
+    #[msg("Receipt hash must not be empty")]
+    InvalidReceiptHash,
+
+    #[msg("Winner scores must match the winners, be non-zero and descend with the prizes")]
+    InvalidWinnerScores,
+
+    #[msg("Charity splits must have 1 to 3 unique wallets starting with the room's charity wallet, with non-zero weights summing to 10000 bps")]
+    InvalidCharitySplits,
+
+    #[msg("Token symbol is too long (max 10 characters)")]
+    InvalidTokenSymbol,
+
+    #[msg("Token registry cap must be between 1 and the registry's capacity")]
+    InvalidTokenRegistryCap,
+
+    #[msg("Minimum players must not exceed max players")]
+    InvalidMinPlayers,
+
+    #[msg("Recovery reason does not match the room's turnout")]
+    InvalidRecoveryReason,
+
+    #[msg("Room has not expired yet")]
+    RoomNotExpired,
+
+    #[msg("Every player must ready up before winners can be declared")]
+    PlayersNotReady,
+
+    #[msg("Player is already ready")]
+    PlayerAlreadyReady,
+
+    #[msg("Room has no players to refund")]
+    NoPlayersToRefund,
+
+    #[msg("Recovery needs a PlayerEntry and token account for every player, and a prize vault and host token account for every deposited prize")]
+    InvalidRefundAccounts,
+
+    #[msg("Player is not on this room's allowlist")]
+    PlayerNotAllowlisted,
+
+    #[msg("Player is blacklisted from joining rooms")]
+    PlayerBlacklisted,
+
+    #[msg("Blacklist is full (max 200 wallets)")]
+    BlacklistFull,
+
+    #[msg("Wallet is already blacklisted")]
+    PlayerAlreadyBlacklisted,
+
+    #[msg("Wallet is not blacklisted")]
+    PlayerNotBlacklisted,
+
+    #[msg("Instruction does not support this room's prize mode (asset rooms end with end_asset_room)")]
+    WrongPrizeMode,
+
+    #[msg("Prize mint is not a 1-of-1 NFT (supply 1, 0 decimals, no further minting)")]
+    NotAnNft,
+
+    #[msg("Platform fee must be between GlobalConfig's min_platform_fee_bps and platform_fee_bps")]
+    InvalidPlatformFee,
+
+    #[msg("Fee token mint has an unsupported extension (e.g., a Token-2022 transfer fee)")]
+    UnsupportedMintExtension,
+
+    #[msg("Prize tiers must have at most 3 tiers with increasing non-zero player thresholds, each summing to 100")]
+    InvalidPrizeTiers,
+
+    #[msg("Account is already initialized by a different admin")]
+    AlreadyInitialized,
+
+    #[msg("Account is not an initialized SPL Token or Token-2022 mint, or its decimals don't match")]
+    InvalidMint,
+
+    #[msg("Room can't be cancelled once a player has joined")]
+    RoomHasPlayers,
+
+    #[msg("Referrer can't be the joining player")]
+    InvalidReferrer,
+
+    #[msg("Early-bird fee can't exceed the entry fee")]
+    InvalidEarlyBirdFee,
+
+    #[msg("Room vault balance can't cover the planned payouts")]
+    InsufficientVaultBalance,
+
+    #[msg("Discount must leave charity at least its minimum share of the full entry fee")]
+    InvalidDiscount,
+
+    #[msg("Charity wallet must be set and differ from the platform wallet")]
+    InvalidCharityWallet,
+
+    #[msg("Tied places must each have at least one winner and together cover every winner")]
+    InvalidTies,
+
+    #[msg("Remaining accounts must be exactly the payout token accounts, in order")]
+    InvalidRemainingAccounts,
+
+    #[msg("Room vault holds less than the room has collected")]
+    VaultBalanceMismatch,
+
+    #[msg("Room account uses an outdated layout; the admin must run migrate_room")]
+    UnsupportedRoomVersion,
+
+    #[msg("Room already uses the current layout version")]
+    RoomAlreadyCurrent,
+
+    #[msg("Leave penalty can't exceed 20% of what the player paid")]
+    InvalidLeavePenalty,
+
+    #[msg("Donation amount must be greater than zero")]
+    InvalidDonationAmount,
+
+    #[msg("Expiration must be between 1 slot and GlobalConfig's max_expiration_slots")]
+    InvalidExpiration,
+
+    #[msg("Charity token account is required to pass on funds held for charity")]
+    CharityTokenAccountRequired,
 }
@@ -20,6 +20,7 @@
 //! ```
 
 use anchor_lang::prelude::*;
+use crate::state::RecoveryReason;
 
 /// Emitted when a new fundraising room is created
 ///
@@ -44,6 +45,9 @@ pub struct RoomCreated {
     /// Slot number when room expires (0 = no expiration)
     pub expiration_slot: u64,
 
+    /// Unix timestamp when room expires (0 = no expiration)
+    pub expiration_unix_ts: i64,
+
     /// Unix timestamp of room creation
     pub timestamp: i64,
 }
@@ -68,10 +72,80 @@ pub struct PlayerJoined {
     /// Current number of players in room after this join
     pub player_count: u32,
 
+    /// Wallet the player credited with referring them, if any
+    pub referrer: Option<Pubkey>,
+
     /// Unix timestamp of join
     pub timestamp: i64,
 }
 
+/// Emitted when a player leaves a room
+///
+/// `refund + penalty` is what the player had paid; the penalty stays in the vault for charity.
+#[event]
+pub struct PlayerLeft {
+    /// Room PDA
+    pub room: Pubkey,
+
+    /// Player's wallet address
+    pub player: Pubkey,
+
+    /// Amount returned to the player
+    pub refund: u64,
+
+    /// Amount withheld for charity (leave_penalty_bps of what they paid)
+    pub penalty: u64,
+
+    /// Players left in the room
+    pub player_count: u32,
+
+    /// Unix timestamp of leave
+    pub timestamp: i64,
+}
+
+/// Emitted when a supporter donates to a room's charity without playing
+#[event]
+pub struct DonationReceived {
+    /// Room PDA
+    pub room: Pubkey,
+
+    /// Donor's wallet address
+    pub donor: Pubkey,
+
+    /// Amount donated in this transaction
+    pub amount: u64,
+
+    /// Total this donor has given to the room
+    pub donor_total: u64,
+
+    /// Total donations the room has received
+    pub total_donations: u64,
+
+    /// Unix timestamp of the donation
+    pub timestamp: i64,
+}
+
+/// Emitted when a player readies up
+///
+/// Lets turn-based game UIs show how many players are ready in real time.
+#[event]
+pub struct PlayerReady {
+    /// Room PDA
+    pub room: Pubkey,
+
+    /// Player's wallet address
+    pub player: Pubkey,
+
+    /// Players ready after this one
+    pub ready_count: u32,
+
+    /// Players in the room
+    pub player_count: u32,
+
+    /// Unix timestamp
+    pub timestamp: i64,
+}
+
 /// Emitted when winners are declared for a room
 ///
 /// Separates winner declaration from fund distribution for transparency.
@@ -81,14 +155,40 @@ pub struct WinnersDeclared {
     /// Room PDA for which winners were declared
     pub room: Pubkey,
 
-    /// List of declared winners (Some = winner declared, None = position unfilled)
-    /// Array always has 3 elements, but trailing elements may be None
-    pub winners: [Option<Pubkey>; 3],
+    /// Declared winners in place order (1 to MAX_WINNERS entries)
+    pub winners: Vec<Pubkey>,
+
+    /// Score of each winner, same order as `winners` (empty if none were recorded)
+    pub scores: Vec<u64>,
+
+    /// Number of winners sharing each prize place, in place order (empty if there are no ties)
+    pub place_sizes: Vec<u8>,
 
     /// Unix timestamp of winner declaration
     pub timestamp: i64,
 }
 
+/// Emitted when the host amends previously declared winners before end_room
+///
+/// Carries both lists so every change to the winners is auditable on-chain.
+#[event]
+pub struct WinnersAmended {
+    /// Room PDA whose winners were amended
+    pub room: Pubkey,
+
+    /// Host who amended the winners
+    pub host: Pubkey,
+
+    /// Winners before the amendment, in place order
+    pub old_winners: Vec<Pubkey>,
+
+    /// Winners after the amendment, in place order
+    pub new_winners: Vec<Pubkey>,
+
+    /// Unix timestamp of the amendment
+    pub timestamp: i64,
+}
+
 /// Emitted when a room ends and funds are distributed
 ///
 /// Critical for verifying transparent fund distribution and charitable impact.
@@ -97,24 +197,307 @@ pub struct RoomEnded {
     /// Room PDA that ended
     pub room: Pubkey,
 
-    /// List of winner wallet addresses (1-3 winners)
+    /// List of winner wallet addresses (1 to MAX_WINNERS winners)
     pub winners: Vec<Pubkey>,
 
+    /// Prize amount sent to each winner, parallel to `winners` (0 for unpaid places);
+    /// for asset rooms, the amount of the place's prize asset in its own mint
+    pub winner_amounts: Vec<u64>,
+
     /// Amount sent to platform wallet
     pub platform_amount: u64,
 
     /// Amount sent to host wallet
     pub host_amount: u64,
 
-    /// Amount sent to charity (includes all extras)
+    /// Room's charity wallet (first charity of a multi-charity room)
+    pub charity_wallet: Pubkey,
+
+    /// Amount sent to charity (includes all extras and the prize split's rounding dust)
     pub charity_amount: u64,
 
-    /// Total prize pool distributed to winners
+    /// Amount sent to each charity, in the room's charity split order
+    /// (a single entry for single-charity rooms)
+    pub charity_amounts: Vec<u64>,
+
+    /// Total prize pool distributed to winners (0 for asset rooms), after rounding each
+    /// place's share down
     pub prize_amount: u64,
 
+    /// Prize percentages per place applied to the prize pool: the room's prize tier for
+    /// `total_players`, or its base prize distribution
+    pub prize_distribution: Vec<u16>,
+
     /// Total number of players who participated
     pub total_players: u32,
 
+    /// True when settled by `finalize_expired_room` (host fee sent to charity)
+    pub expired_finalization: bool,
+
+    /// True when the host waived their fee with `waive_host_fee` (host fee sent to charity)
+    pub host_fee_waived: bool,
+
+    /// Part of `charity_amount` from entry fees: the remainder after the platform, host
+    /// and prize shares, plus unused escrow, prize dust and any host fee or prize sent
+    /// to charity
+    pub charity_from_entry: u64,
+
+    /// Part of `charity_amount` from extras (all extras go to charity, donations included)
+    pub charity_from_extras: u64,
+
+    /// Part of `charity_amount` kept from players who left the room
+    pub charity_from_leave_penalties: u64,
+
     /// Unix timestamp of room end
     pub timestamp: i64,
 }
+
+/// Emitted after every room end with the platform's updated lifetime totals
+///
+/// Mirrors the GlobalConfig counters so a "total raised" display can update live.
+#[event]
+pub struct PlatformStats {
+    /// Room whose end produced these totals
+    pub room: Pubkey,
+
+    /// Total sent to charity by all ended rooms (base units of each room's fee token)
+    pub lifetime_charity_raised: u64,
+
+    /// Number of rooms that have ended
+    pub lifetime_rooms_ended: u64,
+
+    /// Number of players across all ended rooms
+    pub lifetime_players: u64,
+
+    /// Unix timestamp of the room end
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin updates GlobalConfig policy settings
+///
+/// Lets off-chain consumers refresh any cached copy of the platform configuration.
+#[event]
+pub struct ConfigUpdated {
+    /// Admin who performed the update
+    pub admin: Pubkey,
+
+    /// Whether end_room now requires winners declared via declare_winners
+    pub require_declaration: bool,
+
+    /// Attestor allowed to record charity receipts (Pubkey::default() = none)
+    pub receipt_attestor: Pubkey,
+
+    /// Maximum number of tokens the token registry may hold
+    pub max_approved_tokens: u16,
+
+    /// Longest expiration a room may be created with, in slots
+    pub max_expiration_slots: u64,
+
+    /// Expiration for rooms created without a deadline, in slots
+    pub default_expiration_slots: u64,
+
+    /// Unix timestamp of the update
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin adds a mint to the token registry
+///
+/// Lets off-chain consumers refresh any cached copy of the approved token list.
+#[event]
+pub struct TokenApproved {
+    /// Mint added to the approved list
+    pub token_mint: Pubkey,
+
+    /// Admin who approved the token
+    pub admin: Pubkey,
+
+    /// Number of approved tokens after the change
+    pub total_approved: u32,
+
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin removes a mint from the token registry
+///
+/// Existing rooms using the mint are unaffected; new rooms can no longer use it.
+#[event]
+pub struct TokenRemoved {
+    /// Mint removed from the approved list
+    pub token_mint: Pubkey,
+
+    /// Admin who removed the token
+    pub admin: Pubkey,
+
+    /// Number of approved tokens after the change
+    pub total_approved: u32,
+
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin bans a wallet from joining rooms
+///
+/// Published so bans are auditable rather than silent.
+#[event]
+pub struct PlayerBanned {
+    /// Wallet added to the blacklist
+    pub wallet: Pubkey,
+
+    /// Admin who banned the wallet
+    pub admin: Pubkey,
+
+    /// Number of blacklisted wallets after the change
+    pub total_banned: u32,
+
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin lifts a wallet's ban
+#[event]
+pub struct PlayerUnbanned {
+    /// Wallet removed from the blacklist
+    pub wallet: Pubkey,
+
+    /// Admin who lifted the ban
+    pub admin: Pubkey,
+
+    /// Number of blacklisted wallets after the change
+    pub total_banned: u32,
+
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin freezes a room
+///
+/// While frozen, joins, ends and recovery are rejected and vault funds stay in place.
+#[event]
+pub struct RoomFrozen {
+    /// Room public key
+    pub room: Pubkey,
+
+    /// Admin who froze the room
+    pub admin: Pubkey,
+
+    /// Unix timestamp of the freeze
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin overrides a room's platform fee
+///
+/// The waived share of entry fees moves to charity; host fee and prize pool are unchanged.
+#[event]
+pub struct RoomPlatformFeeSet {
+    /// Room public key
+    pub room: Pubkey,
+
+    /// Admin who set the fee
+    pub admin: Pubkey,
+
+    /// New platform fee in basis points
+    pub platform_fee_bps: u16,
+
+    /// Room's charity share after the override, in basis points
+    pub charity_bps: u16,
+
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when a host cancels a room nobody joined (the Room PDA and vault are closed)
+#[event]
+pub struct RoomCancelled {
+    /// Room public key (no longer exists)
+    pub room: Pubkey,
+
+    /// Host who cancelled the room and received its rent
+    pub host: Pubkey,
+
+    /// Guaranteed minimum prize escrow returned to the host (0 if none)
+    pub refunded_escrow: u64,
+
+    /// Unix timestamp of the cancellation
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin lifts a freeze
+#[event]
+pub struct RoomUnfrozen {
+    /// Room public key
+    pub room: Pubkey,
+
+    /// Admin who unfroze the room
+    pub admin: Pubkey,
+
+    /// Unix timestamp of the unfreeze
+    pub timestamp: i64,
+}
+
+/// Emitted when a host escrows a guaranteed minimum prize
+#[event]
+pub struct MinPrizeFunded {
+    /// Room public key
+    pub room: Pubkey,
+
+    /// Host who escrowed the prize
+    pub host: Pubkey,
+
+    /// Escrowed amount in token base units
+    pub amount: u64,
+
+    /// Unix timestamp of the deposit
+    pub timestamp: i64,
+}
+
+/// Emitted when a charity's receipt acknowledgment is recorded against an ended room
+///
+/// Links the donation made by end_room to the charity's off-chain acknowledgment.
+#[event]
+pub struct CharityReceiptRecorded {
+    /// Room PDA the receipt refers to
+    pub room: Pubkey,
+
+    /// Charity wallet configured on the room
+    pub charity_wallet: Pubkey,
+
+    /// Signer who recorded the receipt (charity wallet or attestor)
+    pub recorder: Pubkey,
+
+    /// Hash of the receipt document (e.g., SHA-256 of the signed acknowledgment)
+    pub receipt_hash: [u8; 32],
+
+    /// Unix timestamp of the recording
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin recovers a room that was never ended
+///
+/// Distinguishes refunds for low turnout (no fee) from abandoned rooms (10% recovery fee).
+#[event]
+pub struct RoomRecovered {
+    /// Room PDA that was recovered
+    pub room: Pubkey,
+
+    /// Admin who recovered the room
+    pub admin: Pubkey,
+
+    /// Why the room was recovered
+    pub reason: RecoveryReason,
+
+    /// Whether the 10% platform recovery fee was taken
+    pub fee_taken: bool,
+
+    /// Recovery fee sent to the platform (0 for low turnout)
+    pub platform_fee: u64,
+
+    /// Refund of a player who paid only the entry fee (extras are refunded at the same rate)
+    pub refund_per_player: u64,
+
+    /// Number of players in the room
+    pub player_count: u32,
+
+    /// Unix timestamp of the recovery
+    pub timestamp: i64,
+}
@@ -4,12 +4,30 @@
 //! Rooms can only be created with tokens that exist in this registry. This provides centralized
 //! control over which tokens are acceptable for entry fees and prizes, preventing spam tokens
 //! or malicious mints from being used in the platform.
+//!
+//! The mint account is passed alongside its address and must be an initialized SPL Token or
+//! Token-2022 mint (`InvalidMint` otherwise), so a typo can't approve an address that would
+//! break every room created with it. Its decimals are read from the mint and stored in the
+//! registry; a `decimals` argument is only a cross-check and must agree.
+//!
+//! The admin may attach a display symbol, which clients read back from the registry along with
+//! the decimals instead of hardcoding token metadata. The number of approved tokens is capped by
+//! `GlobalConfig.max_approved_tokens`.
 
 use anchor_lang::prelude::*;
-use crate::{TokenRegistry, errors::FundraiselyError};
+use crate::errors::FundraiselyError;
+use crate::events::TokenApproved;
+use crate::instructions::utils::mint_decimals;
+use crate::state::{ApprovedTokenInfo, MAX_TOKEN_SYMBOL_LEN};
 
 /// Add a token to the approved list
-pub fn handler(ctx: Context<crate::AddApprovedToken>, token_mint: Pubkey) -> Result<()> {
+pub fn handler(
+    ctx: Context<crate::AddApprovedToken>,
+    token_mint: Pubkey,
+    symbol: Option<String>,
+    decimals: Option<u8>,
+) -> Result<()> {
+    let max_approved_tokens = ctx.accounts.global_config.max_approved_tokens as usize;
     let registry = &mut ctx.accounts.token_registry;
 
     // Check admin
@@ -26,16 +44,44 @@ pub fn handler(ctx: Context<crate::AddApprovedToken>, token_mint: Pubkey) -> Res
 
     // Check capacity
     require!(
-        registry.approved_tokens.len() < TokenRegistry::MAX_TOKENS,
+        registry.approved_tokens.len() < max_approved_tokens,
         FundraiselyError::TokenRegistryFull
     );
 
+    let symbol = symbol.unwrap_or_default();
+    require!(
+        symbol.len() <= MAX_TOKEN_SYMBOL_LEN,
+        FundraiselyError::InvalidTokenSymbol
+    );
+
+    // The mint must exist; its on-chain decimals are stored (a caller-supplied value must agree)
+    let mint_decimals = {
+        let mint = &ctx.accounts.mint;
+        mint_decimals(mint.owner, &mint.try_borrow_data()?)?
+    };
+    require!(
+        decimals.is_none_or(|decimals| decimals == mint_decimals),
+        FundraiselyError::InvalidMint
+    );
+    let decimals = Some(mint_decimals);
+
     // Add token
-    registry.approved_tokens.push(token_mint);
+    registry.approved_tokens.push(ApprovedTokenInfo {
+        mint: token_mint,
+        symbol,
+        decimals,
+    });
 
     msg!("Token approved: {}", token_mint);
     msg!("Total approved tokens: {}", registry.approved_tokens.len());
 
+    emit!(TokenApproved {
+        token_mint,
+        admin: ctx.accounts.admin.key(),
+        total_approved: registry.approved_tokens.len() as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
 
@@ -0,0 +1,49 @@
+//! # Add To Blacklist Instruction
+//!
+//! Bans a wallet from joining any room on the platform. The list is capped at
+//! `Blacklist::MAX_ENTRIES` wallets, mirroring the token registry's fixed capacity.
+//! Rooms the wallet already joined are unaffected.
+
+use anchor_lang::prelude::*;
+use crate::errors::FundraiselyError;
+use crate::events::PlayerBanned;
+use crate::state::Blacklist;
+
+/// Ban a wallet from joining rooms
+pub fn handler(ctx: Context<crate::AddToBlacklist>, wallet: Pubkey) -> Result<()> {
+    let blacklist = &mut ctx.accounts.blacklist;
+
+    // Check admin
+    require!(
+        ctx.accounts.admin.key() == blacklist.admin,
+        FundraiselyError::Unauthorized
+    );
+
+    // Check if already banned
+    require!(
+        !blacklist.is_blacklisted(&wallet),
+        FundraiselyError::PlayerAlreadyBlacklisted
+    );
+
+    // Check capacity
+    require!(
+        blacklist.banned.len() < Blacklist::MAX_ENTRIES,
+        FundraiselyError::BlacklistFull
+    );
+
+    blacklist.banned.push(wallet);
+
+    msg!("Wallet blacklisted: {}", wallet);
+    msg!("Total blacklisted: {}", blacklist.banned.len());
+
+    emit!(PlayerBanned {
+        wallet,
+        admin: ctx.accounts.admin.key(),
+        total_banned: blacklist.banned.len() as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Note: Account struct is in lib.rs
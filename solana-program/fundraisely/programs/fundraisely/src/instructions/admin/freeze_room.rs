@@ -0,0 +1,46 @@
+//! # Freeze Room Instruction
+//!
+//! Lets the platform admin place a room on hold (e.g., for a legal hold or dispute).
+//! A frozen room rejects joins, `end_room` and `recover_room`; the vault is not touched,
+//! so every token stays where it is until the admin calls `unfreeze_room`. The status the
+//! room had before freezing is saved and restored on unfreeze.
+
+use anchor_lang::prelude::*;
+use crate::state::RoomStatus;
+use crate::errors::FundraiselyError;
+use crate::events::RoomFrozen;
+
+/// Freeze a room (admin only)
+pub fn handler(ctx: Context<crate::FreezeRoom>, _room_id: String) -> Result<()> {
+    // Check admin
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.global_config.admin,
+        FundraiselyError::Unauthorized
+    );
+
+    let room = &mut ctx.accounts.room;
+
+    // Ended rooms have already distributed their funds
+    require!(!room.ended, FundraiselyError::RoomAlreadyEnded);
+
+    require!(
+        room.status != RoomStatus::Frozen,
+        FundraiselyError::RoomFrozen
+    );
+
+    room.pre_freeze_status = Some(room.status.clone());
+    room.status = RoomStatus::Frozen;
+
+    msg!("Room frozen: {}", room.room_id);
+    msg!("   Previous status: {:?}", room.pre_freeze_status);
+
+    emit!(RoomFrozen {
+        room: room.key(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Note: Account struct is in lib.rs
@@ -29,11 +29,16 @@
 //! 2. **Sets Admin Authority**: Records the admin's pubkey for future config updates
 //! 3. **Configures Wallets**: Sets platform_wallet and charity_wallet for fund routing
 //! 4. **Defines Economic Constraints**: Sets maximum/minimum fee percentages:
-//!    - platform_fee_bps: 2000 (20% fixed platform fee)
+//!    - platform_fee_bps: 2000 (20% default platform fee)
+//!    - min_platform_fee_bps: 1000 (10% floor for per-room overrides)
 //!    - max_host_fee_bps: 500 (5% maximum host can take)
 //!    - max_prize_pool_bps: 3500 (35% maximum for prizes)
 //!    - min_charity_bps: 4000 (40% minimum must go to charity)
 //! 5. **Sets Emergency Controls**: Initializes emergency_pause flag to false
+//! 6. **Sets Winner Policy**: Initializes require_declaration to false (legacy end_room winners allowed)
+//! 7. **Sets Token Registry Cap**: Initializes max_approved_tokens to the registry's full capacity
+//! 8. **Zeroes Lifetime Stats**: lifetime_charity_raised, lifetime_rooms_ended and lifetime_players
+//! 9. **Sets Expiration Bounds**: max_expiration_slots (30 days) and default_expiration_slots (7 days)
 //!
 //! ## Economic Model Enforcement
 //!
@@ -76,7 +81,10 @@
 //!
 //! ## Security Considerations
 //!
-//! - **One-Time Operation**: Once created, GlobalConfig cannot be re-initialized
+//! - **One-Time Operation**: Once created, GlobalConfig cannot be re-initialized. The account
+//!   is `init_if_needed` so deployment scripts can re-run setup: a repeat call by the admin
+//!   is a no-op (existing settings, including the wallets passed again, are kept), and the
+//!   handler checks for an existing config before writing anything
 //! - **Admin Authority**: Only the admin pubkey can modify GlobalConfig values (future updates)
 //! - **PDA Security**: GlobalConfig is a PDA, so only the program can sign for it
 //! - **Economic Immutability**: After initialization, the economic constraints are set
@@ -85,7 +93,7 @@
 //! ## Error Conditions
 //!
 //! This instruction will fail if:
-//! - GlobalConfig PDA already exists (already initialized)
+//! - GlobalConfig already exists and the caller isn't its admin (AlreadyInitialized)
 //! - Caller is not the designated admin (signature validation)
 //! - Insufficient lamports for rent (payer balance check)
 //!
@@ -106,6 +114,8 @@
 //! - **init_pool_room.rs**: Validates room creation against GlobalConfig constraints
 
 use anchor_lang::prelude::*;
+use crate::errors::FundraiselyError;
+use crate::state::{TokenRegistry, DEFAULT_EXPIRATION_SLOTS, DEFAULT_MAX_EXPIRATION_SLOTS};
 
 /// Initialize the global configuration (one-time setup)
 ///
@@ -118,6 +128,17 @@ pub fn handler(
 ) -> Result<()> {
     let global_config = &mut ctx.accounts.global_config;
 
+    // Already initialized: no-op for the admin, never overwrite the live config
+    if global_config.admin != Pubkey::default() {
+        require_keys_eq!(
+            global_config.admin,
+            ctx.accounts.admin.key(),
+            FundraiselyError::AlreadyInitialized
+        );
+        msg!("Fundraisely program already initialized");
+        return Ok(());
+    }
+
     // Set configuration
     global_config.admin = ctx.accounts.admin.key();
     global_config.platform_wallet = platform_wallet;
@@ -127,6 +148,15 @@ pub fn handler(
     global_config.max_prize_pool_bps = 3500;    // 35% max prize pool
     global_config.min_charity_bps = 4000;       // 40% min charity
     global_config.emergency_pause = false;
+    global_config.require_declaration = false;
+    global_config.receipt_attestor = Pubkey::default();
+    global_config.max_approved_tokens = TokenRegistry::MAX_TOKENS as u16;
+    global_config.min_platform_fee_bps = 1000; // 10% floor for per-room overrides
+    global_config.lifetime_charity_raised = 0;
+    global_config.lifetime_rooms_ended = 0;
+    global_config.lifetime_players = 0;
+    global_config.max_expiration_slots = DEFAULT_MAX_EXPIRATION_SLOTS;
+    global_config.default_expiration_slots = DEFAULT_EXPIRATION_SLOTS;
     global_config.bump = ctx.bumps.global_config;
 
     msg!("Fundraisely program initialized");
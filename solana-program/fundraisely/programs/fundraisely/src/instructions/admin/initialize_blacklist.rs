@@ -0,0 +1,27 @@
+//! # Initialize Blacklist Instruction
+//!
+//! One-time setup of the blacklist PDA.
+//! Only the GlobalConfig admin can create it; they become the blacklist's admin.
+
+use anchor_lang::prelude::*;
+use crate::errors::FundraiselyError;
+
+/// Initialize the blacklist (one-time setup)
+pub fn handler(ctx: Context<crate::InitializeBlacklist>) -> Result<()> {
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.global_config.admin,
+        FundraiselyError::Unauthorized
+    );
+
+    let blacklist = &mut ctx.accounts.blacklist;
+    blacklist.admin = ctx.accounts.admin.key();
+    blacklist.banned = Vec::new();
+    blacklist.bump = ctx.bumps.blacklist;
+
+    msg!("Blacklist initialized");
+    msg!("   Admin: {}", blacklist.admin);
+
+    Ok(())
+}
+
+// Note: Account struct is in lib.rs for Anchor compatibility
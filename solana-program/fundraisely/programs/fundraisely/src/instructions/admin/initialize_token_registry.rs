@@ -2,12 +2,31 @@
 //!
 //! One-time setup of the token registry PDA.
 //! Creates the registry and sets the admin who can modify it.
+//!
+//! Idempotent for deployment scripts: calling it again as the registry's admin is a
+//! no-op that leaves the approved tokens untouched, and any other signer gets
+//! `AlreadyInitialized`. The account uses `init_if_needed`, so the handler must check
+//! for an existing registry before writing anything (otherwise a second call would
+//! wipe the approved list).
 
 use anchor_lang::prelude::*;
+use crate::errors::FundraiselyError;
 
-/// Initialize the token registry (one-time setup)
+/// Initialize the token registry (one-time setup; repeat calls by the admin are no-ops)
 pub fn handler(ctx: Context<crate::InitializeTokenRegistry>) -> Result<()> {
     let registry = &mut ctx.accounts.token_registry;
+
+    // An existing registry always has an admin (a signer, never the default key)
+    if registry.admin != Pubkey::default() {
+        require_keys_eq!(
+            registry.admin,
+            ctx.accounts.admin.key(),
+            FundraiselyError::AlreadyInitialized
+        );
+        msg!("Token registry already initialized");
+        return Ok(());
+    }
+
     registry.admin = ctx.accounts.admin.key();
     registry.approved_tokens = Vec::new();
     registry.bump = ctx.bumps.token_registry;
@@ -0,0 +1,81 @@
+//! # Migrate Room Instruction
+//!
+//! Lets the platform admin bring a room written with an older account layout up to
+//! `ROOM_VERSION`, after which the other instructions accept it again (they reject any
+//! other version with `UnsupportedRoomVersion`; see the Room docs, "Layout Versions").
+//!
+//! The room can't be loaded as `Account<Room>` when its data filled the old, smaller
+//! allocation, so it's taken unchecked and handled in three steps:
+//!
+//! 1. **Grow**: resize the account to `Room::LEN`, the admin paying the extra rent.
+//! 2. **Clear**: find the version it was written with (a room allocated smaller than a
+//!    version 1 room predates versioning and is version 0) and zero-fill everything after
+//!    that layout's fields. The tail isn't necessarily zero: a write that shrank the room
+//!    (e.g. `amend_winners` with fewer winners) leaves stale bytes behind, which would
+//!    otherwise be read as the fields added since.
+//! 3. **Rewrite**: decode the room, every field added since at its default, set
+//!    `room_version = ROOM_VERSION` and write it back.
+//!
+//! A room from the program's original release (`LegacyRoom::LEN` bytes) has a different
+//! field layout, not a shorter one; it is decoded as a `LegacyRoom` and rewritten in full
+//! instead (see the Room docs, "Original Layout"). It's reported as version 0.
+//!
+//! ## Errors
+//! - Caller is not the admin (Unauthorized)
+//! - Account is not a Room (AccountDiscriminatorMismatch)
+//! - Room is already at `ROOM_VERSION` (RoomAlreadyCurrent)
+//! - Room was written by a newer program (UnsupportedRoomVersion)
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{Room, ROOM_VERSION};
+use crate::errors::FundraiselyError;
+use crate::instructions::utils::migrate_room_layout;
+
+/// Migrate a room to the current layout (admin only)
+pub fn handler(ctx: Context<crate::MigrateRoom>) -> Result<()> {
+    // Check admin
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.global_config.admin,
+        FundraiselyError::Unauthorized
+    );
+
+    let room_info = ctx.accounts.room.to_account_info();
+
+    // Only Room accounts may be grown and rewritten
+    require!(
+        room_info.try_borrow_data()?.starts_with(Room::DISCRIMINATOR),
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    // Grow to the current size; the admin tops up rent for the new bytes
+    let old_len = room_info.data_len();
+    if old_len < Room::LEN {
+        let rent_shortfall = Rent::get()?
+            .minimum_balance(Room::LEN)
+            .saturating_sub(room_info.lamports());
+        if rent_shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.admin.to_account_info(),
+                        to: room_info.clone(),
+                    },
+                ),
+                rent_shortfall,
+            )?;
+        }
+        room_info.resize(Room::LEN)?;
+    }
+
+    let (from_version, room) = migrate_room_layout(&mut room_info.try_borrow_mut_data()?, old_len)?;
+
+    msg!("Room migrated: {}", room.room_id);
+    msg!("   Version: {} -> {}", from_version, ROOM_VERSION);
+    msg!("   Size: {} -> {} bytes", old_len, room_info.data_len());
+
+    Ok(())
+}
+
+// Note: Account struct is in lib.rs
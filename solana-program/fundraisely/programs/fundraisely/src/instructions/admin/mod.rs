@@ -9,6 +9,13 @@
 //! ## Instructions
 //!
 //! - **initialize**: One-time setup of GlobalConfig (platform wallets, fee structure)
+//! - **update_config**: Adjust GlobalConfig policy flags (e.g., require_declaration)
+//! - **freeze_room** / **unfreeze_room**: Place a room on hold and lift the hold
+//! - **set_room_platform_fee**: Reduce a room's platform fee for a partner campaign
+//!   (the waived share goes to charity)
+//! - **initialize_blacklist** / **add_to_blacklist** / **remove_from_blacklist**:
+//!   Platform-wide wallet bans enforced by join_room
+//! - **migrate_room**: Rewrite a room created with an older account layout at ROOM_VERSION
 //!
 //! ## Future Admin Instructions
 //!
@@ -23,5 +30,13 @@ pub mod initialize_token_registry;
 pub mod add_approved_token;
 pub mod remove_approved_token;
 pub mod recover_room;
+pub mod update_config;
+pub mod freeze_room;
+pub mod unfreeze_room;
+pub mod initialize_blacklist;
+pub mod add_to_blacklist;
+pub mod remove_from_blacklist;
+pub mod set_room_platform_fee;
+pub mod migrate_room;
 
 // Account structs are in lib.rs for Anchor macro compatibility
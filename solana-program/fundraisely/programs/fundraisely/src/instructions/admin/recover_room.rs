@@ -1,19 +1,60 @@
 //! # Recover Room Instruction
 //!
 //! This administrative instruction allows the platform admin to recover funds from abandoned
-//! or expired rooms that never completed. It implements a fair refund mechanism where 90% of
-//! collected funds are returned to players and 10% goes to the platform as a recovery fee.
+//! or expired rooms that never completed. It implements a fair refund mechanism where each
+//! player gets back 90% of what they paid (entry fee plus extras) and 10% goes to the
+//! platform as a recovery fee, paid into the platform wallet's account for the room's fee
+//! token (checked like `end_room`'s, so the fee can't be redirected).
 //! This prevents situations where funds get locked if a host abandons a room before ending it.
 //! The instruction uses remaining_accounts to dynamically handle refunds to any number of players.
+//!
+//! ## Low Turnout
+//!
+//! A room that never reached its `min_players` quorum is treated as cancelled rather than
+//! abandoned: players get 100% back and no platform fee is taken. The reason is derived from
+//! the room's turnout; an optional `reason` argument is only checked against it, so the admin
+//! cannot take a fee from a low-turnout room or waive it for an abandoned one.
+//!
+//! ## Remaining Accounts
+//!
+//! One `(PlayerEntry, player token account)` pair per player, so every player is refunded
+//! in the same transaction. Each pair is checked before any refund is sent:
+//!
+//! - The PlayerEntry must be a Fundraisely PlayerEntry of this room, listed once
+//! - The token account must be owned by `player_entry.player` and hold the room's fee mint
+//!
+//! so the admin cannot misdirect a refund. Asset rooms follow the players with one
+//! `(prize vault, host token account)` pair per deposited prize, in prize order (see
+//! Asset Prizes). A player's refund is their `total_paid` scaled by
+//! `(player_funds - platform_fee) / (player_funds - total_leave_penalties)`, where
+//! `player_funds = total_collected - total_donations`, which shares any penalties kept from
+//! leavers among the remaining players. Rounding can leave a few base units over; that dust
+//! is sent to the first player after the refunds so the vault ends empty.
+//!
+//! ## Donations
+//!
+//! Donors gave to charity, not to the players, so `total_donations` is sent in full to the
+//! room's charity wallet (`charity_token_account`, required when the room has donations)
+//! and is left out of both the recovery fee and the refunds.
+//!
+//! ## Asset Prizes
+//!
+//! The game never ran, so each deposited prize goes back to the host. Its vault must be
+//! the prize's own PDA, `["prize-vault", room, prize_index]`, and the host's token account
+//! must hold the prize mint. Once the room is ended nothing else can drain these vaults.
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, TokenAccount, Transfer};
 use crate::errors::FundraiselyError;
+use crate::events::RoomRecovered;
+use crate::instructions::utils::proportional_refund;
+use crate::state::{PlayerEntry, PrizeAsset, PrizeMode, RecoveryReason};
 
 /// Recover an abandoned room - refund players
 pub fn handler<'info>(
     ctx: Context<'_, '_, 'info, 'info, crate::RecoverRoom<'info>>,
     _room_id: String,
+    reason: Option<RecoveryReason>,
 ) -> Result<()> {
     let room = &mut ctx.accounts.room;
 
@@ -26,68 +67,281 @@ pub fn handler<'info>(
     // Room must not be ended
     require!(!room.ended, FundraiselyError::RoomAlreadyEnded);
 
+    // Frozen rooms keep their funds in the vault until unfrozen
+    require!(
+        room.status != crate::state::RoomStatus::Frozen,
+        FundraiselyError::RoomFrozen
+    );
+
     // Room should be expired or abandoned (we'll just check it has funds)
     require!(room.total_collected > 0, FundraiselyError::InsufficientBalance);
 
-    msg!("Recovering abandoned room: {}", room.room_id);
+    // Nobody to refund: don't take a recovery fee out of an empty room
+    require!(room.player_count > 0, FundraiselyError::NoPlayersToRefund);
+
+    // Every player must be refunded here, or the leftover would be mistaken for dust, and
+    // every deposited prize returned, or it would be locked once the room is ended
+    let player_accounts = room.player_count as usize * 2;
+    let deposited_prizes = if room.prize_mode == PrizeMode::AssetBased {
+        room.prize_assets.iter().flatten().filter(|prize| prize.deposited).count()
+    } else {
+        0
+    };
+    require!(
+        ctx.remaining_accounts.len() == player_accounts + deposited_prizes * 2,
+        FundraiselyError::InvalidRefundAccounts
+    );
+
+    let actual_reason = if room.player_count < room.min_players {
+        RecoveryReason::LowTurnout
+    } else {
+        RecoveryReason::Abandoned
+    };
+    if let Some(reason) = reason {
+        require!(reason == actual_reason, FundraiselyError::InvalidRecoveryReason);
+    }
+
+    msg!("Recovering room: {} ({:?})", room.room_id, actual_reason);
     msg!("Total collected: {}", room.total_collected);
-    msg!("Player count: {}", room.player_count);
+    msg!("Player count: {} (min {})", room.player_count, room.min_players);
 
-    // Calculate amounts
-    let total_to_refund = room.total_collected;
-    let platform_fee = total_to_refund
-        .checked_mul(10)
-        .and_then(|v| v.checked_div(100))
-        .ok_or(FundraiselyError::ArithmeticOverflow)?;
+    // Calculate amounts (no recovery fee if the room never reached quorum); donations
+    // belong to charity, so neither the fee nor the refunds come out of them
+    let donations = room.total_donations;
+    let total_to_refund = room.total_collected
+        .checked_sub(donations)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+    let platform_fee = match actual_reason {
+        RecoveryReason::LowTurnout => 0,
+        RecoveryReason::Abandoned => total_to_refund
+            .checked_mul(10)
+            .and_then(|v| v.checked_div(100))
+            .ok_or(FundraiselyError::ArithmeticOverflow)?,
+    };
 
-    let refund_per_player = total_to_refund
-        .saturating_sub(platform_fee)
-        .checked_div(room.player_count as u64)
-        .unwrap_or(0);
+    let total_refund = total_to_refund
+        .checked_sub(platform_fee)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+    // What the remaining players paid; leave penalties are shared among them
+    let total_paid = total_to_refund
+        .checked_sub(room.total_leave_penalties)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+    // Reported for a player who paid only the entry fee
+    let refund_per_player = proportional_refund(room.entry_fee, total_refund, total_paid);
 
-    msg!("   Platform fee (10%): {}", platform_fee);
-    msg!("   Refund per player (90%): {}", refund_per_player);
+    msg!("   Platform fee: {}", platform_fee);
+    msg!("   Refund per entry fee: {}", refund_per_player);
+    msg!("   Donations to charity: {}", donations);
 
-    // Transfer platform fee
+    // The vault's token authority is the room PDA
     let room_key = room.key();
+    let host_key = room.host;
+    let room_id_bytes = room.room_id.as_bytes();
+    let bump = room.bump;
     let seeds = &[
-        b"room-vault",
-        room_key.as_ref(),
-        &[ctx.bumps.room_vault],
+        b"room",
+        host_key.as_ref(),
+        room_id_bytes,
+        &[bump],
     ];
     let signer_seeds = &[&seeds[..]];
 
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.room_vault.to_account_info(),
-            to: ctx.accounts.platform_token_account.to_account_info(),
-            authority: ctx.accounts.room_vault.to_account_info(),
-        },
-        signer_seeds,
-    );
-    token::transfer(cpi_ctx, platform_fee)?;
-
-    msg!("   Platform fee transferred");
-
-    // Refund each player (uses remaining_accounts)
-    for (i, account_info) in ctx.remaining_accounts.iter().enumerate() {
-        if i % 2 == 1 {
-            // Odd indices are token accounts
-            let player_token_account = Account::<TokenAccount>::try_from(account_info)?;
-
-            let cpi_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.room_vault.to_account_info(),
-                    to: player_token_account.to_account_info(),
-                    authority: ctx.accounts.room_vault.to_account_info(),
-                },
-                signer_seeds,
-            );
-
-            token::transfer(cpi_ctx, refund_per_player)?;
-            msg!("   Refunded player {}: {}", i / 2, refund_per_player);
+    if platform_fee > 0 {
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.room_vault.to_account_info(),
+                to: ctx.accounts.platform_token_account.to_account_info(),
+                authority: room.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, platform_fee)?;
+
+        msg!("   Platform fee transferred");
+    }
+
+    // Pass donations on to the charity they were given to
+    if donations > 0 {
+        let charity_token_account = ctx
+            .accounts
+            .charity_token_account
+            .as_ref()
+            .ok_or(FundraiselyError::CharityTokenAccountRequired)?;
+
+        require!(
+            charity_token_account.owner == room.charity_wallet,
+            FundraiselyError::InvalidTokenOwner
+        );
+        require!(
+            charity_token_account.mint == room.fee_token_mint,
+            FundraiselyError::InvalidTokenMint
+        );
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.room_vault.to_account_info(),
+                to: charity_token_account.to_account_info(),
+                authority: room.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, donations)?;
+
+        msg!("   Donations transferred to charity");
+    }
+
+    // Refund each player what they paid, less their share of the fee (uses remaining_accounts)
+    let mut refunded_entries: Vec<Pubkey> = Vec::with_capacity(room.player_count as usize);
+    let mut distributed: u64 = 0;
+    for pair in ctx.remaining_accounts[..player_accounts].chunks(2) {
+        let (entry_info, token_info) = (&pair[0], &pair[1]);
+
+        // Even indices are this room's PlayerEntry PDAs, each listed once
+        let player_entry = Account::<PlayerEntry>::try_from(entry_info)
+            .map_err(|_| FundraiselyError::InvalidPlayerEntry)?;
+        require!(player_entry.room == room_key, FundraiselyError::InvalidPlayerEntry);
+        require!(
+            !refunded_entries.contains(&entry_info.key()),
+            FundraiselyError::InvalidPlayerEntry
+        );
+        refunded_entries.push(entry_info.key());
+
+        // Odd indices are the matching players' token accounts
+        let player_token_account = Account::<TokenAccount>::try_from(token_info)?;
+        require!(
+            player_token_account.owner == player_entry.player,
+            FundraiselyError::InvalidTokenOwner
+        );
+        require!(
+            player_token_account.mint == room.fee_token_mint,
+            FundraiselyError::InvalidTokenMint
+        );
+
+        let refund = proportional_refund(player_entry.total_paid, total_refund, total_paid);
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.room_vault.to_account_info(),
+                to: player_token_account.to_account_info(),
+                authority: room.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        token::transfer(cpi_ctx, refund)?;
+        distributed = distributed
+            .checked_add(refund)
+            .ok_or(FundraiselyError::ArithmeticOverflow)?;
+        msg!("   Refunded {}: {} (paid {})", player_entry.player, refund, player_entry.total_paid);
+    }
+
+    // Rounding dust from the proportional refunds goes to the first refunded player
+    let dust = total_refund
+        .checked_sub(distributed)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+    if dust > 0 {
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.room_vault.to_account_info(),
+                to: ctx.remaining_accounts[1].to_account_info(),
+                authority: room.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, dust)?;
+        msg!("   Rounding dust to player 0: {}", dust);
+    }
+
+    // Refund an escrowed minimum prize to the host (the game never ran)
+    if room.guaranteed_min_prize > 0 {
+        let host_token_account = ctx
+            .accounts
+            .host_token_account
+            .as_ref()
+            .ok_or(FundraiselyError::HostTokenAccountRequired)?;
+
+        require!(
+            host_token_account.owner == room.host,
+            FundraiselyError::InvalidTokenOwner
+        );
+        require!(
+            host_token_account.mint == room.fee_token_mint,
+            FundraiselyError::InvalidTokenMint
+        );
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.room_vault.to_account_info(),
+                to: host_token_account.to_account_info(),
+                authority: room.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, room.guaranteed_min_prize)?;
+
+        msg!("   Escrowed minimum prize refunded to host: {}", room.guaranteed_min_prize);
+    }
+
+    // Return each deposited prize asset from its vault to the host (uses remaining_accounts);
+    // (prize index, prize) of every deposited prize, in prize order
+    let deposited: Vec<(usize, PrizeAsset)> = room
+        .prize_assets
+        .iter()
+        .enumerate()
+        .filter_map(|(index, prize)| prize.clone().map(|prize| (index, prize)))
+        .filter(|(_, prize)| prize.deposited)
+        .collect();
+    let prize_pairs = ctx.remaining_accounts[player_accounts..].chunks(2);
+    for ((prize_index, prize), pair) in deposited.iter().zip(prize_pairs) {
+        let (vault_info, host_info) = (&pair[0], &pair[1]);
+
+        // Only the prize's own vault PDA may be drained
+        let (expected_vault, _) = Pubkey::find_program_address(
+            &[b"prize-vault", room_key.as_ref(), &[*prize_index as u8]],
+            ctx.program_id,
+        );
+        require_keys_eq!(
+            vault_info.key(),
+            expected_vault,
+            FundraiselyError::InvalidVaultAuthority
+        );
+
+        let prize_vault = Account::<TokenAccount>::try_from(vault_info)?;
+        require!(prize_vault.mint == prize.mint, FundraiselyError::InvalidTokenMint);
+
+        // The host receives in their own account for the prize mint
+        let host_prize_account = Account::<TokenAccount>::try_from(host_info)?;
+        require!(
+            host_prize_account.owner == host_key,
+            FundraiselyError::InvalidTokenOwner
+        );
+        require!(
+            host_prize_account.mint == prize.mint,
+            FundraiselyError::InvalidTokenMint
+        );
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: vault_info.to_account_info(),
+                to: host_info.to_account_info(),
+                authority: room.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, prize.amount)?;
+        msg!("   Prize {} returned to host: {} of mint {}", prize_index + 1, prize.amount, prize.mint);
+    }
+
+    // The returned prizes' vaults are empty again
+    for (prize_index, _) in &deposited {
+        if let Some(prize) = room.prize_assets[*prize_index].as_mut() {
+            prize.deposited = false;
         }
     }
 
@@ -97,5 +351,16 @@ pub fn handler<'info>(
 
     msg!("Room recovered and players refunded");
 
+    emit!(RoomRecovered {
+        room: room_key,
+        admin: ctx.accounts.admin.key(),
+        reason: actual_reason,
+        fee_taken: platform_fee > 0,
+        platform_fee,
+        refund_per_player,
+        player_count: room.player_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
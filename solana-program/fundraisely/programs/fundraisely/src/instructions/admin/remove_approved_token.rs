@@ -5,6 +5,7 @@
 
 use anchor_lang::prelude::*;
 use crate::errors::FundraiselyError;
+use crate::events::TokenRemoved;
 
 /// Remove a token from the approved list
 pub fn handler(ctx: Context<crate::RemoveApprovedToken>, token_mint: Pubkey) -> Result<()> {
@@ -17,7 +18,7 @@ pub fn handler(ctx: Context<crate::RemoveApprovedToken>, token_mint: Pubkey) ->
     );
 
     // Find and remove token
-    if let Some(index) = registry.approved_tokens.iter().position(|&t| t == token_mint) {
+    if let Some(index) = registry.approved_tokens.iter().position(|t| t.mint == token_mint) {
         registry.approved_tokens.remove(index);
         msg!("Token removed: {}", token_mint);
         msg!("   Remaining approved tokens: {}", registry.approved_tokens.len());
@@ -25,6 +26,13 @@ pub fn handler(ctx: Context<crate::RemoveApprovedToken>, token_mint: Pubkey) ->
         return Err(FundraiselyError::TokenNotApproved.into());
     }
 
+    emit!(TokenRemoved {
+        token_mint,
+        admin: ctx.accounts.admin.key(),
+        total_approved: registry.approved_tokens.len() as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
 
@@ -0,0 +1,39 @@
+//! # Remove From Blacklist Instruction
+//!
+//! Lifts a wallet's ban so it can join rooms again.
+//! Only the blacklist admin can call this.
+
+use anchor_lang::prelude::*;
+use crate::errors::FundraiselyError;
+use crate::events::PlayerUnbanned;
+
+/// Lift a wallet's ban
+pub fn handler(ctx: Context<crate::RemoveFromBlacklist>, wallet: Pubkey) -> Result<()> {
+    let blacklist = &mut ctx.accounts.blacklist;
+
+    // Check admin
+    require!(
+        ctx.accounts.admin.key() == blacklist.admin,
+        FundraiselyError::Unauthorized
+    );
+
+    // Find and remove wallet
+    if let Some(index) = blacklist.banned.iter().position(|banned| *banned == wallet) {
+        blacklist.banned.remove(index);
+        msg!("Wallet removed from blacklist: {}", wallet);
+        msg!("   Remaining blacklisted: {}", blacklist.banned.len());
+    } else {
+        return Err(FundraiselyError::PlayerNotBlacklisted.into());
+    }
+
+    emit!(PlayerUnbanned {
+        wallet,
+        admin: ctx.accounts.admin.key(),
+        total_banned: blacklist.banned.len() as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Note: Account struct is in lib.rs
@@ -0,0 +1,75 @@
+//! # Set Room Platform Fee Instruction
+//!
+//! Lets the platform admin grant a partner campaign a reduced platform fee. The new fee must
+//! lie between `GlobalConfig.min_platform_fee_bps` and `GlobalConfig.platform_fee_bps`, and the
+//! waived share is added to the room's `charity_bps`: host fee and prize pool stay as the host
+//! configured them, so an override only ever increases what the charity receives.
+//!
+//! The fee can only change before anyone joins, so every player pays into a split that is
+//! fixed at the time they join. `end_room` applies `Room.platform_fee_bps`.
+
+use anchor_lang::prelude::*;
+use crate::state::RoomStatus;
+use crate::errors::FundraiselyError;
+use crate::events::RoomPlatformFeeSet;
+
+/// Override a room's platform fee (admin only)
+pub fn handler(
+    ctx: Context<crate::SetRoomPlatformFee>,
+    _room_id: String,
+    platform_fee_bps: u16,
+) -> Result<()> {
+    let global_config = &ctx.accounts.global_config;
+
+    // Check admin
+    require!(
+        ctx.accounts.admin.key() == global_config.admin,
+        FundraiselyError::Unauthorized
+    );
+
+    require!(
+        platform_fee_bps >= global_config.min_platform_fee_bps
+            && platform_fee_bps <= global_config.platform_fee_bps,
+        FundraiselyError::InvalidPlatformFee
+    );
+
+    let room = &mut ctx.accounts.room;
+
+    // Asset rooms are created AwaitingFunding; either way no one may have paid in yet
+    require!(
+        matches!(
+            room.status,
+            RoomStatus::AwaitingFunding | RoomStatus::PartiallyFunded | RoomStatus::Ready
+        ) && room.player_count == 0,
+        FundraiselyError::InvalidRoomStatus
+    );
+
+    // Charity absorbs the difference, relative to the room's current fee
+    let charity_bps = room
+        .charity_bps
+        .checked_add(room.platform_fee_bps)
+        .and_then(|bps| bps.checked_sub(platform_fee_bps))
+        .ok_or(FundraiselyError::ArithmeticOverflow)?;
+    require!(
+        charity_bps >= global_config.min_charity_bps,
+        FundraiselyError::CharityBelowMinimum
+    );
+
+    room.platform_fee_bps = platform_fee_bps;
+    room.charity_bps = charity_bps;
+
+    msg!("Room platform fee set: {}", room.room_id);
+    msg!("   Platform: {}bps, Charity: {}bps", platform_fee_bps, charity_bps);
+
+    emit!(RoomPlatformFeeSet {
+        room: room.key(),
+        admin: ctx.accounts.admin.key(),
+        platform_fee_bps,
+        charity_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Note: Account struct is in lib.rs
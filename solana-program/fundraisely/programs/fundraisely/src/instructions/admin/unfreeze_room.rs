@@ -0,0 +1,43 @@
+//! # Unfreeze Room Instruction
+//!
+//! Lifts an admin freeze placed by `freeze_room`, restoring the status the room had
+//! before it was frozen so joins, ends and recovery work again.
+
+use anchor_lang::prelude::*;
+use crate::state::RoomStatus;
+use crate::errors::FundraiselyError;
+use crate::events::RoomUnfrozen;
+
+/// Unfreeze a room (admin only)
+pub fn handler(ctx: Context<crate::UnfreezeRoom>, _room_id: String) -> Result<()> {
+    // Check admin
+    require!(
+        ctx.accounts.admin.key() == ctx.accounts.global_config.admin,
+        FundraiselyError::Unauthorized
+    );
+
+    let room = &mut ctx.accounts.room;
+
+    require!(
+        room.status == RoomStatus::Frozen,
+        FundraiselyError::InvalidRoomStatus
+    );
+
+    room.status = room
+        .pre_freeze_status
+        .take()
+        .ok_or(FundraiselyError::InvalidRoomStatus)?;
+
+    msg!("Room unfrozen: {}", room.room_id);
+    msg!("   Restored status: {:?}", room.status);
+
+    emit!(RoomUnfrozen {
+        room: room.key(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Note: Account struct is in lib.rs
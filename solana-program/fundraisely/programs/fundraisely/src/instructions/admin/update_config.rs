@@ -0,0 +1,90 @@
+//! # Update Config Instruction
+//!
+//! Allows the platform admin to adjust mutable GlobalConfig policy flags after initialization.
+//! Each setting is passed as an `Option`; `None` leaves the current value untouched, so callers
+//! only specify the fields they want to change. Economic limits (fees, charity minimum) are
+//! intentionally not updatable here.
+//!
+//! Lowering `max_approved_tokens` below the number of tokens already approved is allowed;
+//! it only blocks further additions until tokens are removed.
+//!
+//! The expiration bounds are checked together after both are applied: the default must be
+//! non-zero and within the maximum (`InvalidExpiration` otherwise). Rooms already created
+//! keep their expiration.
+
+use anchor_lang::prelude::*;
+use crate::errors::FundraiselyError;
+use crate::events::ConfigUpdated;
+use crate::state::TokenRegistry;
+
+/// Update GlobalConfig policy settings (admin only)
+pub fn handler(
+    ctx: Context<crate::UpdateConfig>,
+    require_declaration: Option<bool>,
+    receipt_attestor: Option<Pubkey>,
+    max_approved_tokens: Option<u16>,
+    max_expiration_slots: Option<u64>,
+    default_expiration_slots: Option<u64>,
+) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    // Check admin
+    require!(
+        ctx.accounts.admin.key() == global_config.admin,
+        FundraiselyError::Unauthorized
+    );
+
+    if let Some(required) = require_declaration {
+        global_config.require_declaration = required;
+    }
+
+    if let Some(attestor) = receipt_attestor {
+        global_config.receipt_attestor = attestor;
+    }
+
+    if let Some(cap) = max_approved_tokens {
+        require!(
+            cap > 0 && cap as usize <= TokenRegistry::MAX_TOKENS,
+            FundraiselyError::InvalidTokenRegistryCap
+        );
+        global_config.max_approved_tokens = cap;
+    }
+
+    if let Some(slots) = max_expiration_slots {
+        global_config.max_expiration_slots = slots;
+    }
+
+    if let Some(slots) = default_expiration_slots {
+        global_config.default_expiration_slots = slots;
+    }
+
+    require!(
+        global_config.default_expiration_slots > 0
+            && global_config.default_expiration_slots <= global_config.max_expiration_slots,
+        FundraiselyError::InvalidExpiration
+    );
+
+    msg!("Global config updated");
+    msg!("   Require declaration: {}", global_config.require_declaration);
+    msg!("   Receipt attestor: {}", global_config.receipt_attestor);
+    msg!("   Max approved tokens: {}", global_config.max_approved_tokens);
+    msg!(
+        "   Expiration slots: default {}, max {}",
+        global_config.default_expiration_slots,
+        global_config.max_expiration_slots
+    );
+
+    emit!(ConfigUpdated {
+        admin: ctx.accounts.admin.key(),
+        require_declaration: global_config.require_declaration,
+        receipt_attestor: global_config.receipt_attestor,
+        max_approved_tokens: global_config.max_approved_tokens,
+        max_expiration_slots: global_config.max_expiration_slots,
+        default_expiration_slots: global_config.default_expiration_slots,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Note: Account struct is in lib.rs
@@ -1,11 +1,20 @@
 //! # Add Prize Asset Instruction
 //!
 //! Escrows a prize asset into the room's prize vault for asset-based rooms
+//!
+//! Each prize gets its own vault PDA, `["prize-vault", room, prize_index]`, created here
+//! as a token account of the prize mint with the Room PDA as authority. Nothing but
+//! `end_asset_room` can move the prize out, and it only accepts these vaults.
+//!
+//! NFT prizes (`PrizeAsset.is_nft`) must be genuine 1-of-1s: the mint has a supply of
+//! one, no decimals, and a mint authority that is revoked or held by its Metaplex
+//! master edition. Collection membership is left to clients reading the metadata.
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Transfer};
-use crate::state::RoomStatus;
+use crate::state::{RoomStatus, MAX_ASSET_PRIZES};
 use crate::errors::FundraiselyError;
+use crate::instructions::utils::{funding_status, is_one_of_one};
 
 /// Escrow a prize asset into the room
 pub fn handler(
@@ -13,6 +22,7 @@ pub fn handler(
     _room_id: String,
     prize_index: u8, // 0, 1, or 2
 ) -> Result<()> {
+    let room_key = ctx.accounts.room.key();
     let room = &mut ctx.accounts.room;
 
     // Only for asset-based rooms
@@ -21,6 +31,13 @@ pub fn handler(
         FundraiselyError::InvalidRoomStatus
     );
 
+    // Prizes are deposited while the room is being funded, never once it is
+    // open, frozen or over
+    require!(
+        matches!(room.status, RoomStatus::AwaitingFunding | RoomStatus::PartiallyFunded),
+        FundraiselyError::InvalidRoomStatus
+    );
+
     // Must be host
     require!(
         ctx.accounts.host.key() == room.host,
@@ -28,7 +45,7 @@ pub fn handler(
     );
 
     // Prize index must be valid
-    require!(prize_index < 3, FundraiselyError::InvalidWinners);
+    require!((prize_index as usize) < MAX_ASSET_PRIZES, FundraiselyError::InvalidWinners);
 
     // Get prize asset info
     let prize_asset = room.prize_assets[prize_index as usize]
@@ -38,6 +55,29 @@ pub fn handler(
     // Check not already deposited
     require!(!prize_asset.deposited, FundraiselyError::PrizeAlreadyDeposited);
 
+    // The vault must hold the declared prize mint and be controlled by the room PDA
+    require!(
+        ctx.accounts.prize_mint.key() == prize_asset.mint,
+        FundraiselyError::InvalidTokenMint
+    );
+    require!(
+        ctx.accounts.prize_vault.owner == room_key,
+        FundraiselyError::InvalidVaultAuthority
+    );
+
+    if prize_asset.is_nft {
+        let prize_mint = &ctx.accounts.prize_mint;
+        require!(
+            is_one_of_one(
+                &prize_mint.key(),
+                prize_mint.supply,
+                prize_mint.decimals,
+                prize_mint.mint_authority.into(),
+            ),
+            FundraiselyError::NotAnNft
+        );
+    }
+
     // Transfer tokens from host to prize vault
     let cpi_ctx = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
@@ -52,18 +92,17 @@ pub fn handler(
     // Mark as deposited
     prize_asset.deposited = true;
 
-    msg!("Prize {} deposited: {} tokens", prize_index + 1, prize_asset.amount);
-
-    // Check if all prizes are now deposited
-    let all_deposited = room.prize_assets.iter().all(|asset| {
-        asset.as_ref().map_or(true, |a| a.deposited)
-    });
+    if prize_asset.is_nft {
+        msg!("Prize {} deposited: NFT {}", prize_index + 1, prize_asset.mint);
+    } else {
+        msg!("Prize {} deposited: {} tokens", prize_index + 1, prize_asset.amount);
+    }
 
-    if all_deposited {
-        room.status = RoomStatus::Ready;
+    // Players may join (join_room requires Ready) only once every prize is escrowed
+    room.status = funding_status(&room.prize_assets);
+    if room.status == RoomStatus::Ready {
         msg!("   All prizes deposited - room is now Ready for players");
     } else {
-        room.status = RoomStatus::PartiallyFunded;
         msg!("   Status: PartiallyFunded (more prizes needed)");
     }
 
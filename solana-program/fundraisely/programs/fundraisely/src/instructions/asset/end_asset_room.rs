@@ -0,0 +1,70 @@
+//! # End Asset Room Instruction
+//!
+//! Ends an asset-based room: hands each escrowed prize asset to the winner of its place
+//! and splits the entry fees between platform, host and charity as in pool rooms
+//! (asset rooms have no prize pool, so charity receives everything else plus all extras).
+//!
+//! ## Remaining Accounts
+//!
+//! ```text
+//! [0 .. winners)                              Winner token accounts for their prize's mint, in place order
+//! [winners .. winners + n - 1)                Token accounts of charities 2..n (multi-charity rooms only)
+//! [winners + n - 1 .. 2 * winners + n - 1)    Prize vault PDAs, in place order
+//! ```
+//!
+//! Every declared prize must be deposited and must have a winner: place `i` receives
+//! `prize_assets[i].amount` of `prize_assets[i].mint` from its vault PDA,
+//! `["prize-vault", room, i]`, whose authority is the room PDA.
+
+use anchor_lang::prelude::*;
+use crate::state::PrizeMode;
+use crate::errors::FundraiselyError;
+use crate::instructions::game::end_room::{begin_end, distribute, Payout};
+
+/// End an asset room and distribute its escrowed prizes to winners
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, crate::EndAssetRoom<'info>>,
+    _room_id: String,
+    winners: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.room.prize_mode == PrizeMode::AssetBased,
+        FundraiselyError::WrongPrizeMode
+    );
+
+    // Every declared prize must be escrowed before it can be handed out
+    let prize_count = ctx.accounts.room.prize_assets.iter().flatten().count();
+    require!(
+        ctx.accounts.room.prize_assets.iter().flatten().all(|prize| prize.deposited),
+        FundraiselyError::PrizesNotFullyFunded
+    );
+
+    let winners_to_use = begin_end(
+        &mut ctx.accounts.room,
+        &ctx.accounts.host.key(),
+        &ctx.accounts.global_config,
+        winners,
+    )?;
+
+    // One winner per prize, in prize order
+    require!(
+        winners_to_use.len() == prize_count,
+        FundraiselyError::InvalidWinners
+    );
+
+    distribute(
+        Payout {
+            room: &mut ctx.accounts.room,
+            room_vault: &ctx.accounts.room_vault,
+            global_config: &mut ctx.accounts.global_config,
+            platform_token_account: &ctx.accounts.platform_token_account,
+            charity_token_account: &ctx.accounts.charity_token_account,
+            host_token_account: Some(&ctx.accounts.host_token_account),
+            token_program: &ctx.accounts.token_program,
+        },
+        ctx.remaining_accounts,
+        winners_to_use,
+    )
+}
+
+// Note: Account struct is in lib.rs
@@ -10,11 +10,17 @@
 //! - Entry fees go to platform (20%) and charity (75-80%)
 //! - Host can take 0-5% of entry fees
 //! - Winners receive the pre-escrowed assets
+//! - A prize flagged in `nft_prizes` is a 1-of-1 NFT: its amount must be 1, and
+//!   `add_prize_asset` verifies the mint before escrowing it
 
 use anchor_lang::prelude::*;
-use crate::state::{RoomStatus, PrizeMode, PrizeAsset};
+use crate::state::{RoomStatus, PrizeMode, PrizeAsset, MAX_ASSET_PRIZES, ROOM_VERSION};
 use crate::errors::FundraiselyError;
 use crate::events::RoomCreated;
+use crate::instructions::utils::{
+    charity_allocation_bps, expiration_slot, validate_charity_wallet, validate_mint_extensions,
+    validate_room_vault,
+};
 
 /// Create an asset-based room where prizes are pre-deposited tokens
 pub fn handler(
@@ -33,6 +39,10 @@ pub fn handler(
     prize_2_amount: Option<u64>,
     prize_3_mint: Option<Pubkey>,
     prize_3_amount: Option<u64>,
+    expiration_unix_ts: Option<i64>,
+    allowlist_root: Option<[u8; 32]>,
+    // Which prizes [1st, 2nd, 3rd] are 1-of-1 NFTs (default: none)
+    nft_prizes: Option<[bool; MAX_ASSET_PRIZES]>,
 ) -> Result<()> {
     // Validation
     require!(
@@ -40,14 +50,31 @@ pub fn handler(
         FundraiselyError::EmergencyPause
     );
 
+    // A transfer-fee mint would leave the vault short of the recorded entry fees
+    {
+        let fee_token_mint = ctx.accounts.fee_token_mint.to_account_info();
+        validate_mint_extensions(fee_token_mint.owner, &fee_token_mint.try_borrow_data()?)?;
+    }
+
+    // SECURITY: The vault must hold the fee token and only the room PDA may move funds out
+    validate_room_vault(
+        &ctx.accounts.room_vault.mint,
+        &ctx.accounts.room_vault.owner,
+        &ctx.accounts.fee_token_mint.key(),
+        &ctx.accounts.room.key(),
+    )?;
+
     // Validate token is approved in registry
     require!(
         ctx.accounts.token_registry.is_token_approved(&ctx.accounts.fee_token_mint.key()),
         FundraiselyError::TokenNotApproved
     );
 
+    // Donations must reach a real wallet that isn't the platform's
+    validate_charity_wallet(&charity_wallet, &ctx.accounts.global_config.platform_wallet)?;
+
     require!(
-        room_id.len() <= 32 && room_id.len() > 0,
+        !room_id.is_empty() && room_id.len() <= 32,
         FundraiselyError::InvalidRoomId
     );
 
@@ -78,6 +105,14 @@ pub fn handler(
         require!(amt > 0, FundraiselyError::InvalidPrizeAmount);
     }
 
+    // An NFT prize is exactly one token
+    let nft_prizes = nft_prizes.unwrap_or_default();
+    for (amount, is_nft) in [Some(prize_1_amount), prize_2_amount, prize_3_amount].iter().zip(nft_prizes) {
+        if is_nft {
+            require!(*amount == Some(1), FundraiselyError::InvalidPrizeAmount);
+        }
+    }
+
     // Initialize room
     let room = &mut ctx.accounts.room;
     room.room_id = room_id.clone();
@@ -90,9 +125,7 @@ pub fn handler(
 
     // Calculate charity percentage (entry fees minus platform and host fees)
     let platform_bps = ctx.accounts.global_config.platform_fee_bps;
-    room.charity_bps = 10000_u16
-        .saturating_sub(platform_bps)
-        .saturating_sub(host_fee_bps);
+    room.charity_bps = charity_allocation_bps(platform_bps, host_fee_bps, 0)?;
 
     // Asset-based rooms have higher charity allocation (75-80%)
     msg!("   Platform: {}bps, Host: {}bps, Charity: {}bps",
@@ -107,7 +140,7 @@ pub fn handler(
     room.total_entry_fees = 0;
     room.total_extras_fees = 0;
     room.ended = false;
-    room.winners = [None, None, None];
+    room.winners = Vec::new();
 
     // Set prize asset info (not yet deposited)
     room.prize_assets = [
@@ -115,12 +148,14 @@ pub fn handler(
             mint: prize_1_mint,
             amount: prize_1_amount,
             deposited: false,
+            is_nft: nft_prizes[0],
         }),
         prize_2_mint.and_then(|mint| {
             prize_2_amount.map(|amount| PrizeAsset {
                 mint,
                 amount,
                 deposited: false,
+                is_nft: nft_prizes[1],
             })
         }),
         prize_3_mint.and_then(|mint| {
@@ -128,6 +163,7 @@ pub fn handler(
                 mint,
                 amount,
                 deposited: false,
+                is_nft: nft_prizes[2],
             })
         }),
     ];
@@ -135,15 +171,50 @@ pub fn handler(
     let current_slot = Clock::get()?.slot;
     room.creation_slot = current_slot;
 
-    // Set expiration slot if specified
-    room.expiration_slot = if let Some(slots) = expiration_slots {
-        current_slot.checked_add(slots).unwrap_or(0)
-    } else {
-        0 // No expiration
+    // Expiration slot: the host's (within the platform cap), or the platform default
+    // when the room has no deadline at all
+    let global_config = &ctx.accounts.global_config;
+    room.expiration_slot = expiration_slot(
+        current_slot,
+        expiration_slots,
+        matches!(expiration_unix_ts, Some(ts) if ts > 0),
+        global_config.default_expiration_slots,
+        global_config.max_expiration_slots,
+    )?;
+
+    // Set expiration timestamp if specified (must be in the future)
+    let now = Clock::get()?.unix_timestamp;
+    room.expiration_unix_ts = match expiration_unix_ts {
+        Some(ts) if ts > 0 => {
+            require!(ts > now, FundraiselyError::RoomExpired);
+            ts
+        }
+        _ => 0, // No expiration
     };
 
     room.charity_memo = charity_memo;
+    room.pre_freeze_status = None;
+    room.guaranteed_min_prize = 0;
+    room.charity_receipt = None;
+    room.winner_scores = Vec::new();
+    room.charity_splits = Vec::new();
+    room.min_players = 0;
+    room.require_ready = false;
+    room.ready_count = 0;
+    room.allowlist_root = allowlist_root.unwrap_or([0u8; 32]);
+    room.platform_fee_bps = platform_bps;
+    room.prize_tiers = Vec::new(); // Asset rooms award escrowed prizes, not pool tiers
+    room.host_fee_waived = false;
+    room.early_bird_fee = 0;
+    room.early_bird_count = 0;
+    room.discount_bps = 0;
+    room.total_discounts = 0;
+    room.place_sizes = Vec::new();
     room.bump = ctx.bumps.room;
+    room.room_version = ROOM_VERSION;
+    room.leave_penalty_bps = 0;
+    room.total_leave_penalties = 0;
+    room.total_donations = 0;
 
     msg!("Asset room created: {}", room_id);
     msg!("   Entry fee: {} lamports", entry_fee);
@@ -158,7 +229,8 @@ pub fn handler(
         entry_fee,
         max_players,
         expiration_slot: room.expiration_slot,
-        timestamp: Clock::get()?.unix_timestamp,
+        expiration_unix_ts: room.expiration_unix_ts,
+        timestamp: now,
     });
 
     Ok(())
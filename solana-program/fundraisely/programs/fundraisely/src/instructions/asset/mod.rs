@@ -1,2 +1,14 @@
+//! # Asset Room Instructions Module
+//!
+//! Instructions for asset-based rooms, whose prizes are escrowed SPL tokens rather
+//! than a share of the entry fees.
+//!
+//! ## Instructions
+//!
+//! - **init_asset_room**: Create room declaring up to three prize assets
+//! - **add_prize_asset**: Host escrows one prize; the room is Ready once all are deposited
+//! - **end_asset_room**: Hand each prize to its place's winner and split the entry fees
+
 pub mod init_asset_room;
 pub mod add_prize_asset;
+pub mod end_asset_room;
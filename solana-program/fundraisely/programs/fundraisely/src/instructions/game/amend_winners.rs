@@ -0,0 +1,72 @@
+//! # Amend Winners Instruction
+//!
+//! Lets the host correct winners recorded by `declare_winners` (e.g., a mis-entered wallet)
+//! without abandoning the room. Amendment is only possible while the room is Active and not
+//! ended; once `end_room` has distributed funds the winners are final.
+//!
+//! The new list goes through the same validation as a declaration (1 to MAX_WINNERS unique
+//! winners, host excluded, PlayerEntry PDAs in `remaining_accounts`). The `WinnersAmended`
+//! event carries both the old and new lists, so every change leaves an audit trail.
+//! Scores and ties recorded with the declaration belong to the old list and are cleared,
+//! so the amended winners take one place each.
+
+use anchor_lang::prelude::*;
+use crate::state::RoomStatus;
+use crate::errors::FundraiselyError;
+use crate::events::WinnersAmended;
+use crate::instructions::game::declare_winners::validate_winners;
+
+/// Replace the declared winners of a room (host only, before end_room)
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, crate::AmendWinners<'info>>,
+    _room_id: String,
+    winners: Vec<Pubkey>,
+) -> Result<()> {
+    let room = &mut ctx.accounts.room;
+
+    // Validation: Only host can amend winners
+    require!(
+        ctx.accounts.host.key() == room.host,
+        FundraiselyError::Unauthorized
+    );
+
+    // Validation: Winners are final once the room has ended
+    require!(
+        !room.ended,
+        FundraiselyError::RoomAlreadyEnded
+    );
+
+    require!(
+        room.status == RoomStatus::Active,
+        FundraiselyError::InvalidRoomStatus
+    );
+
+    // Validation: There must be a declaration to amend
+    require!(
+        !room.winners.is_empty(),
+        FundraiselyError::WinnersNotDeclared
+    );
+
+    validate_winners(room, &winners, &[], ctx.remaining_accounts, ctx.program_id)?;
+
+    let old_winners = std::mem::replace(&mut room.winners, winners);
+    room.winner_scores.clear();
+    room.place_sizes.clear();
+
+    msg!("Winners amended for room");
+    for (i, winner) in room.winners.iter().enumerate() {
+        msg!("   Winner {}: {}", i + 1, winner);
+    }
+
+    emit!(WinnersAmended {
+        room: room.key(),
+        host: ctx.accounts.host.key(),
+        old_winners,
+        new_winners: room.winners.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Note: AmendWinners struct is in lib.rs
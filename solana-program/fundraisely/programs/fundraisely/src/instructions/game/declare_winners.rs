@@ -1,6 +1,6 @@
 //! # Declare Winners Instruction
 //!
-//! Host declares the 1-3 winners of a fundraising game before distributing funds.
+//! Host declares the 1 to MAX_WINNERS winners of a fundraising game before distributing funds.
 //!
 //! ## Overview
 //!
@@ -31,9 +31,10 @@
 //!
 //! 1. **Validates Host Authority**: Ensures only the host can declare winners
 //! 2. **Validates Room State**: Room must be Active (players have joined) and not Ended
-//! 3. **Validates Winners**: Ensures 1-3 unique winners, none is the host
-//! 4. **Stores Winners**: Updates Room.winners array with declared winners
-//! 5. **Emits WinnersDeclared Event**: Notifies frontend/indexers of winner declaration
+//! 3. **Validates Winners**: Ensures 1 to MAX_WINNERS unique winners, none is the host
+//! 4. **Validates Scores** (optional): One non-zero score per winner, descending for top-heavy prizes
+//! 5. **Stores Winners**: Updates Room.winners (and Room.winner_scores) with the declaration
+//! 6. **Emits WinnersDeclared Event**: Notifies frontend/indexers of winner declaration
 //!
 //! ## Winner Validation Rules
 //!
@@ -42,8 +43,9 @@
 //! ### Number of Winners
 //! ```text
 //! - Minimum: 1 winner required
-//! - Maximum: 3 winners allowed
-//! - Matches prize_distribution array length in room configuration
+//! - Maximum: MAX_WINNERS (3) winners allowed
+//! - Pool rooms: at most the number of paid places, so every winner receives a prize
+//!   (e.g., a `[100, 0, 0]` room takes exactly one winner)
 //! ```
 //!
 //! ### Uniqueness
@@ -68,26 +70,44 @@
 //! Trade-off: More upfront validation vs simpler instruction
 //! ```
 //!
+//! ### Scores (Optional)
+//! ```text
+//! - Quiz/trivia hosts can pass `scores`, one per winner in the same order
+//! - Every score must be non-zero
+//! - If each place pays at least as much as the next, scores must not increase
+//! - Pass None to declare without scores (Room.winner_scores stays empty)
+//! ```
+//!
+//! Scores are stored on the room and included in the event so off-chain leaderboards
+//! and dispute resolution can see why each player won.
+//!
+//! ### Ties (Optional, Pool Rooms)
+//! ```text
+//! - `place_sizes[i]` consecutive winners share place i, e.g. [2, 1] for two tied 1st
+//! - Every place needs at least one winner and the places must cover every winner
+//! - Each occupied place must be paid; winners in total still fit MAX_WINNERS
+//! - end_room splits a tied place equally; the remainder goes to the
+//!   lexicographically-first winner of the group
+//! - Pass None (or all 1s) for one winner per place (Room.place_sizes stays empty)
+//! ```
+//!
 //! ## Winner Storage Format
 //!
-//! Winners are stored as `[Option<Pubkey>; 3]` in Room.winners:
+//! Winners are stored in place order as a `Vec<Pubkey>` in Room.winners (space for
+//! MAX_WINNERS entries is reserved in Room::LEN). Winner `i` receives
+//! `prize_distribution[i]` percent of the prize pool (or the matching prize tier's
+//! `distribution[i]`, see `state/room.rs`):
 //!
 //! ### Example 1: Three Winners
 //! ```rust
 //! winners input: vec![alice_pubkey, bob_pubkey, charlie_pubkey]
-//! Room.winners = [Some(alice), Some(bob), Some(charlie)]
+//! Room.winners = [alice, bob, charlie]
 //! ```
 //!
-//! ### Example 2: Two Winners
-//! ```rust
-//! winners input: vec![alice_pubkey, bob_pubkey]
-//! Room.winners = [Some(alice), Some(bob), None]
-//! ```
-//!
-//! ### Example 3: Winner-Takes-All
+//! ### Example 2: Winner-Takes-All
 //! ```rust
 //! winners input: vec![alice_pubkey]
-//! Room.winners = [Some(alice), None, None]
+//! Room.winners = [alice]
 //! ```
 //!
 //! ## Frontend Integration
@@ -132,7 +152,7 @@
 //! 1. **Host Authority**: Only room.host can declare winners
 //! 2. **Room Active**: Room status must be Active (not Ready, not Ended)
 //! 3. **Room Not Ended**: room.ended must be false
-//! 4. **Winner Count**: 1-3 winners required (vec length check)
+//! 4. **Winner Count**: 1 to MAX_WINNERS winners required (vec length check)
 //! 5. **Uniqueness**: All winners must be unique pubkeys
 //! 6. **Host Exclusion**: None of the winners can be the host
 //! 7. **No Re-declaration**: Winners can only be declared once (room.winners must be all None)
@@ -144,9 +164,12 @@
 //! - Room is not Active (InvalidRoomStatus)
 //! - Room already ended (RoomAlreadyEnded)
 //! - Winners already declared (WinnersAlreadyDeclared)
-//! - Invalid number of winners (InvalidWinners - not 1-3)
+//! - Invalid number of winners (InvalidWinners - not 1 to MAX_WINNERS)
 //! - Duplicate winners (InvalidWinners)
 //! - Host is in winners list (HostCannotBeWinner)
+//! - Scores don't match the winners, contain a zero, or ascend for top-heavy prizes (InvalidWinnerScores)
+//! - Tied places are empty or don't cover every winner (InvalidTies)
+//! - Ties declared for an asset room (WrongPrizeMode)
 //!
 //! ## On-Chain Logs
 //!
@@ -162,7 +185,9 @@
 //!
 //! Emits `WinnersDeclared` event containing:
 //! - room: Room PDA address
-//! - winners: Fixed-size array [Option<Pubkey>; 3]
+//! - winners: Declared winners in place order (Vec<Pubkey>)
+//! - scores: Winner scores in the same order (empty if none were passed)
+//! - place_sizes: Winners sharing each place (empty if there are no ties)
 //! - timestamp: Unix timestamp of declaration
 //!
 //! ## Integration with end_room
@@ -195,7 +220,7 @@
 //!
 //! ## Related Files
 //!
-//! - **state/room.rs**: Defines Room.winners field ([Option<Pubkey>; 3])
+//! - **state/room.rs**: Defines Room.winners field (Vec<Pubkey>) and MAX_WINNERS
 //! - **events.rs**: Defines WinnersDeclared event
 //! - **end_room.rs**: Should validate and use declared winners
 //! - **lib.rs**: Entry point that routes to this handler
@@ -204,24 +229,27 @@
 //! ## Future Enhancements
 //!
 //! 1. **Player Verification**: Add remaining_accounts to verify winners actually joined
-//! 2. **Score Recording**: Add scores parameter to record why each player won
-//! 3. **Multi-Signature**: Allow multiple hosts to co-sign winner declaration
-//! 4. **Amendment**: Allow host to change winners before end_room (with event trail)
-//! 5. **Automatic Declaration**: AI/oracle could declare winners based on on-chain game state
+//! 2. **Multi-Signature**: Allow multiple hosts to co-sign winner declaration
+//! 3. **Automatic Declaration**: AI/oracle could declare winners based on on-chain game state
 
 use anchor_lang::prelude::*;
-use crate::state::RoomStatus;
+use crate::state::{PrizeMode, Room, RoomStatus, MAX_WINNERS};
 use crate::errors::FundraiselyError;
 use crate::events::WinnersDeclared;
+use crate::instructions::utils::{
+    place_count, select_prize_distribution, validate_place_sizes, validate_winner_places, validate_winner_scores,
+};
 
 /// Declare winners for a room
 ///
-/// Host-only instruction to officially declare 1-3 winners before fund distribution.
+/// Host-only instruction to officially declare 1 to MAX_WINNERS winners before fund distribution.
 /// Winners are validated for uniqueness, host exclusion, and actual room participation.
 pub fn handler<'info>(
     ctx: Context<'_, '_, '_, 'info, crate::DeclareWinners<'info>>,
     _room_id: String,
     winners: Vec<Pubkey>,
+    scores: Option<Vec<u64>>,
+    place_sizes: Option<Vec<u8>>,
 ) -> Result<()> {
     let room = &mut ctx.accounts.room;
 
@@ -245,16 +273,89 @@ pub fn handler<'info>(
 
     // Validation: Winners not already declared
     require!(
-        room.winners[0].is_none() && room.winners[1].is_none() && room.winners[2].is_none(),
+        room.winners.is_empty(),
         FundraiselyError::WinnersAlreadyDeclared
     );
 
-    // Validation: Must have 1-3 winners
+    // Turn-based rooms: every player must be ready (unless the host waived the check)
+    require!(
+        !room.require_ready || room.ready_count >= room.player_count,
+        FundraiselyError::PlayersNotReady
+    );
+
+    // Ties: one place per group of tied winners; no ties is one winner per place
+    let mut place_sizes = place_sizes.unwrap_or_default();
+    if place_sizes.iter().all(|&size| size == 1) {
+        place_sizes.clear();
+    }
+    require!(
+        place_sizes.is_empty() || room.prize_mode == PrizeMode::PoolSplit,
+        FundraiselyError::WrongPrizeMode
+    );
+    validate_place_sizes(&place_sizes, winners.len())?;
+
+    validate_winners(room, &winners, &place_sizes, ctx.remaining_accounts, ctx.program_id)?;
+
+    let scores = scores.unwrap_or_default();
+    if !scores.is_empty() {
+        let prize_distribution = select_prize_distribution(&room.prize_distribution, &room.prize_tiers, room.player_count);
+        validate_winner_scores(&scores, winners.len(), prize_distribution)?;
+    }
+
+    // Store winners (and their scores and ties) in room, in place order
+    room.winners = winners;
+    room.winner_scores = scores;
+    room.place_sizes = place_sizes;
+
+    msg!("Winners declared for room");
+    for (i, winner) in room.winners.iter().enumerate() {
+        match room.winner_scores.get(i) {
+            Some(score) => msg!("   Winner {}: {} (score {})", i + 1, winner, score),
+            None => msg!("   Winner {}: {}", i + 1, winner),
+        }
+    }
+    if !room.place_sizes.is_empty() {
+        msg!("   Winners per place: {:?}", room.place_sizes);
+    }
+
+    // Emit event for off-chain indexers and frontend
+    emit!(WinnersDeclared {
+        room: room.key(),
+        winners: room.winners.clone(),
+        scores: room.winner_scores.clone(),
+        place_sizes: room.place_sizes.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Validate a winner list for declaration or amendment
+///
+/// Checks 1 to MAX_WINNERS unique winners, none of them the host, each with a
+/// PlayerEntry PDA for this room passed in `remaining_accounts` (same order as `winners`).
+/// In pool rooms every place the winners occupy (see `place_sizes`) must be paid.
+pub(crate) fn validate_winners(
+    room: &Account<Room>,
+    winners: &[Pubkey],
+    place_sizes: &[u8],
+    remaining_accounts: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let room_key = room.key();
+
+    // Validation: Must have 1 to MAX_WINNERS winners
     require!(
-        winners.len() > 0 && winners.len() <= 3,
+        !winners.is_empty() && winners.len() <= MAX_WINNERS,
         FundraiselyError::InvalidWinners
     );
 
+    // Validation: Every pool-room winner must have a paid prize place (in the current tier)
+    if room.prize_mode == PrizeMode::PoolSplit {
+        let prize_distribution = select_prize_distribution(&room.prize_distribution, &room.prize_tiers, room.player_count);
+        validate_winner_places(place_count(place_sizes, winners.len()), prize_distribution)?;
+    }
+
     // Validation: Winners must be unique (no duplicates)
     for i in 0..winners.len() {
         for j in (i+1)..winners.len() {
@@ -266,7 +367,7 @@ pub fn handler<'info>(
     }
 
     // Validation: Host cannot be a winner
-    for winner in &winners {
+    for winner in winners {
         require!(
             *winner != room.host,
             FundraiselyError::HostCannotBeWinner
@@ -276,7 +377,7 @@ pub fn handler<'info>(
     // NEW VALIDATION: Winners must have actually joined the room
     // Verify that remaining_accounts contains valid PlayerEntry PDAs for each winner
     require!(
-        ctx.remaining_accounts.len() >= winners.len(),
+        remaining_accounts.len() >= winners.len(),
         FundraiselyError::InvalidWinners
     );
 
@@ -286,14 +387,14 @@ pub fn handler<'info>(
         let (expected_player_entry_pda, _bump) = Pubkey::find_program_address(
             &[
                 b"player",
-                room.key().as_ref(),
+                room_key.as_ref(),
                 winner.as_ref(),
             ],
-            ctx.program_id,
+            program_id,
         );
 
         // Get the passed-in PlayerEntry account from remaining_accounts
-        let player_entry_account = &ctx.remaining_accounts[i];
+        let player_entry_account = &remaining_accounts[i];
 
         // Validation: The account address must match the derived PDA
         require!(
@@ -309,34 +410,13 @@ pub fn handler<'info>(
 
         // Validation: The account must be owned by this program
         require!(
-            player_entry_account.owner == ctx.program_id,
+            player_entry_account.owner == program_id,
             FundraiselyError::InvalidPlayerEntry
         );
 
         msg!("   Winner {} verified: {} (PlayerEntry exists)", i + 1, winner);
     }
 
-    // Store winners in room (pad with None for unfilled positions)
-    for (i, winner) in winners.iter().enumerate() {
-        if i < 3 {
-            room.winners[i] = Some(*winner);
-        }
-    }
-
-    msg!("Winners declared for room");
-    for (i, winner_opt) in room.winners.iter().enumerate() {
-        if let Some(winner) = winner_opt {
-            msg!("   Winner {}: {}", i + 1, winner);
-        }
-    }
-
-    // Emit event for off-chain indexers and frontend
-    emit!(WinnersDeclared {
-        room: room.key(),
-        winners: room.winners,
-        timestamp: Clock::get()?.unix_timestamp,
-    });
-
     Ok(())
 }
 
@@ -1,12 +1,83 @@
 //! # End Room Instruction
 //!
 //! Finalize room, distribute prizes, and transfer charity donations.
+//!
+//! ## Remaining Accounts
+//!
+//! ```text
+//! [0 .. winners)                  Winner token accounts, in place order
+//! [winners .. winners + n - 1)    Token accounts of charities 2..n (multi-charity rooms only)
+//! ```
+//!
+//! Asset rooms (`end_asset_room`) pass each place's token account for its prize mint as
+//! the winner account, and append one prize vault per place after the charities:
+//!
+//! ```text
+//! [winners + n - 1 .. 2 * winners + n - 1)    Prize vault PDAs, in place order (asset rooms only)
+//! ```
+//!
+//! `remaining_accounts` must hold exactly these accounts: a missing or trailing account
+//! fails with `InvalidRemainingAccounts`. Every winner's token account must belong to that
+//! winner even when their place pays nothing, so the accounts can't be passed out of order.
+//!
+//! The first charity always receives through `charity_token_account`. In a multi-charity
+//! room every charity token account must hold the room's fee mint and be owned by the
+//! split's wallet; rounding dust goes to the first charity.
+//!
+//! The fixed platform, host and charity token accounts are checked by `EndRoom`'s account
+//! constraints: each must hold the room's fee mint (`InvalidTokenMint`) and belong to the
+//! platform wallet, the room host and the room's charity wallet respectively
+//! (`InvalidTokenOwner`), so a host can't redirect any share to an account of their own.
+//!
+//! The payout itself lives in [`distribute`], which `end_asset_room` reuses for asset rooms
+//! and `finalize_expired_room` for expired rooms the host never ended.
+//!
+//! ## Vault Balance
+//!
+//! Before anything is computed the vault balance is reconciled against the room's books:
+//! it must hold at least `total_collected`, or the instruction fails with
+//! `VaultBalanceMismatch` (the observed and expected balances are logged).
+//!
+//! Every amount is computed before the first transfer, and the platform fee, host fee,
+//! charity amount and winner shares together must fit in the room vault's balance or the
+//! instruction fails with `InsufficientVaultBalance` before anyone is paid. Charity receives the
+//! remainder of the entry fees after the platform, host and prize pool cuts, plus the
+//! rounding dust of the per-place prize split (each place's share rounds down) and the
+//! penalties kept from players who left, so the vault always ends empty.
+//! `RoomEnded.prize_amount` is what the winners actually received, and `charity_amount` is
+//! broken down into `charity_from_entry`, `charity_from_extras` and
+//! `charity_from_leave_penalties` so indexers needn't parse the logs.
+//!
+//! ## Compute Budget
+//!
+//! Almost all of the cost is the SPL Token transfer CPIs; everything else is kept to one
+//! pass per account (the shared account infos are built once, and each remaining token
+//! account is deserialized once, only when it's paid). Rough per-item estimates:
+//!
+//! ```text
+//! Fixed (validation, fee math, platform + host + first charity transfers, events)  ~45,000 CU
+//! Per paid pool winner (deserialize + owner/mint checks + transfer)                 ~12,000 CU
+//! Per extra charity                                                                 ~12,000 CU
+//! Per asset prize (vault PDA derivation + two deserializations + transfer)          ~20,000 CU
+//! ```
+//!
+//! A pool room with MAX_WINNERS winners fits the default 200,000 CU instruction budget, but
+//! the total leaves little headroom for Token-2022 accounts or extra instructions in the
+//! same transaction. Clients should prepend `ComputeBudgetProgram.setComputeUnitLimit`
+//! sized as `60_000 + 25_000 * (winners + extra charities)` (the frontend's
+//! `endRoomComputeUnits`). The "End Room Compute Budget" test reports measured usage for
+//! 1, 2 and 3 winners.
 
 use anchor_lang::prelude::*;
-use crate::state::RoomStatus;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{GlobalConfig, PrizeAsset, PrizeMode, Room, RoomStatus, MAX_WINNERS};
 use crate::errors::FundraiselyError;
-use crate::events::RoomEnded;
-use crate::instructions::utils::calculate_bps;
+use crate::events::{PlatformStats, RoomEnded};
+use crate::instructions::utils::{
+    apply_min_prize_guarantee, calculate_bps, calculate_tied_prize_shares, place_count,
+    select_prize_distribution, split_charity_amount, validate_payout_total, validate_vault_balance,
+    validate_winner_places,
+};
 
 /// End room and distribute prizes to winners
 pub fn handler<'info>(
@@ -14,89 +85,265 @@ pub fn handler<'info>(
     _room_id: String,
     winners: Vec<Pubkey>,
 ) -> Result<()> {
+    // Asset rooms hand out escrowed prizes through end_asset_room
+    require!(
+        ctx.accounts.room.prize_mode == PrizeMode::PoolSplit,
+        FundraiselyError::WrongPrizeMode
+    );
+
+    let winners_to_use = begin_end(
+        &mut ctx.accounts.room,
+        &ctx.accounts.host.key(),
+        &ctx.accounts.global_config,
+        winners,
+    )?;
+
+    distribute(
+        Payout {
+            room: &mut ctx.accounts.room,
+            room_vault: &ctx.accounts.room_vault,
+            global_config: &mut ctx.accounts.global_config,
+            platform_token_account: &ctx.accounts.platform_token_account,
+            charity_token_account: &ctx.accounts.charity_token_account,
+            host_token_account: Some(&ctx.accounts.host_token_account),
+            token_program: &ctx.accounts.token_program,
+        },
+        ctx.remaining_accounts,
+        winners_to_use,
+    )
+}
+
+/// Checks that `host` may end the room now, marks it ended and resolves its winners.
+///
+/// Shared by `end_room` and `end_asset_room`. Declared winners (`Room.winners`) take
+/// precedence; otherwise the `winners` parameter is validated (legacy flow).
+pub(crate) fn begin_end(
+    room: &mut Account<Room>,
+    host: &Pubkey,
+    global_config: &GlobalConfig,
+    winners: Vec<Pubkey>,
+) -> Result<Vec<Pubkey>> {
     // REENTRANCY PROTECTION: Check and set flags FIRST before any external calls
     require!(
-        !ctx.accounts.room.ended,
+        !room.ended,
         FundraiselyError::RoomAlreadyEnded
     );
 
     require!(
-        ctx.accounts.room.status == RoomStatus::Active,
+        room.status != RoomStatus::Frozen,
+        FundraiselyError::RoomFrozen
+    );
+
+    require!(
+        room.status == RoomStatus::Active,
         FundraiselyError::InvalidRoomStatus
     );
 
     // Set ended flag immediately to prevent reentrancy
-    ctx.accounts.room.ended = true;
-    ctx.accounts.room.status = RoomStatus::Ended;
+    room.ended = true;
+    room.status = RoomStatus::Ended;
 
-    // Read room data and validate
-    let current_slot = Clock::get()?.slot;
-    let is_expired = ctx.accounts.room.expiration_slot > 0 && current_slot >= ctx.accounts.room.expiration_slot;
+    // Only the host ends a room, expired or not; anyone else settles an expired room with
+    // finalize_expired_room
+    require!(
+        *host == room.host,
+        FundraiselyError::Unauthorized
+    );
 
-    // Validation - only host can end room, unless it's expired (anyone can close expired rooms)
-    if !is_expired {
+    // When the platform mandates the two-step flow, refuse the legacy winners parameter
+    if global_config.require_declaration {
         require!(
-            ctx.accounts.host.key() == ctx.accounts.room.host,
-            FundraiselyError::Unauthorized
+            !room.winners.is_empty(),
+            FundraiselyError::WinnersNotDeclared
         );
     }
 
+    // Turn-based rooms: every player must be ready (unless the host waived the check)
+    require!(
+        !room.require_ready || room.ready_count >= room.player_count,
+        FundraiselyError::PlayersNotReady
+    );
+
     // Determine which winners to use:
     // 1. If winners were declared via declare_winners instruction, use those (room.winners)
     // 2. Otherwise, use the passed-in winners parameter (backward compatibility)
-    let winners_to_use: Vec<Pubkey> = if ctx.accounts.room.winners[0].is_some() {
-        // Winners were declared via declare_winners instruction
-        // Convert [Option<Pubkey>; 3] to Vec<Pubkey>, filtering out None values
-        ctx.accounts.room.winners
-            .iter()
-            .filter_map(|w| *w)
-            .collect()
-    } else {
-        // No declared winners, use passed-in parameter (old flow for backward compatibility)
-        // Validate winner count
+    if !room.winners.is_empty() {
+        // Winners were declared via declare_winners instruction; players who joined since
+        // may have moved the room into a prize tier with fewer places
+        if room.prize_mode == PrizeMode::PoolSplit {
+            let prize_distribution = select_prize_distribution(&room.prize_distribution, &room.prize_tiers, room.player_count);
+            validate_winner_places(place_count(&room.place_sizes, room.winners.len()), prize_distribution)?;
+        }
+        return Ok(room.winners.clone());
+    }
+
+    // No declared winners, use passed-in parameter (old flow for backward compatibility)
+    // Validate winner count
+    require!(
+        !winners.is_empty() && winners.len() <= MAX_WINNERS,
+        FundraiselyError::InvalidWinners
+    );
+
+    // Validate winners are unique
+    for i in 0..winners.len() {
         require!(
-            winners.len() > 0 && winners.len() <= 3,
+            !winners[i + 1..].contains(&winners[i]),
             FundraiselyError::InvalidWinners
         );
+    }
 
-        // Validate host is not a winner
-        require!(
-            !winners.contains(&ctx.accounts.room.host),
-            FundraiselyError::HostCannotBeWinner
-        );
+    // Validate host is not a winner
+    require!(
+        !winners.contains(&room.host),
+        FundraiselyError::HostCannotBeWinner
+    );
 
-        winners
-    };
+    // Validate every winner has a paid prize place
+    if room.prize_mode == PrizeMode::PoolSplit {
+        let prize_distribution = select_prize_distribution(&room.prize_distribution, &room.prize_tiers, room.player_count);
+        validate_winner_places(winners.len(), prize_distribution)?;
+    }
+
+    Ok(winners)
+}
+
+/// Accounts a room payout moves funds between
+pub(crate) struct Payout<'a, 'info> {
+    pub room: &'a mut Account<'info, Room>,
+    pub room_vault: &'a Account<'info, TokenAccount>,
+    /// Receives the room's contribution to the lifetime platform stats
+    pub global_config: &'a mut Account<'info, GlobalConfig>,
+    pub platform_token_account: &'a Account<'info, TokenAccount>,
+    pub charity_token_account: &'a Account<'info, TokenAccount>,
+    /// Host fee destination; `None` for expired finalization, which sends the host fee to charity
+    pub host_token_account: Option<&'a Account<'info, TokenAccount>>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+/// Splits the room's funds between platform, host, charities and winners, transfers
+/// them out of the vault, adds the room to the lifetime platform stats and emits
+/// `RoomEnded` and `PlatformStats`.
+///
+/// The caller has already validated the room and marked it ended. Winner token accounts,
+/// then the extra charities' token accounts, are read from `remaining_accounts`.
+pub(crate) fn distribute<'info>(
+    payout: Payout<'_, 'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    winners_to_use: Vec<Pubkey>,
+) -> Result<()> {
+    let Payout {
+        room,
+        room_vault,
+        global_config,
+        platform_token_account,
+        charity_token_account,
+        host_token_account,
+        token_program,
+    } = payout;
+    let expired_finalization = host_token_account.is_none();
+
+    // Reconcile the vault with the room's counters before trusting them for any payout
+    msg!("Vault balance: {}, collected: {}", room_vault.amount, room.total_collected);
+    validate_vault_balance(room_vault.amount, room.total_collected)?;
 
     // Calculate fee distribution
-    let entry_fees_total = ctx.accounts.room.total_entry_fees;
-    let extras_total = ctx.accounts.room.total_extras_fees;
+    let entry_fees_total = room.total_entry_fees;
+    let extras_total = room.total_extras_fees;
+    // Kept from players who left (see leave_room); all of it goes to charity
+    let leave_penalties = room.total_leave_penalties;
 
     // Apply percentage splits to entry fees only
-    let platform_fee = calculate_bps(entry_fees_total, ctx.accounts.global_config.platform_fee_bps)?;
-    let host_fee = calculate_bps(entry_fees_total, ctx.accounts.room.host_fee_bps)?;
-    let prize_amount = calculate_bps(entry_fees_total, ctx.accounts.room.prize_pool_bps)?;
+    let platform_fee = calculate_bps(entry_fees_total, room.platform_fee_bps)?;
+    let host_fee = calculate_bps(entry_fees_total, room.host_fee_bps)?;
+    let pool_prize = calculate_bps(entry_fees_total, room.prize_pool_bps)?;
 
     // Charity gets remainder of entry fees PLUS all extras
     let charity_from_entry_fees = entry_fees_total
         .checked_sub(platform_fee)
         .and_then(|v| v.checked_sub(host_fee))
-        .and_then(|v| v.checked_sub(prize_amount))
+        .and_then(|v| v.checked_sub(pool_prize))
         .ok_or(FundraiselyError::ArithmeticUnderflow)?;
 
-    let charity_amount = charity_from_entry_fees
+    // Guaranteed minimum prize: top up a short pool from the host's escrow,
+    // unused escrow goes to charity (see Room docs)
+    let guaranteed_min_prize = room.guaranteed_min_prize;
+    let (prize_amount, escrow_to_charity) = apply_min_prize_guarantee(pool_prize, guaranteed_min_prize);
+
+    // Expired finalization: the host abandoned the room, so its fee goes to charity;
+    // likewise when the host waived the fee
+    let host_fee_waived = room.host_fee_waived;
+    let (host_amount, host_fee_to_charity) = if expired_finalization || host_fee_waived {
+        (0, host_fee)
+    } else {
+        (host_fee, 0)
+    };
+
+    // No declared winners (expired finalization only): the prize has no recipient
+    let (prize_amount, prize_to_charity) = if winners_to_use.is_empty() {
+        (0, prize_amount)
+    } else {
+        (prize_amount, 0)
+    };
+
+    // Save values for later use
+    let player_count = room.player_count;
+    // The prize tier matching the final player count (the base distribution below every tier)
+    let prize_distribution =
+        select_prize_distribution(&room.prize_distribution, &room.prize_tiers, player_count).to_vec();
+
+    // Each place's share rounds down; the rounding dust goes to charity so the vault ends empty.
+    // Tied winners (declared winners only) split their place equally.
+    let prize_shares =
+        calculate_tied_prize_shares(prize_amount, &prize_distribution, &winners_to_use, &room.place_sizes);
+    let prizes_distributed: u64 = prize_shares.iter().sum();
+    let prize_dust = prize_amount
+        .checked_sub(prizes_distributed)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+    let prize_amount = prizes_distributed;
+
+    // Everything charity gets out of the entry fees, reported separately from extras
+    let charity_from_entry = charity_from_entry_fees
+        .checked_add(escrow_to_charity)
+        .and_then(|v| v.checked_add(host_fee_to_charity))
+        .and_then(|v| v.checked_add(prize_to_charity))
+        .and_then(|v| v.checked_add(prize_dust))
+        .ok_or(FundraiselyError::ArithmeticOverflow)?;
+
+    let charity_amount = charity_from_entry
         .checked_add(extras_total)
+        .and_then(|v| v.checked_add(leave_penalties))
         .ok_or(FundraiselyError::ArithmeticOverflow)?;
 
-    // Save values for later use
-    let player_count = ctx.accounts.room.player_count;
-    let room_key = ctx.accounts.room.key();
-    let token_prog_key = ctx.accounts.token_program.key();
+    // Every transfer out of the room vault must be covered by its balance; checked up
+    // front so a shortfall fails before anyone is paid
+    let planned_total = platform_fee
+        .checked_add(host_amount)
+        .and_then(|v| v.checked_add(charity_amount))
+        .and_then(|v| v.checked_add(prize_amount))
+        .ok_or(FundraiselyError::ArithmeticOverflow)?;
+    msg!("Planned payouts: {}, vault balance: {}", planned_total, room_vault.amount);
+    validate_payout_total(planned_total, room_vault.amount)?;
+
+    // remaining_accounts: one per winner, one per extra charity, and (asset rooms) one
+    // prize vault per paid place; nothing more
+    let extra_charities = room.charity_splits.len().saturating_sub(1);
+    let prize_vaults = if room.prize_mode == PrizeMode::AssetBased {
+        winners_to_use.len().min(room.prize_assets.iter().flatten().count())
+    } else {
+        0
+    };
+    require!(
+        remaining_accounts.len() == winners_to_use.len() + extra_charities + prize_vaults,
+        FundraiselyError::InvalidRemainingAccounts
+    );
+
+    let room_key = room.key();
+    let token_prog_key = token_program.key();
 
     // Prepare PDA signer seeds
-    let host_key = ctx.accounts.room.host;
-    let bump = ctx.accounts.room.bump;
-    let room_id_bytes = ctx.accounts.room.room_id.as_bytes();
+    let host_key = room.host;
+    let bump = room.bump;
+    let room_id_bytes = room.room_id.as_bytes();
     let seeds = &[
         b"room",
         host_key.as_ref(),
@@ -105,125 +352,255 @@ pub fn handler<'info>(
     ];
     let signer = &[&seeds[..]];
 
-    // Transfer platform fee
-    if platform_fee > 0 {
+    // Account infos shared by every transfer (built once, not per CPI; see Compute Budget)
+    let token_program_info = token_program.to_account_info();
+    let room_info = room.to_account_info();
+    let vault_info = room_vault.to_account_info();
+
+    // Moves `amount` out of a room-owned token account, signed by the room PDA
+    let transfer_signed = |from: &AccountInfo<'info>, to: &AccountInfo<'info>, amount: u64| {
         anchor_spl::token::transfer(
             CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
+                token_program_info.clone(),
                 anchor_spl::token::Transfer {
-                    from: ctx.accounts.room_vault.to_account_info(),
-                    to: ctx.accounts.platform_token_account.to_account_info(),
-                    authority: ctx.accounts.room.to_account_info(),
+                    from: from.clone(),
+                    to: to.clone(),
+                    authority: room_info.clone(),
                 },
                 signer,
             ),
-            platform_fee,
-        )?;
+            amount,
+        )
+    };
+
+    // Transfer platform fee
+    if platform_fee > 0 {
+        transfer_signed(&vault_info, &platform_token_account.to_account_info(), platform_fee)?;
     }
 
     // Transfer host fee
-    if host_fee > 0 {
-        anchor_spl::token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                anchor_spl::token::Transfer {
-                    from: ctx.accounts.room_vault.to_account_info(),
-                    to: ctx.accounts.host_token_account.to_account_info(),
-                    authority: ctx.accounts.room.to_account_info(),
-                },
-                signer,
-            ),
-            host_fee,
-        )?;
+    if let Some(host_token_account) = host_token_account.filter(|_| host_amount > 0) {
+        transfer_signed(&vault_info, &host_token_account.to_account_info(), host_amount)?;
     }
 
-    // Transfer charity donation
-    if charity_amount > 0 {
-        anchor_spl::token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                anchor_spl::token::Transfer {
-                    from: ctx.accounts.room_vault.to_account_info(),
-                    to: ctx.accounts.charity_token_account.to_account_info(),
-                    authority: ctx.accounts.room.to_account_info(),
-                },
-                signer,
-            ),
-            charity_amount,
-        )?;
+    // Split the charity amount across the room's charities (dust to the first)
+    let charity_splits = room.charity_splits.clone();
+    let charity_amounts = if charity_splits.is_empty() {
+        vec![charity_amount]
+    } else {
+        let weights: Vec<u16> = charity_splits.iter().map(|split| split.weight_bps).collect();
+        split_charity_amount(charity_amount, &weights)?
+    };
+
+    // Transfer charity donation (first charity)
+    if let Some(primary) = charity_splits.first() {
+        require!(
+            charity_token_account.owner == primary.wallet,
+            FundraiselyError::InvalidTokenOwner
+        );
+    }
+
+    if charity_amounts[0] > 0 {
+        transfer_signed(&vault_info, &charity_token_account.to_account_info(), charity_amounts[0])?;
+    }
+
+    // Transfer the other charities' shares; their token accounts follow the winners'
+    let charity_offset = winners_to_use.len();
+
+    for (i, split) in charity_splits.iter().enumerate().skip(1) {
+        let charity_token_account_info = &remaining_accounts[charity_offset + i - 1];
+
+        require!(
+            charity_token_account_info.owner == &token_prog_key,
+            FundraiselyError::InvalidTokenOwner
+        );
+
+        let charity_token_account = {
+            let data = charity_token_account_info.try_borrow_data()?;
+            anchor_spl::token::TokenAccount::try_deserialize(&mut &data[..])?
+        };
+
+        require!(
+            charity_token_account.mint == room.fee_token_mint,
+            FundraiselyError::InvalidTokenMint
+        );
+
+        require!(
+            charity_token_account.owner == split.wallet,
+            FundraiselyError::InvalidTokenOwner
+        );
+
+        if charity_amounts[i] > 0 {
+            transfer_signed(&vault_info, charity_token_account_info, charity_amounts[i])?;
+        }
+
+        msg!("   Charity {}: {} receives {} tokens", i + 1, split.wallet, charity_amounts[i]);
     }
 
     // Distribute prizes to winners
-    require!(
-        ctx.remaining_accounts.len() >= winners_to_use.len(),
-        FundraiselyError::InvalidWinners
-    );
+    let mut winner_amounts = vec![0u64; winners_to_use.len()];
 
     for (i, winner) in winners_to_use.iter().enumerate() {
-        if i < ctx.accounts.room.prize_distribution.len() && ctx.accounts.room.prize_distribution[i] > 0 {
-            let winner_amount = (prize_amount as u128 * ctx.accounts.room.prize_distribution[i] as u128 / 100) as u64;
-
-            if winner_amount > 0 && i < ctx.remaining_accounts.len() {
-                let winner_token_account_info = &ctx.remaining_accounts[i];
-
-                // Verify the account is owned by the token program
-                require!(
-                    winner_token_account_info.owner == &token_prog_key,
-                    FundraiselyError::InvalidWinners
-                );
-
-                // Deserialize and validate the token account
-                // Note: We can't use Account::try_from because of lifetime issues
-                // Instead, we deserialize directly and perform manual validation
-                let token_account_data = winner_token_account_info.try_borrow_data()?;
-                let winner_token_account = anchor_spl::token::TokenAccount::try_deserialize(&mut &token_account_data[..])?;
-
-                // Verify token account mint matches room's fee token mint
-                require!(
-                    winner_token_account.mint == ctx.accounts.room.fee_token_mint,
-                    FundraiselyError::InvalidTokenMint
-                );
-
-                // Verify token account owner matches winner pubkey
-                require!(
-                    winner_token_account.owner == *winner,
-                    FundraiselyError::InvalidTokenOwner
-                );
-
-                // Transfer prize to winner
-                anchor_spl::token::transfer(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.token_program.to_account_info(),
-                        anchor_spl::token::Transfer {
-                            from: ctx.accounts.room_vault.to_account_info(),
-                            to: winner_token_account_info.to_account_info(),
-                            authority: ctx.accounts.room.to_account_info(),
-                        },
-                        signer,
-                    ),
-                    winner_amount,
-                )?;
-
-                msg!("   Winner {}: {} receives {} tokens", i + 1, winner, winner_amount);
-            }
+        let winner_token_account_info = &remaining_accounts[i];
+
+        // Verify the account is owned by the token program
+        require!(
+            winner_token_account_info.owner == &token_prog_key,
+            FundraiselyError::InvalidWinners
+        );
+
+        // Deserialize and validate the token account
+        // Note: We can't use Account::try_from because of lifetime issues
+        // Instead, we deserialize directly and perform manual validation
+        // (scoped so the data borrow is released before the transfer CPI)
+        let winner_token_account = {
+            let data = winner_token_account_info.try_borrow_data()?;
+            anchor_spl::token::TokenAccount::try_deserialize(&mut &data[..])?
+        };
+
+        // Verify token account owner matches winner pubkey, whether or not the place pays,
+        // so a zero-prize slot can't hide an out-of-order account
+        require!(
+            winner_token_account.owner == *winner,
+            FundraiselyError::InvalidTokenOwner
+        );
+
+        let winner_amount = prize_shares[i];
+        if winner_amount > 0 {
+            // Verify token account mint matches room's fee token mint (asset rooms check
+            // the prize mint below instead)
+            require!(
+                winner_token_account.mint == room.fee_token_mint,
+                FundraiselyError::InvalidTokenMint
+            );
+
+            // Transfer prize to winner
+            transfer_signed(&vault_info, winner_token_account_info, winner_amount)?;
+
+            winner_amounts[i] = winner_amount;
+            msg!("   Winner {}: {} receives {} tokens", i + 1, winner, winner_amount);
+        }
+    }
+
+    // Asset rooms: place i receives escrowed prize asset i from its prize vault (their pool
+    // prize above is always zero); the vaults follow the extra charities' token accounts
+    if room.prize_mode == PrizeMode::AssetBased {
+        let vault_offset = charity_offset + charity_splits.len().saturating_sub(1);
+        // (prize index, prize) of every declared prize, in place order
+        let prize_assets: Vec<(usize, PrizeAsset)> = room
+            .prize_assets
+            .iter()
+            .enumerate()
+            .filter_map(|(index, prize)| prize.clone().map(|prize| (index, prize)))
+            .collect();
+
+        for (i, (winner, (prize_index, prize))) in winners_to_use.iter().zip(&prize_assets).enumerate() {
+            let prize_vault_info = &remaining_accounts[vault_offset + i];
+            let winner_token_account_info = &remaining_accounts[i];
+
+            // Only the prize's own vault PDA may be drained
+            let (expected_vault, _) = Pubkey::find_program_address(
+                &[b"prize-vault", room_key.as_ref(), &[*prize_index as u8]],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                prize_vault_info.key(),
+                expected_vault,
+                FundraiselyError::InvalidVaultAuthority
+            );
+
+            require!(
+                prize_vault_info.owner == &token_prog_key
+                    && winner_token_account_info.owner == &token_prog_key,
+                FundraiselyError::InvalidTokenOwner
+            );
+
+            let prize_vault = {
+                let data = prize_vault_info.try_borrow_data()?;
+                anchor_spl::token::TokenAccount::try_deserialize(&mut &data[..])?
+            };
+            let winner_token_account = {
+                let data = winner_token_account_info.try_borrow_data()?;
+                anchor_spl::token::TokenAccount::try_deserialize(&mut &data[..])?
+            };
+
+            // The prize vault holds this prize's mint and is controlled by the room PDA
+            require!(
+                prize_vault.mint == prize.mint,
+                FundraiselyError::InvalidTokenMint
+            );
+            require!(
+                prize_vault.owner == room_key,
+                FundraiselyError::InvalidVaultAuthority
+            );
+
+            // The winner receives in their own account for the prize mint
+            require!(
+                winner_token_account.mint == prize.mint,
+                FundraiselyError::InvalidTokenMint
+            );
+            require!(
+                winner_token_account.owner == *winner,
+                FundraiselyError::InvalidTokenOwner
+            );
+
+            transfer_signed(prize_vault_info, winner_token_account_info, prize.amount)?;
+
+            winner_amounts[i] = prize.amount;
+            msg!("   Winner {}: {} receives {} of prize mint {}", i + 1, winner, prize.amount, prize.mint);
         }
     }
 
     msg!("Room ended and prizes distributed");
     msg!("   Entry fees: {}, Extras: {} (100% to charity)", entry_fees_total, extras_total);
+    if guaranteed_min_prize > 0 {
+        msg!("   Guaranteed prize: {}, Pool: {}, Escrow to charity: {}",
+            guaranteed_min_prize, pool_prize, escrow_to_charity);
+    }
+    if prize_dust > 0 {
+        msg!("   Prize rounding dust to charity: {}", prize_dust);
+    }
+    if leave_penalties > 0 {
+        msg!("   Leave penalties to charity: {}", leave_penalties);
+    }
     msg!("   Platform: {}, Host: {}, Charity: {}, Prizes: {}",
-        platform_fee, host_fee, charity_amount, prize_amount);
+        platform_fee, host_amount, charity_amount, prize_amount);
+    if expired_finalization {
+        msg!("   Finalized after expiration: host fee of {} sent to charity", host_fee);
+    } else if host_fee_waived {
+        msg!("   Host waived their fee: {} sent to charity", host_fee);
+    }
 
-    // Emit event for off-chain indexers and frontend
+    global_config.record_room_end(charity_amount, player_count)?;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // Emit events for off-chain indexers and frontend
     emit!(RoomEnded {
         room: room_key,
-        winners: winners_to_use.clone(),
+        winners: winners_to_use,
+        winner_amounts,
         platform_amount: platform_fee,
-        host_amount: host_fee,
+        host_amount,
+        charity_wallet: room.charity_wallet,
         charity_amount,
+        charity_amounts,
         prize_amount,
+        prize_distribution,
         total_players: player_count,
-        timestamp: Clock::get()?.unix_timestamp,
+        expired_finalization,
+        host_fee_waived,
+        charity_from_entry,
+        charity_from_extras: extras_total,
+        charity_from_leave_penalties: leave_penalties,
+        timestamp,
+    });
+
+    emit!(PlatformStats {
+        room: room_key,
+        lifetime_charity_raised: global_config.lifetime_charity_raised,
+        lifetime_rooms_ended: global_config.lifetime_rooms_ended,
+        lifetime_players: global_config.lifetime_players,
+        timestamp,
     });
 
     Ok(())
@@ -0,0 +1,78 @@
+//! # Finalize Expired Room Instruction
+//!
+//! Permissionless settlement of an expired room whose host never called `end_room`.
+//!
+//! Once the room's expiration slot or expiration timestamp has passed (whichever is set
+//! and comes first) anyone can finalize the room. Funds are split
+//! exactly as in `end_room`, except that the host fee goes to the room's charity: the host
+//! abandoned the room, and letting them collect the fee anyway would reward squatting on
+//! players' funds.
+//!
+//! Winners declared before expiration are paid as usual, with their token accounts passed
+//! in `remaining_accounts` (followed by the extra charities' token accounts, as in
+//! `end_room`). If no winners were declared the prize share also goes to charity.
+//!
+//! The platform and charity token accounts are checked against the configured wallets,
+//! so a third-party caller cannot redirect any of the payout. `RoomEnded` is emitted with
+//! `expired_finalization = true` and `host_amount = 0`.
+
+use anchor_lang::prelude::*;
+use crate::state::RoomStatus;
+use crate::errors::FundraiselyError;
+use crate::instructions::game::end_room::{distribute, Payout};
+use crate::instructions::utils::is_expired;
+
+/// Settle an expired room on the host's behalf (callable by anyone)
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, crate::FinalizeExpiredRoom<'info>>,
+    _room_id: String,
+) -> Result<()> {
+    // REENTRANCY PROTECTION: Check and set flags FIRST before any external calls
+    require!(
+        !ctx.accounts.room.ended,
+        FundraiselyError::RoomAlreadyEnded
+    );
+
+    require!(
+        ctx.accounts.room.status != RoomStatus::Frozen,
+        FundraiselyError::RoomFrozen
+    );
+
+    require!(
+        ctx.accounts.room.status == RoomStatus::Active,
+        FundraiselyError::InvalidRoomStatus
+    );
+
+    let clock = Clock::get()?;
+    let expiration_slot = ctx.accounts.room.expiration_slot;
+    let expiration_unix_ts = ctx.accounts.room.expiration_unix_ts;
+    require!(
+        is_expired(expiration_slot, expiration_unix_ts, clock.slot, clock.unix_timestamp),
+        FundraiselyError::RoomNotExpired
+    );
+
+    ctx.accounts.room.ended = true;
+    ctx.accounts.room.status = RoomStatus::Ended;
+
+    msg!("Finalizing expired room {} (expires at slot {} / unix time {}, now slot {} / {})",
+        ctx.accounts.room.room_id, expiration_slot, expiration_unix_ts, clock.slot, clock.unix_timestamp);
+    msg!("   Finalized by: {}", ctx.accounts.caller.key());
+
+    let winners = ctx.accounts.room.winners.clone();
+
+    distribute(
+        Payout {
+            room: &mut ctx.accounts.room,
+            room_vault: &ctx.accounts.room_vault,
+            global_config: &mut ctx.accounts.global_config,
+            platform_token_account: &ctx.accounts.platform_token_account,
+            charity_token_account: &ctx.accounts.charity_token_account,
+            host_token_account: None,
+            token_program: &ctx.accounts.token_program,
+        },
+        ctx.remaining_accounts,
+        winners,
+    )
+}
+
+// Note: FinalizeExpiredRoom struct is in lib.rs for Anchor macro compatibility
@@ -9,8 +9,11 @@
 //!
 //! ## Instructions
 //!
-//! - **declare_winners**: Host declares 1-3 winners (transparent, verifiable)
+//! - **declare_winners**: Host declares 1 to MAX_WINNERS winners (transparent, verifiable)
+//! - **amend_winners**: Host corrects declared winners before end_room (old and new lists in event)
 //! - **end_room**: Distribute funds to all parties (platform, host, charity, winners)
+//! - **finalize_expired_room**: Anyone settles an expired room; the host fee goes to charity
+//! - **record_charity_receipt**: Charity (or attestor) records its receipt acknowledgment
 //!
 //! ## Instruction Flow
 //!
@@ -38,6 +41,9 @@
 //! - **Compliance**: Meets requirements for separated winner declaration (per requirements doc)
 
 pub mod declare_winners;
+pub mod amend_winners;
 pub mod end_room;
+pub mod finalize_expired_room;
+pub mod record_charity_receipt;
 
 // DeclareWinners and EndRoom structs are now in lib.rs for Anchor macro compatibility
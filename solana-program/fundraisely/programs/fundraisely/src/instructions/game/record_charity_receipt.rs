@@ -0,0 +1,67 @@
+//! # Record Charity Receipt Instruction
+//!
+//! Optional compliance step after `end_room`: the charity acknowledges that it received the
+//! donation by recording a hash of its receipt document against the room. The hash is stored
+//! in `Room.charity_receipt` and emitted as `CharityReceiptRecorded`, creating an on-chain link
+//! between the donation transfer and the charity's acknowledgment.
+//!
+//! ## Who Can Record
+//!
+//! - The room's `charity_wallet`, signing as `recorder`
+//! - The platform's `receipt_attestor` (GlobalConfig), for charities that cannot sign
+//!   transactions themselves
+//!
+//! ## Validation
+//!
+//! 1. Room must be ended (the donation has been transferred)
+//! 2. Recorder must be the charity wallet or the configured attestor
+//! 3. Receipt hash must be non-zero
+//! 4. Only one receipt per room
+
+use anchor_lang::prelude::*;
+use crate::errors::FundraiselyError;
+use crate::events::CharityReceiptRecorded;
+
+/// Record a charity receipt hash for an ended room
+pub fn handler(
+    ctx: Context<crate::RecordCharityReceipt>,
+    _room_id: String,
+    receipt_hash: [u8; 32],
+) -> Result<()> {
+    let recorder = ctx.accounts.recorder.key();
+    let attestor = ctx.accounts.global_config.receipt_attestor;
+    let room = &mut ctx.accounts.room;
+
+    // Check recorder is the charity or the configured attestor
+    require!(
+        recorder == room.charity_wallet
+            || (attestor != Pubkey::default() && recorder == attestor),
+        FundraiselyError::Unauthorized
+    );
+
+    require!(room.ended, FundraiselyError::RoomNotEnded);
+
+    require!(
+        room.charity_receipt.is_none(),
+        FundraiselyError::CharityReceiptAlreadyRecorded
+    );
+
+    require!(receipt_hash != [0u8; 32], FundraiselyError::InvalidReceiptHash);
+
+    room.charity_receipt = Some(receipt_hash);
+
+    msg!("Charity receipt recorded: {}", room.room_id);
+    msg!("   Recorder: {}", recorder);
+
+    emit!(CharityReceiptRecorded {
+        room: room.key(),
+        charity_wallet: room.charity_wallet,
+        recorder,
+        receipt_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Note: Account struct is in lib.rs
@@ -10,6 +10,7 @@
 //! - **room**: Room creation and management (init_pool_room, init_asset_room)
 //! - **player**: Player participation (join_room, ready_up, leave_room)
 //! - **game**: Game execution (declare_winners, end_room)
+//! - **asset**: Asset-based rooms (init_asset_room, add_prize_asset, end_asset_room)
 //! - **utils**: Shared utility functions (BPS calculations, validation helpers)
 //!
 //! ## Design Philosophy
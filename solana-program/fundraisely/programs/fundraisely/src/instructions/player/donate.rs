@@ -0,0 +1,117 @@
+//! # Donate Instruction
+//!
+//! Lets a supporter give to a room's charity without joining as a player.
+//!
+//! The donation is transferred from the donor's token account to the room vault and goes
+//! 100% to charity: it's added to `Room.total_extras_fees`, which `end_room` pays to
+//! charity alongside the players' extras, and to `total_collected` and `total_donations`.
+//!
+//! Donors get a `Donation` receipt PDA instead of a PlayerEntry, so they don't count
+//! toward `player_count` or `max_players` and can't win; a full room still accepts
+//! donations. Repeat donations from the same wallet add to the same receipt. A
+//! `DonationReceived` event reports each one.
+//!
+//! Donations are only taken while the room is `Active`: a room with no players can still
+//! be cancelled, which returns the whole vault to the host.
+//!
+//! ## Errors
+//!
+//! - `EmergencyPause`: The platform is paused
+//! - `InvalidDonationAmount`: Amount is zero
+//! - `RoomAlreadyEnded` / `RoomFrozen` / `InvalidRoomStatus`: The room is not in play
+//! - `RoomExpired`: The room is waiting to be finalized
+//! - `PlayerBlacklisted`: The donor is banned platform-wide
+//! - `InvalidTokenOwner` / `InvalidTokenMint`: The source account isn't the donor's
+//!   account for the room's fee token
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::state::RoomStatus;
+use crate::errors::FundraiselyError;
+use crate::events::DonationReceived;
+use crate::instructions::utils::is_expired;
+
+/// Donate to a room's charity without taking a player slot
+pub fn handler(ctx: Context<crate::Donate>, _room_id: String, amount: u64) -> Result<()> {
+    let room = &mut ctx.accounts.room;
+    let clock = Clock::get()?;
+
+    require!(
+        !ctx.accounts.global_config.emergency_pause,
+        FundraiselyError::EmergencyPause
+    );
+
+    require!(amount > 0, FundraiselyError::InvalidDonationAmount);
+
+    require!(!room.ended, FundraiselyError::RoomAlreadyEnded);
+
+    require!(
+        room.status != RoomStatus::Frozen,
+        FundraiselyError::RoomFrozen
+    );
+
+    require!(
+        room.status == RoomStatus::Active,
+        FundraiselyError::InvalidRoomStatus
+    );
+
+    require!(
+        !is_expired(room.expiration_slot, room.expiration_unix_ts, clock.slot, clock.unix_timestamp),
+        FundraiselyError::RoomExpired
+    );
+
+    require!(
+        !ctx.accounts.blacklist.is_blacklisted(&ctx.accounts.donor.key()),
+        FundraiselyError::PlayerBlacklisted
+    );
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.donor_token_account.to_account_info(),
+                to: ctx.accounts.room_vault.to_account_info(),
+                authority: ctx.accounts.donor.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // Record the receipt (new on a wallet's first donation to this room)
+    let donation = &mut ctx.accounts.donation;
+    donation.room = room.key();
+    donation.donor = ctx.accounts.donor.key();
+    donation.amount = donation.amount
+        .checked_add(amount)
+        .ok_or(FundraiselyError::ArithmeticOverflow)?;
+    donation.bump = ctx.bumps.donation;
+
+    // Update room state
+    room.total_collected = room.total_collected
+        .checked_add(amount)
+        .ok_or(FundraiselyError::ArithmeticOverflow)?;
+
+    room.total_extras_fees = room.total_extras_fees
+        .checked_add(amount)
+        .ok_or(FundraiselyError::ArithmeticOverflow)?;
+
+    room.total_donations = room.total_donations
+        .checked_add(amount)
+        .ok_or(FundraiselyError::ArithmeticOverflow)?;
+
+    msg!("Donation received: {} from {}", amount, donation.donor);
+    msg!("   Room donations: {}", room.total_donations);
+
+    emit!(DonationReceived {
+        room: room.key(),
+        donor: donation.donor,
+        amount,
+        donor_total: donation.amount,
+        total_donations: room.total_donations,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Note: Donate struct is in lib.rs for Anchor macro compatibility
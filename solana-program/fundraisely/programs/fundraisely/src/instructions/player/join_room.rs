@@ -28,7 +28,8 @@
 //! ## What This Instruction Does
 //!
 //! 1. **Validates Room State**: Ensures room is Ready/Active, not expired, not full, not ended
-//! 2. **Calculates Payment**: entry_fee + extras_amount = total_payment
+//! 2. **Calculates Payment**: entry fee (early-bird fee for the first `early_bird_count` players,
+//!    reduced by `discount_bps` for wallets with a `Discount` PDA) + extras_amount = total_payment
 //! 3. **Transfers Tokens**: Moves SPL tokens from player's wallet to room vault via CPI
 //! 4. **Creates PlayerEntry PDA**: Immutable receipt using seeds ["player", room, player]
 //! 5. **Updates Room Counters**: Increments player_count, total_collected, total_entry_fees, total_extras_fees
@@ -42,6 +43,9 @@
 //! ### Entry Fee (Required)
 //! ```text
 //! - Fixed amount set by room.entry_fee (e.g., 10 USDC)
+//! - The first room.early_bird_count players pay room.early_bird_fee instead
+//! - Wallets with a Discount PDA pay room.discount_bps less (waived amount in
+//!   Room.total_discounts)
 //! - Subject to percentage splits:
 //!   * Platform: 20%
 //!   * Host: 0-5%
@@ -173,13 +177,15 @@
 //!   const extrasAmount = new BN(extras * (10 ** tokenDecimals));
 //!
 //!   await program.methods
-//!     .joinRoom(roomId, extrasAmount)
+//!     .joinRoom(roomId, extrasAmount, null, referrer ?? null) // null: public room, no allowlist proof
 //!     .accounts({
 //!       room: roomPDA,
 //!       playerEntry: playerEntryPDA,
 //!       roomVault: vaultPDA,
 //!       playerTokenAccount,
 //!       globalConfig: globalConfigPDA,
+//!       blacklist: blacklistPDA, // ["blacklist"]
+//!       discount: discountPDA, // ["discount", room, player] if the host added one, else null
 //!       player: wallet.publicKey,
 //!       tokenProgram: TOKEN_PROGRAM_ID,
 //!       systemProgram: SystemProgram.programId,
@@ -194,18 +200,26 @@
 //!
 //! 1. **Emergency Pause**: Fails if GlobalConfig.emergency_pause is true
 //! 2. **Room Status**: Room must be in Ready status (Active also accepted)
-//! 3. **Room Expiration**: Current slot must be < expiration_slot (if set)
+//! 3. **Room Expiration**: Current slot must be < expiration_slot and the current unix
+//!    time < expiration_unix_ts (each only if set)
 //! 4. **Room Not Ended**: room.ended must be false
 //! 5. **Room Not Full**: player_count must be < max_players
-//! 6. **Player Not Joined**: PlayerEntry PDA must not already exist (enforced by init constraint)
-//! 7. **Token Mint Match**: player_token_account.mint must equal room.fee_token_mint
-//! 8. **Sufficient Balance**: Player must have balance >= (entry_fee + extras_amount)
+//! 6. **Not Blacklisted**: The player's wallet must not be on the platform blacklist
+//! 7. **Allowlist**: In a private room (non-zero `allowlist_root`), `allowlist_proof` must be
+//!    a merkle proof of the player's wallet (at most `MAX_ALLOWLIST_PROOF_LEN` hashes)
+//! 8. **Referrer**: If given, `referrer` must not be the player's own wallet
+//! 9. **Player Not Joined**: PlayerEntry PDA must not already exist (enforced by init constraint)
+//! 10. **Token Mint Match**: player_token_account.mint must equal room.fee_token_mint
+//! 11. **Sufficient Balance**: Player must have balance >= (entry_fee + extras_amount)
 //!
 //! ## Error Conditions
 //!
 //! This instruction fails if:
 //! - Player already joined (PlayerEntry PDA exists)
 //! - Room is full (MaxPlayersReached)
+//! - Player's wallet is blacklisted (PlayerBlacklisted)
+//! - Private room and the player's proof doesn't match its allowlist (PlayerNotAllowlisted)
+//! - Player named themselves as referrer (InvalidReferrer)
 //! - Room has expired (RoomExpired)
 //! - Room already ended (RoomAlreadyEnded)
 //! - Room status is not Ready/Active (RoomNotReady)
@@ -233,6 +247,7 @@
 //! - amount_paid: Total payment (entry + extras)
 //! - extras_paid: Just the extras portion
 //! - player_count: New player count after this join
+//! - referrer: Wallet credited with the referral, if any (no funds move)
 //! - timestamp: Unix timestamp of join
 //!
 //! ## Economic Tracking
@@ -240,7 +255,8 @@
 //! Room maintains separate counters for transparent accounting:
 //!
 //! ```rust
-//! room.total_entry_fees += room.entry_fee;      // Subject to splits
+//! room.total_entry_fees += entry_fee;           // Charged fee (early-bird or full, less any discount), subject to splits
+//! room.total_discounts += discount_amount;      // Waived by a Discount PDA
 //! room.total_extras_fees += extras_amount;      // 100% to charity
 //! room.total_collected += total_payment;        // Grand total
 //! ```
@@ -269,18 +285,23 @@
 //! - **Immutable Receipts**: PlayerEntry PDAs are permanent proof of participation
 
 use anchor_lang::prelude::*;
-use crate::state::RoomStatus;
+use crate::state::{RoomStatus, MAX_ALLOWLIST_PROOF_LEN};
 use crate::errors::FundraiselyError;
 use crate::events::PlayerJoined;
+use crate::instructions::utils::{
+    current_entry_fee, discounted_entry_fee, is_expired, verify_allowlist_proof,
+};
 
 /// Join a room by paying entry fee
 pub fn handler(
     ctx: Context<crate::JoinRoom>,
     _room_id: String,
     extras_amount: u64,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
+    referrer: Option<Pubkey>,
 ) -> Result<()> {
     let room = &mut ctx.accounts.room;
-    let current_slot = Clock::get()?.slot;
+    let clock = Clock::get()?;
 
     // Validation
     require!(
@@ -288,14 +309,21 @@ pub fn handler(
         FundraiselyError::EmergencyPause
     );
 
-    // Check if room has expired
+    // Check if room has expired (by slot or by unix time)
     require!(
-        room.expiration_slot == 0 || current_slot < room.expiration_slot,
+        !is_expired(room.expiration_slot, room.expiration_unix_ts, clock.slot, clock.unix_timestamp),
         FundraiselyError::RoomExpired
     );
 
     require!(
-        room.status == RoomStatus::Ready,
+        room.status != RoomStatus::Frozen,
+        FundraiselyError::RoomFrozen
+    );
+
+    // Ready rooms take their first player, Active rooms the rest; asset rooms
+    // stay AwaitingFunding/PartiallyFunded until every prize is deposited
+    require!(
+        matches!(room.status, RoomStatus::Ready | RoomStatus::Active),
         FundraiselyError::RoomNotReady
     );
 
@@ -310,8 +338,48 @@ pub fn handler(
         FundraiselyError::MaxPlayersReached
     );
 
+    // Platform-wide bans apply to every room
+    require!(
+        !ctx.accounts.blacklist.is_blacklisted(&ctx.accounts.player.key()),
+        FundraiselyError::PlayerBlacklisted
+    );
+
+    // Private rooms: the player must prove they're on the host's allowlist
+    if room.allowlist_root != [0u8; 32] {
+        let proof = allowlist_proof.unwrap_or_default();
+        require!(
+            proof.len() <= MAX_ALLOWLIST_PROOF_LEN
+                && verify_allowlist_proof(&room.allowlist_root, &ctx.accounts.player.key(), &proof),
+            FundraiselyError::PlayerNotAllowlisted
+        );
+    }
+
+    // Referrals are informational only, but a player can't credit themselves
+    require!(
+        referrer != Some(ctx.accounts.player.key()),
+        FundraiselyError::InvalidReferrer
+    );
+
+    // Early birds pay the early-bird fee; the charged fee is what gets recorded
+    let list_fee = current_entry_fee(
+        room.entry_fee,
+        room.early_bird_fee,
+        room.early_bird_count,
+        room.player_count,
+    );
+
+    // Wallets the host registered with add_discount pay the reduced fee
+    let entry_fee = if ctx.accounts.discount.is_some() && room.discount_bps > 0 {
+        discounted_entry_fee(list_fee, room.discount_bps)?
+    } else {
+        list_fee
+    };
+    let discount_amount = list_fee
+        .checked_sub(entry_fee)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+
     // Calculate total payment
-    let total_payment = room.entry_fee
+    let total_payment = entry_fee
         .checked_add(extras_amount)
         .ok_or(FundraiselyError::ArithmeticOverflow)?;
 
@@ -332,10 +400,13 @@ pub fn handler(
     let player_entry = &mut ctx.accounts.player_entry;
     player_entry.player = ctx.accounts.player.key();
     player_entry.room = room.key();
-    player_entry.entry_paid = room.entry_fee;
+    player_entry.entry_paid = entry_fee;
     player_entry.extras_paid = extras_amount;
     player_entry.total_paid = total_payment;
     player_entry.join_slot = Clock::get()?.slot;
+    player_entry.ready = false;
+    player_entry.referrer = referrer;
+    player_entry.discount_amount = discount_amount;
     player_entry.bump = ctx.bumps.player_entry;
 
     // Update room state
@@ -348,13 +419,17 @@ pub fn handler(
         .ok_or(FundraiselyError::ArithmeticOverflow)?;
 
     room.total_entry_fees = room.total_entry_fees
-        .checked_add(room.entry_fee)
+        .checked_add(entry_fee)
         .ok_or(FundraiselyError::ArithmeticOverflow)?;
 
     room.total_extras_fees = room.total_extras_fees
         .checked_add(extras_amount)
         .ok_or(FundraiselyError::ArithmeticOverflow)?;
 
+    room.total_discounts = room.total_discounts
+        .checked_add(discount_amount)
+        .ok_or(FundraiselyError::ArithmeticOverflow)?;
+
     // Change status to Active when first player joins
     if room.player_count == 1 {
         room.status = RoomStatus::Active;
@@ -363,6 +438,9 @@ pub fn handler(
     msg!("Player joined room");
     msg!("   Player: {}", ctx.accounts.player.key());
     msg!("   Payment: {} lamports", total_payment);
+    if discount_amount > 0 {
+        msg!("   Discount: {} lamports", discount_amount);
+    }
     msg!("   Room total: {} lamports", room.total_collected);
 
     // Emit event for off-chain indexers and frontend
@@ -372,6 +450,7 @@ pub fn handler(
         amount_paid: total_payment,
         extras_paid: extras_amount,
         player_count: room.player_count,
+        referrer,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
@@ -0,0 +1,147 @@
+//! # Leave Room Instruction
+//!
+//! Lets a player back out of a room before it's settled and get their money back, less
+//! the host's leave penalty.
+//!
+//! The player is refunded `total_paid * (10000 - leave_penalty_bps) / 10000` from the room
+//! vault. The penalty (including rounding dust) stays in the vault and is added to
+//! `Room.total_leave_penalties`, which `end_room` pays to charity. The player's entry and
+//! extras are taken off `total_entry_fees` / `total_extras_fees` in full, so they no longer
+//! feed the platform, host or prize split, and `total_collected` drops by the refund only.
+//! Any discount they had comes off `total_discounts`.
+//!
+//! When the last player leaves the room goes back to `Ready`, so the host can cancel it
+//! (`cancel_room` passes any penalties and donations left in the vault on to charity) or
+//! wait for new players.
+//!
+//! Early-bird pricing counts seats, not wallets: a player joining while fewer than
+//! `early_bird_count` players are in the room pays the early-bird fee, so a seat an early
+//! bird frees up is offered at the early-bird fee again. This is intended; the room never
+//! holds more than `early_bird_count` early-bird payers at once.
+//!
+//! The PlayerEntry is closed and its rent returned, so the player may rejoin later (paying
+//! again). A `PlayerLeft` event reports the refund and the penalty.
+//!
+//! ## Errors
+//!
+//! - `RoomAlreadyEnded` / `RoomFrozen` / `InvalidRoomStatus`: The room is not in play
+//! - `WinnersAlreadyDeclared`: The game has been settled
+//! - `RoomExpired`: The room is waiting to be finalized
+//! - `InvalidTokenOwner` / `InvalidTokenMint`: The refund account isn't the player's
+//!   account for the room's fee token
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::state::RoomStatus;
+use crate::errors::FundraiselyError;
+use crate::events::PlayerLeft;
+use crate::instructions::utils::{is_expired, leave_refund};
+
+/// Leave a room, refunding the player less the room's leave penalty
+pub fn handler(ctx: Context<crate::LeaveRoom>, _room_id: String) -> Result<()> {
+    let room = &mut ctx.accounts.room;
+    let player_entry = &ctx.accounts.player_entry;
+
+    require!(!room.ended, FundraiselyError::RoomAlreadyEnded);
+
+    require!(
+        room.status != RoomStatus::Frozen,
+        FundraiselyError::RoomFrozen
+    );
+
+    require!(
+        room.status == RoomStatus::Active,
+        FundraiselyError::InvalidRoomStatus
+    );
+
+    // Once winners are declared the players' fees are committed to the payout
+    require!(room.winners.is_empty(), FundraiselyError::WinnersAlreadyDeclared);
+
+    let clock = Clock::get()?;
+    require!(
+        !is_expired(room.expiration_slot, room.expiration_unix_ts, clock.slot, clock.unix_timestamp),
+        FundraiselyError::RoomExpired
+    );
+
+    let (refund, penalty) = leave_refund(player_entry.total_paid, room.leave_penalty_bps)?;
+
+    // Update room state
+    room.player_count = room.player_count
+        .checked_sub(1)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+
+    if player_entry.ready {
+        room.ready_count = room.ready_count
+            .checked_sub(1)
+            .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+    }
+
+    room.total_collected = room.total_collected
+        .checked_sub(refund)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+
+    room.total_entry_fees = room.total_entry_fees
+        .checked_sub(player_entry.entry_paid)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+
+    room.total_extras_fees = room.total_extras_fees
+        .checked_sub(player_entry.extras_paid)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+
+    room.total_discounts = room.total_discounts
+        .checked_sub(player_entry.discount_amount)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+
+    room.total_leave_penalties = room.total_leave_penalties
+        .checked_add(penalty)
+        .ok_or(FundraiselyError::ArithmeticOverflow)?;
+
+    // An empty room is open to cancellation again, as before the first join
+    if room.player_count == 0 {
+        room.status = RoomStatus::Ready;
+    }
+
+    // Refund from the vault, signed by the room PDA
+    if refund > 0 {
+        let host_key = room.host;
+        let room_id_bytes = room.room_id.as_bytes();
+        let bump = room.bump;
+        let seeds = &[
+            b"room",
+            host_key.as_ref(),
+            room_id_bytes,
+            &[bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.room_vault.to_account_info(),
+                    to: ctx.accounts.player_token_account.to_account_info(),
+                    authority: room.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            refund,
+        )?;
+    }
+
+    msg!("Player left: {}", player_entry.player);
+    msg!("   Refund: {}, Penalty to charity: {}", refund, penalty);
+    msg!("   Players: {}/{}", room.player_count, room.max_players);
+
+    emit!(PlayerLeft {
+        room: room.key(),
+        player: player_entry.player,
+        refund,
+        penalty,
+        player_count: room.player_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Note: LeaveRoom struct is in lib.rs for Anchor macro compatibility
@@ -9,13 +9,17 @@
 //! ## Instructions
 //!
 //! - **join_room**: Pay entry fee + optional extras to join a room
+//! - **ready_up**: Signal readiness to start game (for turn-based modes)
+//! - **leave_room**: Exit a room before it's settled, less the host's leave penalty
+//! - **donate**: Give to a room's charity without taking a player slot
 //!
 //! ## Future Player Instructions
 //!
-//! - **leave_room**: Exit room before game starts (if allowed by host)
-//! - **ready_up**: Signal readiness to start game (for turn-based modes)
 //! - **add_extras**: Contribute additional charity donation after joining
 
 pub mod join_room;
+pub mod ready_up;
+pub mod leave_room;
+pub mod donate;
 
 // JoinRoom struct is now in lib.rs for Anchor macro compatibility
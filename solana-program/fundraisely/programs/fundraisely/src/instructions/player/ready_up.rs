@@ -0,0 +1,62 @@
+//! # Ready Up Instruction
+//!
+//! Lets a player signal they are ready to start a turn-based game.
+//!
+//! Sets `ready` on the caller's PlayerEntry and bumps `Room.ready_count`. In rooms created
+//! with `require_ready`, the host can only declare winners (or end the room) once every
+//! player has readied up, so a game can't be settled while players are still waiting to
+//! take their turn. Rooms without the requirement still track readiness for the UI.
+//!
+//! A `PlayerReady` event is emitted with the running count so clients can show
+//! "3 of 5 ready" without polling.
+//!
+//! ## Errors
+//!
+//! - `PlayerAlreadyReady`: The player has already readied up in this room
+//! - `RoomAlreadyEnded` / `RoomFrozen` / `InvalidRoomStatus`: The room is not in play
+
+use anchor_lang::prelude::*;
+use crate::state::RoomStatus;
+use crate::errors::FundraiselyError;
+use crate::events::PlayerReady;
+
+/// Mark the calling player as ready
+pub fn handler(ctx: Context<crate::ReadyUp>, _room_id: String) -> Result<()> {
+    let room = &mut ctx.accounts.room;
+    let player_entry = &mut ctx.accounts.player_entry;
+
+    require!(!room.ended, FundraiselyError::RoomAlreadyEnded);
+
+    require!(
+        room.status != RoomStatus::Frozen,
+        FundraiselyError::RoomFrozen
+    );
+
+    require!(
+        room.status == RoomStatus::Active,
+        FundraiselyError::InvalidRoomStatus
+    );
+
+    require!(!player_entry.ready, FundraiselyError::PlayerAlreadyReady);
+
+    player_entry.ready = true;
+    room.ready_count = room
+        .ready_count
+        .checked_add(1)
+        .ok_or(FundraiselyError::ArithmeticOverflow)?;
+
+    msg!("Player ready: {}", player_entry.player);
+    msg!("   Ready: {}/{}", room.ready_count, room.player_count);
+
+    emit!(PlayerReady {
+        room: room.key(),
+        player: player_entry.player,
+        ready_count: room.ready_count,
+        player_count: room.player_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Note: ReadyUp struct is in lib.rs for Anchor macro compatibility
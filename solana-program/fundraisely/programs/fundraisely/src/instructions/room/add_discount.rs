@@ -0,0 +1,39 @@
+//! # Add Discount Instruction
+//!
+//! Host registration of a wallet that may join at the room's discounted entry fee.
+//!
+//! Creates the `Discount` PDA ["discount", room, player] (rent paid by the host). When
+//! that wallet joins, `join_room` charges `entry_fee * (10000 - discount_bps) / 10000` and
+//! adds the waived amount to `Room.total_discounts`. Only rooms created with a non-zero
+//! `discount_bps` accept discounts, and only until they end; registering the same wallet
+//! twice fails because the PDA already exists.
+
+use anchor_lang::prelude::*;
+use crate::errors::FundraiselyError;
+
+/// Let a wallet join a room at its discounted entry fee (host only)
+pub fn handler(ctx: Context<crate::AddDiscount>, _room_id: String, player: Pubkey) -> Result<()> {
+    let room = &ctx.accounts.room;
+
+    require!(
+        ctx.accounts.host.key() == room.host,
+        FundraiselyError::Unauthorized
+    );
+
+    require!(!room.ended, FundraiselyError::RoomAlreadyEnded);
+
+    require!(room.discount_bps > 0, FundraiselyError::InvalidDiscount);
+
+    let discount = &mut ctx.accounts.discount;
+    discount.room = room.key();
+    discount.player = player;
+    discount.bump = ctx.bumps.discount;
+
+    msg!("Discount added for room: {}", room.room_id);
+    msg!("   Player: {}", player);
+    msg!("   Discount: {}bps", room.discount_bps);
+
+    Ok(())
+}
+
+// Note: AddDiscount struct is in lib.rs for Anchor macro compatibility
@@ -0,0 +1,159 @@
+//! # Cancel Room Instruction
+//!
+//! Host closes a room nobody has joined, e.g. after creating it with the wrong mint or fees.
+//!
+//! ## What This Instruction Does
+//!
+//! 1. Checks the room is still empty (`player_count == 0`, `RoomHasPlayers` otherwise)
+//! 2. Sends what players left behind to charity: leave penalties and donations, kept
+//!    when every player left again (`total_collected`)
+//! 3. Returns any guaranteed minimum prize escrow in the vault to the host
+//! 4. Closes the room vault, sending its rent to the host
+//! 5. Closes the Room PDA (`close = host`), reclaiming its rent
+//! 6. Emits `RoomCancelled`
+//!
+//! The room ID can be reused afterwards, since the Room PDA no longer exists.
+//!
+//! ## Error Conditions
+//!
+//! - Signer is not the room's host (seeds mismatch / Unauthorized)
+//! - Room already ended (RoomAlreadyEnded) or is frozen (RoomFrozen)
+//! - A player has joined (RoomHasPlayers)
+//! - Asset room with prizes deposited (InvalidRoomStatus); only rooms still awaiting
+//!   funding can be cancelled, since escrowed prizes would stay locked in their vaults
+//! - Host token account doesn't hold the room's fee token (InvalidTokenMint)
+//! - Funds held for charity but no charity token account (CharityTokenAccountRequired), or
+//!   one the charity wallet doesn't own or for another mint (InvalidTokenOwner /
+//!   InvalidTokenMint)
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Transfer};
+use crate::state::{PrizeMode, RoomStatus};
+use crate::errors::FundraiselyError;
+use crate::events::RoomCancelled;
+
+/// Close an empty room and its vault, refunding rent and any escrow to the host
+pub fn handler(ctx: Context<crate::CancelRoom>, _room_id: String) -> Result<()> {
+    let room = &ctx.accounts.room;
+
+    require!(
+        ctx.accounts.host.key() == room.host,
+        FundraiselyError::Unauthorized
+    );
+
+    require!(!room.ended, FundraiselyError::RoomAlreadyEnded);
+
+    require!(
+        room.status != RoomStatus::Frozen,
+        FundraiselyError::RoomFrozen
+    );
+
+    require!(room.player_count == 0, FundraiselyError::RoomHasPlayers);
+
+    // Pool rooms are Ready until the first join; asset rooms must have no prizes in escrow
+    let cancellable = match room.prize_mode {
+        PrizeMode::PoolSplit => room.status == RoomStatus::Ready,
+        PrizeMode::AssetBased => room.status == RoomStatus::AwaitingFunding,
+    };
+    require!(cancellable, FundraiselyError::InvalidRoomStatus);
+
+    require!(
+        ctx.accounts.host_token_account.mint == room.fee_token_mint,
+        FundraiselyError::InvalidTokenMint
+    );
+
+    let host_key = room.host;
+    let bump = room.bump;
+    let room_id_bytes = room.room_id.as_bytes();
+    let seeds = &[
+        b"room",
+        host_key.as_ref(),
+        room_id_bytes,
+        &[bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Penalties and donations from players who have all left belong to charity
+    let to_charity = room.total_collected;
+    if to_charity > 0 {
+        let charity_token_account = ctx
+            .accounts
+            .charity_token_account
+            .as_ref()
+            .ok_or(FundraiselyError::CharityTokenAccountRequired)?;
+
+        require!(
+            charity_token_account.owner == room.charity_wallet,
+            FundraiselyError::InvalidTokenOwner
+        );
+        require!(
+            charity_token_account.mint == room.fee_token_mint,
+            FundraiselyError::InvalidTokenMint
+        );
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.room_vault.to_account_info(),
+                    to: charity_token_account.to_account_info(),
+                    authority: room.to_account_info(),
+                },
+                signer,
+            ),
+            to_charity,
+        )?;
+    }
+
+    // Return the guaranteed minimum prize escrow (all that's left in the vault)
+    let refunded_escrow = ctx
+        .accounts
+        .room_vault
+        .amount
+        .checked_sub(to_charity)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+    if refunded_escrow > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.room_vault.to_account_info(),
+                    to: ctx.accounts.host_token_account.to_account_info(),
+                    authority: room.to_account_info(),
+                },
+                signer,
+            ),
+            refunded_escrow,
+        )?;
+    }
+
+    // Close the now-empty vault; the Room PDA itself is closed by `close = host`
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.room_vault.to_account_info(),
+            destination: ctx.accounts.host.to_account_info(),
+            authority: room.to_account_info(),
+        },
+        signer,
+    ))?;
+
+    msg!("Room cancelled: {}", room.room_id);
+    if to_charity > 0 {
+        msg!("   Left by departed players, sent to charity: {}", to_charity);
+    }
+    if refunded_escrow > 0 {
+        msg!("   Guaranteed prize escrow of {} returned to host", refunded_escrow);
+    }
+
+    emit!(RoomCancelled {
+        room: room.key(),
+        host: host_key,
+        refunded_escrow,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Note: CancelRoom struct is in lib.rs for Anchor macro compatibility
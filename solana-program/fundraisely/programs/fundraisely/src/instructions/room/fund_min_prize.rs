@@ -0,0 +1,75 @@
+//! # Fund Min Prize Instruction
+//!
+//! Lets the host of a pool room guarantee a minimum prize by escrowing it into the room vault
+//! before any player joins. At `end_room`, a pool smaller than the guarantee is topped up from
+//! the escrow and the unused escrow goes to charity; a pool at or above the guarantee pays out
+//! as usual and the whole escrow goes to charity. The escrow only returns to the host if the
+//! room is recovered instead of ended. See the Room state docs for the full policy.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::state::{PrizeMode, RoomStatus};
+use crate::errors::FundraiselyError;
+use crate::events::MinPrizeFunded;
+
+/// Escrow a guaranteed minimum prize into the room vault
+pub fn handler(ctx: Context<crate::FundMinPrize>, _room_id: String, amount: u64) -> Result<()> {
+    let room = &mut ctx.accounts.room;
+
+    // Only pool rooms have a prize that depends on turnout
+    require!(
+        room.prize_mode == PrizeMode::PoolSplit,
+        FundraiselyError::InvalidRoomStatus
+    );
+
+    // Must be host
+    require!(
+        ctx.accounts.host.key() == room.host,
+        FundraiselyError::Unauthorized
+    );
+
+    // The guarantee must be known before anyone pays to join
+    require!(
+        room.status == RoomStatus::Ready && room.player_count == 0,
+        FundraiselyError::InvalidRoomStatus
+    );
+
+    require!(
+        room.guaranteed_min_prize == 0,
+        FundraiselyError::MinPrizeAlreadyFunded
+    );
+
+    require!(amount > 0, FundraiselyError::InvalidPrizeAmount);
+
+    require!(
+        ctx.accounts.host_token_account.mint == room.fee_token_mint,
+        FundraiselyError::InvalidTokenMint
+    );
+
+    // Transfer escrow from host to room vault
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.host_token_account.to_account_info(),
+            to: ctx.accounts.room_vault.to_account_info(),
+            authority: ctx.accounts.host.to_account_info(),
+        },
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    room.guaranteed_min_prize = amount;
+
+    msg!("Guaranteed minimum prize funded: {}", amount);
+    msg!("   Room: {}", room.room_id);
+
+    emit!(MinPrizeFunded {
+        room: room.key(),
+        host: ctx.accounts.host.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// Note: Account struct is in lib.rs
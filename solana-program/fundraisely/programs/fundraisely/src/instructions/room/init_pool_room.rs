@@ -44,7 +44,17 @@
 //!   - max_players: Room capacity (1-1000 players)
 //!   - host_fee_bps: Host compensation (0-500 = 0-5%)
 //!   - prize_pool_bps: Prize pool size (0-3500 = 0-35%)
-//!   - [first|second|third]_place_pct: Prize split percentages (must sum to 100)
+//!   - prize_distribution: Prize split percentages per place, up to MAX_WINNERS (must sum to 100)
+//!
+//! Optional:
+//!   - min_players: Quorum; if unmet, recover_room refunds players in full (0/None = no quorum)
+//!   - require_ready: Turn-based games; winners wait until every player calls ready_up
+//!   - prize_tiers: Up to MAX_PRIZE_TIERS (min_players, distribution) pairs; end_room pays
+//!     with the tier the final player count reaches (see state/room.rs)
+//!   - early_bird_fee / early_bird_count: The first early_bird_count players pay
+//!     early_bird_fee (<= entry_fee; defaults to entry_fee) instead of entry_fee
+//!   - leave_penalty_bps: Share of a leaver's payment kept for charity by leave_room
+//!     (0-2000 = 0-20%, default 0)
 //!
 //! Auto-calculated:
 //!   - charity_bps: 10000 - platform_fee(2000) - host_fee_bps - prize_pool_bps
@@ -63,12 +73,13 @@
 //!
 //! ## Prize Distribution
 //!
-//! Hosts specify how prizes are split among up to 3 winners:
+//! Hosts specify how prizes are split among up to MAX_WINNERS (10) winners:
 //!
 //! ```text
-//! - Winner-takes-all: [100, 0, 0]
+//! - Winner-takes-all: [100]
 //! - Top-heavy: [70, 20, 10]
 //! - Balanced: [50, 30, 20]
+//! - Raffle tiers: [30, 20, 15, 10, 10, 5, 5, 5]
 //! - Percentages must sum to exactly 100
 //! ```
 //!
@@ -79,11 +90,21 @@
 //! ```text
 //! - expiration_slots: Number of slots after which room expires
 //! - Typical value: 43,200 (approximately 24 hours on Solana)
+//! - expiration_unix_ts: Unix timestamp the room expires at (must be in the future)
+//! - Either or both can be set; the room expires when the first one passes
 //! - After expiration, anyone can end the room (not just host)
 //! - Prevents abandoned rooms from locking player funds
-//! - Set to None/0 for no expiration (manual host closure required)
+//! - expiration_slots must be 1..=GlobalConfig.max_expiration_slots (InvalidExpiration)
+//! - With neither set, the room expires after GlobalConfig.default_expiration_slots
 //! ```
 //!
+//! ## Private Rooms
+//!
+//! `allowlist_root` restricts entry to invited wallets: it is the merkle root of the
+//! guest list, and `join_room` checks each player's proof against it. None (or all
+//! zeros) keeps the room public. See `utils::verify_allowlist_proof` for how leaves
+//! and nodes are hashed.
+//!
 //! ## PDA Security
 //!
 //! Two accounts are created with deterministic addresses:
@@ -153,13 +174,23 @@
 //! The instruction enforces these constraints:
 //!
 //! 1. **Emergency Pause Check**: Fails if GlobalConfig.emergency_pause is true
-//! 2. **Room ID Length**: 1-32 characters (prevents storage bloat)
-//! 3. **Entry Fee**: Must be > 0 (free rooms not allowed)
-//! 4. **Max Players**: 1-1000 (prevents DoS via unbounded storage)
-//! 5. **Host Fee**: 0-500 bps (0-5%, enforced by GlobalConfig.max_host_fee_bps)
-//! 6. **Prize Pool**: 0-3500 bps (0-35%, enforced by GlobalConfig.max_prize_pool_bps)
-//! 7. **Prize Distribution**: first + second + third = 100 exactly
-//! 8. **Charity Minimum**: charity_bps >= 4000 (40%, enforced by GlobalConfig.min_charity_bps)
+//! 2. **Charity Wallet**: Not the default pubkey or GlobalConfig.platform_wallet
+//! 3. **Room ID Length**: 1-32 characters (prevents storage bloat)
+//! 4. **Entry Fee**: Must be > 0 (free rooms not allowed)
+//! 5. **Max Players**: 1-1000 (prevents DoS via unbounded storage)
+//! 6. **Host Fee**: 0-500 bps (0-5%, enforced by GlobalConfig.max_host_fee_bps)
+//! 7. **Prize Pool**: 0-3500 bps (0-35%, enforced by GlobalConfig.max_prize_pool_bps)
+//! 8. **Prize Distribution**: 1 to MAX_WINNERS places summing to 100 exactly
+//! 9. **Total Allocation**: platform + host + prize pool <= 10000 bps
+//! 10. **Charity Minimum**: charity_bps >= 4000 (40%, enforced by GlobalConfig.min_charity_bps)
+//! 11. **Charity Splits** (optional): 1 to MAX_CHARITIES unique wallets starting with
+//!     `charity_wallet`, non-zero weights summing to 10000 bps
+//! 12. **Fee Token Mint**: No Token-2022 transfer fee (the vault would receive less than
+//!     the entry fees recorded for the split)
+//! 13. **Early-Bird Fee**: At most `entry_fee`
+//! 14. **Discount**: `discount_bps` must leave charity at least `min_charity_bps` of the
+//!     full entry fee (`charity_bps * (10000 - discount_bps) >= min_charity_bps * 10000`)
+//! 15. **Leave Penalty**: At most MAX_LEAVE_PENALTY_BPS (20%)
 //!
 //! ## Error Conditions
 //!
@@ -167,12 +198,22 @@
 //! - Room with same (host, room_id) already exists
 //! - Host fee exceeds 5% (HostFeeTooHigh)
 //! - Prize pool exceeds 35% (PrizePoolTooHigh)
+//! - Platform, host and prize pool together exceed 100% (TotalAllocationTooHigh)
 //! - Charity would be below 40% (CharityBelowMinimum)
 //! - Prize distribution doesn't sum to 100 (InvalidPrizeDistribution)
+//! - Charity splits are malformed (InvalidCharitySplits)
+//! - Charity wallet is the default pubkey or the platform wallet (InvalidCharityWallet)
 //! - Invalid room_id length (InvalidRoomId)
 //! - Invalid entry_fee (InvalidEntryFee)
 //! - Invalid max_players (InvalidMaxPlayers)
+//! - min_players above max_players (InvalidMinPlayers)
+//! - early_bird_fee above entry_fee (InvalidEarlyBirdFee)
+//! - discount_bps would cut charity below its minimum (InvalidDiscount)
+//! - leave_penalty_bps above 20% (InvalidLeavePenalty)
 //! - Emergency pause is active (EmergencyPause)
+//! - Fee token mint has a transfer fee (UnsupportedMintExtension)
+//! - Room vault doesn't hold the fee token or isn't controlled by the room
+//!   (InvalidVaultAccount / InvalidVaultAuthority)
 //! - Insufficient lamports for rent
 //!
 //! ## On-Chain Logs
@@ -192,7 +233,7 @@
 //! - room_id: Human-readable identifier
 //! - host: Host's pubkey
 //! - entry_fee, max_players: Configuration
-//! - expiration_slot: When room expires (0 = never)
+//! - expiration_slot, expiration_unix_ts: When room expires (0 = no deadline of that kind)
 //! - timestamp: Unix timestamp of creation
 //!
 //! ## Related Files
@@ -213,9 +254,13 @@
 //! - **Deterministic Addressing**: Room addresses derived from (host + room_id) prevent collisions
 
 use anchor_lang::prelude::*;
-use crate::state::{RoomStatus, PrizeMode};
+use crate::state::{CharitySplit, PrizeTier, RoomStatus, PrizeMode, MAX_LEAVE_PENALTY_BPS, ROOM_VERSION};
 use crate::errors::FundraiselyError;
 use crate::events::RoomCreated;
+use crate::instructions::utils::{
+    charity_allocation_bps, expiration_slot, validate_charity_splits, validate_charity_wallet, validate_discount,
+    validate_mint_extensions, validate_prize_distribution, validate_prize_tiers, validate_room_vault,
+};
 
 /// Create a pool-based room where prizes come from entry fee pool
 pub fn handler(
@@ -226,11 +271,19 @@ pub fn handler(
     max_players: u32,
     host_fee_bps: u16,
     prize_pool_bps: u16,
-    first_place_pct: u16,
-    second_place_pct: Option<u16>,
-    third_place_pct: Option<u16>,
+    prize_distribution: Vec<u16>,
     charity_memo: String,
     expiration_slots: Option<u64>,
+    charity_splits: Option<Vec<CharitySplit>>,
+    min_players: Option<u32>,
+    require_ready: Option<bool>,
+    expiration_unix_ts: Option<i64>,
+    allowlist_root: Option<[u8; 32]>,
+    prize_tiers: Option<Vec<PrizeTier>>,
+    early_bird_fee: Option<u64>,
+    early_bird_count: Option<u32>,
+    discount_bps: Option<u16>,
+    leave_penalty_bps: Option<u16>,
 ) -> Result<()> {
     // Validation
     require!(
@@ -238,38 +291,31 @@ pub fn handler(
         FundraiselyError::EmergencyPause
     );
 
-    // SECURITY: Validate room_vault is a proper TokenAccount if it already exists
-    // If vault is not yet initialized, this validation is skipped (frontend will create it)
-    if !ctx.accounts.room_vault.data_is_empty() {
-        use anchor_spl::token::TokenAccount;
-
-        let vault_data = ctx.accounts.room_vault.try_borrow_data()
-            .map_err(|_| FundraiselyError::InvalidVaultAccount)?;
-
-        let vault_account = TokenAccount::try_deserialize(&mut vault_data.as_ref())
-            .map_err(|_| FundraiselyError::InvalidVaultAccount)?;
-
-        // Verify vault has correct mint
-        require!(
-            vault_account.mint == ctx.accounts.fee_token_mint.key(),
-            FundraiselyError::InvalidTokenMint
-        );
-
-        // Verify vault authority is the room PDA
-        require!(
-            vault_account.owner == ctx.accounts.room.key(),
-            FundraiselyError::InvalidVaultAuthority
-        );
+    // A transfer-fee mint would leave the vault short of the recorded entry fees
+    {
+        let fee_token_mint = ctx.accounts.fee_token_mint.to_account_info();
+        validate_mint_extensions(fee_token_mint.owner, &fee_token_mint.try_borrow_data()?)?;
     }
 
+    // SECURITY: The vault must hold the fee token and only the room PDA may move funds out
+    validate_room_vault(
+        &ctx.accounts.room_vault.mint,
+        &ctx.accounts.room_vault.owner,
+        &ctx.accounts.fee_token_mint.key(),
+        &ctx.accounts.room.key(),
+    )?;
+
     // Validate token is approved in registry
     require!(
         ctx.accounts.token_registry.is_token_approved(&ctx.accounts.fee_token_mint.key()),
         FundraiselyError::TokenNotApproved
     );
 
+    // Donations must reach a real wallet that isn't the platform's
+    validate_charity_wallet(&charity_wallet, &ctx.accounts.global_config.platform_wallet)?;
+
     require!(
-        room_id.len() <= 32 && room_id.len() > 0,
+        !room_id.is_empty() && room_id.len() <= 32,
         FundraiselyError::InvalidRoomId
     );
 
@@ -278,6 +324,18 @@ pub fn handler(
         FundraiselyError::InvalidEntryFee
     );
 
+    // Early-bird window: the first players may pay less, never more
+    let early_bird_count = early_bird_count.unwrap_or(0);
+    let early_bird_fee = if early_bird_count > 0 {
+        early_bird_fee.unwrap_or(entry_fee)
+    } else {
+        0
+    };
+    require!(
+        early_bird_fee <= entry_fee,
+        FundraiselyError::InvalidEarlyBirdFee
+    );
+
     // Validate max_players (must be reasonable to prevent DoS)
     const MAX_PLAYERS_LIMIT: u32 = 1000;
     require!(
@@ -285,6 +343,18 @@ pub fn handler(
         FundraiselyError::InvalidMaxPlayers
     );
 
+    let leave_penalty_bps = leave_penalty_bps.unwrap_or(0);
+    require!(
+        leave_penalty_bps <= MAX_LEAVE_PENALTY_BPS,
+        FundraiselyError::InvalidLeavePenalty
+    );
+
+    let min_players = min_players.unwrap_or(0);
+    require!(
+        min_players <= max_players,
+        FundraiselyError::InvalidMinPlayers
+    );
+
     // Validate host fee (max 5%)
     require!(
         host_fee_bps <= ctx.accounts.global_config.max_host_fee_bps,
@@ -297,14 +367,21 @@ pub fn handler(
         FundraiselyError::PrizePoolTooHigh
     );
 
-    // Validate prize distribution sums to 100
-    let total_prize_pct = first_place_pct
-        + second_place_pct.unwrap_or(0)
-        + third_place_pct.unwrap_or(0);
-    require!(
-        total_prize_pct == 100,
-        FundraiselyError::InvalidPrizeDistribution
-    );
+    // Validate prize distribution: 1 to MAX_WINNERS places summing to 100
+    validate_prize_distribution(&prize_distribution)?;
+
+    // Validate prize tiers: increasing player thresholds, each distribution summing to 100
+    let prize_tiers = prize_tiers.unwrap_or_default();
+    validate_prize_tiers(&prize_tiers)?;
+
+    // Validate charity splits; a single charity keeps the default single-wallet path
+    let mut charity_splits = charity_splits.unwrap_or_default();
+    if !charity_splits.is_empty() {
+        validate_charity_splits(&charity_splits, &charity_wallet)?;
+    }
+    if charity_splits.len() == 1 {
+        charity_splits.clear();
+    }
 
     // Initialize room
     let room = &mut ctx.accounts.room;
@@ -318,10 +395,7 @@ pub fn handler(
 
     // Calculate charity percentage (remainder after platform + host + prizes)
     let platform_bps = ctx.accounts.global_config.platform_fee_bps;
-    room.charity_bps = 10000_u16
-        .saturating_sub(platform_bps)
-        .saturating_sub(host_fee_bps)
-        .saturating_sub(prize_pool_bps);
+    room.charity_bps = charity_allocation_bps(platform_bps, host_fee_bps, prize_pool_bps)?;
 
     // Enforce minimum charity allocation (40%)
     require!(
@@ -329,8 +403,12 @@ pub fn handler(
         FundraiselyError::CharityBelowMinimum
     );
 
+    // Discounted entries must still leave charity its minimum share of the full fee
+    let discount_bps = discount_bps.unwrap_or(0);
+    validate_discount(discount_bps, room.charity_bps, ctx.accounts.global_config.min_charity_bps)?;
+
     room.prize_mode = PrizeMode::PoolSplit;
-    room.prize_distribution = vec![first_place_pct, second_place_pct.unwrap_or(0), third_place_pct.unwrap_or(0)];
+    room.prize_distribution = prize_distribution;
     room.status = RoomStatus::Ready;
     room.player_count = 0;
     room.max_players = max_players;
@@ -338,27 +416,89 @@ pub fn handler(
     room.total_entry_fees = 0;
     room.total_extras_fees = 0;
     room.ended = false;
-    room.winners = [None, None, None]; // Winners not yet declared
-    room.prize_assets = [None, None, None]; // No asset prizes for pool-based rooms
+    room.winners = Vec::new(); // Winners not yet declared
+    room.prize_assets = std::array::from_fn(|_| None); // No asset prizes for pool-based rooms
 
     let current_slot = Clock::get()?.slot;
     room.creation_slot = current_slot;
 
-    // Set expiration slot if specified
-    room.expiration_slot = if let Some(slots) = expiration_slots {
-        current_slot.checked_add(slots).unwrap_or(0)
-    } else {
-        0 // No expiration
+    // Expiration slot: the host's (within the platform cap), or the platform default
+    // when the room has no deadline at all
+    let global_config = &ctx.accounts.global_config;
+    room.expiration_slot = expiration_slot(
+        current_slot,
+        expiration_slots,
+        matches!(expiration_unix_ts, Some(ts) if ts > 0),
+        global_config.default_expiration_slots,
+        global_config.max_expiration_slots,
+    )?;
+
+    // Set expiration timestamp if specified; a deadline already past would expire the room at once
+    let now = Clock::get()?.unix_timestamp;
+    room.expiration_unix_ts = match expiration_unix_ts {
+        Some(ts) if ts > 0 => {
+            require!(ts > now, FundraiselyError::RoomExpired);
+            ts
+        }
+        _ => 0, // No expiration
     };
 
     room.charity_memo = charity_memo;
+    room.pre_freeze_status = None;
+    room.guaranteed_min_prize = 0;
+    room.charity_receipt = None;
+    room.winner_scores = Vec::new();
+    room.charity_splits = charity_splits;
+    room.min_players = min_players;
+    room.require_ready = require_ready.unwrap_or(false);
+    room.ready_count = 0;
+    room.allowlist_root = allowlist_root.unwrap_or([0u8; 32]);
+    room.platform_fee_bps = platform_bps;
+    room.prize_tiers = prize_tiers;
+    room.host_fee_waived = false;
+    room.early_bird_fee = early_bird_fee;
+    room.early_bird_count = early_bird_count;
+    room.discount_bps = discount_bps;
+    room.total_discounts = 0;
+    room.place_sizes = Vec::new();
     room.bump = ctx.bumps.room;
+    room.room_version = ROOM_VERSION;
+    room.leave_penalty_bps = leave_penalty_bps;
+    room.total_leave_penalties = 0;
+    room.total_donations = 0;
 
     msg!("Pool room created: {}", room_id);
     msg!("   Entry fee: {} lamports", entry_fee);
     msg!("   Max players: {}", max_players);
+    if min_players > 0 {
+        msg!("   Min players: {}", min_players);
+    }
+    if room.require_ready {
+        msg!("   Players must ready up before winners are declared");
+    }
+    if room.expiration_unix_ts > 0 {
+        msg!("   Expires at unix time {}", room.expiration_unix_ts);
+    }
+    if early_bird_count > 0 {
+        msg!("   Early-bird fee: {} for the first {} players", early_bird_fee, early_bird_count);
+    }
+    if discount_bps > 0 {
+        msg!("   Discount: {}bps for wallets the host registers", discount_bps);
+    }
+    if leave_penalty_bps > 0 {
+        msg!("   Leave penalty: {}bps kept for charity", leave_penalty_bps);
+    }
+    if room.allowlist_root != [0u8; 32] {
+        msg!("   Private room: joins require an allowlist proof");
+    }
+    for tier in room.prize_tiers.iter() {
+        msg!("   From {} players, prizes split {:?}", tier.min_players, tier.distribution);
+    }
     msg!("   Host fee: {}bps, Prize pool: {}bps, Charity: {}bps",
         host_fee_bps, prize_pool_bps, room.charity_bps);
+    for split in room.charity_splits.iter() {
+        msg!("   Charity {}: {}bps", split.wallet, split.weight_bps);
+    }
 
     // Emit event for off-chain indexers and frontend
     emit!(RoomCreated {
@@ -368,7 +508,8 @@ pub fn handler(
         entry_fee,
         max_players,
         expiration_slot: room.expiration_slot,
-        timestamp: Clock::get()?.unix_timestamp,
+        expiration_unix_ts: room.expiration_unix_ts,
+        timestamp: now,
     });
 
     Ok(())
@@ -9,14 +9,24 @@
 //! ## Instructions
 //!
 //! - **init_pool_room**: Create new room with pool-based prize distribution
+//! - **add_discount**: Host registers a wallet for the room's discounted entry fee
+//! - **fund_min_prize**: Escrow a guaranteed minimum prize before players join
+//! - **waive_ready_check**: Host lifts the readiness gate of a turn-based room
+//! - **waive_host_fee**: Host sends their fee to charity when the room ends
+//! - **cancel_room**: Host closes a room nobody has joined, reclaiming its rent
+//!
+//! Asset-based rooms (escrowed prizes) live in the `asset` module.
 //!
 //! ## Future Room Instructions
 //!
-//! - **init_asset_room**: Create room with pre-deposited prize assets (Phase 2)
-//! - **deposit_prize_asset**: Add NFT/token prizes to asset room (Phase 2)
 //! - **close_joining**: Stop accepting new players before max_players reached
 //! - **update_expiration**: Extend or shorten room expiration time
 
 pub mod init_pool_room;
+pub mod add_discount;
+pub mod fund_min_prize;
+pub mod waive_ready_check;
+pub mod waive_host_fee;
+pub mod cancel_room;
 
 // InitPoolRoom struct is now in lib.rs for Anchor macro compatibility
@@ -0,0 +1,32 @@
+//! # Waive Host Fee Instruction
+//!
+//! Host opt-out of the host fee, for nonprofit hosts who want their share to go to charity.
+//!
+//! Once waived, `end_room` (and `end_asset_room`) adds the computed host fee to the charity
+//! amount instead of transferring it to the host's token account, and reports the decision
+//! in `RoomEnded.host_fee_waived`. The waiver is one-way and must happen before the room
+//! ends; rooms that never waive are paid exactly as before.
+
+use anchor_lang::prelude::*;
+use crate::errors::FundraiselyError;
+
+/// Send a room's host fee to charity when it ends (host only)
+pub fn handler(ctx: Context<crate::WaiveHostFee>, _room_id: String) -> Result<()> {
+    let room = &mut ctx.accounts.room;
+
+    require!(
+        ctx.accounts.host.key() == room.host,
+        FundraiselyError::Unauthorized
+    );
+
+    require!(!room.ended, FundraiselyError::RoomAlreadyEnded);
+
+    room.host_fee_waived = true;
+
+    msg!("Host fee waived for room: {}", room.room_id);
+    msg!("   {}bps of entry fees will go to charity", room.host_fee_bps);
+
+    Ok(())
+}
+
+// Note: WaiveHostFee struct is in lib.rs for Anchor macro compatibility
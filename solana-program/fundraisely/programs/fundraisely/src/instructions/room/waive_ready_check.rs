@@ -0,0 +1,32 @@
+//! # Waive Ready Check Instruction
+//!
+//! Host override for rooms created with `require_ready`.
+//!
+//! If a player never readies up (they went idle or lost their wallet), the room would be
+//! stuck: winners can't be declared until `ready_count == player_count`. The host can
+//! clear `require_ready` so the game can be settled with whoever is ready. The override is
+//! one-way; readiness keeps being tracked but no longer gates `declare_winners`/`end_room`.
+
+use anchor_lang::prelude::*;
+use crate::errors::FundraiselyError;
+
+/// Lift a room's readiness requirement (host only)
+pub fn handler(ctx: Context<crate::WaiveReadyCheck>, _room_id: String) -> Result<()> {
+    let room = &mut ctx.accounts.room;
+
+    require!(
+        ctx.accounts.host.key() == room.host,
+        FundraiselyError::Unauthorized
+    );
+
+    require!(!room.ended, FundraiselyError::RoomAlreadyEnded);
+
+    room.require_ready = false;
+
+    msg!("Ready check waived for room: {}", room.room_id);
+    msg!("   Ready: {}/{}", room.ready_count, room.player_count);
+
+    Ok(())
+}
+
+// Note: WaiveReadyCheck struct is in lib.rs for Anchor macro compatibility
@@ -3,7 +3,9 @@
 //! Helper functions used across multiple instructions for common calculations.
 
 use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
 use crate::errors::FundraiselyError;
+use crate::state::{CharitySplit, PrizeAsset, PrizeTier, RoomStatus, MAX_CHARITIES, MAX_PRIZE_TIERS, MAX_WINNERS};
 
 /// Calculate basis points (percentage) of an amount
 ///
@@ -33,6 +35,713 @@ pub fn calculate_bps(amount: u64, bps: u16) -> Result<u64> {
         .ok_or(FundraiselyError::ArithmeticOverflow.into())
 }
 
+/// Charity's share of entry fees: whatever platform, host and prizes leave of 10000 bps
+///
+/// # Errors
+/// `TotalAllocationTooHigh` if `platform_fee_bps + host_fee_bps + prize_pool_bps` exceeds
+/// 10000, rather than letting the charity share saturate to 0.
+///
+/// # Example
+/// ```
+/// let charity_bps = charity_allocation_bps(2000, 500, 3500)?; // 4000 (40%)
+/// ```
+pub fn charity_allocation_bps(platform_fee_bps: u16, host_fee_bps: u16, prize_pool_bps: u16) -> Result<u16> {
+    let allocated = platform_fee_bps as u32 + host_fee_bps as u32 + prize_pool_bps as u32;
+    require!(allocated <= 10000, FundraiselyError::TotalAllocationTooHigh);
+    Ok((10000 - allocated) as u16)
+}
+
+/// Check whether a room has passed either of its expiration deadlines
+///
+/// A room can expire at a slot, at a unix timestamp, or both; whichever comes first
+/// applies. A deadline of 0 is not set.
+///
+/// # Arguments
+/// * `expiration_slot` - Slot the room expires at (0 = none)
+/// * `expiration_unix_ts` - Unix timestamp the room expires at (0 = none)
+/// * `current_slot` / `now` - Current slot and unix timestamp from the Clock sysvar
+///
+/// # Example
+/// ```
+/// let expired = is_expired(0, 1_700_000_000, clock.slot, clock.unix_timestamp);
+/// ```
+pub fn is_expired(expiration_slot: u64, expiration_unix_ts: i64, current_slot: u64, now: i64) -> bool {
+    (expiration_slot > 0 && current_slot >= expiration_slot)
+        || (expiration_unix_ts > 0 && now >= expiration_unix_ts)
+}
+
+/// Slot a new room expires at
+///
+/// An explicit `expiration_slots` must be between 1 and `max_slots`. A room given neither a
+/// slot nor a unix-time deadline gets `default_slots`, so it can't stay open forever by
+/// omission; one with only a unix-time deadline keeps no slot deadline (0).
+///
+/// # Arguments
+/// * `current_slot` - Creation slot
+/// * `expiration_slots` - Slots until expiry requested by the host, if any
+/// * `has_unix_deadline` - Whether the host set `expiration_unix_ts`
+/// * `default_slots` / `max_slots` - GlobalConfig's expiration bounds
+///
+/// # Example
+/// ```
+/// let slot = expiration_slot(clock.slot, Some(43_200), false, 1_512_000, 6_480_000)?;
+/// ```
+pub fn expiration_slot(
+    current_slot: u64,
+    expiration_slots: Option<u64>,
+    has_unix_deadline: bool,
+    default_slots: u64,
+    max_slots: u64,
+) -> Result<u64> {
+    let slots = match expiration_slots {
+        Some(slots) => {
+            require!(slots > 0 && slots <= max_slots, FundraiselyError::InvalidExpiration);
+            slots
+        }
+        None if has_unix_deadline => return Ok(0),
+        None => default_slots,
+    };
+    Ok(current_slot
+        .checked_add(slots)
+        .ok_or(FundraiselyError::InvalidExpiration)?)
+}
+
+/// Entry fee owed by the next player to join
+///
+/// The first `early_bird_count` players pay `early_bird_fee`; everyone after pays
+/// `entry_fee`.
+///
+/// # Arguments
+/// * `player_count` - Players already in the room, before this join
+///
+/// # Example
+/// ```
+/// let fee = current_entry_fee(10_000_000, 5_000_000, 3, room.player_count);
+/// ```
+pub fn current_entry_fee(entry_fee: u64, early_bird_fee: u64, early_bird_count: u32, player_count: u32) -> u64 {
+    if player_count < early_bird_count {
+        early_bird_fee
+    } else {
+        entry_fee
+    }
+}
+
+/// Entry fee charged to a wallet holding a `Discount` PDA
+///
+/// `entry_fee * (10000 - discount_bps) / 10000`, rounded down.
+///
+/// # Example
+/// ```
+/// let fee = discounted_entry_fee(10_000_000, 2500)?; // 7_500_000
+/// ```
+pub fn discounted_entry_fee(entry_fee: u64, discount_bps: u16) -> Result<u64> {
+    require!(discount_bps <= 10000, FundraiselyError::InvalidDiscount);
+    calculate_bps(entry_fee, 10000 - discount_bps)
+}
+
+/// Split what a leaving player paid into their refund and the penalty kept for charity
+///
+/// The refund rounds down, so rounding dust stays with charity.
+///
+/// # Example
+/// ```
+/// let (refund, penalty) = leave_refund(12_000_000, 1000)?; // (10_800_000, 1_200_000)
+/// ```
+pub fn leave_refund(total_paid: u64, leave_penalty_bps: u16) -> Result<(u64, u64)> {
+    require!(leave_penalty_bps <= 10000, FundraiselyError::InvalidLeavePenalty);
+    let refund = calculate_bps(total_paid, 10000 - leave_penalty_bps)?;
+    let penalty = total_paid
+        .checked_sub(refund)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+    Ok((refund, penalty))
+}
+
+/// Validate a room's discount against its charity share
+///
+/// A discounted entry is split like any other, so charity receives `charity_bps` of the
+/// reduced fee. That must still be at least `min_charity_bps` of the full entry fee:
+/// `charity_bps * (10000 - discount_bps) >= min_charity_bps * 10000`. A room already at
+/// the charity minimum therefore can't offer discounts, and no room can offer a 100% one.
+pub fn validate_discount(discount_bps: u16, charity_bps: u16, min_charity_bps: u16) -> Result<()> {
+    require!(discount_bps <= 10000, FundraiselyError::InvalidDiscount);
+    let charity_share = charity_bps as u64 * (10000 - discount_bps as u64);
+    require!(
+        charity_share >= min_charity_bps as u64 * 10000,
+        FundraiselyError::InvalidDiscount
+    );
+    Ok(())
+}
+
+/// Merkle leaf for an allowlisted wallet: `sha256(0x00 || wallet)`
+///
+/// The `0x00` prefix (`0x01` for inner nodes) keeps a leaf from being passed off as
+/// an inner node.
+pub fn allowlist_leaf(wallet: &Pubkey) -> [u8; 32] {
+    hashv(&[&[0u8], wallet.as_ref()]).to_bytes()
+}
+
+/// Verify a merkle proof that `wallet` is on a room's allowlist
+///
+/// Hashing scheme (frontends must build trees the same way):
+/// - Leaf: `sha256(0x00 || wallet_pubkey_bytes)`
+/// - Inner node: `sha256(0x01 || min(a, b) || max(a, b))`, children compared as bytes
+/// - An odd node out at any level is carried up to the next level unchanged
+///
+/// Children are sorted before hashing, so a proof is just the sibling hashes from the
+/// leaf up to the root, without left/right flags.
+///
+/// # Arguments
+/// * `root` - The room's `allowlist_root`
+/// * `wallet` - Joining player's wallet
+/// * `proof` - Sibling hashes, leaf level first
+///
+/// # Returns
+/// `true` if the proof leads from the wallet's leaf to `root`
+pub fn verify_allowlist_proof(root: &[u8; 32], wallet: &Pubkey, proof: &[[u8; 32]]) -> bool {
+    let computed = proof.iter().fold(allowlist_leaf(wallet), |node, sibling| {
+        let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+        hashv(&[&[1u8], &left, &right]).to_bytes()
+    });
+    computed == *root
+}
+
+/// Apply a host's guaranteed minimum prize to the pool computed from entry fees
+///
+/// The escrow is a pledge to the fundraiser: if the pool falls short, the shortfall is
+/// topped up from the escrow; whatever escrow is not needed goes to charity.
+///
+/// # Arguments
+/// * `pool_prize` - Prize pool from entry fees (`prize_pool_bps` of total entry fees)
+/// * `guaranteed_min_prize` - Escrowed minimum prize (0 = no guarantee)
+///
+/// # Returns
+/// `(prize_amount, escrow_to_charity)`
+///
+/// # Example
+/// ```
+/// let (prize, to_charity) = apply_min_prize_guarantee(200, 500); // (500, 200)
+/// ```
+pub fn apply_min_prize_guarantee(pool_prize: u64, guaranteed_min_prize: u64) -> (u64, u64) {
+    // Top-up = guarantee - pool, so the unused escrow is exactly min(pool, guarantee)
+    (
+        pool_prize.max(guaranteed_min_prize),
+        pool_prize.min(guaranteed_min_prize),
+    )
+}
+
+/// Validate a pool room's prize distribution
+///
+/// Must have 1 to MAX_WINNERS places whose percentages sum to exactly 100.
+///
+/// # Example
+/// ```
+/// validate_prize_distribution(&[50, 30, 20])?;
+/// ```
+pub fn validate_prize_distribution(prize_distribution: &[u16]) -> Result<()> {
+    require!(
+        !prize_distribution.is_empty() && prize_distribution.len() <= MAX_WINNERS,
+        FundraiselyError::InvalidPrizeDistribution
+    );
+
+    let total_pct: u32 = prize_distribution.iter().map(|&pct| pct as u32).sum();
+    require!(total_pct == 100, FundraiselyError::InvalidPrizeDistribution);
+
+    Ok(())
+}
+
+/// Validate a pool room's prize tiers
+///
+/// At most MAX_PRIZE_TIERS tiers with strictly increasing, non-zero `min_players`, each
+/// distribution valid on its own (see [`validate_prize_distribution`]).
+///
+/// # Example
+/// ```
+/// validate_prize_tiers(&[PrizeTier { min_players: 10, distribution: vec![50, 30, 20] }])?;
+/// ```
+pub fn validate_prize_tiers(prize_tiers: &[PrizeTier]) -> Result<()> {
+    require!(
+        prize_tiers.len() <= MAX_PRIZE_TIERS
+            && prize_tiers.first().is_none_or(|tier| tier.min_players > 0)
+            && prize_tiers.windows(2).all(|pair| pair[0].min_players < pair[1].min_players),
+        FundraiselyError::InvalidPrizeTiers
+    );
+
+    for tier in prize_tiers {
+        validate_prize_distribution(&tier.distribution)?;
+    }
+
+    Ok(())
+}
+
+/// The prize distribution that applies to a room with `player_count` players
+///
+/// The tier with the highest `min_players` the count reaches, or the room's base
+/// `prize_distribution` if it reaches none.
+///
+/// # Example
+/// ```
+/// let distribution = select_prize_distribution(&room.prize_distribution, &room.prize_tiers, room.player_count);
+/// ```
+pub fn select_prize_distribution<'a>(
+    prize_distribution: &'a [u16],
+    prize_tiers: &'a [PrizeTier],
+    player_count: u32,
+) -> &'a [u16] {
+    prize_tiers
+        .iter()
+        .rev()
+        .find(|tier| player_count >= tier.min_players)
+        .map_or(prize_distribution, |tier| &tier.distribution)
+}
+
+/// Each winner's share of a pool prize
+///
+/// Place `i` receives `prize_amount * distribution[i] / 100`, rounded down; places
+/// without a percentage receive nothing.
+///
+/// # Arguments
+/// * `prize_amount` - Prize pool in token base units
+/// * `distribution` - Percentage per place
+/// * `winner_count` - Number of declared winners
+///
+/// # Example
+/// ```
+/// let shares = calculate_prize_shares(1000, &[50, 30, 20], 2); // [500, 300]
+/// ```
+pub fn calculate_prize_shares(prize_amount: u64, distribution: &[u16], winner_count: usize) -> Vec<u64> {
+    (0..winner_count)
+        .map(|i| {
+            distribution
+                .get(i)
+                .map_or(0, |&pct| (prize_amount as u128 * pct as u128 / 100) as u64)
+        })
+        .collect()
+}
+
+/// Number of prize places occupied by `winner_count` declared winners
+///
+/// With ties (`place_sizes` non-empty) each group of tied winners takes one place;
+/// otherwise every winner has their own.
+pub fn place_count(place_sizes: &[u8], winner_count: usize) -> usize {
+    if place_sizes.is_empty() {
+        winner_count
+    } else {
+        place_sizes.len()
+    }
+}
+
+/// Validate how declared winners are grouped into tied places
+///
+/// `place_sizes[i]` consecutive winners share place `i`. Every place must have at
+/// least one winner and the places must cover every winner exactly. Empty means
+/// one winner per place.
+///
+/// # Example
+/// ```
+/// validate_place_sizes(&[2, 1], 3)?; // two tied for 1st, one 2nd
+/// ```
+pub fn validate_place_sizes(place_sizes: &[u8], winner_count: usize) -> Result<()> {
+    if place_sizes.is_empty() {
+        return Ok(());
+    }
+    let covered: usize = place_sizes.iter().map(|&size| size as usize).sum();
+    require!(
+        place_sizes.iter().all(|&size| size > 0) && covered == winner_count,
+        FundraiselyError::InvalidTies
+    );
+    Ok(())
+}
+
+/// Each winner's share of a pool prize, splitting tied places equally
+///
+/// Place `i` pays `prize_amount * distribution[i] / 100`, rounded down (see
+/// `calculate_prize_shares`), divided equally between the `place_sizes[i]` winners
+/// sharing it. The remainder of that division goes to the lexicographically-first
+/// winner of the group. Returns one amount per winner, in `winners` order.
+///
+/// # Example
+/// ```
+/// let shares = calculate_tied_prize_shares(1001, &[50, 50], &winners, &[2, 1]);
+/// ```
+pub fn calculate_tied_prize_shares(
+    prize_amount: u64,
+    distribution: &[u16],
+    winners: &[Pubkey],
+    place_sizes: &[u8],
+) -> Vec<u64> {
+    if place_sizes.is_empty() {
+        return calculate_prize_shares(prize_amount, distribution, winners.len());
+    }
+
+    let place_amounts = calculate_prize_shares(prize_amount, distribution, place_sizes.len());
+    let mut shares = vec![0u64; winners.len()];
+    let mut start = 0;
+    for (&place_amount, &size) in place_amounts.iter().zip(place_sizes) {
+        let end = (start + size as usize).min(winners.len());
+        if start == end {
+            break;
+        }
+        let group = &winners[start..end];
+        let each = place_amount / group.len() as u64;
+        let remainder = place_amount % group.len() as u64;
+        shares[start..end].fill(each);
+        // Lexicographically-first winner of the group takes the split's remainder
+        if let Some(first) = (0..group.len()).min_by_key(|&i| group[i].to_bytes()) {
+            shares[start + first] += remainder;
+        }
+        start = end;
+    }
+    shares
+}
+
+/// A player's share of a recovery refund
+///
+/// Scales what the player paid by `total_refund / total_collected`, so every player
+/// bears the same proportional fee. Rounds down; the shares never sum to more than
+/// `total_refund`.
+///
+/// # Example
+/// ```
+/// let refund = proportional_refund(1_500_000, 9_000_000, 10_000_000); // 1_350_000
+/// ```
+pub fn proportional_refund(paid: u64, total_refund: u64, total_collected: u64) -> u64 {
+    if total_collected == 0 {
+        return 0;
+    }
+    (paid as u128 * total_refund as u128 / total_collected as u128) as u64
+}
+
+/// Validate that every declared winner has a paid prize place
+///
+/// Winner `i` receives `prize_distribution[i]` percent of the prize pool, so declaring
+/// more winners than there are non-zero places (e.g., 3 winners for `[100, 0, 0]`)
+/// would leave the extra winners with nothing.
+///
+/// # Example
+/// ```
+/// validate_winner_places(2, &[70, 30, 0])?;
+/// ```
+pub fn validate_winner_places(winner_count: usize, prize_distribution: &[u16]) -> Result<()> {
+    require!(
+        winner_count <= prize_distribution.len()
+            && prize_distribution[..winner_count].iter().all(|&pct| pct > 0),
+        FundraiselyError::InvalidWinners
+    );
+
+    Ok(())
+}
+
+/// Validate the scores recorded alongside declared winners
+///
+/// There must be one non-zero score per winner. When prizes are top-heavy (each place
+/// pays at least as much as the next), scores must not increase from one place to the
+/// next, so a higher-paid place never has a lower score.
+///
+/// # Example
+/// ```
+/// validate_winner_scores(&[980, 750, 750], 3, &[50, 30, 20])?;
+/// ```
+pub fn validate_winner_scores(scores: &[u64], winner_count: usize, prize_distribution: &[u16]) -> Result<()> {
+    require!(
+        scores.len() == winner_count && scores.iter().all(|&score| score > 0),
+        FundraiselyError::InvalidWinnerScores
+    );
+
+    let top_heavy = prize_distribution.windows(2).all(|pair| pair[0] >= pair[1]);
+    if top_heavy {
+        require!(
+            scores.windows(2).all(|pair| pair[0] >= pair[1]),
+            FundraiselyError::InvalidWinnerScores
+        );
+    }
+
+    Ok(())
+}
+
+/// Validate a room's charity wallet
+///
+/// Rejects the default pubkey and the platform wallet, either of which would
+/// silently misroute the room's donations.
+///
+/// # Example
+/// ```
+/// validate_charity_wallet(&charity_wallet, &global_config.platform_wallet)?;
+/// ```
+pub fn validate_charity_wallet(charity_wallet: &Pubkey, platform_wallet: &Pubkey) -> Result<()> {
+    require!(
+        *charity_wallet != Pubkey::default() && charity_wallet != platform_wallet,
+        FundraiselyError::InvalidCharityWallet
+    );
+    Ok(())
+}
+
+/// Validate a multi-charity room's charity splits
+///
+/// Must have 1 to MAX_CHARITIES unique wallets, the first being the room's
+/// `charity_wallet`, with non-zero weights summing to exactly 10000 bps.
+///
+/// # Example
+/// ```
+/// validate_charity_splits(&splits, &charity_wallet)?;
+/// ```
+pub fn validate_charity_splits(splits: &[CharitySplit], charity_wallet: &Pubkey) -> Result<()> {
+    require!(
+        !splits.is_empty() && splits.len() <= MAX_CHARITIES,
+        FundraiselyError::InvalidCharitySplits
+    );
+
+    require!(
+        splits[0].wallet == *charity_wallet,
+        FundraiselyError::InvalidCharitySplits
+    );
+
+    for (i, split) in splits.iter().enumerate() {
+        require!(
+            split.weight_bps > 0 && !splits[i + 1..].iter().any(|other| other.wallet == split.wallet),
+            FundraiselyError::InvalidCharitySplits
+        );
+    }
+
+    let total_bps: u32 = splits.iter().map(|split| split.weight_bps as u32).sum();
+    require!(total_bps == 10000, FundraiselyError::InvalidCharitySplits);
+
+    Ok(())
+}
+
+/// Split a charity amount by weight, adding the rounding dust to the first charity
+///
+/// # Arguments
+/// * `charity_amount` - Total charity amount in token base units
+/// * `weights_bps` - Weight of each charity in basis points (summing to 10000)
+///
+/// # Returns
+/// One amount per weight, summing to exactly `charity_amount`
+///
+/// # Example
+/// ```
+/// let amounts = split_charity_amount(1001, &[5000, 3000, 2000])?; // [501, 300, 200]
+/// ```
+pub fn split_charity_amount(charity_amount: u64, weights_bps: &[u16]) -> Result<Vec<u64>> {
+    let mut amounts = weights_bps
+        .iter()
+        .map(|&weight| calculate_bps(charity_amount, weight))
+        .collect::<Result<Vec<u64>>>()?;
+
+    let distributed: u64 = amounts.iter().sum();
+    let dust = charity_amount
+        .checked_sub(distributed)
+        .ok_or(FundraiselyError::ArithmeticUnderflow)?;
+    if let Some(first) = amounts.first_mut() {
+        *first = first.checked_add(dust).ok_or(FundraiselyError::ArithmeticOverflow)?;
+    }
+
+    Ok(amounts)
+}
+
+/// Metaplex Token Metadata program, whose master edition PDA holds an NFT's mint authority
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Address of the Metaplex master edition account of `mint`
+pub fn master_edition_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+            b"edition",
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Check that a mint is a genuine 1-of-1 NFT
+///
+/// The supply must be exactly one indivisible token, and nobody must be able to mint
+/// more: the mint authority is either revoked or held by the mint's Metaplex master
+/// edition, which only prints editions under separate mints.
+///
+/// # Arguments
+/// * `mint` - Address of the mint
+/// * `supply` / `decimals` / `mint_authority` - The mint account's fields
+pub fn is_one_of_one(mint: &Pubkey, supply: u64, decimals: u8, mint_authority: Option<Pubkey>) -> bool {
+    supply == 1
+        && decimals == 0
+        && mint_authority.is_none_or(|authority| authority == master_edition_address(mint))
+}
+
+/// Funding status of an asset room from its declared prizes
+///
+/// An asset room only accepts players once every declared prize is escrowed.
+///
+/// # Returns
+/// `AwaitingFunding` if no prize is deposited, `PartiallyFunded` if some are,
+/// `Ready` once all of them are
+pub fn funding_status(prize_assets: &[Option<PrizeAsset>]) -> RoomStatus {
+    let declared = prize_assets.iter().flatten().count();
+    let deposited = prize_assets.iter().flatten().filter(|prize| prize.deposited).count();
+
+    if deposited == declared {
+        RoomStatus::Ready
+    } else if deposited == 0 {
+        RoomStatus::AwaitingFunding
+    } else {
+        RoomStatus::PartiallyFunded
+    }
+}
+
+/// Check that a room vault holds the room's fee token and is controlled by the room PDA
+///
+/// A vault with any other authority could be drained by whoever holds it, bypassing the
+/// program's fee split.
+///
+/// # Arguments
+/// * `vault_mint` / `vault_authority` - Mint and owner fields of the vault token account
+/// * `fee_token_mint` - The room's fee token mint
+/// * `room` - The room PDA
+pub fn validate_room_vault(
+    vault_mint: &Pubkey,
+    vault_authority: &Pubkey,
+    fee_token_mint: &Pubkey,
+    room: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(*vault_mint, *fee_token_mint, FundraiselyError::InvalidVaultAccount);
+    require_keys_eq!(*vault_authority, *room, FundraiselyError::InvalidVaultAuthority);
+    Ok(())
+}
+
+/// Check that a room vault still holds everything the room recorded as collected
+///
+/// Every entry fee and extra paid into the room is added to `total_collected`, so a vault
+/// holding less means the accounting diverged from the actual transfers (for example a
+/// transfer-fee mint) and no payout computed from the counters can be trusted.
+///
+/// # Example
+/// ```
+/// validate_vault_balance(room_vault.amount, room.total_collected)?;
+/// ```
+pub fn validate_vault_balance(vault_amount: u64, total_collected: u64) -> Result<()> {
+    require!(vault_amount >= total_collected, FundraiselyError::VaultBalanceMismatch);
+    Ok(())
+}
+
+/// Check that a room vault can cover every payout planned from it
+///
+/// Run before the first transfer, so a shortfall reverts the whole payout instead of
+/// failing after some recipients were already paid.
+///
+/// # Example
+/// ```
+/// validate_payout_total(planned_total, room_vault.amount)?;
+/// ```
+pub fn validate_payout_total(planned_total: u64, vault_amount: u64) -> Result<()> {
+    require!(planned_total <= vault_amount, FundraiselyError::InsufficientVaultBalance);
+    Ok(())
+}
+
+/// Decimals of an initialized SPL Token or Token-2022 mint
+///
+/// Rejects accounts owned by any other program, and data that isn't an initialized mint,
+/// with `InvalidMint`.
+///
+/// # Example
+/// ```
+/// let decimals = mint_decimals(mint_info.owner, &mint_info.try_borrow_data()?)?;
+/// ```
+pub fn mint_decimals(mint_owner: &Pubkey, mint_data: &[u8]) -> Result<u8> {
+    use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensions;
+    use anchor_spl::token_2022::spl_token_2022::state::Mint;
+
+    require!(
+        *mint_owner == anchor_spl::token::ID || *mint_owner == anchor_spl::token_2022::ID,
+        FundraiselyError::InvalidMint
+    );
+
+    // Token-2022 mints share the SPL Token mint layout, followed by any extensions
+    let mint = StateWithExtensions::<Mint>::unpack(mint_data)
+        .map_err(|_| FundraiselyError::InvalidMint)?;
+    require!(mint.base.is_initialized, FundraiselyError::InvalidMint);
+
+    Ok(mint.base.decimals)
+}
+
+/// Reject fee token mints whose extensions would break the room's split accounting
+///
+/// With a Token-2022 transfer fee, the vault receives less than `join_room` records in
+/// `total_entry_fees`, so `end_room` would try to pay out more than the vault holds.
+/// Classic SPL Token mints have no extensions and always pass.
+///
+/// # Arguments
+/// * `mint_owner` - Program owning the mint account
+/// * `mint_data` - Raw mint account data
+pub fn validate_mint_extensions(mint_owner: &Pubkey, mint_data: &[u8]) -> Result<()> {
+    use anchor_spl::token_2022::spl_token_2022::extension::{
+        transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+    };
+    use anchor_spl::token_2022::spl_token_2022::state::Mint;
+
+    if *mint_owner != anchor_spl::token_2022::ID {
+        return Ok(());
+    }
+
+    let mint = StateWithExtensions::<Mint>::unpack(mint_data)
+        .map_err(|_| FundraiselyError::InvalidTokenMint)?;
+    require!(
+        mint.get_extension::<TransferFeeConfig>().is_err(),
+        FundraiselyError::UnsupportedMintExtension
+    );
+    Ok(())
+}
+
+/// Rewrite a room's account data at the current layout (`migrate_room`)
+///
+/// A room of `LegacyRoom::LEN` bytes was written by the original release and is decoded
+/// as a `LegacyRoom`, reported as version 0. Otherwise the layout version is read from
+/// the room, except that a room allocated smaller than a version 1 room predates
+/// versioning and is version 0 whatever byte sits where `room_version` would be.
+/// Everything after the old layout's fields is zero-filled before
+/// the room is decoded again, so stale bytes a shrinking write left behind can't be read
+/// as the fields added since (see the Room docs, "Layout Versions").
+///
+/// # Arguments
+/// * `data` - Room account data, already grown to `Room::LEN`
+/// * `old_len` - Account size before it was grown
+///
+/// # Returns
+/// The version migrated from and the migrated room (also written to `data`)
+pub fn migrate_room_layout(data: &mut [u8], old_len: usize) -> Result<(u8, crate::state::Room)> {
+    use crate::state::{LegacyRoom, Room, ROOM_VERSION};
+
+    if old_len == LegacyRoom::LEN {
+        let legacy = LegacyRoom::deserialize(&mut &data[Room::DISCRIMINATOR.len()..old_len])
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize)?;
+        let room = legacy.into_room();
+        data.fill(0);
+        room.try_serialize(&mut &mut data[..])?;
+        return Ok((0, room));
+    }
+
+    let room = Room::try_deserialize(&mut &data[..])?;
+    let from_version = if old_len < Room::LEN - Room::bytes_added_since(1) {
+        0
+    } else {
+        room.room_version
+    };
+
+    require!(from_version != ROOM_VERSION, FundraiselyError::RoomAlreadyCurrent);
+    require!(from_version < ROOM_VERSION, FundraiselyError::UnsupportedRoomVersion);
+
+    // Fields added since are fixed-size, so their values don't change the serialized length
+    let layout_end = 8 + room.try_to_vec()?.len() - Room::bytes_added_since(from_version);
+    data[layout_end..].fill(0);
+
+    let mut room = Room::try_deserialize(&mut &data[..])?;
+    room.room_version = ROOM_VERSION;
+    room.try_serialize(&mut &mut data[..])?;
+
+    Ok((from_version, room))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,4 +760,798 @@ mod tests {
         // 0% of 1000 = 0
         assert_eq!(calculate_bps(1000, 0).unwrap(), 0);
     }
+
+    #[test]
+    fn test_charity_allocation_bps() {
+        assert_eq!(charity_allocation_bps(2000, 500, 3500).unwrap(), 4000);
+        assert_eq!(charity_allocation_bps(2000, 0, 0).unwrap(), 8000);
+        assert_eq!(charity_allocation_bps(5000, 2000, 3000).unwrap(), 0);
+
+        // Over 100%: a precise error instead of a saturated 0 failing CharityBelowMinimum
+        let err = charity_allocation_bps(5000, 2000, 3001).unwrap_err();
+        assert_eq!(err, FundraiselyError::TotalAllocationTooHigh.into());
+        assert!(charity_allocation_bps(u16::MAX, u16::MAX, u16::MAX).is_err());
+    }
+
+    #[test]
+    fn test_is_expired() {
+        // No deadlines: never expires
+        assert!(!is_expired(0, 0, u64::MAX, i64::MAX));
+        // Slot deadline only
+        assert!(!is_expired(100, 0, 99, i64::MAX));
+        assert!(is_expired(100, 0, 100, 0));
+        // Timestamp deadline only
+        assert!(!is_expired(0, 1_700_000_000, u64::MAX, 1_699_999_999));
+        assert!(is_expired(0, 1_700_000_000, 0, 1_700_000_000));
+        // Both: whichever passes first
+        assert!(is_expired(100, 1_700_000_000, 50, 1_700_000_001));
+        assert!(is_expired(100, 1_700_000_000, 150, 1_600_000_000));
+        assert!(!is_expired(100, 1_700_000_000, 50, 1_600_000_000));
+    }
+
+    #[test]
+    fn test_expiration_slot() {
+        // Explicit expiration within the cap
+        assert_eq!(expiration_slot(1_000, Some(43_200), false, 500, 50_000).unwrap(), 44_200);
+        assert_eq!(expiration_slot(1_000, Some(50_000), true, 500, 50_000).unwrap(), 51_000);
+        // No deadline at all: the platform default
+        assert_eq!(expiration_slot(1_000, None, false, 500, 50_000).unwrap(), 1_500);
+        // Unix-time deadline only: no slot deadline
+        assert_eq!(expiration_slot(1_000, None, true, 500, 50_000).unwrap(), 0);
+
+        // Zero ("never"), above the cap, or overflowing the slot counter
+        for (current_slot, slots) in [(1_000, 0), (1_000, 50_001), (u64::MAX - 10, 11)] {
+            let err = expiration_slot(current_slot, Some(slots), false, 500, 50_000).unwrap_err();
+            assert_eq!(err, FundraiselyError::InvalidExpiration.into());
+        }
+    }
+
+    #[test]
+    fn test_current_entry_fee() {
+        // No early-bird window
+        assert_eq!(current_entry_fee(10, 0, 0, 0), 10);
+        // The first two players get the discount, the rest pay full price
+        let fees: Vec<u64> = (0..4).map(|joined| current_entry_fee(10, 6, 2, joined)).collect();
+        assert_eq!(fees, vec![6, 6, 10, 10]);
+        // Mixed pricing still accumulates exactly what was charged
+        assert_eq!(fees.iter().sum::<u64>(), 32);
+    }
+
+    #[test]
+    fn test_discounts() {
+        assert_eq!(discounted_entry_fee(10_000_000, 0).unwrap(), 10_000_000);
+        assert_eq!(discounted_entry_fee(10_000_000, 2500).unwrap(), 7_500_000);
+        assert_eq!(discounted_entry_fee(1_000_003, 5000).unwrap(), 500_001);
+        assert!(discounted_entry_fee(10, 10001).is_err());
+
+        // 6000 bps to charity: 40% of the full fee is kept down to a 1/3 discount
+        assert!(validate_discount(0, 4000, 4000).is_ok());
+        assert!(validate_discount(3333, 6000, 4000).is_ok());
+        assert!(validate_discount(3334, 6000, 4000).is_err());
+        // Already at the minimum: no discount possible
+        assert!(validate_discount(1, 4000, 4000).is_err());
+        assert!(validate_discount(10000, 10000, 4000).is_err());
+    }
+
+    #[test]
+    fn test_is_one_of_one() {
+        let mint = Pubkey::new_unique();
+        let edition = master_edition_address(&mint);
+
+        assert!(is_one_of_one(&mint, 1, 0, None));
+        assert!(is_one_of_one(&mint, 1, 0, Some(edition)));
+        // Fungible, divisible, or still mintable by someone else
+        assert!(!is_one_of_one(&mint, 2, 0, None));
+        assert!(!is_one_of_one(&mint, 1, 6, None));
+        assert!(!is_one_of_one(&mint, 1, 0, Some(Pubkey::new_unique())));
+        // Another mint's master edition doesn't count
+        assert!(!is_one_of_one(&mint, 1, 0, Some(master_edition_address(&Pubkey::new_unique()))));
+    }
+
+    #[test]
+    fn test_funding_status() {
+        let prize = |deposited| {
+            Some(PrizeAsset { mint: Pubkey::new_unique(), amount: 1, deposited, is_nft: false })
+        };
+
+        assert_eq!(funding_status(&[prize(false), prize(false), None]), RoomStatus::AwaitingFunding);
+        assert_eq!(funding_status(&[prize(true), prize(false), None]), RoomStatus::PartiallyFunded);
+        assert_eq!(funding_status(&[prize(true), prize(true), None]), RoomStatus::Ready);
+        // Undeclared slots don't hold the room back, even between declared prizes
+        assert_eq!(funding_status(&[prize(true), None, prize(true)]), RoomStatus::Ready);
+    }
+
+    #[test]
+    fn test_allowlist_proof() {
+        let node = |a: [u8; 32], b: [u8; 32]| {
+            let (left, right) = if a <= b { (a, b) } else { (b, a) };
+            hashv(&[&[1u8], &left, &right]).to_bytes()
+        };
+        let wallets: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = wallets.iter().map(allowlist_leaf).collect();
+
+        // Three leaves: the third is carried up unchanged
+        let pair = node(leaves[0], leaves[1]);
+        let root = node(pair, leaves[2]);
+
+        assert!(verify_allowlist_proof(&root, &wallets[0], &[leaves[1], leaves[2]]));
+        assert!(verify_allowlist_proof(&root, &wallets[1], &[leaves[0], leaves[2]]));
+        assert!(verify_allowlist_proof(&root, &wallets[2], &[pair]));
+
+        // Outsiders, wrong proofs and inner nodes posing as leaves are rejected
+        assert!(!verify_allowlist_proof(&root, &Pubkey::new_unique(), &[leaves[1], leaves[2]]));
+        assert!(!verify_allowlist_proof(&root, &wallets[0], &[leaves[2], leaves[1]]));
+        assert!(!verify_allowlist_proof(&root, &wallets[0], &[]));
+        assert!(!verify_allowlist_proof(&root, &Pubkey::new_from_array(pair), &[leaves[2]]));
+    }
+
+    #[test]
+    fn test_min_prize_guarantee() {
+        // No guarantee: prize is the pool, nothing extra to charity
+        assert_eq!(apply_min_prize_guarantee(200, 0), (200, 0));
+
+        // Under-subscribed: 300 topped up from a 500 escrow, remaining 200 to charity
+        assert_eq!(apply_min_prize_guarantee(200, 500), (500, 200));
+
+        // Pool exceeds guarantee: whole escrow goes to charity
+        assert_eq!(apply_min_prize_guarantee(800, 500), (800, 500));
+
+        // Escrow is always fully accounted for: top-up + charity share == escrow
+        for (pool, guarantee) in [(0, 500), (499, 500), (500, 500), (u64::MAX, 500)] {
+            let (prize, to_charity) = apply_min_prize_guarantee(pool, guarantee);
+            assert_eq!((prize - pool) + to_charity, guarantee);
+        }
+    }
+
+    #[test]
+    fn test_validate_prize_distribution() {
+        assert!(validate_prize_distribution(&[100]).is_ok());
+        assert!(validate_prize_distribution(&[50, 30, 20]).is_ok());
+        assert!(validate_prize_distribution(&[10; MAX_WINNERS]).is_ok());
+
+        // Empty, too many places, or not summing to 100
+        assert!(validate_prize_distribution(&[]).is_err());
+        assert!(validate_prize_distribution(&[5; MAX_WINNERS + 1]).is_err());
+        assert!(validate_prize_distribution(&[50, 30]).is_err());
+        assert!(validate_prize_distribution(&[u16::MAX, 101]).is_err());
+    }
+
+    #[test]
+    fn test_validate_prize_tiers() {
+        let tier = |min_players, distribution: &[u16]| PrizeTier { min_players, distribution: distribution.to_vec() };
+
+        assert!(validate_prize_tiers(&[]).is_ok());
+        assert!(validate_prize_tiers(&[tier(10, &[50, 30, 20])]).is_ok());
+        assert!(validate_prize_tiers(&[tier(5, &[70, 30]), tier(10, &[50, 30, 20]), tier(50, &[10; 10])]).is_ok());
+
+        // Thresholds must be non-zero and strictly increasing
+        assert!(validate_prize_tiers(&[tier(0, &[100])]).is_err());
+        assert!(validate_prize_tiers(&[tier(10, &[70, 30]), tier(10, &[50, 30, 20])]).is_err());
+        assert!(validate_prize_tiers(&[tier(10, &[70, 30]), tier(5, &[50, 30, 20])]).is_err());
+
+        // Too many tiers, or a tier not summing to 100
+        assert!(validate_prize_tiers(&[tier(1, &[100]), tier(2, &[100]), tier(3, &[100]), tier(4, &[100])]).is_err());
+        assert!(validate_prize_tiers(&[tier(10, &[50, 30])]).is_err());
+    }
+
+    #[test]
+    fn test_select_prize_distribution() {
+        let base = [100];
+        let tiers = [
+            PrizeTier { min_players: 10, distribution: vec![50, 30, 20] },
+            PrizeTier { min_players: 50, distribution: vec![40, 25, 15, 10, 10] },
+        ];
+
+        assert_eq!(select_prize_distribution(&base, &tiers, 0), &[100]);
+        assert_eq!(select_prize_distribution(&base, &tiers, 9), &[100]);
+        assert_eq!(select_prize_distribution(&base, &tiers, 10), &[50, 30, 20]);
+        assert_eq!(select_prize_distribution(&base, &tiers, 49), &[50, 30, 20]);
+        assert_eq!(select_prize_distribution(&base, &tiers, 50), &[40, 25, 15, 10, 10]);
+        assert_eq!(select_prize_distribution(&base, &[], 50), &[100]);
+    }
+
+    #[test]
+    fn test_calculate_prize_shares() {
+        assert_eq!(calculate_prize_shares(1000, &[50, 30, 20], 3), vec![500, 300, 200]);
+        assert_eq!(calculate_prize_shares(1000, &[50, 30, 20], 2), vec![500, 300]);
+        assert_eq!(calculate_prize_shares(100, &[100], 2), vec![100, 0]);
+        // Rounds down per place
+        assert_eq!(calculate_prize_shares(1001, &[33, 33, 34], 3), vec![330, 330, 340]);
+        assert_eq!(calculate_prize_shares(u64::MAX, &[100], 1), vec![u64::MAX]);
+    }
+
+    #[test]
+    fn test_ties() {
+        assert!(validate_place_sizes(&[], 3).is_ok());
+        assert!(validate_place_sizes(&[2, 1], 3).is_ok());
+        assert!(validate_place_sizes(&[3], 3).is_ok());
+        // Groups must be non-empty and cover every winner exactly
+        assert!(validate_place_sizes(&[2, 0, 1], 3).is_err());
+        assert!(validate_place_sizes(&[2, 2], 3).is_err());
+        assert!(validate_place_sizes(&[1], 3).is_err());
+        assert_eq!(place_count(&[], 3), 3);
+        assert_eq!(place_count(&[2, 1], 3), 2);
+
+        let low = Pubkey::new_from_array([1; 32]);
+        let high = Pubkey::new_from_array([9; 32]);
+        let third = Pubkey::new_from_array([5; 32]);
+
+        // 1st (505) shared by two: 252 each, the odd unit to the lower pubkey; 2nd gets 494
+        assert_eq!(
+            calculate_tied_prize_shares(1010, &[50, 49, 1], &[high, low, third], &[2, 1]),
+            vec![252, 253, 494]
+        );
+        // Three-way tie for 1001: the whole remainder of 2 goes to the lowest pubkey
+        let shares = calculate_tied_prize_shares(1001, &[100], &[high, third, low], &[3]);
+        assert_eq!(shares, vec![333, 333, 335]);
+        // No ties: same as one winner per place
+        assert_eq!(
+            calculate_tied_prize_shares(1000, &[50, 30, 20], &[low, high, third], &[]),
+            calculate_prize_shares(1000, &[50, 30, 20], 3)
+        );
+    }
+
+    #[test]
+    fn test_proportional_refund() {
+        // 10% fee: each player gets 90% of what they paid
+        assert_eq!(proportional_refund(1_000_000, 2_250_000, 2_500_000), 900_000);
+        assert_eq!(proportional_refund(1_500_000, 2_250_000, 2_500_000), 1_350_000);
+
+        // No fee refunds in full; shares round down and never exceed the refund
+        assert_eq!(proportional_refund(7, 10, 10), 7);
+        let paid = [10_000_001u64, 10_000_000];
+        let shares: u64 = paid.iter().map(|&p| proportional_refund(p, 18_000_001, 20_000_001)).sum();
+        assert!(shares <= 18_000_001);
+        assert_eq!(proportional_refund(5, 0, 0), 0);
+    }
+
+    #[test]
+    fn test_validate_winner_places() {
+        assert!(validate_winner_places(1, &[100, 0, 0]).is_ok());
+        assert!(validate_winner_places(2, &[70, 30, 0]).is_ok());
+        assert!(validate_winner_places(3, &[50, 30, 20]).is_ok());
+
+        // More winners than paid places, or past the end of the distribution
+        assert!(validate_winner_places(2, &[100, 0, 0]).is_err());
+        assert!(validate_winner_places(3, &[70, 30, 0]).is_err());
+        assert!(validate_winner_places(2, &[100]).is_err());
+    }
+
+    #[test]
+    fn test_validate_winner_scores() {
+        assert!(validate_winner_scores(&[980, 750, 750], 3, &[50, 30, 20]).is_ok());
+        assert!(validate_winner_scores(&[10], 1, &[100]).is_ok());
+
+        // Count mismatch or a zero score
+        assert!(validate_winner_scores(&[980, 750], 3, &[50, 30, 20]).is_err());
+        assert!(validate_winner_scores(&[980, 0, 500], 3, &[50, 30, 20]).is_err());
+
+        // Ascending scores are rejected for top-heavy prizes, allowed otherwise
+        assert!(validate_winner_scores(&[500, 750], 2, &[60, 40]).is_err());
+        assert!(validate_winner_scores(&[500, 750], 2, &[40, 60]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_charity_wallet() {
+        let charity = Pubkey::new_unique();
+        let platform = Pubkey::new_unique();
+
+        assert!(validate_charity_wallet(&charity, &platform).is_ok());
+        assert_eq!(
+            validate_charity_wallet(&Pubkey::default(), &platform).unwrap_err(),
+            FundraiselyError::InvalidCharityWallet.into()
+        );
+        assert_eq!(
+            validate_charity_wallet(&platform, &platform).unwrap_err(),
+            FundraiselyError::InvalidCharityWallet.into()
+        );
+    }
+
+    #[test]
+    fn test_charity_splits() {
+        let primary = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let split = |wallet: Pubkey, weight_bps: u16| CharitySplit { wallet, weight_bps };
+
+        assert!(validate_charity_splits(&[split(primary, 10000)], &primary).is_ok());
+        assert!(validate_charity_splits(&[split(primary, 6000), split(second, 4000)], &primary).is_ok());
+
+        // First split must be the room's charity wallet, wallets unique, weights non-zero and summing to 10000
+        assert!(validate_charity_splits(&[split(second, 6000), split(primary, 4000)], &primary).is_err());
+        assert!(validate_charity_splits(&[split(primary, 5000), split(primary, 5000)], &primary).is_err());
+        assert!(validate_charity_splits(&[split(primary, 10000), split(second, 0)], &primary).is_err());
+        assert!(validate_charity_splits(&[split(primary, 6000), split(second, 3000)], &primary).is_err());
+        assert!(validate_charity_splits(&vec![split(primary, 2500); MAX_CHARITIES + 1], &primary).is_err());
+
+        // Dust goes to the first charity and the amounts always add up
+        assert_eq!(split_charity_amount(1001, &[5000, 3000, 2000]).unwrap(), vec![501, 300, 200]);
+        assert_eq!(split_charity_amount(2, &[3333, 3333, 3334]).unwrap(), vec![2, 0, 0]);
+        assert_eq!(split_charity_amount(0, &[10000]).unwrap(), vec![0]);
+    }
+
+    /// PDA seeds must match the shared vectors the backend also checks against
+    #[test]
+    fn test_pda_vectors() {
+        let fixture: serde_json::Value = serde_json::from_str(include_str!(
+            "../../../../tests/fixtures/pda_vectors.json"
+        ))
+        .unwrap();
+        let key = |value: &serde_json::Value| value.as_str().unwrap().parse::<Pubkey>().unwrap();
+
+        assert_eq!(key(&fixture["program_id"]), crate::ID);
+
+        let (global_config, _) = Pubkey::find_program_address(&[b"global-config"], &crate::ID);
+        assert_eq!(global_config, key(&fixture["global_config"]));
+
+        for vector in fixture["vectors"].as_array().unwrap() {
+            let description = vector["description"].as_str().unwrap();
+            let room_id = vector["room_id"].as_str().unwrap();
+            let host = key(&vector["host"]);
+            let player = key(&vector["player"]);
+
+            let (room, _) =
+                Pubkey::find_program_address(&[b"room", host.as_ref(), room_id.as_bytes()], &crate::ID);
+            let (player_entry, _) =
+                Pubkey::find_program_address(&[b"player", room.as_ref(), player.as_ref()], &crate::ID);
+            let (room_vault, _) =
+                Pubkey::find_program_address(&[b"room-vault", room.as_ref()], &crate::ID);
+
+            assert_eq!(room, key(&vector["room"]), "room PDA: {}", description);
+            assert_eq!(player_entry, key(&vector["player_entry"]), "player PDA: {}", description);
+            assert_eq!(room_vault, key(&vector["room_vault"]), "vault PDA: {}", description);
+        }
+    }
+
+    /// The short-vault accounts the validator preloads for the end_room integration test
+    /// must decode with the current Room layout and leave the real vault below
+    /// `total_collected`
+    #[test]
+    fn test_short_vault_fixture() {
+        use anchor_lang::solana_program::program_pack::Pack;
+        use anchor_spl::token::spl_token;
+        use base64::Engine;
+        use crate::state::{Room, ROOM_VERSION};
+
+        let account = |json: &str| {
+            let fixture: serde_json::Value = serde_json::from_str(json).unwrap();
+            let pubkey = fixture["pubkey"].as_str().unwrap().parse::<Pubkey>().unwrap();
+            let owner = fixture["account"]["owner"].as_str().unwrap().parse::<Pubkey>().unwrap();
+            let data = base64::engine::general_purpose::STANDARD
+                .decode(fixture["account"]["data"][0].as_str().unwrap())
+                .unwrap();
+            (pubkey, owner, data)
+        };
+
+        let (room_key, room_owner, room_data) =
+            account(include_str!("../../../../tests/fixtures/short-vault/room.json"));
+        let (vault_key, vault_owner, vault_data) =
+            account(include_str!("../../../../tests/fixtures/short-vault/room-vault.json"));
+        let (mint_key, mint_owner, _) =
+            account(include_str!("../../../../tests/fixtures/short-vault/mint.json"));
+
+        assert_eq!(room_owner, crate::ID);
+        assert_eq!(room_data.len(), Room::LEN);
+        let room = Room::try_deserialize(&mut room_data.as_slice()).unwrap();
+        assert_eq!(room.room_version, ROOM_VERSION);
+        let (expected_room, bump) = Pubkey::find_program_address(
+            &[b"room", room.host.as_ref(), room.room_id.as_bytes()],
+            &crate::ID,
+        );
+        assert_eq!((room_key, room.bump), (expected_room, bump));
+
+        assert_eq!(vault_owner, spl_token::ID);
+        assert_eq!(mint_owner, spl_token::ID);
+        let vault = spl_token::state::Account::unpack(&vault_data).unwrap();
+        let (expected_vault, _) =
+            Pubkey::find_program_address(&[b"room-vault", room_key.as_ref()], &crate::ID);
+        assert_eq!(vault_key, expected_vault);
+        assert_eq!((vault.owner, vault.mint), (room_key, mint_key));
+        assert_eq!(room.fee_token_mint, mint_key);
+        assert!(validate_vault_balance(vault.amount, room.total_collected).is_err());
+    }
+
+    /// A room with every variable-length field at its maximum
+    fn max_room() -> crate::state::Room {
+        use crate::state::{PrizeAsset, PrizeMode, Room, RoomStatus, ROOM_VERSION};
+
+        Room {
+            room_id: "r".repeat(32),
+            host: Pubkey::new_unique(),
+            charity_wallet: Pubkey::new_unique(),
+            fee_token_mint: Pubkey::new_unique(),
+            entry_fee: u64::MAX,
+            host_fee_bps: 500,
+            prize_pool_bps: 3500,
+            charity_bps: 4000,
+            prize_mode: PrizeMode::AssetBased,
+            prize_distribution: vec![u16::MAX; MAX_WINNERS],
+            status: RoomStatus::Active,
+            player_count: u32::MAX,
+            max_players: u32::MAX,
+            total_collected: u64::MAX,
+            total_entry_fees: u64::MAX,
+            total_extras_fees: u64::MAX,
+            ended: false,
+            creation_slot: u64::MAX,
+            expiration_slot: u64::MAX,
+            charity_memo: "m".repeat(28),
+            winners: (0..MAX_WINNERS).map(|_| Pubkey::new_unique()).collect(),
+            prize_assets: std::array::from_fn(|_| {
+                Some(PrizeAsset { mint: Pubkey::new_unique(), amount: u64::MAX, deposited: true, is_nft: true })
+            }),
+            pre_freeze_status: Some(RoomStatus::Active),
+            guaranteed_min_prize: u64::MAX,
+            charity_receipt: Some([u8::MAX; 32]),
+            winner_scores: vec![u64::MAX; MAX_WINNERS],
+            charity_splits: (0..MAX_CHARITIES)
+                .map(|_| CharitySplit { wallet: Pubkey::new_unique(), weight_bps: u16::MAX })
+                .collect(),
+            min_players: u32::MAX,
+            require_ready: true,
+            ready_count: u32::MAX,
+            expiration_unix_ts: i64::MAX,
+            allowlist_root: [u8::MAX; 32],
+            platform_fee_bps: 2000,
+            prize_tiers: (0..MAX_PRIZE_TIERS)
+                .map(|_| PrizeTier { min_players: u32::MAX, distribution: vec![u16::MAX; MAX_WINNERS] })
+                .collect(),
+            host_fee_waived: true,
+            early_bird_fee: u64::MAX,
+            early_bird_count: u32::MAX,
+            discount_bps: u16::MAX,
+            total_discounts: u64::MAX,
+            place_sizes: vec![1; MAX_WINNERS],
+            bump: 255,
+            room_version: ROOM_VERSION,
+            leave_penalty_bps: u16::MAX,
+            total_leave_penalties: u64::MAX,
+            total_donations: u64::MAX,
+        }
+    }
+
+    /// A room with MAX_WINNERS winners and distribution places (and every other
+    /// variable-length field at its maximum) must fit exactly in Room::LEN
+    #[test]
+    fn test_max_winners_fits_room_account() {
+        use crate::events::WinnersDeclared;
+        use crate::state::{Room, MAX_ASSET_PRIZES};
+
+        let room = max_room();
+        let serialized = room.try_to_vec().unwrap();
+        assert_eq!(8 + serialized.len(), Room::LEN);
+
+        let event = WinnersDeclared {
+            room: Pubkey::new_unique(),
+            winners: room.winners.clone(),
+            scores: room.winner_scores.clone(),
+            place_sizes: room.place_sizes.clone(),
+            timestamp: 0,
+        };
+        assert_eq!(event.winners.len(), MAX_WINNERS);
+        assert_eq!(room.prize_assets.len(), MAX_ASSET_PRIZES);
+    }
+
+    /// What migrate_room relies on: a room written before `room_version` existed decodes
+    /// as version 0, with every later field at its default, once its account is
+    /// zero-extended to Room::LEN
+    #[test]
+    fn test_pre_version_room_reads_as_version_0() {
+        use crate::state::Room;
+
+        let room = max_room();
+        let mut data = Vec::new();
+        room.try_serialize(&mut data).unwrap();
+
+        // A full pre-version room: the same bytes up to and including the bump
+        let added_since = 1 + 2 + 8 + 8; // room_version, leave penalty fields, total_donations
+        data.truncate(data.len() - added_since);
+        assert!(Room::try_deserialize(&mut &data[..]).is_err());
+
+        // migrate_room grows the account, then decodes and rewrites it
+        data.resize(Room::LEN, 0);
+        let mut migrated = Room::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(migrated.room_version, 0);
+        assert_eq!(migrated.room_id, room.room_id);
+        assert_eq!(migrated.place_sizes, room.place_sizes);
+        assert_eq!(migrated.bump, room.bump);
+        assert_eq!((migrated.leave_penalty_bps, migrated.total_leave_penalties), (0, 0));
+        assert_eq!(migrated.total_donations, 0);
+
+        migrated.room_version = crate::state::ROOM_VERSION;
+        let mut rewritten = Vec::new();
+        migrated.try_serialize(&mut rewritten).unwrap();
+        assert_eq!(rewritten.len(), Room::LEN);
+    }
+
+    /// A room whose winners were amended to a shorter list has the tail of the longer
+    /// write behind its data; migrating it must not read that tail as the new fields
+    #[test]
+    fn test_migrate_amended_room() {
+        use crate::state::{Room, ROOM_VERSION};
+
+        for from_version in 0..ROOM_VERSION {
+            // Data written at `from_version`'s layout, into an account of that version's size
+            let old_len = Room::LEN - Room::bytes_added_since(from_version);
+            let write = |room: &Room, data: &mut Vec<u8>| {
+                let mut bytes = Vec::new();
+                room.try_serialize(&mut bytes).unwrap();
+                bytes.truncate(bytes.len() - Room::bytes_added_since(from_version));
+                data[..bytes.len()].copy_from_slice(&bytes);
+            };
+
+            let mut room = max_room();
+            room.room_version = from_version;
+            let mut data = vec![0; old_len];
+            write(&room, &mut data);
+
+            // amend_winners: one winner, scores and ties cleared
+            room.winners.truncate(1);
+            room.winner_scores.clear();
+            room.place_sizes.clear();
+            write(&room, &mut data);
+
+            data.resize(Room::LEN, 0);
+            let stale = Room::try_deserialize(&mut &data[..]).unwrap();
+            assert_ne!(stale.total_donations, 0, "stale tail expected (version {})", from_version);
+
+            let (migrated_from, migrated) = migrate_room_layout(&mut data, old_len).unwrap();
+            assert_eq!(migrated_from, from_version);
+            assert_eq!(migrated.room_version, ROOM_VERSION);
+            assert_eq!(migrated.winners, room.winners);
+            assert!(migrated.winner_scores.is_empty() && migrated.place_sizes.is_empty());
+            assert_eq!(migrated.total_donations, 0);
+            if from_version < 2 {
+                assert_eq!((migrated.leave_penalty_bps, migrated.total_leave_penalties), (0, 0));
+            } else {
+                assert_eq!(migrated.leave_penalty_bps, room.leave_penalty_bps);
+            }
+
+            // What was written is what the next instruction will load
+            let reloaded = Room::try_deserialize(&mut &data[..]).unwrap();
+            assert_eq!(reloaded.room_version, ROOM_VERSION);
+            assert_eq!(reloaded.total_donations, 0);
+        }
+
+        // A current room can't be migrated again
+        let mut data = Vec::new();
+        max_room().try_serialize(&mut data).unwrap();
+        let err = migrate_room_layout(&mut data, Room::LEN).unwrap_err();
+        assert_eq!(err, FundraiselyError::RoomAlreadyCurrent.into());
+    }
+
+    /// A room written by the original release, with its fixed winner slots, is rewritten
+    /// in full at the current layout
+    #[test]
+    fn test_migrate_legacy_room() {
+        use crate::state::{LegacyPrizeAsset, LegacyRoom, PrizeMode, Room, RoomStatus, ROOM_VERSION};
+
+        let winner = Pubkey::new_unique();
+        let legacy = LegacyRoom {
+            room_id: "r".repeat(32),
+            host: Pubkey::new_unique(),
+            charity_wallet: Pubkey::new_unique(),
+            fee_token_mint: Pubkey::new_unique(),
+            entry_fee: 10_000_000,
+            host_fee_bps: 500,
+            prize_pool_bps: 3000,
+            charity_bps: 4500,
+            prize_mode: PrizeMode::AssetBased,
+            prize_distribution: vec![50, 30, 20],
+            status: RoomStatus::Active,
+            player_count: 2,
+            max_players: 10,
+            total_collected: 20_000_000,
+            total_entry_fees: 20_000_000,
+            total_extras_fees: 0,
+            ended: false,
+            creation_slot: 100,
+            expiration_slot: 1_000,
+            charity_memo: "m".repeat(28),
+            winners: [Some(winner), None, None],
+            prize_assets: std::array::from_fn(|_| {
+                Some(LegacyPrizeAsset { mint: Pubkey::new_unique(), amount: u64::MAX, deposited: true })
+            }),
+            bump: 254,
+        };
+
+        // Written into an account of the original size
+        let mut data = Room::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        assert!(data.len() <= LegacyRoom::LEN);
+        data.resize(LegacyRoom::LEN, 0);
+        assert!(Room::try_deserialize(&mut &data[..]).is_err());
+
+        data.resize(Room::LEN, 0);
+        let (from_version, migrated) = migrate_room_layout(&mut data, LegacyRoom::LEN).unwrap();
+        assert_eq!(from_version, 0);
+        assert_eq!(migrated.room_version, ROOM_VERSION);
+        assert_eq!(migrated.room_id, legacy.room_id);
+        assert_eq!(migrated.host, legacy.host);
+        assert_eq!(migrated.prize_distribution, legacy.prize_distribution);
+        assert_eq!(migrated.total_collected, legacy.total_collected);
+        assert_eq!(migrated.winners, vec![winner]);
+        assert_eq!(migrated.bump, legacy.bump);
+        for (prize, old) in migrated.prize_assets.iter().zip(&legacy.prize_assets) {
+            let (prize, old) = (prize.as_ref().unwrap(), old.as_ref().unwrap());
+            assert_eq!((prize.mint, prize.amount, prize.deposited), (old.mint, old.amount, old.deposited));
+            assert!(!prize.is_nft);
+        }
+        // The platform kept what the original split left over
+        assert_eq!(migrated.platform_fee_bps, 2000);
+        assert!(migrated.charity_splits.is_empty() && migrated.place_sizes.is_empty());
+        assert_eq!((migrated.min_players, migrated.early_bird_count, migrated.total_donations), (0, 0, 0));
+
+        // What was written is what the next instruction will load
+        let reloaded = Room::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(reloaded.try_to_vec().unwrap(), migrated.try_to_vec().unwrap());
+
+        // ...and it isn't migrated twice
+        let err = migrate_room_layout(&mut data, Room::LEN).unwrap_err();
+        assert_eq!(err, FundraiselyError::RoomAlreadyCurrent.into());
+    }
+    #[test]
+    fn test_validate_room_vault() {
+        let (mint, room) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(validate_room_vault(&mint, &room, &mint, &room).is_ok());
+
+        let err = validate_room_vault(&Pubkey::new_unique(), &room, &mint, &room).unwrap_err();
+        assert_eq!(err, FundraiselyError::InvalidVaultAccount.into());
+
+        // A vault the host (or anyone but the room PDA) controls is rejected
+        let err = validate_room_vault(&mint, &Pubkey::new_unique(), &mint, &room).unwrap_err();
+        assert_eq!(err, FundraiselyError::InvalidVaultAuthority.into());
+    }
+
+    #[test]
+    fn test_leave_refund() {
+        assert_eq!(leave_refund(12_000_000, 1000).unwrap(), (10_800_000, 1_200_000));
+        assert_eq!(leave_refund(12_000_000, 0).unwrap(), (12_000_000, 0));
+
+        // The refund rounds down; the dust is part of the penalty
+        assert_eq!(leave_refund(999, 2000).unwrap(), (799, 200));
+        assert_eq!(leave_refund(1, 1).unwrap(), (0, 1));
+
+        let err = leave_refund(1_000, 10001).unwrap_err();
+        assert_eq!(err, FundraiselyError::InvalidLeavePenalty.into());
+    }
+
+    #[test]
+    fn test_validate_payout_total() {
+        assert!(validate_payout_total(1_000, 1_000).is_ok());
+        assert!(validate_payout_total(0, 0).is_ok());
+
+        // A vault short by one base unit (e.g. a transfer fee) fails before any transfer
+        let err = validate_payout_total(1_000, 999).unwrap_err();
+        assert_eq!(err, FundraiselyError::InsufficientVaultBalance.into());
+    }
+
+    #[test]
+    fn test_validate_vault_balance() {
+        assert!(validate_vault_balance(1_000, 1_000).is_ok());
+        // Extra balance (e.g. a funded prize guarantee) is fine
+        assert!(validate_vault_balance(1_500, 1_000).is_ok());
+
+        // A vault drained below the collected total refuses to pay out
+        let err = validate_vault_balance(999, 1_000).unwrap_err();
+        assert_eq!(err, FundraiselyError::VaultBalanceMismatch.into());
+    }
+
+    #[test]
+    fn test_validate_mint_extensions() {
+        use anchor_spl::token_2022::spl_token_2022::extension::{
+            transfer_fee::TransferFeeConfig, BaseStateWithExtensionsMut, ExtensionType,
+            StateWithExtensionsMut,
+        };
+        use anchor_spl::token_2022::spl_token_2022::state::Mint;
+
+        // Token-2022 mint data with the given extensions (initialized, 6 decimals)
+        let token_2022_mint = |extensions: &[ExtensionType]| {
+            let len = ExtensionType::try_calculate_account_len::<Mint>(extensions).unwrap();
+            let mut data = vec![0u8; len];
+            let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+            if extensions.contains(&ExtensionType::TransferFeeConfig) {
+                state.init_extension::<TransferFeeConfig>(true).unwrap();
+            }
+            state.base.decimals = 6;
+            state.base.is_initialized = true;
+            state.pack_base();
+            state.init_account_type().unwrap();
+            data
+        };
+
+        let classic = vec![0u8; 82]; // SPL Token mint size
+        assert!(validate_mint_extensions(&anchor_spl::token::ID, &classic).is_ok());
+        let plain = token_2022_mint(&[ExtensionType::MintCloseAuthority]);
+        assert!(validate_mint_extensions(&anchor_spl::token_2022::ID, &plain).is_ok());
+
+        let transfer_fee = token_2022_mint(&[ExtensionType::TransferFeeConfig]);
+        let err = validate_mint_extensions(&anchor_spl::token_2022::ID, &transfer_fee).unwrap_err();
+        assert_eq!(err, FundraiselyError::UnsupportedMintExtension.into());
+
+        // The same data is a valid mint for either token program
+        let base = token_2022_mint(&[]);
+        assert_eq!(mint_decimals(&anchor_spl::token::ID, &base).unwrap(), 6);
+        assert_eq!(mint_decimals(&anchor_spl::token_2022::ID, &plain).unwrap(), 6);
+
+        // Wrong owner, uninitialized mint or not a mint at all
+        let err = mint_decimals(&Pubkey::new_unique(), &base).unwrap_err();
+        assert_eq!(err, FundraiselyError::InvalidMint.into());
+        assert!(mint_decimals(&anchor_spl::token::ID, &classic).is_err());
+        assert!(mint_decimals(&anchor_spl::token::ID, &[0u8; 165]).is_err());
+    }
+
+    #[test]
+    fn test_global_config_records_room_end() {
+        use crate::state::GlobalConfig;
+
+        let mut config = GlobalConfig {
+            admin: Pubkey::new_unique(),
+            platform_wallet: Pubkey::new_unique(),
+            charity_wallet: Pubkey::new_unique(),
+            platform_fee_bps: 2000,
+            max_host_fee_bps: 500,
+            max_prize_pool_bps: 3500,
+            min_charity_bps: 4000,
+            emergency_pause: false,
+            require_declaration: false,
+            receipt_attestor: Pubkey::default(),
+            max_approved_tokens: 50,
+            min_platform_fee_bps: 1000,
+            lifetime_charity_raised: 0,
+            lifetime_rooms_ended: 0,
+            lifetime_players: 0,
+            max_expiration_slots: 6_480_000,
+            default_expiration_slots: 1_512_000,
+            bump: 255,
+        };
+        assert_eq!(8 + config.try_to_vec().unwrap().len(), GlobalConfig::LEN);
+
+        config.record_room_end(5_700_000, 3).unwrap();
+        config.record_room_end(0, 0).unwrap();
+        assert_eq!(config.lifetime_charity_raised, 5_700_000);
+        assert_eq!(config.lifetime_rooms_ended, 2);
+        assert_eq!(config.lifetime_players, 3);
+
+        // Overflow fails the room end instead of wrapping the tally
+        config.lifetime_charity_raised = u64::MAX;
+        assert!(config.record_room_end(1, 1).is_err());
+    }
+
+    #[test]
+    fn test_full_blacklist_fits_account() {
+        use crate::state::Blacklist;
+
+        let blacklist = Blacklist {
+            admin: Pubkey::new_unique(),
+            banned: (0..Blacklist::MAX_ENTRIES).map(|_| Pubkey::new_unique()).collect(),
+            bump: 255,
+        };
+
+        let serialized = blacklist.try_to_vec().unwrap();
+        assert_eq!(8 + serialized.len(), Blacklist::LEN);
+        assert!(blacklist.is_blacklisted(&blacklist.banned[Blacklist::MAX_ENTRIES - 1]));
+        assert!(!blacklist.is_blacklisted(&Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn test_full_token_registry_fits_account() {
+        use crate::state::{ApprovedTokenInfo, TokenRegistry, MAX_TOKEN_SYMBOL_LEN};
+
+        let registry = TokenRegistry {
+            admin: Pubkey::new_unique(),
+            approved_tokens: (0..TokenRegistry::MAX_TOKENS)
+                .map(|_| ApprovedTokenInfo {
+                    mint: Pubkey::new_unique(),
+                    symbol: "S".repeat(MAX_TOKEN_SYMBOL_LEN),
+                    decimals: Some(9),
+                })
+                .collect(),
+            bump: 255,
+        };
+
+        let serialized = registry.try_to_vec().unwrap();
+        assert_eq!(8 + serialized.len(), TokenRegistry::LEN);
+        assert!(registry.is_token_approved(&registry.approved_tokens[7].mint));
+        assert!(!registry.is_token_approved(&Pubkey::new_unique()));
+    }
 }
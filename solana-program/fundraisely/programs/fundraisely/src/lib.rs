@@ -16,13 +16,13 @@
 //! 1. **initialize** - One-time setup of global configuration (platform wallets, fee structure)
 //! 2. **init_pool_room** - Create a new fundraising game room with prize pool distribution
 //! 3. **join_room** - Player entry with automatic fee collection and distribution
-//! 4. **declare_winners** - Host declares 1-3 winners before fund distribution (transparency)
+//! 4. **declare_winners** - Host declares its winners before fund distribution (transparency)
 //! 5. **end_room** - Finalize game, distribute prizes, and transfer charity donations
 //!
 //! ## Economic Model (Trustless Distribution)
 //!
 //! Entry fees are automatically split via on-chain execution:
-//! - **Platform Fee**: 20% (fixed) - Covers infrastructure and development
+//! - **Platform Fee**: 20% (admin may lower it per room, charity gets the difference) - Covers infrastructure and development
 //! - **Host Fee**: 0-5% (configurable) - Incentivizes room creation
 //! - **Prize Pool**: 0-35% (configurable) - Rewards to winners
 //! - **Charity**: 40%+ minimum (calculated remainder) - Primary beneficiary
@@ -58,6 +58,9 @@
 //! - **Host Restrictions**: Hosts cannot be winners, preventing self-dealing
 //! - **Arithmetic Safety**: All calculations use checked math to prevent overflow/underflow exploits
 
+// Instruction handlers mirror their Anchor arguments one-to-one
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
 
 // Module declarations
@@ -82,7 +85,7 @@ declare_id!("DurTiNFFQK62B5nMimfhuvztJXsFyu8skMz6rNtp2Wmq");
 mod fundraisely {
     use super::*;
 
-    ///Initialize the global configuration (one-time setup)
+    ///Initialize the global configuration (one-time setup; repeat calls by the admin are no-ops)
     pub fn initialize(
         ctx: Context<Initialize>,
         platform_wallet: Pubkey,
@@ -100,11 +103,19 @@ mod fundraisely {
         max_players: u32,
         host_fee_bps: u16,
         prize_pool_bps: u16,
-        first_place_pct: u16,
-        second_place_pct: Option<u16>,
-        third_place_pct: Option<u16>,
+        prize_distribution: Vec<u16>,
         charity_memo: String,
         expiration_slots: Option<u64>,
+        charity_splits: Option<Vec<CharitySplit>>,
+        min_players: Option<u32>,
+        require_ready: Option<bool>,
+        expiration_unix_ts: Option<i64>,
+        allowlist_root: Option<[u8; 32]>,
+        prize_tiers: Option<Vec<PrizeTier>>,
+        early_bird_fee: Option<u64>,
+        early_bird_count: Option<u32>,
+        discount_bps: Option<u16>,
+        leave_penalty_bps: Option<u16>,
     ) -> Result<()> {
         crate::instructions::room::init_pool_room::handler(
             ctx,
@@ -114,21 +125,81 @@ mod fundraisely {
             max_players,
             host_fee_bps,
             prize_pool_bps,
-            first_place_pct,
-            second_place_pct,
-            third_place_pct,
+            prize_distribution,
             charity_memo,
             expiration_slots,
+            charity_splits,
+            min_players,
+            require_ready,
+            expiration_unix_ts,
+            allowlist_root,
+            prize_tiers,
+            early_bird_fee,
+            early_bird_count,
+            discount_bps,
+            leave_penalty_bps,
         )
     }
 
+    /// Let a wallet join a room at its discounted entry fee (host only)
+    pub fn add_discount(ctx: Context<AddDiscount>, room_id: String, player: Pubkey) -> Result<()> {
+        crate::instructions::room::add_discount::handler(ctx, room_id, player)
+    }
+
+    /// Escrow a guaranteed minimum prize for a pool room (host only, before any joins)
+    pub fn fund_min_prize(
+        ctx: Context<FundMinPrize>,
+        room_id: String,
+        amount: u64,
+    ) -> Result<()> {
+        crate::instructions::room::fund_min_prize::handler(ctx, room_id, amount)
+    }
+
     /// Join a room by paying entry fee
     pub fn join_room(
         ctx: Context<JoinRoom>,
         room_id: String,
         extras_amount: u64,
+        allowlist_proof: Option<Vec<[u8; 32]>>,
+        referrer: Option<Pubkey>,
     ) -> Result<()> {
-        crate::instructions::player::join_room::handler(ctx, room_id, extras_amount)
+        crate::instructions::player::join_room::handler(
+            ctx,
+            room_id,
+            extras_amount,
+            allowlist_proof,
+            referrer,
+        )
+    }
+
+    /// Leave a room and get a refund, less the room's leave penalty (kept for charity)
+    pub fn leave_room(ctx: Context<LeaveRoom>, room_id: String) -> Result<()> {
+        crate::instructions::player::leave_room::handler(ctx, room_id)
+    }
+
+    /// Donate to a room's charity without joining as a player
+    pub fn donate(ctx: Context<Donate>, room_id: String, amount: u64) -> Result<()> {
+        crate::instructions::player::donate::handler(ctx, room_id, amount)
+    }
+
+    /// Signal readiness to start a turn-based game
+    pub fn ready_up(ctx: Context<ReadyUp>, room_id: String) -> Result<()> {
+        crate::instructions::player::ready_up::handler(ctx, room_id)
+    }
+
+    /// Lift a room's readiness requirement (host only)
+    pub fn waive_ready_check(ctx: Context<WaiveReadyCheck>, room_id: String) -> Result<()> {
+        crate::instructions::room::waive_ready_check::handler(ctx, room_id)
+    }
+
+    /// Send the room's host fee to charity when it ends (host only)
+    pub fn waive_host_fee(ctx: Context<WaiveHostFee>, room_id: String) -> Result<()> {
+        crate::instructions::room::waive_host_fee::handler(ctx, room_id)
+    }
+
+    /// Close a room nobody has joined, returning its rent and any escrow to the host
+    pub fn cancel_room(ctx: Context<CancelRoom>, room_id: String) -> Result<()> {
+        crate::instructions::room::cancel_room::handler(ctx, room_id)
     }
 
     /// Declare winners for a room (must be called before end_room)
@@ -136,8 +207,19 @@ mod fundraisely {
         ctx: Context<'_, '_, '_, 'info, DeclareWinners<'info>>,
         room_id: String,
         winners: Vec<Pubkey>,
+        scores: Option<Vec<u64>>,
+        place_sizes: Option<Vec<u8>>,
+    ) -> Result<()> {
+        crate::instructions::game::declare_winners::handler(ctx, room_id, winners, scores, place_sizes)
+    }
+
+    /// Replace declared winners before end_room (host only)
+    pub fn amend_winners<'info>(
+        ctx: Context<'_, '_, '_, 'info, AmendWinners<'info>>,
+        room_id: String,
+        winners: Vec<Pubkey>,
     ) -> Result<()> {
-        crate::instructions::game::declare_winners::handler(ctx, room_id, winners)
+        crate::instructions::game::amend_winners::handler(ctx, room_id, winners)
     }
 
     /// End room and distribute prizes to winners
@@ -149,14 +231,36 @@ mod fundraisely {
         crate::instructions::game::end_room::handler(ctx, room_id, winners)
     }
 
-    /// Initialize the token registry (one-time setup)
+    /// Settle an expired room the host never ended (anyone can call)
+    pub fn finalize_expired_room<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeExpiredRoom<'info>>,
+        room_id: String,
+    ) -> Result<()> {
+        crate::instructions::game::finalize_expired_room::handler(ctx, room_id)
+    }
+
+    /// Record the charity's receipt acknowledgment for an ended room
+    pub fn record_charity_receipt(
+        ctx: Context<RecordCharityReceipt>,
+        room_id: String,
+        receipt_hash: [u8; 32],
+    ) -> Result<()> {
+        crate::instructions::game::record_charity_receipt::handler(ctx, room_id, receipt_hash)
+    }
+
+    /// Initialize the token registry (one-time setup; repeat calls by the admin are no-ops)
     pub fn initialize_token_registry(ctx: Context<InitializeTokenRegistry>) -> Result<()> {
         crate::instructions::admin::initialize_token_registry::handler(ctx)
     }
 
-    /// Add a token to the approved list
-    pub fn add_approved_token(ctx: Context<AddApprovedToken>, token_mint: Pubkey) -> Result<()> {
-        crate::instructions::admin::add_approved_token::handler(ctx, token_mint)
+    /// Add a token to the approved list, with optional symbol (decimals are read from the mint)
+    pub fn add_approved_token(
+        ctx: Context<AddApprovedToken>,
+        token_mint: Pubkey,
+        symbol: Option<String>,
+        decimals: Option<u8>,
+    ) -> Result<()> {
+        crate::instructions::admin::add_approved_token::handler(ctx, token_mint, symbol, decimals)
     }
 
     /// Remove a token from the approved list
@@ -164,6 +268,21 @@ mod fundraisely {
         crate::instructions::admin::remove_approved_token::handler(ctx, token_mint)
     }
 
+    /// Initialize the player blacklist (one-time setup, admin only)
+    pub fn initialize_blacklist(ctx: Context<InitializeBlacklist>) -> Result<()> {
+        crate::instructions::admin::initialize_blacklist::handler(ctx)
+    }
+
+    /// Ban a wallet from joining rooms
+    pub fn add_to_blacklist(ctx: Context<AddToBlacklist>, wallet: Pubkey) -> Result<()> {
+        crate::instructions::admin::add_to_blacklist::handler(ctx, wallet)
+    }
+
+    /// Lift a wallet's ban
+    pub fn remove_from_blacklist(ctx: Context<RemoveFromBlacklist>, wallet: Pubkey) -> Result<()> {
+        crate::instructions::admin::remove_from_blacklist::handler(ctx, wallet)
+    }
+
     /// Initialize asset-based room
     pub fn init_asset_room(
         ctx: Context<InitAssetRoom>,
@@ -180,6 +299,9 @@ mod fundraisely {
         prize_2_amount: Option<u64>,
         prize_3_mint: Option<Pubkey>,
         prize_3_amount: Option<u64>,
+        expiration_unix_ts: Option<i64>,
+        allowlist_root: Option<[u8; 32]>,
+        nft_prizes: Option<[bool; 3]>,
     ) -> Result<()> {
         crate::instructions::asset::init_asset_room::handler(
             ctx,
@@ -196,6 +318,9 @@ mod fundraisely {
             prize_2_amount,
             prize_3_mint,
             prize_3_amount,
+            expiration_unix_ts,
+            allowlist_root,
+            nft_prizes,
         )
     }
 
@@ -208,12 +333,65 @@ mod fundraisely {
         crate::instructions::asset::add_prize_asset::handler(ctx, room_id, prize_index)
     }
 
+    /// End asset-based room and hand escrowed prizes to winners
+    pub fn end_asset_room<'info>(
+        ctx: Context<'_, '_, '_, 'info, EndAssetRoom<'info>>,
+        room_id: String,
+        winners: Vec<Pubkey>,
+    ) -> Result<()> {
+        crate::instructions::asset::end_asset_room::handler(ctx, room_id, winners)
+    }
+
+    /// Update global configuration policy flags (admin only)
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        require_declaration: Option<bool>,
+        receipt_attestor: Option<Pubkey>,
+        max_approved_tokens: Option<u16>,
+        max_expiration_slots: Option<u64>,
+        default_expiration_slots: Option<u64>,
+    ) -> Result<()> {
+        crate::instructions::admin::update_config::handler(
+            ctx,
+            require_declaration,
+            receipt_attestor,
+            max_approved_tokens,
+            max_expiration_slots,
+            default_expiration_slots,
+        )
+    }
+
     /// Recover abandoned room (admin only)
     pub fn recover_room<'info>(
         ctx: Context<'_, '_, 'info, 'info, RecoverRoom<'info>>,
         room_id: String,
+        reason: Option<RecoveryReason>,
+    ) -> Result<()> {
+        crate::instructions::admin::recover_room::handler(ctx, room_id, reason)
+    }
+
+    /// Freeze a room, blocking joins, ends and recovery (admin only)
+    pub fn freeze_room(ctx: Context<FreezeRoom>, room_id: String) -> Result<()> {
+        crate::instructions::admin::freeze_room::handler(ctx, room_id)
+    }
+
+    /// Lift a freeze and restore the room's previous status (admin only)
+    pub fn unfreeze_room(ctx: Context<UnfreezeRoom>, room_id: String) -> Result<()> {
+        crate::instructions::admin::unfreeze_room::handler(ctx, room_id)
+    }
+
+    /// Reduce a room's platform fee before anyone joins; charity gets the difference (admin only)
+    pub fn set_room_platform_fee(
+        ctx: Context<SetRoomPlatformFee>,
+        room_id: String,
+        platform_fee_bps: u16,
     ) -> Result<()> {
-        crate::instructions::admin::recover_room::handler(ctx, room_id)
+        crate::instructions::admin::set_room_platform_fee::handler(ctx, room_id, platform_fee_bps)
+    }
+
+    /// Bring a room written with an older account layout up to ROOM_VERSION (admin only)
+    pub fn migrate_room(ctx: Context<MigrateRoom>) -> Result<()> {
+        crate::instructions::admin::migrate_room::handler(ctx)
     }
 }
 
@@ -222,8 +400,9 @@ mod fundraisely {
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
+    /// Created on the first call; later calls see the existing config (see handler)
     #[account(
-        init,
+        init_if_needed,
         payer = admin,
         space = GlobalConfig::LEN,
         seeds = [b"global-config"],
@@ -235,6 +414,18 @@ pub struct Initialize<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
+
 #[derive(Accounts)]
 #[instruction(room_id: String)]
 pub struct InitPoolRoom<'info> {
@@ -247,18 +438,22 @@ pub struct InitPoolRoom<'info> {
     )]
     pub room: Account<'info, Room>,
 
-    /// CHECK: Room vault PDA - will be initialized as TokenAccount by frontend before or during room creation.
-    /// SECURITY: Handler validates this is a proper TokenAccount with correct mint/authority.
-    /// Using AccountInfo to avoid initialization order issues (room must exist before vault can use it as authority).
-    /// The handler performs manual deserialization and validation of TokenAccount structure.
-    /// Mutability is not enforced by constraint since this is AccountInfo - writable via account list order.
+    /// Room vault PDA, created here for the fee token with the room PDA as authority, so
+    /// only the program can move entry fees out. The handler re-checks mint and authority.
     #[account(
+        init,
+        payer = host,
         seeds = [b"room-vault", room.key().as_ref()],
-        bump
+        bump,
+        token::mint = fee_token_mint,
+        token::authority = room,
+        token::token_program = token_program,
     )]
-    pub room_vault: AccountInfo<'info>,
+    pub room_vault: InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>,
 
-    pub fee_token_mint: Account<'info, anchor_spl::token::Mint>,
+    /// Fee token mint; Token-2022 mints are accepted here so the handler can reject
+    /// unsupported extensions (e.g., transfer fees) with a clear error
+    pub fee_token_mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
 
     #[account(
         seeds = [b"token-registry"],
@@ -276,17 +471,45 @@ pub struct InitPoolRoom<'info> {
     pub host: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, anchor_spl::token::Token>,
+    pub token_program: Interface<'info, anchor_spl::token_interface::TokenInterface>,
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[derive(Accounts)]
+#[instruction(room_id: String)]
+pub struct FundMinPrize<'info> {
+    #[account(
+        mut,
+        seeds = [b"room", host.key().as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+    )]
+    pub room: Account<'info, Room>,
+
+    #[account(
+        mut,
+        seeds = [b"room-vault", room.key().as_ref()],
+        bump
+    )]
+    pub room_vault: Account<'info, anchor_spl::token::TokenAccount>,
+
+    #[account(mut)]
+    pub host_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+
+    #[account(mut)]
+    pub host: Signer<'info>,
+
+    pub token_program: Program<'info, anchor_spl::token::Token>,
+}
+
 #[derive(Accounts)]
 #[instruction(room_id: String)]
 pub struct JoinRoom<'info> {
     #[account(
         mut,
         seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
-        bump = room.bump
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
     )]
     pub room: Account<'info, Room>,
 
@@ -317,6 +540,19 @@ pub struct JoinRoom<'info> {
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
+    #[account(
+        seeds = [b"blacklist"],
+        bump = blacklist.bump
+    )]
+    pub blacklist: Account<'info, Blacklist>,
+
+    /// The player's discount for this room, if the host registered one
+    #[account(
+        seeds = [b"discount", room.key().as_ref(), player.key().as_ref()],
+        bump = discount.bump
+    )]
+    pub discount: Option<Account<'info, Discount>>,
+
     #[account(mut)]
     pub player: Signer<'info>,
 
@@ -325,119 +561,127 @@ pub struct JoinRoom<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(room_id: String)]
-pub struct DeclareWinners<'info> {
+#[instruction(room_id: String, player: Pubkey)]
+pub struct AddDiscount<'info> {
     #[account(
-        mut,
         seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
         bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
     )]
     pub room: Account<'info, Room>,
 
+    #[account(
+        init,
+        payer = host,
+        space = Discount::LEN,
+        seeds = [b"discount", room.key().as_ref(), player.as_ref()],
+        bump
+    )]
+    pub discount: Account<'info, Discount>,
+
     #[account(mut)]
     pub host: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
 #[instruction(room_id: String)]
-pub struct EndRoom<'info> {
+pub struct ReadyUp<'info> {
     #[account(
         mut,
-        seeds = [b"room", host.key().as_ref(), room_id.as_bytes()],
+        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
         bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
     )]
     pub room: Account<'info, Room>,
 
-    #[account(mut)]
-    pub room_vault: Account<'info, anchor_spl::token::TokenAccount>,
-
-    #[account(seeds = [b"global-config"], bump = global_config.bump)]
-    pub global_config: Account<'info, GlobalConfig>,
-
-    #[account(mut)]
-    pub platform_token_account: Account<'info, anchor_spl::token::TokenAccount>,
-
-    #[account(mut)]
-    pub charity_token_account: Account<'info, anchor_spl::token::TokenAccount>,
-
-    #[account(mut)]
-    pub host_token_account: Account<'info, anchor_spl::token::TokenAccount>,
-
-    #[account(mut)]
-    pub host: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"player", room.key().as_ref(), player.key().as_ref()],
+        bump = player_entry.bump
+    )]
+    pub player_entry: Account<'info, PlayerEntry>,
 
-    pub token_program: Program<'info, anchor_spl::token::Token>,
+    pub player: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeTokenRegistry<'info> {
+#[instruction(room_id: String)]
+pub struct LeaveRoom<'info> {
     #[account(
-        init,
-        payer = admin,
-        space = TokenRegistry::LEN,
-        seeds = [b"token-registry"],
-        bump
+        mut,
+        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
     )]
-    pub token_registry: Account<'info, TokenRegistry>,
-
-    #[account(mut)]
-    pub admin: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
-}
+    pub room: Account<'info, Room>,
 
-#[derive(Accounts)]
-pub struct AddApprovedToken<'info> {
+    /// Closed on leave, returning its rent to the player
     #[account(
         mut,
-        seeds = [b"token-registry"],
-        bump = token_registry.bump
+        seeds = [b"player", room.key().as_ref(), player.key().as_ref()],
+        bump = player_entry.bump,
+        close = player
     )]
-    pub token_registry: Account<'info, TokenRegistry>,
+    pub player_entry: Account<'info, PlayerEntry>,
 
-    #[account(mut)]
-    pub admin: Signer<'info>,
-}
+    #[account(
+        mut,
+        seeds = [b"room-vault", room.key().as_ref()],
+        bump
+    )]
+    pub room_vault: Account<'info, anchor_spl::token::TokenAccount>,
 
-#[derive(Accounts)]
-pub struct RemoveApprovedToken<'info> {
+    /// Receives the refund; must be the player's own account for the room's fee token
     #[account(
         mut,
-        seeds = [b"token-registry"],
-        bump = token_registry.bump
+        constraint = player_token_account.owner == player.key() @ FundraiselyError::InvalidTokenOwner,
+        constraint = player_token_account.mint == room.fee_token_mint @ FundraiselyError::InvalidTokenMint,
     )]
-    pub token_registry: Account<'info, TokenRegistry>,
+    pub player_token_account: Account<'info, anchor_spl::token::TokenAccount>,
 
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub player: Signer<'info>,
+
+    pub token_program: Program<'info, anchor_spl::token::Token>,
 }
 
 #[derive(Accounts)]
 #[instruction(room_id: String)]
-pub struct InitAssetRoom<'info> {
+pub struct Donate<'info> {
     #[account(
-        init,
-        payer = host,
-        space = Room::LEN,
-        seeds = [b"room", host.key().as_ref(), room_id.as_bytes()],
-        bump
+        mut,
+        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
     )]
     pub room: Account<'info, Room>,
 
-    /// CHECK: Room vault PDA
+    /// The donor's receipt for this room, created on their first donation
     #[account(
-        seeds = [b"room-vault", room.key().as_ref()],
+        init_if_needed,
+        payer = donor,
+        space = Donation::LEN,
+        seeds = [b"donation", room.key().as_ref(), donor.key().as_ref()],
         bump
     )]
-    pub room_vault: AccountInfo<'info>,
+    pub donation: Account<'info, Donation>,
 
-    pub fee_token_mint: Account<'info, anchor_spl::token::Mint>,
+    #[account(
+        mut,
+        seeds = [b"room-vault", room.key().as_ref()],
+        bump
+    )]
+    pub room_vault: Account<'info, anchor_spl::token::TokenAccount>,
 
+    /// Pays the donation; must be the donor's own account for the room's fee token
     #[account(
-        seeds = [b"token-registry"],
-        bump = token_registry.bump
+        mut,
+        constraint = donor_token_account.owner == donor.key() @ FundraiselyError::InvalidTokenOwner,
+        constraint = donor_token_account.mint == room.fee_token_mint @ FundraiselyError::InvalidTokenMint,
     )]
-    pub token_registry: Account<'info, TokenRegistry>,
+    pub donor_token_account: Account<'info, anchor_spl::token::TokenAccount>,
 
     #[account(
         seeds = [b"global-config"],
@@ -445,43 +689,56 @@ pub struct InitAssetRoom<'info> {
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
+    #[account(
+        seeds = [b"blacklist"],
+        bump = blacklist.bump
+    )]
+    pub blacklist: Account<'info, Blacklist>,
+
     #[account(mut)]
-    pub host: Signer<'info>,
+    pub donor: Signer<'info>,
 
-    pub system_program: Program<'info, System>,
     pub token_program: Program<'info, anchor_spl::token::Token>,
-    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
 #[instruction(room_id: String)]
-pub struct AddPrizeAsset<'info> {
+pub struct WaiveReadyCheck<'info> {
     #[account(
         mut,
         seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
-        bump = room.bump
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
     )]
     pub room: Account<'info, Room>,
 
-    #[account(mut)]
-    pub prize_vault: Account<'info, anchor_spl::token::TokenAccount>,
+    pub host: Signer<'info>,
+}
 
-    #[account(mut)]
-    pub host_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+#[derive(Accounts)]
+#[instruction(room_id: String)]
+pub struct WaiveHostFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+    )]
+    pub room: Account<'info, Room>,
 
-    #[account(mut)]
     pub host: Signer<'info>,
-
-    pub token_program: Program<'info, anchor_spl::token::Token>,
 }
 
 #[derive(Accounts)]
 #[instruction(room_id: String)]
-pub struct RecoverRoom<'info> {
+pub struct CancelRoom<'info> {
     #[account(
         mut,
-        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
-        bump = room.bump
+        seeds = [b"room", host.key().as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+        close = host
     )]
     pub room: Account<'info, Room>,
 
@@ -492,17 +749,521 @@ pub struct RecoverRoom<'info> {
     )]
     pub room_vault: Account<'info, anchor_spl::token::TokenAccount>,
 
-    #[account(
-        seeds = [b"global-config"],
-        bump = global_config.bump
-    )]
-    pub global_config: Account<'info, GlobalConfig>,
+    /// Receives any guaranteed minimum prize escrow
+    #[account(mut, token::authority = host)]
+    pub host_token_account: Account<'info, anchor_spl::token::TokenAccount>,
 
+    /// Charity wallet's token account, required only when players left funds behind
     #[account(mut)]
-    pub platform_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+    pub charity_token_account: Option<Account<'info, anchor_spl::token::TokenAccount>>,
 
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub host: Signer<'info>,
 
     pub token_program: Program<'info, anchor_spl::token::Token>,
 }
+
+#[derive(Accounts)]
+#[instruction(room_id: String)]
+pub struct DeclareWinners<'info> {
+    #[account(
+        mut,
+        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+    )]
+    pub room: Account<'info, Room>,
+
+    #[account(mut)]
+    pub host: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(room_id: String)]
+pub struct AmendWinners<'info> {
+    #[account(
+        mut,
+        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+    )]
+    pub room: Account<'info, Room>,
+
+    pub host: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(room_id: String)]
+pub struct EndRoom<'info> {
+    #[account(
+        mut,
+        seeds = [b"room", host.key().as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+    )]
+    pub room: Account<'info, Room>,
+
+    #[account(
+        mut,
+        seeds = [b"room-vault", room.key().as_ref()],
+        bump
+    )]
+    pub room_vault: Account<'info, anchor_spl::token::TokenAccount>,
+
+    #[account(mut, seeds = [b"global-config"], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        constraint = platform_token_account.owner == global_config.platform_wallet @ FundraiselyError::InvalidTokenOwner,
+        constraint = platform_token_account.mint == room.fee_token_mint @ FundraiselyError::InvalidTokenMint,
+    )]
+    pub platform_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+
+    /// Must belong to the room's charity wallet so donations can't be redirected
+    #[account(
+        mut,
+        constraint = charity_token_account.owner == room.charity_wallet @ FundraiselyError::InvalidTokenOwner,
+        constraint = charity_token_account.mint == room.fee_token_mint @ FundraiselyError::InvalidTokenMint,
+    )]
+    pub charity_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = host_token_account.owner == room.host @ FundraiselyError::InvalidTokenOwner,
+        constraint = host_token_account.mint == room.fee_token_mint @ FundraiselyError::InvalidTokenMint,
+    )]
+    pub host_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+
+    #[account(mut)]
+    pub host: Signer<'info>,
+
+    pub token_program: Program<'info, anchor_spl::token::Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(room_id: String)]
+pub struct FinalizeExpiredRoom<'info> {
+    #[account(
+        mut,
+        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+    )]
+    pub room: Account<'info, Room>,
+
+    #[account(
+        mut,
+        seeds = [b"room-vault", room.key().as_ref()],
+        bump
+    )]
+    pub room_vault: Account<'info, anchor_spl::token::TokenAccount>,
+
+    #[account(mut, seeds = [b"global-config"], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        constraint = platform_token_account.owner == global_config.platform_wallet @ FundraiselyError::InvalidTokenOwner,
+        constraint = platform_token_account.mint == room.fee_token_mint @ FundraiselyError::InvalidTokenMint,
+    )]
+    pub platform_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = charity_token_account.owner == room.charity_wallet @ FundraiselyError::InvalidTokenOwner,
+        constraint = charity_token_account.mint == room.fee_token_mint @ FundraiselyError::InvalidTokenMint,
+    )]
+    pub charity_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+
+    /// Anyone may finalize an expired room
+    pub caller: Signer<'info>,
+
+    pub token_program: Program<'info, anchor_spl::token::Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(room_id: String)]
+pub struct RecordCharityReceipt<'info> {
+    #[account(
+        mut,
+        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+    )]
+    pub room: Account<'info, Room>,
+
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Room's charity wallet or the configured receipt attestor
+    pub recorder: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTokenRegistry<'info> {
+    /// Created on the first call; later calls see the existing registry (see handler)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = TokenRegistry::LEN,
+        seeds = [b"token-registry"],
+        bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(token_mint: Pubkey)]
+pub struct AddApprovedToken<'info> {
+    #[account(
+        mut,
+        seeds = [b"token-registry"],
+        bump = token_registry.bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// CHECK: Must be `token_mint`; the handler verifies it's an initialized mint and reads
+    /// its decimals
+    #[account(address = token_mint @ FundraiselyError::InvalidMint)]
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveApprovedToken<'info> {
+    #[account(
+        mut,
+        seeds = [b"token-registry"],
+        bump = token_registry.bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeBlacklist<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Blacklist::LEN,
+        seeds = [b"blacklist"],
+        bump
+    )]
+    pub blacklist: Account<'info, Blacklist>,
+
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddToBlacklist<'info> {
+    #[account(
+        mut,
+        seeds = [b"blacklist"],
+        bump = blacklist.bump
+    )]
+    pub blacklist: Account<'info, Blacklist>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveFromBlacklist<'info> {
+    #[account(
+        mut,
+        seeds = [b"blacklist"],
+        bump = blacklist.bump
+    )]
+    pub blacklist: Account<'info, Blacklist>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(room_id: String)]
+pub struct InitAssetRoom<'info> {
+    #[account(
+        init,
+        payer = host,
+        space = Room::LEN,
+        seeds = [b"room", host.key().as_ref(), room_id.as_bytes()],
+        bump
+    )]
+    pub room: Account<'info, Room>,
+
+    /// Room vault PDA, created here for the fee token with the room PDA as authority, so
+    /// only the program can move entry fees out. The handler re-checks mint and authority.
+    #[account(
+        init,
+        payer = host,
+        seeds = [b"room-vault", room.key().as_ref()],
+        bump,
+        token::mint = fee_token_mint,
+        token::authority = room,
+        token::token_program = token_program,
+    )]
+    pub room_vault: InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>,
+
+    /// Fee token mint; Token-2022 mints are accepted here so the handler can reject
+    /// unsupported extensions (e.g., transfer fees) with a clear error
+    pub fee_token_mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+
+    #[account(
+        seeds = [b"token-registry"],
+        bump = token_registry.bump
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub host: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, anchor_spl::token_interface::TokenInterface>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(room_id: String, prize_index: u8)]
+pub struct AddPrizeAsset<'info> {
+    #[account(
+        mut,
+        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+    )]
+    pub room: Account<'info, Room>,
+
+    /// Prize vault PDA for this prize, created on deposit with the room PDA as authority
+    /// so only the program can release the prize (to the winner in end_asset_room).
+    #[account(
+        init,
+        payer = host,
+        seeds = [b"prize-vault", room.key().as_ref(), &[prize_index]],
+        bump,
+        token::mint = prize_mint,
+        token::authority = room,
+    )]
+    pub prize_vault: Account<'info, anchor_spl::token::TokenAccount>,
+
+    /// Mint of the prize being deposited (must match the room's declared prize)
+    pub prize_mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(mut)]
+    pub host_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+
+    #[account(mut)]
+    pub host: Signer<'info>,
+
+    pub token_program: Program<'info, anchor_spl::token::Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(room_id: String)]
+pub struct EndAssetRoom<'info> {
+    #[account(
+        mut,
+        seeds = [b"room", host.key().as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+    )]
+    pub room: Account<'info, Room>,
+
+    #[account(
+        mut,
+        seeds = [b"room-vault", room.key().as_ref()],
+        bump
+    )]
+    pub room_vault: Account<'info, anchor_spl::token::TokenAccount>,
+
+    #[account(mut, seeds = [b"global-config"], bump = global_config.bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        constraint = platform_token_account.owner == global_config.platform_wallet @ FundraiselyError::InvalidTokenOwner,
+        constraint = platform_token_account.mint == room.fee_token_mint @ FundraiselyError::InvalidTokenMint,
+    )]
+    pub platform_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = charity_token_account.owner == room.charity_wallet @ FundraiselyError::InvalidTokenOwner,
+        constraint = charity_token_account.mint == room.fee_token_mint @ FundraiselyError::InvalidTokenMint,
+    )]
+    pub charity_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = host_token_account.owner == room.host @ FundraiselyError::InvalidTokenOwner,
+        constraint = host_token_account.mint == room.fee_token_mint @ FundraiselyError::InvalidTokenMint,
+    )]
+    pub host_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+
+    #[account(mut)]
+    pub host: Signer<'info>,
+
+    pub token_program: Program<'info, anchor_spl::token::Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(room_id: String)]
+pub struct RecoverRoom<'info> {
+    #[account(
+        mut,
+        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+    )]
+    pub room: Account<'info, Room>,
+
+    #[account(
+        mut,
+        seeds = [b"room-vault", room.key().as_ref()],
+        bump
+    )]
+    pub room_vault: Account<'info, anchor_spl::token::TokenAccount>,
+
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Receives the recovery fee; must be the platform wallet's account for the fee token
+    #[account(
+        mut,
+        constraint = platform_token_account.owner == global_config.platform_wallet @ FundraiselyError::InvalidTokenOwner,
+        constraint = platform_token_account.mint == room.fee_token_mint @ FundraiselyError::InvalidTokenMint,
+    )]
+    pub platform_token_account: Account<'info, anchor_spl::token::TokenAccount>,
+
+    /// Host's token account, required only to refund an escrowed minimum prize
+    #[account(mut)]
+    pub host_token_account: Option<Account<'info, anchor_spl::token::TokenAccount>>,
+
+    /// Charity wallet's token account, required only to pass on donations
+    #[account(mut)]
+    pub charity_token_account: Option<Account<'info, anchor_spl::token::TokenAccount>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, anchor_spl::token::Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(room_id: String)]
+pub struct FreezeRoom<'info> {
+    #[account(
+        mut,
+        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+    )]
+    pub room: Account<'info, Room>,
+
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(room_id: String)]
+pub struct UnfreezeRoom<'info> {
+    #[account(
+        mut,
+        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+    )]
+    pub room: Account<'info, Room>,
+
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(room_id: String)]
+pub struct SetRoomPlatformFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"room", room.host.as_ref(), room_id.as_bytes()],
+        bump = room.bump,
+        constraint = room.room_version == ROOM_VERSION @ FundraiselyError::UnsupportedRoomVersion,
+    )]
+    pub room: Account<'info, Room>,
+
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateRoom<'info> {
+    /// CHECK: May predate the current `Room` layout, so it can't be loaded as `Account<Room>`;
+    /// the handler checks the discriminator before growing and rewriting it
+    #[account(mut, owner = crate::ID)]
+    pub room: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"global-config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Pays the rent for the grown account
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
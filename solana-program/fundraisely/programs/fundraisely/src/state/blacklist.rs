@@ -0,0 +1,56 @@
+//! # Blacklist State
+//!
+//! Platform-wide list of wallets barred from joining rooms.
+//!
+//! ## Purpose
+//!
+//! Lets the platform admin keep known bad actors (cheaters, wallets tied to stolen
+//! funds) out of every room, without each host having to maintain their own list.
+//!
+//! ## PDA Derivation
+//!
+//! Seeds: ["blacklist"]
+//! Bump: Stored in Blacklist.bump
+//!
+//! ## Admin Operations
+//!
+//! - initialize_blacklist: One-time setup (creates PDA, GlobalConfig admin only)
+//! - add_to_blacklist: Ban a wallet (fails with BlacklistFull at `MAX_ENTRIES`)
+//! - remove_from_blacklist: Lift a ban
+//!
+//! ## Validation
+//!
+//! join_room rejects players found in `banned` with PlayerBlacklisted. Players who
+//! joined before being banned keep their entry; the ban only applies to new joins.
+
+use anchor_lang::prelude::*;
+
+/// Platform-wide list of banned wallets
+#[account]
+#[derive(Debug)]
+pub struct Blacklist {
+    /// Admin who can modify the blacklist
+    pub admin: Pubkey,
+
+    /// Banned wallets
+    pub banned: Vec<Pubkey>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Blacklist {
+    /// Maximum number of banned wallets the account is sized for (prevents unbounded growth)
+    pub const MAX_ENTRIES: usize = 200;
+
+    /// Account size calculation
+    pub const LEN: usize = 8 + // discriminator
+        32 + // admin
+        (4 + 32 * Self::MAX_ENTRIES) + // banned Vec<Pubkey>
+        1; // bump
+
+    /// Check if a wallet is banned
+    pub fn is_blacklisted(&self, wallet: &Pubkey) -> bool {
+        self.banned.contains(wallet)
+    }
+}
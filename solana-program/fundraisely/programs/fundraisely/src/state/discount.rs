@@ -0,0 +1,43 @@
+//! # Discount State
+//!
+//! Marks a wallet as entitled to a room's discounted entry fee.
+//!
+//! ## Purpose
+//!
+//! Hosts running community events can let specific wallets join for less. A room
+//! created with a non-zero `discount_bps` accepts `add_discount(player)` from its host,
+//! which creates one of these PDAs; when that wallet joins, `join_room` charges
+//! `entry_fee * (10000 - discount_bps) / 10000` and the reduced fee is split as usual.
+//!
+//! ## PDA Derivation
+//!
+//! Seeds: ["discount", room_pubkey, player_pubkey]
+//! Bump: Stored in Discount.bump
+//!
+//! ## Validation
+//!
+//! Clients pass the PDA as `join_room`'s optional `discount` account; the seeds tie it
+//! to the joining player, so one wallet's discount can't be used by another.
+
+use anchor_lang::prelude::*;
+
+/// A wallet's entitlement to a room's discounted entry fee
+#[account]
+#[derive(Debug)]
+pub struct Discount {
+    /// Room the discount applies to
+    pub room: Pubkey,
+
+    /// Wallet entitled to the discount
+    pub player: Pubkey,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Discount {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // room
+        32 + // player
+        1; // bump
+}
@@ -0,0 +1,45 @@
+//! # Donation State
+//!
+//! Receipt of what a supporter has donated to a room without playing.
+//!
+//! ## Purpose
+//!
+//! Some supporters want to give to a room's charity without taking a player slot or
+//! being eligible to win. `donate` transfers their tokens to the room vault, counts them
+//! as charity extras, and records them here instead of in a PlayerEntry, so donors never
+//! count toward `player_count` or `max_players`.
+//!
+//! ## PDA Derivation
+//!
+//! Seeds: ["donation", room_pubkey, donor_pubkey]
+//! Bump: Stored in Donation.bump
+//!
+//! One receipt per donor per room: repeat donations add to `amount` rather than creating
+//! another account. A wallet can both play and donate in the same room.
+
+use anchor_lang::prelude::*;
+
+/// A supporter's donations to a room's charity
+#[account]
+#[derive(Debug)]
+pub struct Donation {
+    /// Room donated to
+    pub room: Pubkey,
+
+    /// Donor's wallet
+    pub donor: Pubkey,
+
+    /// Total donated to the room by this wallet
+    pub amount: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Donation {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // room
+        32 + // donor
+        8 + // amount
+        1; // bump
+}
@@ -31,10 +31,14 @@
 //!
 //! GlobalConfig enforces the platform's economic constraints through validation at room creation:
 //!
-//! ### Fixed Platform Fee
+//! ### Platform Fee
 //! - **platform_fee_bps**: 2000 (20% of entry fees)
-//! - Non-negotiable infrastructure and development cost
-//! - Applied to entry fees only (not extras)
+//! - Default infrastructure and development cost, applied to entry fees only (not extras)
+//! - Copied onto each room at creation; `end_room` uses the room's copy
+//!
+//! - **min_platform_fee_bps**: 1000 (10% floor)
+//!   - Lowest fee the admin can grant a partner campaign via `set_room_platform_fee`
+//!   - The waived share goes to the room's charity, never to the host or prizes
 //!
 //! ### Configurable Limits
 //! - **max_host_fee_bps**: 500 (5% maximum)
@@ -105,6 +109,46 @@
 //! - Allows admin to respond to critical vulnerabilities or exploits
 //! - Frontend checks this flag before submitting transactions
 //!
+//! ## Winner Declaration Policy
+//!
+//! - **require_declaration**: When true, `end_room` only distributes to winners previously
+//!   recorded by `declare_winners`; the legacy `winners` argument of `end_room` is rejected
+//! - Defaults to false at initialization for backward compatibility
+//! - Toggled by the admin via `update_config`
+//!
+//! ## Charity Receipt Attestor
+//!
+//! - **receipt_attestor**: Optional key allowed to record charity receipts via
+//!   `record_charity_receipt` on behalf of charities that cannot sign themselves
+//! - `Pubkey::default()` means no attestor; the room's charity wallet can always record
+//! - Set (or cleared with `Pubkey::default()`) by the admin via `update_config`
+//!
+//! ## Token Registry Cap
+//!
+//! - **max_approved_tokens**: How many tokens `add_approved_token` may approve
+//! - Initialized to `TokenRegistry::MAX_TOKENS` (the registry account's capacity)
+//! - Adjusted by the admin via `update_config` (1 to `TokenRegistry::MAX_TOKENS`)
+//!
+//! ## Room Expiration Bounds
+//!
+//! - **max_expiration_slots**: Longest `expiration_slots` a room may be created with
+//!   (initialized to 30 days of slots)
+//! - **default_expiration_slots**: Expiration applied to a room created with neither
+//!   `expiration_slots` nor `expiration_unix_ts` (initialized to 7 days of slots), so no
+//!   room stays open forever by omission
+//! - Adjusted by the admin via `update_config` (`0 < default <= max`)
+//!
+//! ## Lifetime Platform Stats
+//!
+//! - **lifetime_charity_raised**: Sum of every room's charity amount (entry-fee share, extras
+//!   and anything redirected to charity), in base units of each room's fee token; only
+//!   meaningful as a single figure while rooms share one fee token (or tokens of equal value)
+//! - **lifetime_rooms_ended**: Rooms settled by `end_room`, `end_asset_room` or
+//!   `finalize_expired_room`
+//! - **lifetime_players**: Players across all ended rooms
+//! - Updated with checked math on every room end, which also emits `PlatformStats`, so a
+//!   "total raised" banner is a single account read instead of indexing `RoomEnded` events
+//!
 //! ## Security Considerations
 //!
 //! - **Admin Authority**: Only admin can modify GlobalConfig values
@@ -113,6 +157,16 @@
 //! - **PDA Security**: Only the program can sign transactions using this account
 
 use anchor_lang::prelude::*;
+use crate::errors::FundraiselyError;
+
+/// Slots in a day at Solana's ~400ms target slot time
+pub const SLOTS_PER_DAY: u64 = 216_000;
+
+/// Initial `GlobalConfig.max_expiration_slots` (30 days)
+pub const DEFAULT_MAX_EXPIRATION_SLOTS: u64 = 30 * SLOTS_PER_DAY;
+
+/// Initial `GlobalConfig.default_expiration_slots` (7 days)
+pub const DEFAULT_EXPIRATION_SLOTS: u64 = 7 * SLOTS_PER_DAY;
 
 /// Platform-wide configuration and economic parameters
 ///
@@ -145,6 +199,33 @@ pub struct GlobalConfig {
     /// Emergency pause flag
     pub emergency_pause: bool,
 
+    /// Require winners to be declared via declare_winners before end_room
+    pub require_declaration: bool,
+
+    /// Attestor allowed to record charity receipts (Pubkey::default() = none)
+    pub receipt_attestor: Pubkey,
+
+    /// Maximum number of tokens the token registry may hold
+    pub max_approved_tokens: u16,
+
+    /// Lowest per-room platform fee the admin may set, in basis points (1000 = 10%)
+    pub min_platform_fee_bps: u16,
+
+    /// Total sent to charity by all ended rooms (base units of each room's fee token)
+    pub lifetime_charity_raised: u64,
+
+    /// Number of rooms that have ended
+    pub lifetime_rooms_ended: u64,
+
+    /// Number of players across all ended rooms
+    pub lifetime_players: u64,
+
+    /// Longest expiration a room may be created with, in slots
+    pub max_expiration_slots: u64,
+
+    /// Expiration for rooms created without a deadline, in slots
+    pub default_expiration_slots: u64,
+
     /// PDA bump seed
     pub bump: u8,
 }
@@ -159,5 +240,31 @@ impl GlobalConfig {
         2 + // max_prize_pool_bps
         2 + // min_charity_bps
         1 + // emergency_pause
+        1 + // require_declaration
+        32 + // receipt_attestor
+        2 + // max_approved_tokens
+        2 + // min_platform_fee_bps
+        8 + // lifetime_charity_raised
+        8 + // lifetime_rooms_ended
+        8 + // lifetime_players
+        8 + // max_expiration_slots
+        8 + // default_expiration_slots
         1; // bump
+
+    /// Adds an ended room's charity amount and players to the lifetime counters
+    pub fn record_room_end(&mut self, charity_amount: u64, players: u32) -> Result<()> {
+        self.lifetime_charity_raised = self
+            .lifetime_charity_raised
+            .checked_add(charity_amount)
+            .ok_or(FundraiselyError::ArithmeticOverflow)?;
+        self.lifetime_rooms_ended = self
+            .lifetime_rooms_ended
+            .checked_add(1)
+            .ok_or(FundraiselyError::ArithmeticOverflow)?;
+        self.lifetime_players = self
+            .lifetime_players
+            .checked_add(u64::from(players))
+            .ok_or(FundraiselyError::ArithmeticOverflow)?;
+        Ok(())
+    }
 }
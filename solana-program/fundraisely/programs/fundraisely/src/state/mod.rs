@@ -26,6 +26,10 @@
 //! Room PDA:         ["room", host_pubkey, room_id]
 //! PlayerEntry PDA:  ["player", room_pubkey, player_pubkey]
 //! Room Vault PDA:   ["room-vault", room_pubkey]
+//! Prize Vault PDA:  ["prize-vault", room_pubkey, prize_index]
+//! Blacklist PDA:    ["blacklist"]
+//! Discount PDA:     ["discount", room_pubkey, player_pubkey]
+//! Donation PDA:     ["donation", room_pubkey, donor_pubkey]
 //! ```
 //!
 //! ## State Lifecycle
@@ -101,8 +105,14 @@ pub mod global_config;
 pub mod room;
 pub mod player_entry;
 pub mod token_registry;
+pub mod blacklist;
+pub mod discount;
+pub mod donation;
 
 pub use global_config::*;
 pub use room::*;
 pub use player_entry::*;
 pub use token_registry::*;
+pub use blacklist::*;
+pub use discount::*;
+pub use donation::*;
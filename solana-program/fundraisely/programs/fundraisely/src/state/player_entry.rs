@@ -82,7 +82,7 @@
 //! - PlayerEntry PDA created with payment details
 //!
 //! ### Immutability
-//! - Once created, PlayerEntry never changes
+//! - Once created, PlayerEntry never changes, apart from `ready` (set once by `ready_up`)
 //! - Serves as permanent receipt of participation
 //! - Cannot be closed or modified (even after room ends)
 //!
@@ -101,6 +101,24 @@
 //!
 //! Solana slots increment approximately every 400ms, providing precise temporal ordering.
 //!
+//! ## Readiness
+//!
+//! - **ready**: Set by `ready_up` when the player signals they are ready to start
+//! - Counted in `Room.ready_count`; rooms created with `require_ready` can't declare
+//!   winners or end until every player is ready (or the host waives the check)
+//!
+//! ## Referrals
+//!
+//! - **referrer**: Optional wallet the player names as having brought them in
+//! - Purely informational: no funds move on referral; indexers read it (and the
+//!   `PlayerJoined` event) to build referral leaderboards
+//! - A player can't name themselves (`InvalidReferrer`)
+//!
+//! ## Discounts
+//!
+//! - **discount_amount**: What the player's `Discount` PDA took off their entry fee
+//! - Added to `Room.total_discounts` on join and taken off again by `leave_room`
+//!
 //! ## Frontend Integration
 //!
 //! The `useFundraiselyContract.ts` hook interacts with PlayerEntry accounts:
@@ -129,7 +147,7 @@
 //! const extrasAmount = new BN(5_000_000); // 5 USDC (6 decimals)
 //!
 //! await program.methods
-//!   .joinRoom(roomId, extrasAmount)
+//!   .joinRoom(roomId, extrasAmount, null, referrer)
 //!   .accounts({
 //!     room: roomPDA,
 //!     playerEntry: playerEntryPDA,
@@ -187,7 +205,7 @@
 //!
 //! ## Data Structure Layout
 //!
-//! Account size: 105 bytes
+//! Account size: 139 bytes
 //! - Discriminator: 8 bytes
 //! - Player pubkey: 32 bytes
 //! - Room pubkey: 32 bytes
@@ -195,6 +213,8 @@
 //! - Extras paid: 8 bytes
 //! - Total paid: 8 bytes
 //! - Join slot: 8 bytes
+//! - Ready: 1 byte
+//! - Referrer: 33 bytes (Option<Pubkey>)
 //! - Bump: 1 byte
 //!
 //! ## Use Cases
@@ -232,6 +252,15 @@ pub struct PlayerEntry {
     /// Slot when player joined
     pub join_slot: u64,
 
+    /// Whether the player has called `ready_up`
+    pub ready: bool,
+
+    /// Wallet credited with bringing this player in (reporting only, no payout)
+    pub referrer: Option<Pubkey>,
+
+    /// Entry fee waived by the player's `Discount` PDA (0 without one)
+    pub discount_amount: u64,
+
     /// PDA bump seed
     pub bump: u8,
 }
@@ -244,5 +273,8 @@ impl PlayerEntry {
         8 + // extras_paid
         8 + // total_paid
         8 + // join_slot
+        1 + // ready
+        (1 + 32) + // referrer
+        8 + // discount_amount
         1; // bump
 }
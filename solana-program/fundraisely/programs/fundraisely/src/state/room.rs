@@ -39,26 +39,62 @@
 //! - First player joins via `join_room` instruction
 //! - Subsequent players continue joining (up to max_players)
 //! - Funds accumulate in room_vault
-//! - Can be ended by host at any time (or by anyone after expiration)
+//! - Can be ended by the host at any time with `end_room`; once expired, anyone can
+//!   settle it with `finalize_expired_room`
 //!
 //! ### 3. Ended State (Final)
-//! - Host calls `end_room` with winner list
+//! - Host calls `end_room` with winner list, or anyone calls `finalize_expired_room`
 //! - Funds distributed from vault to all recipients
 //! - Room becomes immutable (no further state changes)
 //! - Historical record maintained on-chain
 //!
+//! ### Frozen State (Admin Hold)
+//! - Admin calls `freeze_room` (e.g., for a legal hold)
+//! - Joins, `end_room` and `recover_room` are rejected; vault funds stay untouched
+//! - `unfreeze_room` restores the status the room had before freezing
+//!
+//! ### Charity Receipt (Optional, After End)
+//! - The charity wallet or the configured attestor calls `record_charity_receipt`
+//! - Stores a hash of the charity's acknowledgment, linking it to the donation
+//! - Can be recorded once; the room's funds and status are unaffected
+//!
 //! ## Economic Model Per Room
 //!
 //! Each room defines its own fee structure within platform constraints:
 //!
 //! ### Fee Allocation (Entry Fees)
 //! ```text
-//! Platform Fee: 20% (fixed by GlobalConfig)
+//! Platform Fee: platform_fee_bps (20% from GlobalConfig, or an admin override)
 //! Host Fee:     host_fee_bps (0-5%, host chooses)
 //! Prize Pool:   prize_pool_bps (0-35%, host chooses)
 //! Charity:      charity_bps (calculated remainder, min 40%)
 //! ```
 //!
+//! ### Platform Fee Override
+//! The room copies `GlobalConfig.platform_fee_bps` at creation, and `end_room` reads the
+//! room's copy. For partner campaigns the admin can lower it with `set_room_platform_fee`
+//! before anyone joins, down to `GlobalConfig.min_platform_fee_bps`. The waived share is
+//! added to `charity_bps`, so an override only ever increases the charity's share.
+//!
+//! ### Host Fee Waiver
+//! A nonprofit host can call `waive_host_fee` before the room ends; `end_room` then adds
+//! the host fee to the charity amount instead of paying the host (`host_fee_waived`).
+//!
+//! ### Early-Bird Pricing
+//! The first `early_bird_count` players pay `early_bird_fee` (at most `entry_fee`) instead
+//! of `entry_fee`. Each player's actual fee is recorded in `PlayerEntry.entry_paid` and
+//! added to `total_entry_fees`, so the splits above apply unchanged to mixed pricing.
+//! The window is counted in seats: a seat an early bird frees with `leave_room` is
+//! offered at `early_bird_fee` again while fewer than `early_bird_count` players remain.
+//!
+//! ### Discounts
+//! Wallets the host registers with `add_discount` (a `Discount` PDA) pay
+//! `entry_fee * (10000 - discount_bps) / 10000` (applied to the early-bird fee during the
+//! early-bird window). The reduced fee is split like any other; what discounts waived is
+//! tracked in `total_discounts` (and in `PlayerEntry.discount_amount`, so a leaver's
+//! discount comes off it again). `discount_bps` must leave charity at least
+//! `min_charity_bps` of the full entry fee (see `validate_discount`).
+//!
 //! ### Extras Allocation
 //! ```text
 //! All extras (beyond entry fee) go 100% to charity
@@ -67,7 +103,7 @@
 //!
 //! ### Distribution Calculation
 //! ```rust
-//! let platform_fee = total_entry_fees * 20 / 100;
+//! let platform_fee = total_entry_fees * platform_fee_bps / 10000;
 //! let host_fee = total_entry_fees * host_fee_bps / 10000;
 //! let prize_amount = total_entry_fees * prize_pool_bps / 10000;
 //! let charity_from_entry = total_entry_fees - platform_fee - host_fee - prize_amount;
@@ -77,19 +113,169 @@
 //! ## Prize Distribution
 //!
 //! - **prize_mode**: Currently PoolSplit (prizes from collected fees)
-//! - **prize_distribution**: Vec of percentages [1st, 2nd, 3rd] (must sum to 100)
+//! - **prize_distribution**: Vec of percentages per place, 1 to MAX_WINNERS entries (must sum to 100)
 //!
 //! Example: [50, 30, 20] means:
 //! - 1st place: 50% of prize_amount
 //! - 2nd place: 30% of prize_amount
 //! - 3rd place: 20% of prize_amount
 //!
+//! ### Ties
+//!
+//! `declare_winners` can group winners into tied places with `place_sizes`: place `i` is
+//! shared by `place_sizes[i]` consecutive winners, who split its amount equally. The
+//! remainder of that split goes to the lexicographically-first winner of the group:
+//!
+//! ```text
+//! prize 1000, distribution [50, 30, 20], place_sizes [2, 1]:
+//!   1st (shared by two) -> 250 each
+//!   2nd                 -> 300
+//! ```
+//!
+//! ### Prize Tiers
+//!
+//! A host who doesn't know the turnout in advance can add up to MAX_PRIZE_TIERS
+//! `prize_tiers`, each switching to another distribution once `player_count` reaches its
+//! `min_players`. `end_room` pays out with the tier of the highest threshold the final
+//! player count reached, or `prize_distribution` if it reached none:
+//!
+//! ```text
+//! prize_distribution [100], prize_tiers [(10, [50, 30, 20])]:
+//!   9 players  -> winner takes all
+//!   10 players -> top-3 split
+//! ```
+//!
+//! Thresholds are strictly increasing and every tier sums to 100, both checked at creation.
+//! The distribution used is reported in `RoomEnded.prize_distribution`.
+//!
+//! ## Multiple Charities
+//!
+//! A room raising for several causes can split its charity amount across up to
+//! MAX_CHARITIES wallets by weight (`charity_splits`, weights sum to 10000 bps). The first
+//! split is always `charity_wallet`, which also receives rounding dust:
+//!
+//! ```text
+//! splits [(A, 5000), (B, 3000), (C, 2000)], charity amount 1001:
+//!   B = 1001 * 3000 / 10000 = 300, C = 200, A = 500 + 1 dust = 501
+//! ```
+//!
+//! With a single charity `charity_splits` stays empty and `charity_wallet` gets everything.
+//!
+//! ## Layout Versions
+//!
+//! `room_version` records the layout a room was written with; the current one is
+//! `ROOM_VERSION` (3). Every instruction except room creation rejects a room of any other
+//! version with `UnsupportedRoomVersion`, and the admin brings it up to date with
+//! `migrate_room`, which grows the account to `Room::LEN` and rewrites it.
+//!
+//! Rooms created before versioning have no `room_version` byte, so `migrate_room` treats
+//! any room allocated smaller than a version 1 room as version 0. Fields added later go
+//! after `room_version`, must be fixed-size and must decode from zero bytes as their
+//! default (0, false, None); bump `ROOM_VERSION` and extend `Room::VERSION_FIELD_BYTES`
+//! with each such change.
+//!
+//! The bytes past an old room's data aren't necessarily zero: a write that shrinks the
+//! serialized room (e.g. `amend_winners` shortening `winners`, or `unfreeze_room`
+//! clearing `pre_freeze_status`) leaves the tail of the previous write behind. So
+//! `migrate_room` zero-fills everything after the old layout's fields before decoding
+//! the new ones as their defaults (see `utils::migrate_room_layout`).
+//!
+//! ```text
+//! 1: room_version
+//! 2: leave_penalty_bps, total_leave_penalties
+//! 3: total_donations
+//! ```
+//!
+//! ### Original Layout
+//!
+//! Rooms created by the program's first release don't share a prefix with the layouts
+//! above: `winners` is `[Option<Pubkey>; 3]`, `prize_distribution` has room for 3 places,
+//! prize assets have no `is_nft` flag and every field from `pre_freeze_status` on is
+//! missing. `migrate_room` recognises them by their size, `LegacyRoom::LEN`, decodes them
+//! as `LegacyRoom` and rewrites them at the current layout (`LegacyRoom::into_room`):
+//!
+//! - Declared winners keep their order in `winners`; asset prizes are not NFTs
+//! - `platform_fee_bps` is recovered from the split the room was created with,
+//!   `10000 - host_fee_bps - prize_pool_bps - charity_bps`
+//! - Every other new field takes its default: one charity, no quorum, no readiness
+//!   check, no expiration timestamp, public, no tiers, early-bird pricing, discounts,
+//!   ties or leave penalty
+//!
+//! ## Guaranteed Minimum Prize
+//!
+//! A pool room's prize is a share of actual entry fees, so an under-subscribed room pays a
+//! small prize. A host can promise a floor by escrowing `guaranteed_min_prize` into the
+//! vault via `fund_min_prize` before anyone joins. The escrow is a pledge to the fundraiser
+//! and never returns to the host once the room ends:
+//!
+//! ```text
+//! pool < guarantee:  prize = guarantee (shortfall topped up from escrow),
+//!                    unused escrow (= pool) goes to charity
+//! pool >= guarantee: prize = pool, the whole escrow goes to charity
+//! ```
+//!
+//! If the room is recovered instead of ended, the escrow is refunded to the host.
+//!
+//! ## Minimum Players and Recovery
+//!
+//! - **min_players**: Quorum the host sets at creation (0 = no quorum)
+//! - If the admin recovers a room that never reached `min_players`, the event effectively
+//!   never happened: players are refunded in full (`RecoveryReason::LowTurnout`)
+//! - A room that met quorum and was abandoned keeps the 10% recovery fee
+//!   (`RecoveryReason::Abandoned`)
+//!
+//! ## Leaving a Room
+//!
+//! A player can `leave_room` while the room is active, before winners are declared and
+//! before it expires. They get back `total_paid` less the host's `leave_penalty_bps`
+//! (0 = full refund, at most MAX_LEAVE_PENALTY_BPS); the penalty stays in the vault for
+//! charity:
+//!
+//! ```text
+//! leave_penalty_bps 1000, player paid 10 (entry) + 2 (extras):
+//!   refund 10.8, penalty 1.2 -> total_leave_penalties
+//!   total_entry_fees -= 10, total_extras_fees -= 2, total_collected -= 10.8
+//! ```
+//!
+//! The leaver's fees no longer count toward the platform, host or prize split; `end_room`
+//! adds `total_leave_penalties` to the charity amount. A recovered room refunds its
+//! remaining players from everything it holds, penalties included.
+//!
+//! ## Donations
+//!
+//! Supporters who don't play can `donate` to an active room. A donation is added to
+//! `total_extras_fees` (so `end_room` pays it to charity with the players' extras) and to
+//! `total_collected`, and tracked in `total_donations`. Donors don't take a player slot, so
+//! a full room still accepts them. If the room is recovered instead of ended, donations
+//! still go to the charity wallet in full: they're excluded from the recovery fee and the
+//! player refunds.
+//!
+//! ## Player Readiness
+//!
+//! - **require_ready**: Set at creation for turn-based games (false = no readiness gate)
+//! - **ready_count**: Players who have called `ready_up`
+//! - While `require_ready` is set, `declare_winners` and `end_room` fail with
+//!   `PlayersNotReady` until `ready_count == player_count`
+//! - The host can lift the gate with `waive_ready_check` (e.g., a player went idle)
+//!
+//! ## Private Rooms
+//!
+//! - **allowlist_root**: Merkle root of the wallets invited to a closed tournament
+//! - All zeros means the room is public; otherwise `join_room` requires a merkle proof
+//!   of the player's wallet (see `utils::verify_allowlist_proof` for the hashing scheme)
+//! - Only the 32-byte root is stored, so the account size doesn't depend on the guest list
+//!
 //! ## Room Expiration
 //!
 //! - **expiration_slot**: Solana slot when room expires (0 = no expiration)
-//! - After expiration, anyone can end the room (not just host)
+//! - **expiration_unix_ts**: Unix timestamp when room expires (0 = no expiration)
+//! - A room can use either deadline or both; it expires once either has passed.
+//!   Timestamps suit "closes at midnight" deadlines, since slot timing drifts
+//! - After expiration, anyone can call `finalize_expired_room`; the host fee goes to charity
 //! - Prevents abandoned rooms from locking funds indefinitely
 //! - Typical expiration: ~43,200 slots (approximately 24 hours)
+//! - Creation caps `expiration_slots` at GlobalConfig's `max_expiration_slots`, and a room
+//!   created with neither deadline gets `default_expiration_slots`
 //!
 //! ## Financial Tracking
 //!
@@ -162,7 +348,7 @@
 //!
 //! ## Security Considerations
 //!
-//! - **Host Authority**: Only host can end room (unless expired)
+//! - **Host Authority**: Only host can end room (expired rooms are finalized without the host)
 //! - **Host Cannot Win**: Explicit check prevents host from awarding themselves prizes
 //! - **Capacity Limits**: max_players prevents unbounded state growth
 //! - **Immutable After End**: ended flag prevents double-distribution exploits
@@ -170,7 +356,7 @@
 //!
 //! ## Data Structure Layout
 //!
-//! Account size: 263 bytes
+//! Account size: `Room::LEN` (winners and distribution reserved at MAX_WINNERS capacity)
 //! - Discriminator: 8 bytes
 //! - Strings: 36 bytes (room_id) + 32 bytes (charity_memo)
 //! - Pubkeys: 64 bytes (host, fee_token_mint)
@@ -178,12 +364,70 @@
 //! - Fees: 6 bytes (host_fee_bps, prize_pool_bps, charity_bps)
 //! - Counters: 8 bytes (player_count, max_players)
 //! - Enums/Flags: 3 bytes (prize_mode, status, ended)
-//! - Timing: 16 bytes (creation_slot, expiration_slot)
-//! - Prize distribution: 10 bytes (Vec<u16>)
+//! - Timing: 24 bytes (creation_slot, expiration_slot, expiration_unix_ts)
+//! - Prize distribution: 4 + 2 * MAX_WINNERS bytes (Vec<u16>)
+//! - Winners: 4 + 32 * MAX_WINNERS bytes (Vec<Pubkey>)
+//! - Allowlist root: 32 bytes
+//! - Platform fee: 2 bytes (platform_fee_bps)
+//! - Prize tiers: 4 + MAX_PRIZE_TIERS * (4 + 4 + 2 * MAX_WINNERS) bytes (Vec<PrizeTier>)
+//! - Host fee waiver: 1 byte (host_fee_waived)
+//! - Early-bird fee: 8 bytes (early_bird_fee)
+//! - Early-bird count: 4 bytes (early_bird_count)
+//! - Discount: 2 bytes (discount_bps)
+//! - Total discounts: 8 bytes (total_discounts)
+//! - Place sizes: 4 + MAX_WINNERS bytes (Vec<u8>)
 //! - Bump: 1 byte
+//! - Layout version: 1 byte (room_version)
+//! - Leave penalty: 2 bytes (leave_penalty_bps)
+//! - Leave penalties: 8 bytes (total_leave_penalties)
+//! - Donations: 8 bytes (total_donations)
 
 use anchor_lang::prelude::*;
 
+/// Maximum number of prize places (winners) per room
+///
+/// Reserves space for `Room.winners` and `prize_distribution`, and bounds the distribution
+/// accepted by `init_pool_room` and the winner lists accepted by `declare_winners` and
+/// `end_room`. Raising the cap is a change to this constant only (plus account reallocation
+/// for existing rooms).
+pub const MAX_WINNERS: usize = 10;
+
+/// Number of asset prizes an asset-based room can escrow (`init_asset_room` takes three)
+pub const MAX_ASSET_PRIZES: usize = 3;
+
+/// Maximum number of charities a room's charity amount can be split across
+pub const MAX_CHARITIES: usize = 3;
+
+/// Maximum merkle proof length `join_room` accepts (allowlists of up to 2^20 wallets)
+pub const MAX_ALLOWLIST_PROOF_LEN: usize = 20;
+
+/// Maximum number of player-count prize tiers a pool room can define
+pub const MAX_PRIZE_TIERS: usize = 3;
+
+/// Room layout version written by this program (see "Layout Versions")
+pub const ROOM_VERSION: u8 = 3;
+
+/// Largest share of a leaver's payment a host can keep for charity (20%)
+pub const MAX_LEAVE_PENALTY_BPS: u16 = 2000;
+
+/// Prize distribution that applies once a room reaches a player count
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PrizeTier {
+    /// Final player count from which this tier applies
+    pub min_players: u32,
+    /// Prize percentages per place, like `Room.prize_distribution` (must sum to 100)
+    pub distribution: Vec<u16>,
+}
+
+/// One charity's share of a multi-charity room
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct CharitySplit {
+    /// Charity wallet receiving this share
+    pub wallet: Pubkey,
+    /// Share of the charity amount in basis points (all splits sum to 10000)
+    pub weight_bps: u16,
+}
+
 /// Asset prize information for asset-based rooms
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
 pub struct PrizeAsset {
@@ -193,6 +437,8 @@ pub struct PrizeAsset {
     pub amount: u64,
     /// Whether this prize has been deposited/escrowed
     pub deposited: bool,
+    /// Whether this prize is a 1-of-1 NFT (amount 1; the mint is verified on deposit)
+    pub is_nft: bool,
 }
 
 /// Prize distribution mode
@@ -204,12 +450,21 @@ pub enum PrizeMode {
     AssetBased,
 }
 
+/// Why the admin recovered a room, derived from its turnout
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RecoveryReason {
+    /// Room met quorum but the host never ended it; a 10% recovery fee is taken
+    Abandoned,
+    /// Room never reached min_players; players are refunded in full
+    LowTurnout,
+}
+
 /// Room lifecycle state
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
 pub enum RoomStatus {
-    /// Asset room waiting for prize deposits (future feature)
+    /// Asset room waiting for prize deposits
     AwaitingFunding,
-    /// Some prizes deposited (future feature)
+    /// Asset room with some, but not all, prizes deposited
     PartiallyFunded,
     /// Ready to accept players
     Ready,
@@ -217,6 +472,8 @@ pub enum RoomStatus {
     Active,
     /// Game completed, funds distributed
     Ended,
+    /// Held by the admin (e.g., legal hold); joins, ends and recovery are blocked
+    Frozen,
 }
 
 /// Individual game room state and configuration
@@ -254,7 +511,7 @@ pub struct Room {
     /// Prize distribution mode
     pub prize_mode: PrizeMode,
 
-    /// Prize distribution percentages [1st, 2nd, 3rd]
+    /// Prize distribution percentages per place [1st, 2nd, ...] (at most MAX_WINNERS places)
     pub prize_distribution: Vec<u16>,
 
     /// Room status
@@ -287,19 +544,190 @@ pub struct Room {
     /// Charity memo for transfers
     pub charity_memo: String,
 
-    /// Declared winners (up to 3, set by declare_winners instruction)
-    /// None values indicate no winner declared for that position
-    pub winners: [Option<Pubkey>; 3],
+    /// Declared winners in place order (up to MAX_WINNERS, set by declare_winners instruction)
+    /// Empty until winners are declared
+    pub winners: Vec<Pubkey>,
 
     /// Prize assets for asset-based rooms (None for pool-based rooms)
     /// [1st place, 2nd place, 3rd place]
-    pub prize_assets: [Option<PrizeAsset>; 3],
+    pub prize_assets: [Option<PrizeAsset>; MAX_ASSET_PRIZES],
+
+    /// Status to restore when the admin unfreezes the room (None unless Frozen)
+    pub pre_freeze_status: Option<RoomStatus>,
+
+    /// Host-escrowed minimum prize held in the vault (0 = no guarantee)
+    pub guaranteed_min_prize: u64,
+
+    /// Hash of the charity's receipt acknowledgment (set by record_charity_receipt)
+    pub charity_receipt: Option<[u8; 32]>,
+
+    /// Score of each declared winner, same order as `winners` (empty if none were recorded)
+    pub winner_scores: Vec<u64>,
+
+    /// Charity shares when raising for several charities (empty = all to charity_wallet)
+    /// The first split is always charity_wallet
+    pub charity_splits: Vec<CharitySplit>,
+
+    /// Players needed for the event to go ahead (0 = no quorum)
+    pub min_players: u32,
+
+    /// Whether winners can only be declared once every player is ready
+    pub require_ready: bool,
+
+    /// Number of players who have called `ready_up`
+    pub ready_count: u32,
+
+    /// Unix timestamp when room expires (0 = no expiration)
+    /// Applies alongside `expiration_slot`; whichever passes first expires the room
+    pub expiration_unix_ts: i64,
+
+    /// Merkle root of the wallets allowed to join (all zeros = public room)
+    pub allowlist_root: [u8; 32],
+
+    /// Platform fee in basis points applied by end_room (GlobalConfig's at creation
+    /// unless the admin set an override)
+    pub platform_fee_bps: u16,
+
+    /// Distributions that replace `prize_distribution` once enough players have joined,
+    /// by increasing `min_players` (empty = always `prize_distribution`)
+    pub prize_tiers: Vec<PrizeTier>,
+
+    /// Whether the host gave up their fee (set by waive_host_fee); end_room sends it to charity
+    pub host_fee_waived: bool,
+
+    /// Entry fee charged to the first `early_bird_count` players (0 when there's no early-bird window)
+    pub early_bird_fee: u64,
+
+    /// Number of players who pay `early_bird_fee` instead of `entry_fee`
+    pub early_bird_count: u32,
+
+    /// Entry fee reduction in basis points for wallets with a `Discount` PDA (0 = no discounts)
+    pub discount_bps: u16,
+
+    /// Entry fees waived by discounts (not part of `total_entry_fees`)
+    pub total_discounts: u64,
+
+    /// Number of declared winners sharing each prize place, in place order
+    /// (empty = one winner per place)
+    pub place_sizes: Vec<u8>,
 
     /// PDA bump seed
     pub bump: u8,
+
+    /// Account layout version (`ROOM_VERSION`; 0 = created before versioning)
+    pub room_version: u8,
+
+    /// Share of `total_paid` withheld for charity when a player leaves, in basis points
+    /// (0 = leavers get a full refund)
+    pub leave_penalty_bps: u16,
+
+    /// Penalties withheld from leavers, paid to charity by end_room
+    pub total_leave_penalties: u64,
+
+    /// Donations from non-players, included in `total_extras_fees` and `total_collected`
+    pub total_donations: u64,
 }
 
 impl Room {
+    pub const LEN: usize = 8 + // discriminator
+        (4 + 32) + // room_id (String)
+        32 + // host
+        32 + // charity_wallet
+        32 + // fee_token_mint
+        8 + // entry_fee
+        2 + // host_fee_bps
+        2 + // prize_pool_bps
+        2 + // charity_bps
+        1 + // prize_mode
+        (4 + MAX_WINNERS * 2) + // prize_distribution (Vec<u16>)
+        1 + // status
+        4 + // player_count
+        4 + // max_players
+        8 + // total_collected
+        8 + // total_entry_fees
+        8 + // total_extras_fees
+        1 + // ended
+        8 + // creation_slot
+        8 + // expiration_slot
+        (4 + 28) + // charity_memo (String)
+        (4 + MAX_WINNERS * 32) + // winners (Vec<Pubkey>)
+        (MAX_ASSET_PRIZES * (1 + 32 + 8 + 1 + 1)) + // prize_assets ([Option<PrizeAsset>; MAX_ASSET_PRIZES])
+        (1 + 1) + // pre_freeze_status (Option<RoomStatus>)
+        8 + // guaranteed_min_prize
+        (1 + 32) + // charity_receipt (Option<[u8; 32]>)
+        (4 + MAX_WINNERS * 8) + // winner_scores (Vec<u64>)
+        (4 + MAX_CHARITIES * (32 + 2)) + // charity_splits (Vec<CharitySplit>)
+        4 + // min_players
+        1 + // require_ready
+        4 + // ready_count
+        8 + // expiration_unix_ts
+        32 + // allowlist_root
+        2 + // platform_fee_bps
+        (4 + MAX_PRIZE_TIERS * (4 + 4 + MAX_WINNERS * 2)) + // prize_tiers (Vec<PrizeTier>)
+        1 + // host_fee_waived
+        8 + // early_bird_fee
+        4 + // early_bird_count
+        2 + // discount_bps
+        8 + // total_discounts
+        (4 + MAX_WINNERS) + // place_sizes (Vec<u8>)
+        1 + // bump
+        1 + // room_version
+        2 + // leave_penalty_bps
+        8 + // total_leave_penalties
+        8; // total_donations
+
+    /// Serialized bytes of the fields each layout version appended, oldest first
+    pub const VERSION_FIELD_BYTES: [usize; ROOM_VERSION as usize] = [
+        1, // 1: room_version
+        2 + 8, // 2: leave_penalty_bps, total_leave_penalties
+        8, // 3: total_donations
+    ];
+
+    /// Serialized bytes of the fields added after layout `version`
+    pub fn bytes_added_since(version: u8) -> usize {
+        Self::VERSION_FIELD_BYTES[version as usize..].iter().sum()
+    }
+}
+
+/// Prize asset as stored by the original program release (no NFT flag)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct LegacyPrizeAsset {
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub deposited: bool,
+}
+
+/// Room as stored by the original program release, read only by `migrate_room`
+/// (see "Original Layout" in the module docs)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct LegacyRoom {
+    pub room_id: String,
+    pub host: Pubkey,
+    pub charity_wallet: Pubkey,
+    pub fee_token_mint: Pubkey,
+    pub entry_fee: u64,
+    pub host_fee_bps: u16,
+    pub prize_pool_bps: u16,
+    pub charity_bps: u16,
+    pub prize_mode: PrizeMode,
+    pub prize_distribution: Vec<u16>,
+    pub status: RoomStatus,
+    pub player_count: u32,
+    pub max_players: u32,
+    pub total_collected: u64,
+    pub total_entry_fees: u64,
+    pub total_extras_fees: u64,
+    pub ended: bool,
+    pub creation_slot: u64,
+    pub expiration_slot: u64,
+    pub charity_memo: String,
+    pub winners: [Option<Pubkey>; 3],
+    pub prize_assets: [Option<LegacyPrizeAsset>; 3],
+    pub bump: u8,
+}
+
+impl LegacyRoom {
+    /// Size the original release allocated for every room
     pub const LEN: usize = 8 + // discriminator
         (4 + 32) + // room_id (String)
         32 + // host
@@ -322,6 +750,70 @@ impl Room {
         8 + // expiration_slot
         (4 + 28) + // charity_memo (String)
         (3 * (1 + 32)) + // winners ([Option<Pubkey>; 3])
-        (3 * (1 + 32 + 8 + 1)) + // prize_assets ([Option<PrizeAsset>; 3])
+        (3 * (1 + 32 + 8 + 1)) + // prize_assets ([Option<LegacyPrizeAsset>; 3])
         1; // bump
+
+    /// The same room at the current layout and `ROOM_VERSION`
+    pub fn into_room(self) -> Room {
+        // The original release split 10000 bps between platform, host, prizes and charity
+        let platform_fee_bps = 10000_u16
+            .saturating_sub(self.host_fee_bps)
+            .saturating_sub(self.prize_pool_bps)
+            .saturating_sub(self.charity_bps);
+
+        Room {
+            room_id: self.room_id,
+            host: self.host,
+            charity_wallet: self.charity_wallet,
+            fee_token_mint: self.fee_token_mint,
+            entry_fee: self.entry_fee,
+            host_fee_bps: self.host_fee_bps,
+            prize_pool_bps: self.prize_pool_bps,
+            charity_bps: self.charity_bps,
+            prize_mode: self.prize_mode,
+            prize_distribution: self.prize_distribution,
+            status: self.status,
+            player_count: self.player_count,
+            max_players: self.max_players,
+            total_collected: self.total_collected,
+            total_entry_fees: self.total_entry_fees,
+            total_extras_fees: self.total_extras_fees,
+            ended: self.ended,
+            creation_slot: self.creation_slot,
+            expiration_slot: self.expiration_slot,
+            charity_memo: self.charity_memo,
+            winners: self.winners.into_iter().flatten().collect(),
+            prize_assets: self.prize_assets.map(|prize| {
+                prize.map(|prize| PrizeAsset {
+                    mint: prize.mint,
+                    amount: prize.amount,
+                    deposited: prize.deposited,
+                    is_nft: false,
+                })
+            }),
+            pre_freeze_status: None,
+            guaranteed_min_prize: 0,
+            charity_receipt: None,
+            winner_scores: Vec::new(),
+            charity_splits: Vec::new(),
+            min_players: 0,
+            require_ready: false,
+            ready_count: 0,
+            expiration_unix_ts: 0,
+            allowlist_root: [0u8; 32],
+            platform_fee_bps,
+            prize_tiers: Vec::new(),
+            host_fee_waived: false,
+            early_bird_fee: 0,
+            early_bird_count: 0,
+            discount_bps: 0,
+            total_discounts: 0,
+            place_sizes: Vec::new(),
+            bump: self.bump,
+            room_version: ROOM_VERSION,
+            leave_penalty_bps: 0,
+            total_leave_penalties: 0,
+            total_donations: 0,
+        }
+    }
 }
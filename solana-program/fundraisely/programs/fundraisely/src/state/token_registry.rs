@@ -15,15 +15,44 @@
 //! ## Admin Operations
 //!
 //! - initialize_token_registry: One-time setup (creates PDA)
-//! - add_approved_token: Add token to allowlist
+//! - add_approved_token: Add token to allowlist, with optional symbol/decimals metadata
 //! - remove_approved_token: Remove token from allowlist
 //!
+//! ## Capacity
+//!
+//! The account is sized for `MAX_TOKENS` entries. The number of tokens the admin may
+//! actually approve is `GlobalConfig.max_approved_tokens` (at most `MAX_TOKENS`),
+//! adjustable via update_config.
+//!
 //! ## Validation
 //!
 //! init_pool_room checks that fee_token_mint exists in approved_tokens Vec
 
 use anchor_lang::prelude::*;
 
+/// Maximum length of an approved token's symbol
+pub const MAX_TOKEN_SYMBOL_LEN: usize = 10;
+
+/// An approved token mint and the metadata surfaced to clients
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ApprovedTokenInfo {
+    /// Token mint
+    pub mint: Pubkey,
+
+    /// Display symbol, e.g. "USDC" (empty if not provided)
+    pub symbol: String,
+
+    /// Mint decimals (read from the mint; None only for tokens approved before that check)
+    pub decimals: Option<u8>,
+}
+
+impl ApprovedTokenInfo {
+    /// Serialized size of one entry at the maximum symbol length
+    pub const LEN: usize = 32 + // mint
+        (4 + MAX_TOKEN_SYMBOL_LEN) + // symbol String
+        (1 + 1); // decimals Option<u8>
+}
+
 /// Token registry containing allowlist of approved SPL tokens
 #[account]
 #[derive(Debug)]
@@ -31,25 +60,25 @@ pub struct TokenRegistry {
     /// Admin who can modify the registry
     pub admin: Pubkey,
 
-    /// List of approved token mints
-    pub approved_tokens: Vec<Pubkey>,
+    /// Approved token mints with their metadata
+    pub approved_tokens: Vec<ApprovedTokenInfo>,
 
     /// PDA bump seed
     pub bump: u8,
 }
 
 impl TokenRegistry {
-    /// Maximum number of approved tokens (prevents unbounded growth)
+    /// Maximum number of approved tokens the account is sized for (prevents unbounded growth)
     pub const MAX_TOKENS: usize = 50;
 
     /// Account size calculation
     pub const LEN: usize = 8 + // discriminator
         32 + // admin
-        (4 + 32 * Self::MAX_TOKENS) + // approved_tokens Vec
+        (4 + ApprovedTokenInfo::LEN * Self::MAX_TOKENS) + // approved_tokens Vec
         1; // bump
 
     /// Check if a token is approved
     pub fn is_token_approved(&self, token_mint: &Pubkey) -> bool {
-        self.approved_tokens.contains(token_mint)
+        self.approved_tokens.iter().any(|token| token.mint == *token_mint)
     }
 }
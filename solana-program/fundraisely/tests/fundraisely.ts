@@ -5,16 +5,27 @@ import {
   PublicKey,
   Keypair,
   SystemProgram,
+  Transaction,
   LAMPORTS_PER_SOL,
+  sendAndConfirmTransaction,
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
+  getMintLen,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  AuthorityType,
   createMint,
   createAccount,
   mintTo,
   getAccount,
+  setAuthority,
 } from "@solana/spl-token";
 import { assert, expect } from "chai";
+import { createHash } from "crypto";
+import { readFileSync } from "fs";
 
 describe("fundraisely", () => {
   // Configure the client to use the local cluster
@@ -178,22 +189,128 @@ describe("fundraisely", () => {
       assert.equal(config.maxPrizePoolBps, 3500); // 35%
       assert.equal(config.minCharityBps, 4000); // 40% minimum
       assert.equal(config.emergencyPause, false);
+      assert.equal(config.lifetimeCharityRaised.toNumber(), 0);
+      assert.equal(config.lifetimeRoomsEnded.toNumber(), 0);
+      assert.equal(config.lifetimePlayers.toNumber(), 0);
     });
 
-    it("Fails to initialize twice", async () => {
+    it("Treats a repeat initialize by the admin as a no-op", async () => {
+      // Different wallets on the repeat call must not overwrite the live config
+      await program.methods
+        .initialize(hostWallet.publicKey, hostWallet.publicKey)
+        .accounts({
+          globalConfig: globalConfigPda,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const config = await program.account.globalConfig.fetch(globalConfigPda);
+      assert.equal(config.platformWallet.toString(), platformWallet.publicKey.toString());
+      assert.equal(config.charityWallet.toString(), charityWallet.publicKey.toString());
+    });
+
+    it("Fails to initialize again as a different admin", async () => {
       try {
         await program.methods
           .initialize(platformWallet.publicKey, charityWallet.publicKey)
           .accounts({
             globalConfig: globalConfigPda,
-            admin: admin.publicKey,
+            admin: hostWallet.publicKey,
             systemProgram: SystemProgram.programId,
           })
+          .signers([hostWallet])
           .rpc();
         assert.fail("Should have thrown error");
       } catch (err) {
-        // Expected to fail - account already exists
-        expect(err.message).to.include("already in use");
+        expect(err.toString()).to.include("AlreadyInitialized");
+      }
+    });
+
+    it("Initializes the player blacklist", async () => {
+      const [blacklistPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("blacklist")],
+        program.programId
+      );
+
+      await program.methods
+        .initializeBlacklist()
+        .accounts({
+          blacklist: blacklistPda,
+          globalConfig: globalConfigPda,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const blacklist = await program.account.blacklist.fetch(blacklistPda);
+      assert.equal(blacklist.admin.toString(), admin.publicKey.toString());
+      assert.equal(blacklist.banned.length, 0);
+    });
+
+    it("Initializes the token registry with the test mint approved", async () => {
+      const [tokenRegistryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("token-registry")],
+        program.programId
+      );
+
+      await program.methods
+        .initializeTokenRegistry()
+        .accounts({
+          tokenRegistry: tokenRegistryPda,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .addApprovedToken(tokenMint, "TEST", null)
+        .accounts({
+          tokenRegistry: tokenRegistryPda,
+          globalConfig: globalConfigPda,
+          mint: tokenMint,
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+      // Decimals are read from the mint
+      const registry = await program.account.tokenRegistry.fetch(tokenRegistryPda);
+      assert.equal(registry.approvedTokens.length, 1);
+      assert.equal(registry.approvedTokens[0].decimals, 6);
+
+      // Re-running setup keeps the approved list
+      await program.methods
+        .initializeTokenRegistry()
+        .accounts({
+          tokenRegistry: tokenRegistryPda,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const rerun = await program.account.tokenRegistry.fetch(tokenRegistryPda);
+      assert.equal(rerun.approvedTokens.length, 1);
+    });
+
+    it("Rejects approving an address that isn't a mint", async () => {
+      const [tokenRegistryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("token-registry")],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .addApprovedToken(charityWallet.publicKey, "FAKE", null)
+          .accounts({
+            tokenRegistry: tokenRegistryPda,
+            globalConfig: globalConfigPda,
+            mint: charityWallet.publicKey,
+            admin: admin.publicKey,
+          })
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidMint");
       }
     });
   });
@@ -230,9 +347,7 @@ describe("fundraisely", () => {
           entryFee,
           hostFeeBps,
           prizePoolBps,
-          firstPlacePct,
-          secondPlacePct,
-          thirdPlacePct,
+          [firstPlacePct, secondPlacePct, thirdPlacePct],
           charityMemo
         )
         .accounts({
@@ -257,6 +372,123 @@ describe("fundraisely", () => {
       assert.equal(room.playerCount, 0);
       assert.equal(room.totalCollected.toNumber(), 0);
       assert.equal(room.ended, false);
+
+      // The program creates the vault for the fee token, controlled by the room PDA
+      const vault = await getAccount(provider.connection, roomVaultPda);
+      assert.equal(vault.mint.toString(), tokenMint.toString());
+      assert.equal(vault.owner.toString(), roomPda.toString());
+    });
+
+    it("Fails with a room vault the host controls", async () => {
+      const roomId2 = "test-room-host-vault";
+      const [roomPda2] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId2)],
+        program.programId
+      );
+      const hostVault = await createAccount(
+        provider.connection,
+        admin.payer,
+        tokenMint,
+        hostWallet.publicKey,
+        Keypair.generate()
+      );
+
+      try {
+        await program.methods
+          .initPoolRoom(
+            roomId2,
+            new anchor.BN(10 * 1_000_000),
+            300,
+            2000,
+            [60, 30, 10],
+            "Host vault"
+          )
+          .accounts({
+            room: roomPda2,
+            roomVault: hostVault,
+            globalConfig: globalConfigPda,
+            host: hostWallet.publicKey,
+            feeTokenMint: tokenMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([hostWallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        // Only the room's own vault PDA is accepted
+        expect(err.toString()).to.include("ConstraintSeeds");
+      }
+    });
+
+    it("Fails with a Token-2022 transfer-fee mint", async () => {
+      const roomId2 = "test-room-transfer-fee";
+      const [roomPda2] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId2)],
+        program.programId
+      );
+      const [roomVaultPda2] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda2.toBuffer()],
+        program.programId
+      );
+
+      // 1% transfer fee: a 10 token entry would only put 9.9 tokens in the vault
+      const feeMint = Keypair.generate();
+      const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: admin.publicKey,
+            newAccountPubkey: feeMint.publicKey,
+            space: mintLen,
+            lamports: await provider.connection.getMinimumBalanceForRentExemption(mintLen),
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+          createInitializeTransferFeeConfigInstruction(
+            feeMint.publicKey,
+            admin.publicKey,
+            admin.publicKey,
+            100,
+            BigInt(1_000_000),
+            TOKEN_2022_PROGRAM_ID
+          ),
+          createInitializeMintInstruction(
+            feeMint.publicKey,
+            6,
+            admin.publicKey,
+            null,
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [admin.payer, feeMint]
+      );
+
+      try {
+        await program.methods
+          .initPoolRoom(
+            roomId2,
+            new anchor.BN(10 * 1_000_000),
+            300,
+            2000,
+            [60, 30, 10],
+            "Transfer fee mint"
+          )
+          .accounts({
+            room: roomPda2,
+            roomVault: roomVaultPda2,
+            globalConfig: globalConfigPda,
+            host: hostWallet.publicKey,
+            feeTokenMint: feeMint.publicKey,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([hostWallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("UnsupportedMintExtension");
+      }
     });
 
     it("Fails with host fee too high", async () => {
@@ -277,9 +509,7 @@ describe("fundraisely", () => {
             new anchor.BN(10 * 1_000_000),
             600, // 6% - exceeds max of 5%
             2000,
-            100,
-            null,
-            null,
+            [100],
             "Test"
           )
           .accounts({
@@ -317,9 +547,7 @@ describe("fundraisely", () => {
             new anchor.BN(10 * 1_000_000),
             500, // 5% host
             3600, // 36% prize - total = 41% exceeds max 40%
-            100,
-            null,
-            null,
+            [100],
             "Test"
           )
           .accounts({
@@ -357,9 +585,7 @@ describe("fundraisely", () => {
             new anchor.BN(10 * 1_000_000),
             300,
             2000,
-            50, // Only 50% - doesn't sum to 100%
-            30,
-            null,
+            [50, 30], // Only 80% - doesn't sum to 100%
             "Test"
           )
           .accounts({
@@ -405,9 +631,7 @@ describe("fundraisely", () => {
           new anchor.BN(10 * 1_000_000),
           300,
           2000,
-          60,
-          30,
-          10,
+          [60, 30, 10],
           "Join test"
         )
         .accounts({
@@ -437,7 +661,7 @@ describe("fundraisely", () => {
       const extrasAmount = new anchor.BN(5 * 1_000_000); // 5 tokens extra donation
 
       await program.methods
-        .joinRoom(roomId, extrasAmount)
+        .joinRoom(roomId, extrasAmount, null, null)
         .accounts({
           room: roomPda,
           roomVault: roomVaultPda,
@@ -467,7 +691,7 @@ describe("fundraisely", () => {
 
     it("Player 2 joins room successfully", async () => {
       await program.methods
-        .joinRoom(roomId, new anchor.BN(0)) // No extras
+        .joinRoom(roomId, new anchor.BN(0), null, null) // No extras
         .accounts({
           room: roomPda,
           roomVault: roomVaultPda,
@@ -488,7 +712,7 @@ describe("fundraisely", () => {
     it("Fails when player tries to join twice", async () => {
       try {
         await program.methods
-          .joinRoom(roomId, new anchor.BN(0))
+          .joinRoom(roomId, new anchor.BN(0), null, null)
           .accounts({
             room: roomPda,
             roomVault: roomVaultPda,
@@ -531,9 +755,7 @@ describe("fundraisely", () => {
           new anchor.BN(100 * 1_000_000), // 100 tokens entry
           400, // 4% host
           3000, // 30% prize pool
-          60,
-          30,
-          10,
+          [60, 30, 10],
           "End test"
         )
         .accounts({
@@ -555,7 +777,7 @@ describe("fundraisely", () => {
       );
 
       await program.methods
-        .joinRoom(roomId, new anchor.BN(0))
+        .joinRoom(roomId, new anchor.BN(0), null, null)
         .accounts({
           room: roomPda,
           roomVault: roomVaultPda,
@@ -574,7 +796,7 @@ describe("fundraisely", () => {
       );
 
       await program.methods
-        .joinRoom(roomId, new anchor.BN(0))
+        .joinRoom(roomId, new anchor.BN(0), null, null)
         .accounts({
           room: roomPda,
           roomVault: roomVaultPda,
@@ -593,7 +815,7 @@ describe("fundraisely", () => {
       );
 
       await program.methods
-        .joinRoom(roomId, new anchor.BN(0))
+        .joinRoom(roomId, new anchor.BN(0), null, null)
         .accounts({
           room: roomPda,
           roomVault: roomVaultPda,
@@ -612,6 +834,7 @@ describe("fundraisely", () => {
       const initialPlatformBalance = (await getAccount(provider.connection, platformTokenAccount)).amount;
       const initialCharityBalance = (await getAccount(provider.connection, charityTokenAccount)).amount;
       const initialHostBalance = (await getAccount(provider.connection, hostTokenAccount)).amount;
+      const initialConfig = await program.account.globalConfig.fetch(globalConfigPda);
 
       const winners = [
         player1Wallet.publicKey, // 1st place
@@ -675,6 +898,15 @@ describe("fundraisely", () => {
         expectedHost
       );
 
+      // The room's donation and players are added to the platform's lifetime stats
+      const config = await program.account.globalConfig.fetch(globalConfigPda);
+      assert.equal(
+        config.lifetimeCharityRaised.sub(initialConfig.lifetimeCharityRaised).toNumber(),
+        expectedCharity
+      );
+      assert.equal(config.lifetimeRoomsEnded.sub(initialConfig.lifetimeRoomsEnded).toNumber(), 1);
+      assert.equal(config.lifetimePlayers.sub(initialConfig.lifetimePlayers).toNumber(), 3);
+
       // Verify prize distribution (60%, 30%, 10% of prize pool)
       const expectedFirstPrize = (expectedPrizePool * 60) / 100; // 54 tokens
       const expectedSecondPrize = (expectedPrizePool * 30) / 100; // 27 tokens
@@ -708,9 +940,7 @@ describe("fundraisely", () => {
           new anchor.BN(10 * 1_000_000),
           300,
           2000,
-          100,
-          null,
-          null,
+          [100],
           "Test"
         )
         .accounts({
@@ -749,57 +979,5583 @@ describe("fundraisely", () => {
     });
   });
 
-  describe("Edge Cases", () => {
-    it("Cannot create room with empty room ID", async () => {
-      const emptyRoomId = "";
-      const [roomPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(emptyRoomId)],
+  describe("Winner Declaration Requirement", () => {
+    const roomId = "declare-required-room";
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    let player1EntryPda: PublicKey;
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
         program.programId
       );
-      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("room-vault"), roomPda.toBuffer()],
         program.programId
       );
+      [player1EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+
+      // Mandate the two-step declare -> end flow
+      await program.methods
+        .updateConfig(true, null, null, null, null)
+        .accounts({
+          globalConfig: globalConfigPda,
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(10 * 1_000_000),
+          10,
+          300,
+          2000,
+          [100],
+          "Declare test",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      await program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: player1EntryPda,
+          player: player1Wallet.publicKey,
+          playerTokenAccount: player1TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1Wallet])
+        .rpc();
+    });
+
+    after(async () => {
+      // Restore the default policy for the remaining suites
+      await program.methods
+        .updateConfig(false, null, null, null, null)
+        .accounts({
+          globalConfig: globalConfigPda,
+          admin: admin.publicKey,
+        })
+        .rpc();
+    });
 
+    it("Rejects end_room without declared winners when declaration is required", async () => {
       try {
         await program.methods
-          .initPoolRoom(
-            emptyRoomId,
-            new anchor.BN(10 * 1_000_000),
-            300,
-            2000,
-            100,
-            null,
-            null,
-            "Test"
-          )
+          .endRoom(roomId, [player1Wallet.publicKey])
           .accounts({
             room: roomPda,
             roomVault: roomVaultPda,
             globalConfig: globalConfigPda,
+            platformTokenAccount: platformTokenAccount,
+            charityTokenAccount: charityTokenAccount,
+            hostTokenAccount: hostTokenAccount,
             host: hostWallet.publicKey,
-            feeTokenMint: tokenMint,
             tokenProgram: TOKEN_PROGRAM_ID,
-            systemProgram: SystemProgram.programId,
           })
+          .remainingAccounts([
+            { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+          ])
           .signers([hostWallet])
           .rpc();
         assert.fail("Should have thrown error");
       } catch (err) {
-        expect(err.toString()).to.include("InvalidRoomId");
+        expect(err.toString()).to.include("WinnersNotDeclared");
       }
     });
 
-    it("Cannot create room with zero entry fee", async () => {
-      const roomId = "zero-fee-room";
-      const [roomPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
-        program.programId
-      );
-      const [roomVaultPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("room-vault"), roomPda.toBuffer()],
-        program.programId
-      );
+    it("Allows end_room once winners are declared", async () => {
+      await program.methods
+        .declareWinners(roomId, [player1Wallet.publicKey], null, null)
+        .accounts({
+          room: roomPda,
+          host: hostWallet.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: player1EntryPda, isSigner: false, isWritable: false },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+      await program.methods
+        .endRoom(roomId, [])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.ended, true);
+    });
+  });
+
+  describe("Freeze Room", () => {
+    const roomId = "freeze-room";
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    let player1EntryPda: PublicKey;
+    let player2EntryPda: PublicKey;
+
+    const endRoomCall = () =>
+      program.methods
+        .endRoom(roomId, [player1Wallet.publicKey])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+        ])
+        .signers([hostWallet]);
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      [player1EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      [player2EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player2Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(10 * 1_000_000),
+          10,
+          300,
+          2000,
+          [100],
+          "Freeze test",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      await program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: player1EntryPda,
+          player: player1Wallet.publicKey,
+          playerTokenAccount: player1TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1Wallet])
+        .rpc();
+    });
+
+    it("Rejects freeze from non-admin", async () => {
+      try {
+        await program.methods
+          .freezeRoom(roomId)
+          .accounts({
+            room: roomPda,
+            globalConfig: globalConfigPda,
+            admin: hostWallet.publicKey,
+          })
+          .signers([hostWallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+    });
+
+    it("Freezes the room", async () => {
+      await program.methods
+        .freezeRoom(roomId)
+        .accounts({
+          room: roomPda,
+          globalConfig: globalConfigPda,
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.deepEqual(room.status, { frozen: {} });
+      assert.deepEqual(room.preFreezeStatus, { active: {} });
+    });
+
+    it("Rejects joins, ends and recovery while frozen", async () => {
+      const vaultBefore = (await getAccount(provider.connection, roomVaultPda)).amount;
+
+      try {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry: player2EntryPda,
+            player: player2Wallet.publicKey,
+            playerTokenAccount: player2TokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player2Wallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("RoomFrozen");
+      }
+
+      try {
+        await endRoomCall().rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("RoomFrozen");
+      }
+
+      try {
+        await program.methods
+          .recoverRoom(roomId, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            globalConfig: globalConfigPda,
+            platformTokenAccount: platformTokenAccount,
+            admin: admin.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts([
+            { pubkey: player1EntryPda, isSigner: false, isWritable: false },
+            { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+          ])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("RoomFrozen");
+      }
+
+      // Funds never left the vault
+      const vaultAfter = (await getAccount(provider.connection, roomVaultPda)).amount;
+      assert.equal(vaultAfter.toString(), vaultBefore.toString());
+    });
+
+    it("Unfreezes the room and allows it to end", async () => {
+      await program.methods
+        .unfreezeRoom(roomId)
+        .accounts({
+          room: roomPda,
+          globalConfig: globalConfigPda,
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+      let room = await program.account.room.fetch(roomPda);
+      assert.deepEqual(room.status, { active: {} });
+      assert.isNull(room.preFreezeStatus);
+
+      await endRoomCall().rpc();
+
+      room = await program.account.room.fetch(roomPda);
+      assert.equal(room.ended, true);
+    });
+  });
+
+  describe("Guaranteed Minimum Prize", () => {
+    const entryFee = 10 * 1_000_000;
+    // 20% platform, 3% host, 20% prize pool of one 10-token entry
+    const platformFee = 2_000_000;
+    const hostFee = 300_000;
+    const poolPrize = 2_000_000;
+    const charityFromEntry = entryFee - platformFee - hostFee - poolPrize;
+
+    const setupRoom = async (roomId: string, guarantee: number) => {
+      const [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      const [player1EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100],
+          "Min prize test",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      await program.methods
+        .fundMinPrize(roomId, new anchor.BN(guarantee))
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      await program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: player1EntryPda,
+          player: player1Wallet.publicKey,
+          playerTokenAccount: player1TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1Wallet])
+        .rpc();
+
+      return { roomPda, roomVaultPda };
+    };
+
+    const endAndMeasure = async (roomId: string, roomPda: PublicKey, roomVaultPda: PublicKey) => {
+      const winnerBefore = (await getAccount(provider.connection, player1TokenAccount)).amount;
+      const charityBefore = (await getAccount(provider.connection, charityTokenAccount)).amount;
+
+      await program.methods
+        .endRoom(roomId, [player1Wallet.publicKey])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+      const winnerAfter = (await getAccount(provider.connection, player1TokenAccount)).amount;
+      const charityAfter = (await getAccount(provider.connection, charityTokenAccount)).amount;
+      const vault = await getAccount(provider.connection, roomVaultPda);
+
+      return {
+        prize: Number(winnerAfter - winnerBefore),
+        charity: Number(charityAfter - charityBefore),
+        vaultLeft: Number(vault.amount),
+      };
+    };
+
+    before(async () => {
+      // The host needs tokens to escrow guarantees
+      await mintTo(
+        provider.connection,
+        admin.payer,
+        tokenMint,
+        hostTokenAccount,
+        admin.publicKey,
+        100 * 1_000_000
+      );
+    });
+
+    it("Tops up a short prize pool from the escrow", async () => {
+      const roomId = "min-prize-topup";
+      const guarantee = 5_000_000;
+      const { roomPda, roomVaultPda } = await setupRoom(roomId, guarantee);
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.guaranteedMinPrize.toNumber(), guarantee);
+
+      const { prize, charity, vaultLeft } = await endAndMeasure(roomId, roomPda, roomVaultPda);
+
+      // Pool of 2 is topped up to 5; the 2 of escrow not needed goes to charity
+      assert.equal(prize, guarantee);
+      assert.equal(charity, charityFromEntry + poolPrize);
+      assert.equal(vaultLeft, 0);
+    });
+
+    it("Sends the whole escrow to charity when the pool exceeds the guarantee", async () => {
+      const roomId = "min-prize-excess";
+      const guarantee = 1_000_000;
+      const { roomPda, roomVaultPda } = await setupRoom(roomId, guarantee);
+
+      const { prize, charity, vaultLeft } = await endAndMeasure(roomId, roomPda, roomVaultPda);
+
+      assert.equal(prize, poolPrize);
+      assert.equal(charity, charityFromEntry + guarantee);
+      assert.equal(vaultLeft, 0);
+    });
+
+    it("Rejects funding a guarantee after players have joined", async () => {
+      const roomId = "min-prize-late";
+      const [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      await setupRoom(roomId, 1_000_000);
+
+      try {
+        await program.methods
+          .fundMinPrize(roomId, new anchor.BN(1_000_000))
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            hostTokenAccount: hostTokenAccount,
+            host: hostWallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([hostWallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidRoomStatus");
+      }
+    });
+  });
+
+  describe("Max Winners", () => {
+    // Must match state::MAX_WINNERS in the program
+    const MAX_WINNERS = 10;
+    const roomId = "max-winners-room";
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    let players: { wallet: Keypair; tokenAccount: PublicKey; entryPda: PublicKey }[];
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      // One funded player per prize place
+      players = [];
+      for (let i = 0; i < MAX_WINNERS; i++) {
+        const wallet = Keypair.generate();
+        await provider.connection.confirmTransaction(
+          await provider.connection.requestAirdrop(wallet.publicKey, LAMPORTS_PER_SOL)
+        );
+        const tokenAccount = await createAccount(
+          provider.connection,
+          admin.payer,
+          tokenMint,
+          wallet.publicKey
+        );
+        await mintTo(
+          provider.connection,
+          admin.payer,
+          tokenMint,
+          tokenAccount,
+          admin.publicKey,
+          100 * 1_000_000
+        );
+        const [entryPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("player"), roomPda.toBuffer(), wallet.publicKey.toBuffer()],
+          program.programId
+        );
+        players.push({ wallet, tokenAccount, entryPda });
+      }
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(10 * 1_000_000),
+          MAX_WINNERS,
+          300,
+          2000,
+          Array(MAX_WINNERS).fill(100 / MAX_WINNERS),
+          "Max winners test",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      for (const [player, playerTokenAccount] of players) {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry: player.entryPda,
+            player: player.wallet.publicKey,
+            playerTokenAccount: player.tokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player.wallet])
+          .rpc();
+      }
+    });
+
+    it("Rejects more than MAX_WINNERS winners", async () => {
+      const tooMany = [...players.map((p) => p.wallet.publicKey), Keypair.generate().publicKey];
+      assert.equal(tooMany.length, MAX_WINNERS + 1);
+
+      try {
+        await program.methods
+          .declareWinners(roomId, tooMany, null, null)
+          .accounts({
+            room: roomPda,
+            host: hostWallet.publicKey,
+          })
+          .remainingAccounts(
+            players.map((p) => ({ pubkey: p.entryPda, isSigner: false, isWritable: false }))
+          )
+          .signers([hostWallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidWinners");
+      }
+    });
+
+    it("Rejects a prize distribution with more than MAX_WINNERS places", async () => {
+      const tooManyRoomId = "max-winners-overflow";
+      const [tooManyRoomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(tooManyRoomId)],
+        program.programId
+      );
+      const [tooManyVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), tooManyRoomPda.toBuffer()],
+        program.programId
+      );
+      const distribution = [...Array(MAX_WINNERS).fill(9), 100 - 9 * MAX_WINNERS];
+
+      try {
+        await program.methods
+          .initPoolRoom(
+            tooManyRoomId,
+            charityWallet.publicKey,
+            new anchor.BN(10 * 1_000_000),
+            10,
+            300,
+            2000,
+            distribution,
+            "Too many places",
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null
+          )
+          .accounts({
+            room: tooManyRoomPda,
+            roomVault: tooManyVaultPda,
+            globalConfig: globalConfigPda,
+            host: hostWallet.publicKey,
+            feeTokenMint: tokenMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([hostWallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidPrizeDistribution");
+      }
+    });
+
+    it("Declares exactly MAX_WINNERS winners and pays each of them", async () => {
+      const before = await Promise.all(
+        players.map((p) => getAccount(provider.connection, p.tokenAccount))
+      );
+
+      await program.methods
+        .declareWinners(roomId, players.map((p) => p.wallet.publicKey), null, null)
+        .accounts({
+          room: roomPda,
+          host: hostWallet.publicKey,
+        })
+        .remainingAccounts(
+          players.map((p) => ({ pubkey: p.entryPda, isSigner: false, isWritable: false }))
+        )
+        .signers([hostWallet])
+        .rpc();
+
+      let room = await program.account.room.fetch(roomPda);
+      assert.equal(room.winners.length, MAX_WINNERS);
+      room.winners.forEach((winner, i) => {
+        assert.ok(winner.equals(players[i].wallet.publicKey));
+      });
+
+      await program.methods
+        .endRoom(roomId, [])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          players.map((p) => ({ pubkey: p.tokenAccount, isSigner: false, isWritable: true }))
+        )
+        .signers([hostWallet])
+        .rpc();
+
+      room = await program.account.room.fetch(roomPda);
+      assert.equal(room.ended, true);
+
+      const after = await Promise.all(
+        players.map((p) => getAccount(provider.connection, p.tokenAccount))
+      );
+      after.forEach((account, i) => {
+        assert.ok(Number(account.amount) > Number(before[i].amount), `winner ${i + 1} paid`);
+      });
+    });
+  });
+
+  describe("Charity Receipt", () => {
+    const roomId = "charity-receipt-room";
+    const receiptHash = Array.from(Buffer.alloc(32, 7));
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    let player1EntryPda: PublicKey;
+
+    const recordReceiptCall = (recorder: Keypair) =>
+      program.methods
+        .recordCharityReceipt(roomId, receiptHash)
+        .accounts({
+          room: roomPda,
+          globalConfig: globalConfigPda,
+          recorder: recorder.publicKey,
+        })
+        .signers([recorder]);
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      [player1EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(10 * 1_000_000),
+          10,
+          300,
+          2000,
+          [100],
+          "Receipt test",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      await program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: player1EntryPda,
+          player: player1Wallet.publicKey,
+          playerTokenAccount: player1TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1Wallet])
+        .rpc();
+    });
+
+    it("Rejects a receipt before the room has ended", async () => {
+      try {
+        await recordReceiptCall(charityWallet).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("RoomNotEnded");
+      }
+    });
+
+    it("Rejects a receipt from a signer that is neither charity nor attestor", async () => {
+      await program.methods
+        .endRoom(roomId, [player1Wallet.publicKey])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+      try {
+        await recordReceiptCall(hostWallet).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+    });
+
+    it("Records the charity's receipt and emits CharityReceiptRecorded", async () => {
+      let listener: number;
+      const event = new Promise<any>((resolve) => {
+        listener = program.addEventListener("charityReceiptRecorded", resolve);
+      });
+
+      await recordReceiptCall(charityWallet).rpc();
+
+      const emitted = await event;
+      await program.removeEventListener(listener);
+      assert.ok(emitted.room.equals(roomPda));
+      assert.ok(emitted.charityWallet.equals(charityWallet.publicKey));
+      assert.ok(emitted.recorder.equals(charityWallet.publicKey));
+      assert.deepEqual(Array.from(emitted.receiptHash), receiptHash);
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.deepEqual(Array.from(room.charityReceipt), receiptHash);
+    });
+
+    it("Rejects recording a second receipt", async () => {
+      try {
+        await recordReceiptCall(charityWallet).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("CharityReceiptAlreadyRecorded");
+      }
+    });
+  });
+
+  describe("Amend Winners", () => {
+    const roomId = "amend-winners-room";
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    let player1EntryPda: PublicKey;
+    let player2EntryPda: PublicKey;
+
+    const amendWinnersCall = (winners: PublicKey[], entries: PublicKey[], signer: Keypair) =>
+      program.methods
+        .amendWinners(roomId, winners)
+        .accounts({
+          room: roomPda,
+          host: signer.publicKey,
+        })
+        .remainingAccounts(
+          entries.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+        )
+        .signers([signer]);
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      [player1EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      [player2EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player2Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(10 * 1_000_000),
+          10,
+          300,
+          2000,
+          [100],
+          "Amend test",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      for (const [wallet, entry, tokenAccount] of [
+        [player1Wallet, player1EntryPda, player1TokenAccount],
+        [player2Wallet, player2EntryPda, player2TokenAccount],
+      ] as [Keypair, PublicKey, PublicKey][]) {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry: entry,
+            player: wallet.publicKey,
+            playerTokenAccount: tokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([wallet])
+          .rpc();
+      }
+    });
+
+    it("Rejects amendment before winners are declared", async () => {
+      try {
+        await amendWinnersCall([player2Wallet.publicKey], [player2EntryPda], hostWallet).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("WinnersNotDeclared");
+      }
+    });
+
+    it("Rejects amendment by a non-host", async () => {
+      await program.methods
+        .declareWinners(roomId, [player1Wallet.publicKey], null, null)
+        .accounts({
+          room: roomPda,
+          host: hostWallet.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: player1EntryPda, isSigner: false, isWritable: false },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+      try {
+        await amendWinnersCall([player1Wallet.publicKey], [player1EntryPda], player1Wallet).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+    });
+
+    it("Rejects duplicate winners in an amendment", async () => {
+      try {
+        await amendWinnersCall(
+          [player2Wallet.publicKey, player2Wallet.publicKey],
+          [player2EntryPda, player2EntryPda],
+          hostWallet
+        ).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidWinners");
+      }
+    });
+
+    it("Replaces the winners and emits old and new lists", async () => {
+      let listener: number;
+      const event = new Promise<any>((resolve) => {
+        listener = program.addEventListener("winnersAmended", resolve);
+      });
+
+      await amendWinnersCall([player2Wallet.publicKey], [player2EntryPda], hostWallet).rpc();
+
+      const emitted = await event;
+      await program.removeEventListener(listener);
+      assert.deepEqual(
+        emitted.oldWinners.map((w: PublicKey) => w.toBase58()),
+        [player1Wallet.publicKey.toBase58()]
+      );
+      assert.deepEqual(
+        emitted.newWinners.map((w: PublicKey) => w.toBase58()),
+        [player2Wallet.publicKey.toBase58()]
+      );
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.winners.length, 1);
+      assert.ok(room.winners[0].equals(player2Wallet.publicKey));
+    });
+
+    it("Rejects amendment after end_room", async () => {
+      await program.methods
+        .endRoom(roomId, [])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: player2TokenAccount, isSigner: false, isWritable: true },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+      try {
+        await amendWinnersCall([player1Wallet.publicKey], [player1EntryPda], hostWallet).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("RoomAlreadyEnded");
+      }
+    });
+  });
+
+  describe("Winner Scores", () => {
+    const roomId = "winner-scores-room";
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    let player1EntryPda: PublicKey;
+    let player2EntryPda: PublicKey;
+
+    const declareWithScores = (scores: number[] | null) =>
+      program.methods
+        .declareWinners(
+          roomId,
+          [player1Wallet.publicKey, player2Wallet.publicKey],
+          scores ? scores.map((score) => new anchor.BN(score)) : null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          host: hostWallet.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: player1EntryPda, isSigner: false, isWritable: false },
+          { pubkey: player2EntryPda, isSigner: false, isWritable: false },
+        ])
+        .signers([hostWallet]);
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      [player1EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      [player2EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player2Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(10 * 1_000_000),
+          10,
+          300,
+          2000,
+          [70, 30],
+          "Scores test",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      for (const [wallet, entry, tokenAccount] of [
+        [player1Wallet, player1EntryPda, player1TokenAccount],
+        [player2Wallet, player2EntryPda, player2TokenAccount],
+      ] as [Keypair, PublicKey, PublicKey][]) {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry: entry,
+            player: wallet.publicKey,
+            playerTokenAccount: tokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([wallet])
+          .rpc();
+      }
+    });
+
+    it("Rejects a score count that doesn't match the winners", async () => {
+      try {
+        await declareWithScores([980]).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidWinnerScores");
+      }
+    });
+
+    it("Rejects ascending scores for top-heavy prizes", async () => {
+      try {
+        await declareWithScores([500, 750]).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidWinnerScores");
+      }
+    });
+
+    it("Stores scores on the room and emits them", async () => {
+      let listener: number;
+      const event = new Promise<any>((resolve) => {
+        listener = program.addEventListener("winnersDeclared", resolve);
+      });
+
+      await declareWithScores([980, 750]).rpc();
+
+      const emitted = await event;
+      await program.removeEventListener(listener);
+      assert.deepEqual(emitted.scores.map((s: anchor.BN) => s.toNumber()), [980, 750]);
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.deepEqual(room.winnerScores.map((s: anchor.BN) => s.toNumber()), [980, 750]);
+    });
+
+    it("Reports each winner's prize and the charity wallet in RoomEnded", async () => {
+      let listener: number;
+      const event = new Promise<any>((resolve) => {
+        listener = program.addEventListener("roomEnded", resolve);
+      });
+
+      await program.methods
+        .endRoom(roomId, [])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+          { pubkey: player2TokenAccount, isSigner: false, isWritable: true },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+      const emitted = await event;
+      await program.removeEventListener(listener);
+
+      // 20 tokens in entry fees, 20% prize pool split 70/30
+      assert.deepEqual(
+        emitted.winnerAmounts.map((a: anchor.BN) => a.toNumber()),
+        [2_800_000, 1_200_000]
+      );
+      assert.ok(emitted.charityWallet.equals(charityWallet.publicKey));
+
+      // Charity amount broken down by source
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(emitted.charityFromExtras.toString(), room.totalExtrasFees.toString());
+      assert.equal(emitted.charityFromLeavePenalties.toNumber(), 0);
+      assert.equal(
+        emitted.charityFromEntry.add(emitted.charityFromExtras).toString(),
+        emitted.charityAmount.toString()
+      );
+    });
+  });
+
+  describe("Multiple Charities", () => {
+    const secondCharity = Keypair.generate();
+
+    const initWithSplits = (roomId: string, splits: { wallet: PublicKey; weightBps: number }[]) => {
+      const [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+
+      return {
+        roomPda,
+        tx: program.methods
+          .initPoolRoom(
+            roomId,
+            charityWallet.publicKey,
+            new anchor.BN(10 * 1_000_000),
+            10,
+            300,
+            2000,
+            [100],
+            "Split test",
+            null,
+            splits,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null
+          )
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            globalConfig: globalConfigPda,
+            host: hostWallet.publicKey,
+            feeTokenMint: tokenMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([hostWallet]),
+      };
+    };
+
+    it("Rejects weights that don't sum to 100%", async () => {
+      const { tx } = initWithSplits("split-bad-weights", [
+        { wallet: charityWallet.publicKey, weightBps: 6000 },
+        { wallet: secondCharity.publicKey, weightBps: 3000 },
+      ]);
+      try {
+        await tx.rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidCharitySplits");
+      }
+    });
+
+    it("Rejects splits that don't start with the charity wallet", async () => {
+      const { tx } = initWithSplits("split-bad-first", [
+        { wallet: secondCharity.publicKey, weightBps: 6000 },
+        { wallet: charityWallet.publicKey, weightBps: 4000 },
+      ]);
+      try {
+        await tx.rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidCharitySplits");
+      }
+    });
+
+    it("Stores the weighted charities on the room", async () => {
+      const { roomPda, tx } = initWithSplits("split-ok", [
+        { wallet: charityWallet.publicKey, weightBps: 6000 },
+        { wallet: secondCharity.publicKey, weightBps: 4000 },
+      ]);
+      await tx.rpc();
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.charitySplits.length, 2);
+      assert.ok(room.charitySplits[1].wallet.equals(secondCharity.publicKey));
+      assert.equal(room.charitySplits[1].weightBps, 4000);
+    });
+  });
+
+  describe("Token Registry Cap", () => {
+    const setCap = (cap: number) =>
+      program.methods
+        .updateConfig(null, null, cap, null, null)
+        .accounts({
+          globalConfig: globalConfigPda,
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+    it("Defaults to the registry's full capacity", async () => {
+      const config = await program.account.globalConfig.fetch(globalConfigPda);
+      assert.equal(config.maxApprovedTokens, 50);
+    });
+
+    it("Rejects a cap of zero or above the registry's capacity", async () => {
+      for (const cap of [0, 51]) {
+        try {
+          await setCap(cap);
+          assert.fail("Should have thrown error");
+        } catch (err) {
+          expect(err.toString()).to.include("InvalidTokenRegistryCap");
+        }
+      }
+    });
+
+    it("Lets the admin adjust the cap", async () => {
+      await setCap(10);
+      let config = await program.account.globalConfig.fetch(globalConfigPda);
+      assert.equal(config.maxApprovedTokens, 10);
+
+      await setCap(50);
+      config = await program.account.globalConfig.fetch(globalConfigPda);
+      assert.equal(config.maxApprovedTokens, 50);
+    });
+  });
+
+  describe("Expiration Bounds", () => {
+    const slotsPerDay = 216_000;
+
+    const setBounds = (maxSlots: number | null, defaultSlots: number | null) =>
+      program.methods
+        .updateConfig(
+          null,
+          null,
+          null,
+          maxSlots === null ? null : new anchor.BN(maxSlots),
+          defaultSlots === null ? null : new anchor.BN(defaultSlots)
+        )
+        .accounts({
+          globalConfig: globalConfigPda,
+          admin: admin.publicKey,
+        })
+        .rpc();
+
+    const createCall = (roomId: string, expirationSlots: number | null) => {
+      const [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      return {
+        roomPda,
+        call: program.methods
+          .initPoolRoom(
+            roomId,
+            charityWallet.publicKey,
+            new anchor.BN(10 * 1_000_000),
+            10,
+            300,
+            2000,
+            [100],
+            "Expiry test",
+            expirationSlots === null ? null : new anchor.BN(expirationSlots),
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null
+          )
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            globalConfig: globalConfigPda,
+            host: hostWallet.publicKey,
+            feeTokenMint: tokenMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([hostWallet]),
+      };
+    };
+
+    it("Defaults to 30 days maximum and 7 days default", async () => {
+      const config = await program.account.globalConfig.fetch(globalConfigPda);
+      assert.equal(config.maxExpirationSlots.toNumber(), 30 * slotsPerDay);
+      assert.equal(config.defaultExpirationSlots.toNumber(), 7 * slotsPerDay);
+    });
+
+    it("Gives a room without a deadline the default expiration", async () => {
+      const { roomPda, call } = createCall("expiry-default", null);
+      await call.rpc();
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.expirationSlot.sub(room.creationSlot).toNumber(), 7 * slotsPerDay);
+    });
+
+    it("Rejects a zero or over-long expiration", async () => {
+      for (const [roomId, slots] of [
+        ["expiry-zero", 0],
+        ["expiry-too-long", 30 * slotsPerDay + 1],
+        ["expiry-overflow", Number.MAX_SAFE_INTEGER],
+      ] as [string, number][]) {
+        try {
+          await createCall(roomId, slots).call.rpc();
+          assert.fail("Should have thrown error");
+        } catch (err) {
+          expect(err.toString()).to.include("InvalidExpiration");
+        }
+      }
+    });
+
+    it("Lets the admin adjust the bounds, keeping the default within the maximum", async () => {
+      try {
+        await setBounds(slotsPerDay, null); // below the 7-day default
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidExpiration");
+      }
+
+      await setBounds(14 * slotsPerDay, slotsPerDay);
+      const config = await program.account.globalConfig.fetch(globalConfigPda);
+      assert.equal(config.maxExpirationSlots.toNumber(), 14 * slotsPerDay);
+      assert.equal(config.defaultExpirationSlots.toNumber(), slotsPerDay);
+
+      await setBounds(30 * slotsPerDay, 7 * slotsPerDay);
+    });
+  });
+
+  describe("Low Turnout Recovery", () => {
+    const roomId = "low-turnout-room";
+    const entryFee = 10 * 1_000_000;
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    let player1EntryPda: PublicKey;
+
+    const recoverCall = (reason: any) =>
+      program.methods
+        .recoverRoom(roomId, reason)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          admin: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: player1EntryPda, isSigner: false, isWritable: false },
+          { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+        ]);
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      [player1EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+
+      // Needs 3 players; only one shows up
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100],
+          "Quorum test",
+          null,
+          null,
+          3,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      await program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: player1EntryPda,
+          player: player1Wallet.publicKey,
+          playerTokenAccount: player1TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1Wallet])
+        .rpc();
+    });
+
+    it("Rejects a reason that doesn't match the turnout", async () => {
+      try {
+        await recoverCall({ abandoned: {} }).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidRecoveryReason");
+      }
+    });
+
+    it("Rejects a refund to a token account the player doesn't own", async () => {
+      try {
+        await program.methods
+          .recoverRoom(roomId, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            globalConfig: globalConfigPda,
+            platformTokenAccount: platformTokenAccount,
+            admin: admin.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts([
+            { pubkey: player1EntryPda, isSigner: false, isWritable: false },
+            { pubkey: player2TokenAccount, isSigner: false, isWritable: true },
+          ])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidTokenOwner");
+      }
+    });
+
+    it("Rejects a platform token account the platform wallet doesn't own", async () => {
+      try {
+        await program.methods
+          .recoverRoom(roomId, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            globalConfig: globalConfigPda,
+            platformTokenAccount: player2TokenAccount,
+            admin: admin.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts([
+            { pubkey: player1EntryPda, isSigner: false, isWritable: false },
+            { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+          ])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidTokenOwner");
+      }
+    });
+
+    it("Refunds players in full without a platform fee", async () => {
+      const playerBefore = (await getAccount(provider.connection, player1TokenAccount)).amount;
+      const platformBefore = (await getAccount(provider.connection, platformTokenAccount)).amount;
+
+      let listener: number;
+      const event = new Promise<any>((resolve) => {
+        listener = program.addEventListener("roomRecovered", resolve);
+      });
+
+      await recoverCall({ lowTurnout: {} }).rpc();
+
+      const emitted = await event;
+      await program.removeEventListener(listener);
+      assert.deepEqual(emitted.reason, { lowTurnout: {} });
+      assert.equal(emitted.feeTaken, false);
+
+      const playerAfter = (await getAccount(provider.connection, player1TokenAccount)).amount;
+      const platformAfter = (await getAccount(provider.connection, platformTokenAccount)).amount;
+      assert.equal((playerAfter - playerBefore).toString(), entryFee.toString());
+      assert.equal(platformAfter.toString(), platformBefore.toString());
+    });
+  });
+
+  describe("Expired Room Finalization", () => {
+    const entryFee = 10 * 1_000_000;
+
+    const pdas = (roomId: string) => {
+      const [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      return { roomPda, roomVaultPda };
+    };
+
+    const createRoomWithPlayers = async (
+      roomId: string,
+      expirationSlots: number,
+      players: [Keypair, PublicKey][]
+    ) => {
+      const { roomPda, roomVaultPda } = pdas(roomId);
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100],
+          "Expiry test",
+          new anchor.BN(expirationSlots),
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      for (const [player, playerTokenAccount] of players) {
+        const [playerEntryPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("player"), roomPda.toBuffer(), player.publicKey.toBuffer()],
+          program.programId
+        );
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry: playerEntryPda,
+            player: player.publicKey,
+            playerTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      }
+    };
+
+    const finalizeCall = (roomId: string, caller: Keypair) => {
+      const { roomPda, roomVaultPda } = pdas(roomId);
+      return program.methods
+        .finalizeExpiredRoom(roomId)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          caller: caller.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([caller]);
+    };
+
+    it("Rejects finalizing a room before it expires", async () => {
+      await createRoomWithPlayers("not-expired-room", 1_000_000, [[player1Wallet, player1TokenAccount]]);
+
+      try {
+        await finalizeCall("not-expired-room", player3Wallet).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("RoomNotExpired");
+      }
+    });
+
+    it("Lets anyone finalize an expired room and sends the host fee to charity", async () => {
+      const roomId = "expired-room";
+      await createRoomWithPlayers(roomId, 2, [
+        [player1Wallet, player1TokenAccount],
+        [player2Wallet, player2TokenAccount],
+      ]);
+
+      const { roomPda } = pdas(roomId);
+      const room = await program.account.room.fetch(roomPda);
+      while ((await provider.connection.getSlot()) < room.expirationSlot.toNumber()) {
+        await new Promise((resolve) => setTimeout(resolve, 400));
+      }
+
+      const balance = async (account: PublicKey) =>
+        (await getAccount(provider.connection, account)).amount;
+      const hostBefore = await balance(hostTokenAccount);
+      const platformBefore = await balance(platformTokenAccount);
+      const charityBefore = await balance(charityTokenAccount);
+
+      let listener: number;
+      const event = new Promise<any>((resolve) => {
+        listener = program.addEventListener("roomEnded", resolve);
+      });
+
+      // A player who didn't win anything settles the room
+      await finalizeCall(roomId, player3Wallet).rpc();
+
+      const emitted = await event;
+      await program.removeEventListener(listener);
+      assert.equal(emitted.expiredFinalization, true);
+      assert.equal(emitted.hostAmount.toNumber(), 0);
+      assert.equal(emitted.prizeAmount.toNumber(), 0, "no declared winners, prize goes to charity");
+
+      const platformGain = (await balance(platformTokenAccount)) - platformBefore;
+      const charityGain = (await balance(charityTokenAccount)) - charityBefore;
+      assert.equal((await balance(hostTokenAccount)).toString(), hostBefore.toString());
+      assert.equal(charityGain.toString(), emitted.charityAmount.toString());
+      assert.equal((platformGain + charityGain).toString(), (2 * entryFee).toString());
+
+      const ended = await program.account.room.fetch(roomPda);
+      assert.equal(ended.ended, true);
+    });
+  });
+
+  describe("Player Readiness", () => {
+    const roomId = "ready-room";
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    let player1EntryPda: PublicKey;
+    let player2EntryPda: PublicKey;
+
+    const declareCall = () =>
+      program.methods
+        .declareWinners(roomId, [player1Wallet.publicKey], null, null)
+        .accounts({
+          room: roomPda,
+          host: hostWallet.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: player1EntryPda, isSigner: false, isWritable: false },
+        ])
+        .signers([hostWallet]);
+
+    const readyCall = (player: Keypair, playerEntryPda: PublicKey) =>
+      program.methods
+        .readyUp(roomId)
+        .accounts({
+          room: roomPda,
+          playerEntry: playerEntryPda,
+          player: player.publicKey,
+        })
+        .signers([player]);
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      [player1EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      [player2EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player2Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(10 * 1_000_000),
+          10,
+          300,
+          2000,
+          [100],
+          "Readiness test",
+          null,
+          null,
+          null,
+          true,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      for (const [player, playerEntry, playerTokenAccount] of [
+        [player1Wallet, player1EntryPda, player1TokenAccount],
+        [player2Wallet, player2EntryPda, player2TokenAccount],
+      ] as [Keypair, PublicKey, PublicKey][]) {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry,
+            player: player.publicKey,
+            playerTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      }
+    });
+
+    it("Blocks winner declaration until players are ready", async () => {
+      try {
+        await declareCall().rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("PlayersNotReady");
+      }
+    });
+
+    it("Records readiness and emits PlayerReady", async () => {
+      let listener: number;
+      const event = new Promise<any>((resolve) => {
+        listener = program.addEventListener("playerReady", resolve);
+      });
+
+      await readyCall(player1Wallet, player1EntryPda).rpc();
+
+      const emitted = await event;
+      await program.removeEventListener(listener);
+      assert.equal(emitted.readyCount, 1);
+      assert.equal(emitted.playerCount, 2);
+
+      const entry = await program.account.playerEntry.fetch(player1EntryPda);
+      assert.equal(entry.ready, true);
+
+      try {
+        await readyCall(player1Wallet, player1EntryPda).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("PlayerAlreadyReady");
+      }
+    });
+
+    it("Lets the host waive the check for an idle player", async () => {
+      await program.methods
+        .waiveReadyCheck(roomId)
+        .accounts({
+          room: roomPda,
+          host: hostWallet.publicKey,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      await declareCall().rpc();
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.requireReady, false);
+      assert.equal(room.readyCount, 1);
+      assert.equal(room.winners.length, 1);
+    });
+  });
+
+  describe("Recovery Rounding Dust", () => {
+    const roomId = "dust-room";
+    const entryFee = 10 * 1_000_000;
+
+    it("Sends refund dust to the first player and empties the vault", async () => {
+      const [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      const entryPda = (player: Keypair) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("player"), roomPda.toBuffer(), player.publicKey.toBuffer()],
+          program.programId
+        )[0];
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100],
+          "Dust test",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      // 20_000_001 collected: 2_000_000 fee, 18_000_001 doesn't split evenly in two
+      for (const [player, playerTokenAccount, extras] of [
+        [player1Wallet, player1TokenAccount, 1],
+        [player2Wallet, player2TokenAccount, 0],
+      ] as [Keypair, PublicKey, number][]) {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(extras), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry: entryPda(player),
+            player: player.publicKey,
+            playerTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      }
+
+      const balance = async (account: PublicKey) =>
+        (await getAccount(provider.connection, account)).amount;
+      const player1Before = await balance(player1TokenAccount);
+      const player2Before = await balance(player2TokenAccount);
+      const platformBefore = await balance(platformTokenAccount);
+
+      await program.methods
+        .recoverRoom(roomId, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          admin: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: entryPda(player1Wallet), isSigner: false, isWritable: false },
+          { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+          { pubkey: entryPda(player2Wallet), isSigner: false, isWritable: false },
+          { pubkey: player2TokenAccount, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+
+      assert.equal((await balance(roomVaultPda)).toString(), "0");
+      assert.equal(((await balance(platformTokenAccount)) - platformBefore).toString(), "2000000");
+      assert.equal(((await balance(player1TokenAccount)) - player1Before).toString(), "9000001");
+      assert.equal(((await balance(player2TokenAccount)) - player2Before).toString(), "9000000");
+    });
+  });
+
+  describe("Contribution-Based Refunds", () => {
+    const roomId = "uneven-extras-room";
+    const entryFee = 10 * 1_000_000;
+
+    it("Refunds each player their own total paid, less the recovery fee", async () => {
+      const [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      const entryPda = (player: Keypair) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("player"), roomPda.toBuffer(), player.publicKey.toBuffer()],
+          program.programId
+        )[0];
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100],
+          "Uneven extras",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      // Player 1 pays 30 (10 entry + 20 extras), player 2 pays 10: 40 collected, 4 fee
+      for (const [player, playerTokenAccount, extras] of [
+        [player1Wallet, player1TokenAccount, 20 * 1_000_000],
+        [player2Wallet, player2TokenAccount, 0],
+      ] as [Keypair, PublicKey, number][]) {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(extras), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry: entryPda(player),
+            player: player.publicKey,
+            playerTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      }
+
+      const balance = async (account: PublicKey) =>
+        (await getAccount(provider.connection, account)).amount;
+      const player1Before = await balance(player1TokenAccount);
+      const player2Before = await balance(player2TokenAccount);
+
+      await program.methods
+        .recoverRoom(roomId, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          admin: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: entryPda(player1Wallet), isSigner: false, isWritable: false },
+          { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+          { pubkey: entryPda(player2Wallet), isSigner: false, isWritable: false },
+          { pubkey: player2TokenAccount, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+
+      // 90% of what each paid, not a flat 18 each
+      assert.equal(((await balance(player1TokenAccount)) - player1Before).toString(), "27000000");
+      assert.equal(((await balance(player2TokenAccount)) - player2Before).toString(), "9000000");
+      assert.equal((await balance(roomVaultPda)).toString(), "0");
+    });
+  });
+
+  describe("Prize Places", () => {
+    const roomId = "winner-takes-all";
+    const entryFee = 10 * 1_000_000;
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    let player1EntryPda: PublicKey;
+    let player2EntryPda: PublicKey;
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      [player1EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      [player2EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player2Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+
+      // One paid place; the padded places pay nothing
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100, 0, 0],
+          "Winner takes all",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      for (const [player, playerEntry, playerTokenAccount] of [
+        [player1Wallet, player1EntryPda, player1TokenAccount],
+        [player2Wallet, player2EntryPda, player2TokenAccount],
+      ] as [Keypair, PublicKey, PublicKey][]) {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry,
+            player: player.publicKey,
+            playerTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      }
+    });
+
+    it("Rejects more winners than paid prize places", async () => {
+      try {
+        await program.methods
+          .declareWinners(roomId, [player1Wallet.publicKey, player2Wallet.publicKey], null, null)
+          .accounts({
+            room: roomPda,
+            host: hostWallet.publicKey,
+          })
+          .remainingAccounts([
+            { pubkey: player1EntryPda, isSigner: false, isWritable: false },
+            { pubkey: player2EntryPda, isSigner: false, isWritable: false },
+          ])
+          .signers([hostWallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidWinners");
+      }
+    });
+
+    it("Pays the whole prize pool to a single declared winner", async () => {
+      await program.methods
+        .declareWinners(roomId, [player1Wallet.publicKey], null, null)
+        .accounts({
+          room: roomPda,
+          host: hostWallet.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: player1EntryPda, isSigner: false, isWritable: false },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+      const winnerBefore = (await getAccount(provider.connection, player1TokenAccount)).amount;
+
+      await program.methods
+        .endRoom(roomId, [])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+      // Prize pool is 20% of the 20 tokens collected
+      const winnerAfter = (await getAccount(provider.connection, player1TokenAccount)).amount;
+      assert.equal((winnerAfter - winnerBefore).toString(), (4 * 1_000_000).toString());
+    });
+  });
+
+  describe("Unix Timestamp Expiration", () => {
+    const entryFee = 10 * 1_000_000;
+
+    const chainTime = async () =>
+      (await provider.connection.getBlockTime(await provider.connection.getSlot())) ?? 0;
+
+    const pdas = (roomId: string) => {
+      const [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      return { roomPda, roomVaultPda };
+    };
+
+    const createCall = (roomId: string, expirationUnixTs: number) => {
+      const { roomPda, roomVaultPda } = pdas(roomId);
+      return program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100],
+          "Deadline test",
+          null,
+          null,
+          null,
+          null,
+          new anchor.BN(expirationUnixTs),
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet]);
+    };
+
+    it("Rejects an expiration timestamp in the past", async () => {
+      try {
+        await createCall("deadline-passed-room", (await chainTime()) - 60).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("RoomExpired");
+      }
+    });
+
+    it("Closes joins once the expiration timestamp passes", async () => {
+      const roomId = "deadline-room";
+      const deadline = (await chainTime()) + 3;
+      await createCall(roomId, deadline).rpc();
+
+      const { roomPda, roomVaultPda } = pdas(roomId);
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.expirationUnixTs.toNumber(), deadline);
+      assert.equal(room.expirationSlot.toNumber(), 0);
+
+      while ((await chainTime()) < deadline) {
+        await new Promise((resolve) => setTimeout(resolve, 400));
+      }
+
+      const [playerEntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      try {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry: playerEntryPda,
+            player: player1Wallet.publicKey,
+            playerTokenAccount: player1TokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player1Wallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("RoomExpired");
+      }
+    });
+  });
+
+  describe("Private Rooms", () => {
+    const roomId = "invite-only-room";
+    const entryFee = 10 * 1_000_000;
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+
+    // Same scheme as utils::verify_allowlist_proof
+    const sha256 = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+    const leaf = (wallet: PublicKey) => sha256(Buffer.from([0]), wallet.toBuffer());
+    const node = (a: Buffer, b: Buffer) =>
+      Buffer.compare(a, b) <= 0 ? sha256(Buffer.from([1]), a, b) : sha256(Buffer.from([1]), b, a);
+
+    const joinCall = (player: Keypair, playerTokenAccount: PublicKey, proof: Buffer[] | null) => {
+      const [playerEntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player.publicKey.toBuffer()],
+        program.programId
+      );
+      return program.methods
+        .joinRoom(
+          roomId,
+          new anchor.BN(0),
+          proof ? proof.map((hash) => Array.from(hash)) : null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: playerEntryPda,
+          player: player.publicKey,
+          playerTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player]);
+    };
+
+    // Player 1 and player 2 are invited; player 3 is not
+    const leaf1 = () => leaf(player1Wallet.publicKey);
+    const leaf2 = () => leaf(player2Wallet.publicKey);
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+
+      const root = node(leaf1(), leaf2());
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100],
+          "Closed tournament",
+          null,
+          null,
+          null,
+          null,
+          null,
+          Array.from(root),
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+    });
+
+    it("Rejects a join without a proof", async () => {
+      try {
+        await joinCall(player1Wallet, player1TokenAccount, null).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("PlayerNotAllowlisted");
+      }
+    });
+
+    it("Rejects a wallet that isn't on the allowlist", async () => {
+      try {
+        // Borrowing an invited player's proof doesn't help
+        await joinCall(player3Wallet, player3TokenAccount, [leaf2()]).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("PlayerNotAllowlisted");
+      }
+    });
+
+    it("Lets invited wallets join with their proof", async () => {
+      await joinCall(player1Wallet, player1TokenAccount, [leaf2()]).rpc();
+      await joinCall(player2Wallet, player2TokenAccount, [leaf1()]).rpc();
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.playerCount, 2);
+    });
+  });
+
+  describe("Blacklist", () => {
+    const roomId = "blacklist-room";
+    const entryFee = 10 * 1_000_000;
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    let blacklistPda: PublicKey;
+
+    const joinCall = () => {
+      const [playerEntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player3Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      return program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: playerEntryPda,
+          blacklist: blacklistPda,
+          player: player3Wallet.publicKey,
+          playerTokenAccount: player3TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player3Wallet]);
+    };
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      [blacklistPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("blacklist")],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100],
+          "Blacklist test",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+    });
+
+    it("Only the admin can ban wallets", async () => {
+      try {
+        await program.methods
+          .addToBlacklist(player3Wallet.publicKey)
+          .accounts({ blacklist: blacklistPda, admin: hostWallet.publicKey })
+          .signers([hostWallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+    });
+
+    it("Rejects joins from a banned wallet", async () => {
+      let listener: number;
+      const event = new Promise<any>((resolve) => {
+        listener = program.addEventListener("playerBanned", resolve);
+      });
+
+      await program.methods
+        .addToBlacklist(player3Wallet.publicKey)
+        .accounts({ blacklist: blacklistPda, admin: admin.publicKey })
+        .rpc();
+
+      const emitted = await event;
+      await program.removeEventListener(listener);
+      assert.equal(emitted.wallet.toString(), player3Wallet.publicKey.toString());
+      assert.equal(emitted.totalBanned, 1);
+
+      try {
+        await joinCall().rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("PlayerBlacklisted");
+      }
+    });
+
+    it("Lets the wallet join again once the ban is lifted", async () => {
+      await program.methods
+        .removeFromBlacklist(player3Wallet.publicKey)
+        .accounts({ blacklist: blacklistPda, admin: admin.publicKey })
+        .rpc();
+
+      try {
+        await program.methods
+          .removeFromBlacklist(player3Wallet.publicKey)
+          .accounts({ blacklist: blacklistPda, admin: admin.publicKey })
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("PlayerNotBlacklisted");
+      }
+
+      await joinCall().rpc();
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.playerCount, 1);
+    });
+  });
+
+  describe("Asset Rooms", () => {
+    const entryFee = 10 * 1_000_000;
+    const prizeAmount = 5 * 1_000_000;
+    let prizeMint: PublicKey;
+
+    const roomPdas = (roomId: string) => {
+      const [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      return { roomPda, roomVaultPda };
+    };
+
+    const initAssetRoom = async (roomId: string, secondPrize: boolean) => {
+      const { roomPda, roomVaultPda } = roomPdas(roomId);
+      await program.methods
+        .initAssetRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          "Asset prize test",
+          null,
+          prizeMint,
+          new anchor.BN(prizeAmount),
+          secondPrize ? prizeMint : null,
+          secondPrize ? new anchor.BN(prizeAmount) : null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          feeTokenMint: tokenMint,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+      return { roomPda, roomVaultPda };
+    };
+
+    // Escrows a prize from a freshly funded host account into its prize vault PDA
+    const depositPrize = async (roomId: string, roomPda: PublicKey, prizeIndex: number) => {
+      const [prizeVault] = PublicKey.findProgramAddressSync(
+        [Buffer.from("prize-vault"), roomPda.toBuffer(), Buffer.from([prizeIndex])],
+        program.programId
+      );
+      const hostPrizeAccount = await createAccount(
+        provider.connection,
+        admin.payer,
+        prizeMint,
+        hostWallet.publicKey,
+        Keypair.generate()
+      );
+      await mintTo(provider.connection, admin.payer, prizeMint, hostPrizeAccount, admin.publicKey, prizeAmount);
+
+      await program.methods
+        .addPrizeAsset(roomId, prizeIndex)
+        .accounts({
+          room: roomPda,
+          prizeVault,
+          prizeMint,
+          hostTokenAccount: hostPrizeAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+      return prizeVault;
+    };
+
+    before(async () => {
+      prizeMint = await createMint(provider.connection, admin.payer, admin.publicKey, null, 0);
+    });
+
+    it("Hands the escrowed prize to the winner and entry fees to platform, host and charity", async () => {
+      const roomId = "asset-end-room";
+      const { roomPda, roomVaultPda } = await initAssetRoom(roomId, false);
+      const prizeVault = await depositPrize(roomId, roomPda, 0);
+
+      const [player1EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: player1EntryPda,
+          player: player1Wallet.publicKey,
+          playerTokenAccount: player1TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1Wallet])
+        .rpc();
+
+      const winnerPrizeAccount = await createAccount(
+        provider.connection,
+        admin.payer,
+        prizeMint,
+        player1Wallet.publicKey
+      );
+      const balance = async (account: PublicKey) =>
+        (await getAccount(provider.connection, account)).amount;
+      const charityBefore = await balance(charityTokenAccount);
+
+      const endRoom = (vault: PublicKey) =>
+        program.methods
+          .endAssetRoom(roomId, [player1Wallet.publicKey])
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            globalConfig: globalConfigPda,
+            platformTokenAccount: platformTokenAccount,
+            charityTokenAccount: charityTokenAccount,
+            hostTokenAccount: hostTokenAccount,
+            host: hostWallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts([
+            { pubkey: winnerPrizeAccount, isSigner: false, isWritable: true },
+            { pubkey: vault, isSigner: false, isWritable: true },
+          ])
+          .signers([hostWallet])
+          .rpc();
+
+      // A room-owned token account that is not the prize's vault PDA is refused
+      const decoyVault = await createAccount(
+        provider.connection,
+        admin.payer,
+        prizeMint,
+        roomPda,
+        Keypair.generate()
+      );
+      try {
+        await endRoom(decoyVault);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidVaultAuthority");
+      }
+
+      await endRoom(prizeVault);
+
+      assert.equal((await balance(winnerPrizeAccount)).toString(), prizeAmount.toString());
+      assert.equal((await balance(prizeVault)).toString(), "0");
+      // 20% platform and 3% host leave 77% of the entry fee for charity
+      assert.equal(((await balance(charityTokenAccount)) - charityBefore).toString(), "7700000");
+      assert.equal((await balance(roomVaultPda)).toString(), "0");
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.isTrue(room.ended);
+    });
+
+    it("Returns every deposited prize to the host when the room is recovered", async () => {
+      const roomId = "asset-recover-room";
+      const { roomPda, roomVaultPda } = await initAssetRoom(roomId, true);
+      const prizeVaults = [
+        await depositPrize(roomId, roomPda, 0),
+        await depositPrize(roomId, roomPda, 1),
+      ];
+
+      const [player1EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: player1EntryPda,
+          player: player1Wallet.publicKey,
+          playerTokenAccount: player1TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1Wallet])
+        .rpc();
+
+      const hostPrizeAccount = await createAccount(
+        provider.connection,
+        admin.payer,
+        prizeMint,
+        hostWallet.publicKey,
+        Keypair.generate()
+      );
+      const balance = async (account: PublicKey) =>
+        (await getAccount(provider.connection, account)).amount;
+      const playerBefore = await balance(player1TokenAccount);
+
+      const recover = (vaults: PublicKey[]) =>
+        program.methods
+          .recoverRoom(roomId, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            globalConfig: globalConfigPda,
+            platformTokenAccount: platformTokenAccount,
+            admin: admin.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts([
+            { pubkey: player1EntryPda, isSigner: false, isWritable: false },
+            { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+            ...vaults.flatMap((vault) => [
+              { pubkey: vault, isSigner: false, isWritable: true },
+              { pubkey: hostPrizeAccount, isSigner: false, isWritable: true },
+            ]),
+          ])
+          .rpc();
+
+      // Leaving a prize behind would lock it in its vault once the room is ended
+      try {
+        await recover([prizeVaults[0]]);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidRefundAccounts");
+      }
+
+      // Vaults out of prize order don't match their prizes' PDAs
+      try {
+        await recover([prizeVaults[1], prizeVaults[0]]);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidVaultAuthority");
+      }
+
+      await recover(prizeVaults);
+
+      assert.equal((await balance(hostPrizeAccount)).toString(), (2 * prizeAmount).toString());
+      for (const vault of prizeVaults) {
+        assert.equal((await balance(vault)).toString(), "0");
+      }
+      // Abandoned (no quorum set): the player gets 90% back
+      assert.equal(((await balance(player1TokenAccount)) - playerBefore).toString(), "9000000");
+      assert.equal((await balance(roomVaultPda)).toString(), "0");
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.isTrue(room.ended);
+      assert.isTrue(room.prizeAssets.filter((prize) => prize).every((prize) => !prize.deposited));
+    });
+
+    it("Opens the room to players only once every prize is deposited", async () => {
+      const roomId = "asset-join-gate-room";
+      const { roomPda, roomVaultPda } = await initAssetRoom(roomId, true);
+      const [player2EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player2Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      const join = () =>
+        program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry: player2EntryPda,
+            player: player2Wallet.publicKey,
+            playerTokenAccount: player2TokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player2Wallet])
+          .rpc();
+
+      let room = await program.account.room.fetch(roomPda);
+      assert.deepEqual(room.status, { awaitingFunding: {} });
+
+      await depositPrize(roomId, roomPda, 0);
+      room = await program.account.room.fetch(roomPda);
+      assert.deepEqual(room.status, { partiallyFunded: {} });
+
+      try {
+        await join();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("RoomNotReady");
+      }
+
+      await depositPrize(roomId, roomPda, 1);
+      room = await program.account.room.fetch(roomPda);
+      assert.deepEqual(room.status, { ready: {} });
+
+      await join();
+      room = await program.account.room.fetch(roomPda);
+      assert.equal(room.playerCount, 1);
+      assert.deepEqual(room.status, { active: {} });
+    });
+
+    it("Refuses to end a room whose prizes are not all deposited", async () => {
+      const roomId = "asset-unfunded-room";
+      const { roomPda, roomVaultPda } = await initAssetRoom(roomId, true);
+      await depositPrize(roomId, roomPda, 0);
+
+      try {
+        await program.methods
+          .endAssetRoom(roomId, [player1Wallet.publicKey, player2Wallet.publicKey])
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            globalConfig: globalConfigPda,
+            platformTokenAccount: platformTokenAccount,
+            charityTokenAccount: charityTokenAccount,
+            hostTokenAccount: hostTokenAccount,
+            host: hostWallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([hostWallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("PrizesNotFullyFunded");
+      }
+    });
+
+    it("Escrows a 1-of-1 NFT prize only once its supply is fixed", async () => {
+      const roomId = "asset-nft-room";
+      const { roomPda, roomVaultPda } = roomPdas(roomId);
+      const nftMint = await createMint(provider.connection, admin.payer, admin.publicKey, null, 0);
+      const hostNftAccount = await createAccount(
+        provider.connection,
+        admin.payer,
+        nftMint,
+        hostWallet.publicKey
+      );
+      await mintTo(provider.connection, admin.payer, nftMint, hostNftAccount, admin.publicKey, 1);
+
+      await program.methods
+        .initAssetRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          "NFT prize test",
+          null,
+          nftMint,
+          new anchor.BN(1),
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          [true, false, false]
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          feeTokenMint: tokenMint,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      const [prizeVault] = PublicKey.findProgramAddressSync(
+        [Buffer.from("prize-vault"), roomPda.toBuffer(), Buffer.from([0])],
+        program.programId
+      );
+      const deposit = () =>
+        program.methods
+          .addPrizeAsset(roomId, 0)
+          .accounts({
+            room: roomPda,
+            prizeVault,
+            prizeMint: nftMint,
+            hostTokenAccount: hostNftAccount,
+            host: hostWallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([hostWallet])
+          .rpc();
+
+      // The admin can still mint more, so this is not a 1-of-1 yet
+      try {
+        await deposit();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("NotAnNft");
+      }
+
+      await setAuthority(
+        provider.connection,
+        admin.payer,
+        nftMint,
+        admin.publicKey,
+        AuthorityType.MintTokens,
+        null
+      );
+      await deposit();
+
+      assert.equal((await getAccount(provider.connection, prizeVault)).amount.toString(), "1");
+      const room = await program.account.room.fetch(roomPda);
+      assert.isTrue(room.prizeAssets[0].isNft);
+      assert.deepEqual(room.status, { ready: {} });
+    });
+  });
+
+  describe("Platform Fee Override", () => {
+    const roomId = "partner-fee-room";
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    let playerEntryPda: PublicKey;
+
+    const setFee = (platformFeeBps: number, signer = admin.payer) =>
+      program.methods
+        .setRoomPlatformFee(roomId, platformFeeBps)
+        .accounts({
+          room: roomPda,
+          globalConfig: globalConfigPda,
+          admin: signer.publicKey,
+        })
+        .signers([signer])
+        .rpc();
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      [playerEntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+
+      // Host 3%, prizes 20%: charity 57% at the standard 20% platform fee
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(10 * 1_000_000),
+          10,
+          300,
+          2000,
+          [100],
+          "Partner campaign",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.platformFeeBps, 2000);
+      assert.equal(room.charityBps, 5700);
+    });
+
+    it("Rejects overrides from non-admins and outside the configured bounds", async () => {
+      try {
+        await setFee(1000, hostWallet);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+
+      const config = await program.account.globalConfig.fetch(globalConfigPda);
+      assert.equal(config.minPlatformFeeBps, 1000);
+      for (const bps of [config.minPlatformFeeBps - 1, config.platformFeeBps + 1]) {
+        try {
+          await setFee(bps);
+          assert.fail("Should have thrown error");
+        } catch (err) {
+          expect(err.toString()).to.include("InvalidPlatformFee");
+        }
+      }
+    });
+
+    it("Moves the waived platform fee to charity", async () => {
+      await setFee(1000);
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.platformFeeBps, 1000);
+      assert.equal(room.charityBps, 6700);
+      assert.equal(room.hostFeeBps, 300);
+      assert.equal(room.prizePoolBps, 2000);
+    });
+
+    it("Applies the room's fee at end_room and locks it once players join", async () => {
+      await program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: playerEntryPda,
+          player: player1Wallet.publicKey,
+          playerTokenAccount: player1TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1Wallet])
+        .rpc();
+
+      try {
+        await setFee(1500);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidRoomStatus");
+      }
+
+      const platformBefore = (await getAccount(provider.connection, platformTokenAccount)).amount;
+      const charityBefore = (await getAccount(provider.connection, charityTokenAccount)).amount;
+
+      await program.methods
+        .endRoom(roomId, [player1Wallet.publicKey])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+      // 10 token entry: platform 10% = 1, charity 67% = 6.7
+      const platformAfter = (await getAccount(provider.connection, platformTokenAccount)).amount;
+      const charityAfter = (await getAccount(provider.connection, charityTokenAccount)).amount;
+      assert.equal((platformAfter - platformBefore).toString(), "1000000");
+      assert.equal((charityAfter - charityBefore).toString(), "6700000");
+    });
+  });
+
+  describe("Prize Tiers", () => {
+    const roomId = "tiered-prizes";
+    const entryFee = 10 * 1_000_000;
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    let player1EntryPda: PublicKey;
+    let player2EntryPda: PublicKey;
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      [player1EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      [player2EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player2Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+    });
+
+    it("Rejects prize tiers that don't sum to 100", async () => {
+      try {
+        await program.methods
+          .initPoolRoom(
+            roomId,
+            charityWallet.publicKey,
+            new anchor.BN(entryFee),
+            10,
+            300,
+            2000,
+            [100],
+            "Tiered prizes",
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            [{ minPlayers: 2, distribution: [70, 20] }],
+            null,
+            null,
+            null,
+            null
+          )
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            globalConfig: globalConfigPda,
+            host: hostWallet.publicKey,
+            feeTokenMint: tokenMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([hostWallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidPrizeDistribution");
+      }
+    });
+
+    it("Switches to the top-2 split once the room reaches two players", async () => {
+      // Winner takes all below two players, 70/30 from two players
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100],
+          "Tiered prizes",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          [{ minPlayers: 2, distribution: [70, 30] }],
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.prizeTiers.length, 1);
+      assert.deepEqual(room.prizeTiers[0].distribution, [70, 30]);
+
+      for (const [player, playerEntry, playerTokenAccount] of [
+        [player1Wallet, player1EntryPda, player1TokenAccount],
+        [player2Wallet, player2EntryPda, player2TokenAccount],
+      ] as [Keypair, PublicKey, PublicKey][]) {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry,
+            player: player.publicKey,
+            playerTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      }
+
+      // Two winners only have paid places in the two-player tier
+      await program.methods
+        .declareWinners(roomId, [player1Wallet.publicKey, player2Wallet.publicKey], null, null)
+        .accounts({
+          room: roomPda,
+          host: hostWallet.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: player1EntryPda, isSigner: false, isWritable: false },
+          { pubkey: player2EntryPda, isSigner: false, isWritable: false },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+      const firstBefore = (await getAccount(provider.connection, player1TokenAccount)).amount;
+      const secondBefore = (await getAccount(provider.connection, player2TokenAccount)).amount;
+
+      await program.methods
+        .endRoom(roomId, [])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+          { pubkey: player2TokenAccount, isSigner: false, isWritable: true },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+      // Prize pool is 20% of the 20 tokens collected, split 70/30
+      const firstAfter = (await getAccount(provider.connection, player1TokenAccount)).amount;
+      const secondAfter = (await getAccount(provider.connection, player2TokenAccount)).amount;
+      assert.equal((firstAfter - firstBefore).toString(), (2.8 * 1_000_000).toString());
+      assert.equal((secondAfter - secondBefore).toString(), (1.2 * 1_000_000).toString());
+    });
+  });
+
+  describe("Host Fee Waiver", () => {
+    const roomId = "waived-host-fee";
+    const entryFee = 10 * 1_000_000;
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    let player1EntryPda: PublicKey;
+    let player2EntryPda: PublicKey;
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      [player1EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      [player2EntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player2Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100],
+          "Nonprofit host",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      for (const [player, playerEntry, playerTokenAccount] of [
+        [player1Wallet, player1EntryPda, player1TokenAccount],
+        [player2Wallet, player2EntryPda, player2TokenAccount],
+      ] as [Keypair, PublicKey, PublicKey][]) {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry,
+            player: player.publicKey,
+            playerTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      }
+    });
+
+    it("Only the host can waive the host fee", async () => {
+      try {
+        await program.methods
+          .waiveHostFee(roomId)
+          .accounts({
+            room: roomPda,
+            host: player1Wallet.publicKey,
+          })
+          .signers([player1Wallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+    });
+
+    it("Sends a waived host fee to charity", async () => {
+      await program.methods
+        .waiveHostFee(roomId)
+        .accounts({
+          room: roomPda,
+          host: hostWallet.publicKey,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.hostFeeWaived, true);
+
+      const hostBefore = (await getAccount(provider.connection, hostTokenAccount)).amount;
+      const charityBefore = (await getAccount(provider.connection, charityTokenAccount)).amount;
+
+      await program.methods
+        .endRoom(roomId, [player1Wallet.publicKey])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+      // 20 tokens collected: platform 4, prizes 4, charity 11.4 + the waived 0.6 host fee
+      const hostAfter = (await getAccount(provider.connection, hostTokenAccount)).amount;
+      const charityAfter = (await getAccount(provider.connection, charityTokenAccount)).amount;
+      assert.equal((hostAfter - hostBefore).toString(), "0");
+      assert.equal((charityAfter - charityBefore).toString(), (12 * 1_000_000).toString());
+    });
+  });
+
+  describe("Cancel Room", () => {
+    const roomPdas = (roomId: string) => {
+      const [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+      return { roomPda, roomVaultPda };
+    };
+
+    const createRoom = async (roomId: string) => {
+      const { roomPda, roomVaultPda } = roomPdas(roomId);
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(10 * 1_000_000),
+          10,
+          300,
+          2000,
+          [100],
+          "Cancelled",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+      return { roomPda, roomVaultPda };
+    };
+
+    const cancelCall = (roomId: string) => {
+      const { roomPda, roomVaultPda } = roomPdas(roomId);
+      return program.methods
+        .cancelRoom(roomId)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([hostWallet]);
+    };
+
+    it("Closes an empty room and returns the prize escrow to the host", async () => {
+      const roomId = "cancel-empty";
+      const { roomPda, roomVaultPda } = await createRoom(roomId);
+
+      await program.methods
+        .fundMinPrize(roomId, new anchor.BN(1_000_000))
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      const hostBefore = (await getAccount(provider.connection, hostTokenAccount)).amount;
+      await cancelCall(roomId).rpc();
+      const hostAfter = (await getAccount(provider.connection, hostTokenAccount)).amount;
+
+      assert.equal((hostAfter - hostBefore).toString(), (1_000_000).toString());
+      assert.isNull(await provider.connection.getAccountInfo(roomPda));
+      assert.isNull(await provider.connection.getAccountInfo(roomVaultPda));
+    });
+
+    it("Fails once a player has joined", async () => {
+      const roomId = "cancel-joined";
+      const { roomPda, roomVaultPda } = await createRoom(roomId);
+      const [playerEntryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: playerEntryPda,
+          player: player1Wallet.publicKey,
+          playerTokenAccount: player1TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1Wallet])
+        .rpc();
+
+      try {
+        await cancelCall(roomId).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("RoomHasPlayers");
+      }
+    });
+  });
+
+  describe("End Room Compute Budget", () => {
+    // Mirrors endRoomComputeUnits in src/chains/solana/config.ts
+    const computeBudget = (winners: number) => 60_000 + 25_000 * winners;
+
+    const distributions: Record<number, number[]> = {
+      1: [100],
+      2: [60, 40],
+      3: [50, 30, 20],
+    };
+
+    for (const winnerCount of [1, 2, 3]) {
+      it(`Ends a room with ${winnerCount} winner(s) within the documented budget`, async () => {
+        const roomId = `cu-${winnerCount}-winners`;
+        const players = [
+          [player1Wallet, player1TokenAccount],
+          [player2Wallet, player2TokenAccount],
+          [player3Wallet, player3TokenAccount],
+        ].slice(0, winnerCount) as [Keypair, PublicKey][];
+
+        const [roomPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+          program.programId
+        );
+        const [roomVaultPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("room-vault"), roomPda.toBuffer()],
+          program.programId
+        );
+
+        await program.methods
+          .initPoolRoom(
+            roomId,
+            charityWallet.publicKey,
+            new anchor.BN(10 * 1_000_000),
+            10,
+            300,
+            2000,
+            distributions[winnerCount],
+            "CU benchmark",
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null,
+            null
+          )
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            globalConfig: globalConfigPda,
+            host: hostWallet.publicKey,
+            feeTokenMint: tokenMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([hostWallet])
+          .rpc();
+
+        for (const [player, playerTokenAccount] of players) {
+          const [playerEntry] = PublicKey.findProgramAddressSync(
+            [Buffer.from("player"), roomPda.toBuffer(), player.publicKey.toBuffer()],
+            program.programId
+          );
+          await program.methods
+            .joinRoom(roomId, new anchor.BN(0), null, null)
+            .accounts({
+              room: roomPda,
+              roomVault: roomVaultPda,
+              playerEntry,
+              player: player.publicKey,
+              playerTokenAccount,
+              tokenProgram: TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([player])
+            .rpc();
+        }
+
+        const signature = await program.methods
+          .endRoom(roomId, players.map(([player]) => player.publicKey))
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            globalConfig: globalConfigPda,
+            platformTokenAccount: platformTokenAccount,
+            charityTokenAccount: charityTokenAccount,
+            hostTokenAccount: hostTokenAccount,
+            host: hostWallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts(
+            players.map(([, playerTokenAccount]) => ({
+              pubkey: playerTokenAccount,
+              isSigner: false,
+              isWritable: true,
+            }))
+          )
+          .signers([hostWallet])
+          .rpc({ commitment: "confirmed" });
+
+        const tx = await provider.connection.getTransaction(signature, {
+          commitment: "confirmed",
+          maxSupportedTransactionVersion: 0,
+        });
+        const unitsConsumed = tx.meta.computeUnitsConsumed;
+        console.log(`      end_room with ${winnerCount} winner(s): ${unitsConsumed} CU`);
+        assert.isBelow(unitsConsumed, computeBudget(winnerCount));
+      });
+    }
+  });
+
+  describe("Referrals", () => {
+    const roomId = "referral-room";
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+
+    const joinCall = (player: Keypair, playerTokenAccount: PublicKey, referrer: PublicKey | null) =>
+      program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, referrer)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: PublicKey.findProgramAddressSync(
+            [Buffer.from("player"), roomPda.toBuffer(), player.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          player: player.publicKey,
+          playerTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player]);
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(10 * 1_000_000),
+          10,
+          300,
+          2000,
+          [100],
+          "Referral test",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+    });
+
+    it("Rejects a player naming themselves as referrer", async () => {
+      try {
+        await joinCall(player1Wallet, player1TokenAccount, player1Wallet.publicKey).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidReferrer");
+      }
+    });
+
+    it("Stores the referrer and emits it in PlayerJoined", async () => {
+      let listener: number;
+      const event = new Promise<any>((resolve) => {
+        listener = program.addEventListener("playerJoined", resolve);
+      });
+
+      await joinCall(player1Wallet, player1TokenAccount, player2Wallet.publicKey).rpc();
+
+      const emitted = await event;
+      await program.removeEventListener(listener);
+      assert.equal(emitted.referrer.toString(), player2Wallet.publicKey.toString());
+
+      const [entryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      const entry = await program.account.playerEntry.fetch(entryPda);
+      assert.equal(entry.referrer.toString(), player2Wallet.publicKey.toString());
+    });
+
+    it("Leaves the referrer empty when none is given", async () => {
+      await joinCall(player2Wallet, player2TokenAccount, null).rpc();
+
+      const [entryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player2Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      const entry = await program.account.playerEntry.fetch(entryPda);
+      assert.isNull(entry.referrer);
+    });
+  });
+
+  describe("Early-Bird Pricing", () => {
+    const roomId = "early-bird-room";
+    const entryFee = 10 * 1_000_000;
+    const earlyBirdFee = 6 * 1_000_000;
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+
+    const entryPda = (player: Keypair) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player.publicKey.toBuffer()],
+        program.programId
+      )[0];
+
+    const createCall = (id: string, room: PublicKey, vault: PublicKey, earlyFee: number) =>
+      program.methods
+        .initPoolRoom(
+          id,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100],
+          "Early-bird test",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          new anchor.BN(earlyFee),
+          1,
+          null,
+          null
+        )
+        .accounts({
+          room,
+          roomVault: vault,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet]);
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+    });
+
+    it("Rejects an early-bird fee above the entry fee", async () => {
+      const badRoomId = "early-bird-bad";
+      const [badRoomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(badRoomId)],
+        program.programId
+      );
+      const [badVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), badRoomPda.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await createCall(badRoomId, badRoomPda, badVaultPda, entryFee + 1).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidEarlyBirdFee");
+      }
+    });
+
+    it("Charges early birds the discounted fee and later players the full fee", async () => {
+      await createCall(roomId, roomPda, roomVaultPda, earlyBirdFee).rpc();
+
+      for (const [player, playerTokenAccount] of [
+        [player1Wallet, player1TokenAccount],
+        [player2Wallet, player2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry: entryPda(player),
+            player: player.publicKey,
+            playerTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      }
+
+      const earlyEntry = await program.account.playerEntry.fetch(entryPda(player1Wallet));
+      const lateEntry = await program.account.playerEntry.fetch(entryPda(player2Wallet));
+      assert.equal(earlyEntry.entryPaid.toNumber(), earlyBirdFee);
+      assert.equal(lateEntry.entryPaid.toNumber(), entryFee);
+
+      // end_room splits the accumulated fees, so mixed pricing needs no special casing
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.totalEntryFees.toNumber(), earlyBirdFee + entryFee);
+      assert.equal(room.totalCollected.toNumber(), earlyBirdFee + entryFee);
+    });
+  });
+
+  describe("Distribution Rounding", () => {
+    // 3 x 1_000_003 = 3_000_009: every bps cut of it rounds down
+    const entryFee = 1_000_003;
+    const players: [Keypair, PublicKey][] = [
+      [player1Wallet, player1TokenAccount],
+      [player2Wallet, player2TokenAccount],
+      [player3Wallet, player3TokenAccount],
+    ];
+
+    // Creates a room, fills it with all three players and ends it with the first
+    // `winnerCount` players as winners; returns the room and vault PDAs
+    const playRoom = async (roomId: string, distribution: number[], winnerCount: number) => {
+      const [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          333,
+          1777,
+          distribution,
+          "Rounding test",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      for (const [player, playerTokenAccount] of players) {
+        const [playerEntry] = PublicKey.findProgramAddressSync(
+          [Buffer.from("player"), roomPda.toBuffer(), player.publicKey.toBuffer()],
+          program.programId
+        );
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry,
+            player: player.publicKey,
+            playerTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      }
+
+      const collected = (await getAccount(provider.connection, roomVaultPda)).amount;
+      assert.equal(collected.toString(), (3 * entryFee).toString());
+
+      const winners = players.slice(0, winnerCount);
+      await program.methods
+        .endRoom(roomId, winners.map(([player]) => player.publicKey))
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          winners.map(([, playerTokenAccount]) => ({
+            pubkey: playerTokenAccount,
+            isSigner: false,
+            isWritable: true,
+          }))
+        )
+        .signers([hostWallet])
+        .rpc();
+
+      return { roomPda, roomVaultPda };
+    };
+
+    it("Empties the vault exactly when the fee splits don't divide evenly", async () => {
+      const { roomVaultPda } = await playRoom("rounding-room", [100], 1);
+
+      // Charity takes the remainder, so nothing is stranded
+      const vault = await getAccount(provider.connection, roomVaultPda);
+      assert.equal(vault.amount.toString(), "0");
+    });
+
+    it("Sends prize split rounding dust to charity", async () => {
+      const charityBefore = (await getAccount(provider.connection, charityTokenAccount)).amount;
+      const balancesBefore = await Promise.all(
+        players.map(([, tokenAccount]) => getAccount(provider.connection, tokenAccount))
+      );
+
+      const { roomPda, roomVaultPda } = await playRoom("prize-dust-room", [33, 33, 34], 3);
+
+      const room = await program.account.room.fetch(roomPda);
+      const total = 3 * entryFee;
+      const bps = (share: number) => Math.floor((total * share) / 10000);
+      // 1777 bps of 3_000_009 = 533_101; 33% / 33% / 34% of it round down and leave 1 over
+      const pool = bps(1777);
+      const shares = [33, 33, 34].map((pct) => Math.floor((pool * pct) / 100));
+      const dust = pool - shares.reduce((a, b) => a + b, 0);
+      assert.equal(dust, 1);
+
+      const balancesAfter = await Promise.all(
+        players.map(([, tokenAccount]) => getAccount(provider.connection, tokenAccount))
+      );
+      shares.forEach((share, i) => {
+        // Each player paid the entry fee and received their share
+        assert.equal(
+          Number(balancesAfter[i].amount) - Number(balancesBefore[i].amount),
+          share - entryFee
+        );
+      });
+
+      const charityAfter = (await getAccount(provider.connection, charityTokenAccount)).amount;
+      const expectedCharity = total - bps(room.platformFeeBps) - bps(333) - pool + dust;
+      assert.equal(Number(charityAfter) - Number(charityBefore), expectedCharity);
+
+      const vault = await getAccount(provider.connection, roomVaultPda);
+      assert.equal(vault.amount.toString(), "0");
+    });
+  });
+
+  describe("Entry Discounts", () => {
+    const roomId = "discount-room";
+    const entryFee = 10 * 1_000_000;
+    const discountBps = 2500;
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+
+    const discountPda = (player: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("discount"), roomPda.toBuffer(), player.toBuffer()],
+        program.programId
+      )[0];
+
+    const joinCall = (player: Keypair, playerTokenAccount: PublicKey, discount: PublicKey | null) =>
+      program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: PublicKey.findProgramAddressSync(
+            [Buffer.from("player"), roomPda.toBuffer(), player.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          discount,
+          player: player.publicKey,
+          playerTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player]);
+
+    const initCall = (id: string, discount: number) => {
+      const [room] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(id)],
+        program.programId
+      );
+      return program.methods
+        .initPoolRoom(
+          id,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100],
+          "Community night",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          discount,
+          null
+        )
+        .accounts({
+          room,
+          roomVault: PublicKey.findProgramAddressSync(
+            [Buffer.from("room-vault"), room.toBuffer()],
+            program.programId
+          )[0],
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet]);
+    };
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+
+      // 5700 bps to charity: a 25% discount still leaves it above 40% of the full fee
+      await initCall(roomId, discountBps).rpc();
+    });
+
+    it("Rejects a discount that cuts charity below its minimum", async () => {
+      try {
+        await initCall("discount-too-deep", 5000).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidDiscount");
+      }
+    });
+
+    it("Only the host can add a discount", async () => {
+      try {
+        await program.methods
+          .addDiscount(roomId, player1Wallet.publicKey)
+          .accounts({
+            room: roomPda,
+            discount: discountPda(player1Wallet.publicKey),
+            host: player1Wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player1Wallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+    });
+
+    it("Charges registered wallets the discounted fee", async () => {
+      await program.methods
+        .addDiscount(roomId, player1Wallet.publicKey)
+        .accounts({
+          room: roomPda,
+          discount: discountPda(player1Wallet.publicKey),
+          host: hostWallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      await joinCall(player1Wallet, player1TokenAccount, discountPda(player1Wallet.publicKey)).rpc();
+
+      const entry = await program.account.playerEntry.fetch(
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+          program.programId
+        )[0]
+      );
+      assert.equal(entry.entryPaid.toNumber(), 7_500_000);
+      assert.equal(entry.discountAmount.toNumber(), 2_500_000);
+    });
+
+    it("A discount can't be used by another wallet", async () => {
+      try {
+        await joinCall(player2Wallet, player2TokenAccount, discountPda(player1Wallet.publicKey)).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("ConstraintSeeds");
+      }
+
+      // Without a discount of their own, player 2 pays full price
+      await joinCall(player2Wallet, player2TokenAccount, null).rpc();
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.totalEntryFees.toNumber(), 17_500_000);
+      assert.equal(room.totalDiscounts.toNumber(), 2_500_000);
+      const vault = await getAccount(provider.connection, roomVaultPda);
+      assert.equal(vault.amount.toString(), "17500000");
+    });
+
+    it("Takes a leaver's discount off the room's total", async () => {
+      await program.methods
+        .leaveRoom(roomId)
+        .accounts({
+          room: roomPda,
+          playerEntry: PublicKey.findProgramAddressSync(
+            [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          roomVault: roomVaultPda,
+          playerTokenAccount: player1TokenAccount,
+          player: player1Wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([player1Wallet])
+        .rpc();
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.totalEntryFees.toNumber(), 10_000_000);
+      assert.equal(room.totalDiscounts.toNumber(), 0);
+    });
+  });
+
+  describe("Charity Wallet Validation", () => {
+    const roomPdas = (id: string) => {
+      const [room] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(id)],
+        program.programId
+      );
+      const [roomVault] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), room.toBuffer()],
+        program.programId
+      );
+      return { room, roomVault };
+    };
+
+    const initCall = (id: string, charity: PublicKey) => {
+      const { room, roomVault } = roomPdas(id);
+      return program.methods
+        .initPoolRoom(
+          id,
+          charity,
+          new anchor.BN(1_000_000),
+          10,
+          200,
+          3000,
+          [100],
+          "Charity check",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room,
+          roomVault,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet]);
+    };
+
+    it("Rejects the default pubkey as charity wallet", async () => {
+      try {
+        await initCall("zero-charity", PublicKey.default).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidCharityWallet");
+      }
+    });
+
+    it("Rejects the platform wallet as charity wallet", async () => {
+      try {
+        await initCall("platform-charity", platformWallet.publicKey).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidCharityWallet");
+      }
+    });
+
+    it("Rejects ending a room into a token account the charity doesn't own", async () => {
+      const roomId = "charity-owner-room";
+      const { room, roomVault } = roomPdas(roomId);
+      await initCall(roomId, charityWallet.publicKey).rpc();
+
+      await program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room,
+          roomVault,
+          playerEntry: PublicKey.findProgramAddressSync(
+            [Buffer.from("player"), room.toBuffer(), player1Wallet.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          player: player1Wallet.publicKey,
+          playerTokenAccount: player1TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1Wallet])
+        .rpc();
+
+      try {
+        await program.methods
+          .endRoom(roomId, [player1Wallet.publicKey])
+          .accounts({
+            room,
+            roomVault,
+            globalConfig: globalConfigPda,
+            platformTokenAccount: platformTokenAccount,
+            // The platform's account in the charity slot
+            charityTokenAccount: platformTokenAccount,
+            hostTokenAccount: hostTokenAccount,
+            host: hostWallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts([
+            { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+          ])
+          .signers([hostWallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidTokenOwner");
+      }
+    });
+  });
+
+  describe("Tied Winners", () => {
+    const roomId = "tied-winners-room";
+    // 3 x 1_000_003 at 1777 bps: a 533_101 pool, so 51% (271_881) splits unevenly
+    const entryFee = 1_000_003;
+    const players: [Keypair, PublicKey][] = [
+      [player1Wallet, player1TokenAccount],
+      [player2Wallet, player2TokenAccount],
+      [player3Wallet, player3TokenAccount],
+    ];
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+
+    const playerEntry = (player: Keypair) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player.publicKey.toBuffer()],
+        program.programId
+      )[0];
+
+    const declareCall = (placeSizes: number[] | null) =>
+      program.methods
+        .declareWinners(
+          roomId,
+          players.map(([player]) => player.publicKey),
+          null,
+          placeSizes ? Buffer.from(placeSizes) : null
+        )
+        .accounts({
+          room: roomPda,
+          host: hostWallet.publicKey,
+        })
+        .remainingAccounts(
+          players.map(([player]) => ({ pubkey: playerEntry(player), isSigner: false, isWritable: false }))
+        )
+        .signers([hostWallet]);
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          333,
+          1777,
+          [51, 49],
+          "Ties test",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      for (const [player, playerTokenAccount] of players) {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry: playerEntry(player),
+            player: player.publicKey,
+            playerTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      }
+    });
+
+    it("Rejects tied places that don't cover every winner", async () => {
+      try {
+        await declareCall([2, 2]).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidTies");
+      }
+    });
+
+    it("Rejects more tied places than paid places", async () => {
+      try {
+        await declareCall([1, 1, 1]).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        // All 1s is no tie at all: three winners for two paid places
+        expect(err.toString()).to.include("InvalidWinners");
+      }
+    });
+
+    it("Splits a tied place equally with the remainder to the first pubkey", async () => {
+      await declareCall([2, 1]).rpc();
+
+      const declared = await program.account.room.fetch(roomPda);
+      assert.deepEqual(Array.from(declared.placeSizes), [2, 1]);
+
+      const balancesBefore = await Promise.all(
+        players.map(([, tokenAccount]) => getAccount(provider.connection, tokenAccount))
+      );
+
+      await program.methods
+        .endRoom(roomId, [])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          players.map(([, tokenAccount]) => ({ pubkey: tokenAccount, isSigner: false, isWritable: true }))
+        )
+        .signers([hostWallet])
+        .rpc();
+
+      const pool = Math.floor((3 * entryFee * 1777) / 10000);
+      const firstPlace = Math.floor((pool * 51) / 100);
+      const secondPlace = Math.floor((pool * 49) / 100);
+      const half = Math.floor(firstPlace / 2);
+      assert.equal(firstPlace % 2, 1);
+
+      // The tied pair's odd unit goes to whichever of the two sorts first
+      const player1First = Buffer.compare(player1Wallet.publicKey.toBuffer(), player2Wallet.publicKey.toBuffer()) < 0;
+      const expected = [
+        player1First ? half + 1 : half,
+        player1First ? half : half + 1,
+        secondPlace,
+      ];
+
+      const balancesAfter = await Promise.all(
+        players.map(([, tokenAccount]) => getAccount(provider.connection, tokenAccount))
+      );
+      expected.forEach((prize, i) => {
+        assert.equal(Number(balancesAfter[i].amount) - Number(balancesBefore[i].amount), prize);
+      });
+
+      const vault = await getAccount(provider.connection, roomVaultPda);
+      assert.equal(vault.amount.toString(), "0");
+    });
+  });
+
+  describe("End Room Token Accounts", () => {
+    const roomId = "end-room-accounts";
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(1_000_000),
+          10,
+          200,
+          3000,
+          [100],
+          "Token account check",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      await program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: PublicKey.findProgramAddressSync(
+            [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          player: player1Wallet.publicKey,
+          playerTokenAccount: player1TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1Wallet])
+        .rpc();
+    });
+
+    const endWith = (accounts: { platform?: PublicKey; charity?: PublicKey; host?: PublicKey }) =>
+      program.methods
+        .endRoom(roomId, [player1Wallet.publicKey])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: accounts.platform ?? platformTokenAccount,
+          charityTokenAccount: accounts.charity ?? charityTokenAccount,
+          hostTokenAccount: accounts.host ?? hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+        ])
+        .signers([hostWallet])
+        .rpc();
+
+    it("Rejects a platform token account the platform wallet doesn't own", async () => {
+      try {
+        await endWith({ platform: player2TokenAccount });
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidTokenOwner");
+      }
+    });
+
+    it("Rejects a host token account the host doesn't own", async () => {
+      try {
+        await endWith({ host: player2TokenAccount });
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidTokenOwner");
+      }
+    });
+
+    it("Rejects a host token account for another mint", async () => {
+      const otherMint = await createMint(provider.connection, admin.payer, admin.publicKey, null, 6);
+      const hostOtherAccount = await createAccount(
+        provider.connection,
+        admin.payer,
+        otherMint,
+        hostWallet.publicKey,
+        Keypair.generate()
+      );
+
+      try {
+        await endWith({ host: hostOtherAccount });
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidTokenMint");
+      }
+    });
+
+    it("Ends the room once every account belongs to its wallet", async () => {
+      await endWith({});
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.isTrue(room.ended);
+    });
+  });
+
+  describe("Remaining Account Ordering", () => {
+    // No prize pool: both places pay nothing, so only the ordering checks stand in the way
+    const roomId = "remaining-order-room";
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+    const entryPda = (player: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player.toBuffer()],
+        program.programId
+      )[0];
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(1_000_000),
+          10,
+          200,
+          0,
+          [60, 40],
+          "Ordering check",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+
+      for (const [player, playerTokenAccount] of [
+        [player1Wallet, player1TokenAccount],
+        [player2Wallet, player2TokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        await program.methods
+          .joinRoom(roomId, new anchor.BN(0), null, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            playerEntry: entryPda(player.publicKey),
+            player: player.publicKey,
+            playerTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([player])
+          .rpc();
+      }
+
+      await program.methods
+        .declareWinners(roomId, [player1Wallet.publicKey, player2Wallet.publicKey], null, null)
+        .accounts({
+          room: roomPda,
+          host: hostWallet.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: entryPda(player1Wallet.publicKey), isSigner: false, isWritable: false },
+          { pubkey: entryPda(player2Wallet.publicKey), isSigner: false, isWritable: false },
+        ])
+        .signers([hostWallet])
+        .rpc();
+    });
+
+    const endWith = (remaining: PublicKey[]) =>
+      program.methods
+        .endRoom(roomId, [])
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformTokenAccount,
+          charityTokenAccount: charityTokenAccount,
+          hostTokenAccount: hostTokenAccount,
+          host: hostWallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          remaining.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+        )
+        .signers([hostWallet])
+        .rpc();
+
+    it("Rejects winner accounts out of order even when no place pays", async () => {
+      try {
+        await endWith([player2TokenAccount, player1TokenAccount]);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidTokenOwner");
+      }
+    });
+
+    it("Rejects trailing remaining accounts", async () => {
+      try {
+        await endWith([player1TokenAccount, player2TokenAccount, player3TokenAccount]);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidRemainingAccounts");
+      }
+    });
+
+    it("Rejects a missing winner account", async () => {
+      try {
+        await endWith([player1TokenAccount]);
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidRemainingAccounts");
+      }
+    });
+
+    it("Ends the room with exactly the winners' accounts in place order", async () => {
+      await endWith([player1TokenAccount, player2TokenAccount]);
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.isTrue(room.ended);
+    });
+  });
+
+  describe("Room Vault Validation", () => {
+    const pdas = (id: string) => {
+      const [room] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(id)],
+        program.programId
+      );
+      const [roomVault] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), room.toBuffer()],
+        program.programId
+      );
+      return { room, roomVault };
+    };
+
+    const initCall = (id: string, roomVault: PublicKey) =>
+      program.methods
+        .initPoolRoom(
+          id,
+          charityWallet.publicKey,
+          new anchor.BN(1_000_000),
+          10,
+          200,
+          3000,
+          [100],
+          "Vault check",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: pdas(id).room,
+          roomVault,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet]);
+
+    it("Rejects another room's vault, even with the right mint and a room authority", async () => {
+      const other = pdas("vault-check-first");
+      await initCall("vault-check-first", other.roomVault).rpc();
+
+      try {
+        await initCall("vault-check-second", other.roomVault).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("ConstraintSeeds");
+      }
+    });
+
+    it("Rejects a vault for another mint", async () => {
+      const otherMint = await createMint(provider.connection, admin.payer, admin.publicKey, null, 6);
+      const { room } = pdas("vault-check-mint");
+      const wrongMintVault = await createAccount(
+        provider.connection,
+        admin.payer,
+        otherMint,
+        room,
+        Keypair.generate()
+      );
+
+      try {
+        await initCall("vault-check-mint", wrongMintVault).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("ConstraintSeeds");
+      }
+    });
+  });
+
+  describe("Vault Reconciliation", () => {
+    // A room whose vault holds 5 tokens against 10 collected. No instruction can take
+    // tokens out of a vault without updating the room, so the validator preloads it from
+    // tests/fixtures/short-vault (see Anchor.toml; kept in sync by test_short_vault_fixture)
+    const roomId = "short-vault";
+    const shortHost = Keypair.fromSecretKey(
+      Uint8Array.from(JSON.parse(readFileSync("tests/fixtures/short-vault/host-keypair.json", "utf8")))
+    );
+    const shortMint = new PublicKey("4gkk7c3U6zjMxfuurqYKLjrxarMKwCz8w3UBKpUFVFm5");
+    const shortCharity = new PublicKey("C5WYuaJobxvHjpgQtq9NWeZGZEtwT9BJL1TDEkugWdSP");
+    const shortWinner = new PublicKey("H1rhc9vFyWY5fL3aLLEcAsf5DgQae2jvntdFoLHnk2Df");
+
+    const [roomPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("room"), shortHost.publicKey.toBuffer(), Buffer.from(roomId)],
+      program.programId
+    );
+    const [roomVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("room-vault"), roomPda.toBuffer()],
+      program.programId
+    );
+
+    let platformAccount: PublicKey;
+    let charityAccount: PublicKey;
+    let hostAccount: PublicKey;
+    let winnerAccount: PublicKey;
+
+    before(async () => {
+      const config = await program.account.globalConfig.fetch(globalConfigPda);
+      const tokenAccount = (owner: PublicKey) =>
+        createAccount(provider.connection, admin.payer, shortMint, owner);
+      platformAccount = await tokenAccount(config.platformWallet);
+      charityAccount = await tokenAccount(shortCharity);
+      hostAccount = await tokenAccount(shortHost.publicKey);
+      winnerAccount = await tokenAccount(shortWinner);
+    });
+
+    const endCall = (roomVault: PublicKey) =>
+      program.methods
+        .endRoom(roomId, [])
+        .accounts({
+          room: roomPda,
+          roomVault,
+          globalConfig: globalConfigPda,
+          platformTokenAccount: platformAccount,
+          charityTokenAccount: charityAccount,
+          hostTokenAccount: hostAccount,
+          host: shortHost.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([{ pubkey: winnerAccount, isSigner: false, isWritable: true }])
+        .signers([shortHost]);
+
+    it("Refuses to pay out of a well-funded decoy instead of the room's vault", async () => {
+      // Owned by the room PDA and holding more than was collected, but not the vault PDA
+      const decoy = await createAccount(provider.connection, admin.payer, shortMint, roomPda, Keypair.generate());
+      await mintTo(provider.connection, admin.payer, shortMint, decoy, shortHost, 20_000_000);
+
+      try {
+        await endCall(decoy).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("ConstraintSeeds");
+      }
+      assert.equal((await getAccount(provider.connection, decoy)).amount.toString(), "20000000");
+    });
+
+    it("Refuses to distribute when the vault holds less than was collected", async () => {
+      const roomBefore = await program.account.room.fetch(roomPda);
+      assert.equal(roomBefore.totalCollected.toNumber(), 10_000_000);
+      assert.equal((await getAccount(provider.connection, roomVaultPda)).amount.toString(), "5000000");
+
+      try {
+        await endCall(roomVaultPda).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("VaultBalanceMismatch");
+      }
+
+      // Nothing moved and the room is still open
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.ended, false);
+      assert.equal((await getAccount(provider.connection, roomVaultPda)).amount.toString(), "5000000");
+      for (const account of [platformAccount, charityAccount, hostAccount, winnerAccount]) {
+        assert.equal((await getAccount(provider.connection, account)).amount.toString(), "0");
+      }
+    });
+  });
+
+  describe("Room Layout Migration", () => {
+    const roomId = "migration-room";
+    let roomPda: PublicKey;
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(1_000_000),
+          10,
+          200,
+          3000,
+          [100],
+          "Migration check",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+    });
+
+    it("Writes new rooms at the current layout version", async () => {
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.roomVersion, 3);
+    });
+
+    it("Rejects migrating a room that's already current", async () => {
+      try {
+        await program.methods
+          .migrateRoom()
+          .accounts({
+            room: roomPda,
+            globalConfig: globalConfigPda,
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("RoomAlreadyCurrent");
+      }
+    });
+
+    it("Only the admin can migrate rooms", async () => {
+      try {
+        await program.methods
+          .migrateRoom()
+          .accounts({
+            room: roomPda,
+            globalConfig: globalConfigPda,
+            admin: hostWallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([hostWallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+    });
+
+    it("Refuses to rewrite program accounts that aren't rooms", async () => {
+      try {
+        await program.methods
+          .migrateRoom()
+          .accounts({
+            room: globalConfigPda,
+            globalConfig: globalConfigPda,
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("AccountDiscriminatorMismatch");
+      }
+    });
+  });
+
+  describe("Leave Room", () => {
+    const roomId = "leave-room";
+    const entryFee = 10 * 1_000_000;
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+
+    const playerEntryPda = (player: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player.toBuffer()],
+        program.programId
+      )[0];
+
+    const initCall = (id: string, leavePenalty: number) => {
+      const [room] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(id)],
+        program.programId
+      );
+      return program.methods
+        .initPoolRoom(
+          id,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          10,
+          300,
+          2000,
+          [100],
+          "Community night",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          leavePenalty
+        )
+        .accounts({
+          room,
+          roomVault: PublicKey.findProgramAddressSync(
+            [Buffer.from("room-vault"), room.toBuffer()],
+            program.programId
+          )[0],
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet]);
+    };
+
+    const leaveCall = (player: Keypair, playerTokenAccount: PublicKey) =>
+      program.methods
+        .leaveRoom(roomId)
+        .accounts({
+          room: roomPda,
+          playerEntry: playerEntryPda(player.publicKey),
+          roomVault: roomVaultPda,
+          playerTokenAccount,
+          player: player.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([player]);
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+
+      // 10% of a leaving player's payment stays behind for charity
+      await initCall(roomId, 1000).rpc();
+
+      await program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry: playerEntryPda(player1Wallet.publicKey),
+          discount: null,
+          player: player1Wallet.publicKey,
+          playerTokenAccount: player1TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1Wallet])
+        .rpc();
+    });
+
+    it("Rejects a leave penalty above the maximum", async () => {
+      try {
+        await initCall("leave-penalty-too-high", 2001).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidLeavePenalty");
+      }
+    });
+
+    it("Refunds a leaving player less the penalty", async () => {
+      const before = (await getAccount(provider.connection, player1TokenAccount)).amount;
+
+      await leaveCall(player1Wallet, player1TokenAccount).rpc();
+
+      const after = (await getAccount(provider.connection, player1TokenAccount)).amount;
+      assert.equal((after - before).toString(), "9000000");
+
+      // The last player out leaves the room empty, and cancellable, again
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.playerCount, 0);
+      assert.deepEqual(room.status, { ready: {} });
+      assert.equal(room.totalEntryFees.toNumber(), 0);
+      assert.equal(room.totalCollected.toNumber(), 1_000_000);
+      assert.equal(room.totalLeavePenalties.toNumber(), 1_000_000);
+
+      // The penalty stays in the vault for charity
+      const vault = await getAccount(provider.connection, roomVaultPda);
+      assert.equal(vault.amount.toString(), "1000000");
+
+      // The player's entry is closed
+      const entry = await provider.connection.getAccountInfo(playerEntryPda(player1Wallet.publicKey));
+      assert.isNull(entry);
+    });
+
+    it("Only a player in the room can leave", async () => {
+      try {
+        await leaveCall(player2Wallet, player2TokenAccount).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("AccountNotInitialized");
+      }
+    });
+
+    it("Lets the host cancel the emptied room, sending the penalty to charity", async () => {
+      const cancelCall = (charity: PublicKey | null) =>
+        program.methods
+          .cancelRoom(roomId)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            hostTokenAccount: hostTokenAccount,
+            charityTokenAccount: charity,
+            host: hostWallet.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([hostWallet]);
+
+      // The penalty is charity's, so it can't go back to the host with the vault
+      try {
+        await cancelCall(null).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("CharityTokenAccountRequired");
+      }
+
+      const hostBefore = (await getAccount(provider.connection, hostTokenAccount)).amount;
+      const charityBefore = (await getAccount(provider.connection, charityTokenAccount)).amount;
+
+      await cancelCall(charityTokenAccount).rpc();
+
+      const charityAfter = (await getAccount(provider.connection, charityTokenAccount)).amount;
+      const hostAfter = (await getAccount(provider.connection, hostTokenAccount)).amount;
+      assert.equal((charityAfter - charityBefore).toString(), "1000000");
+      assert.equal(hostAfter.toString(), hostBefore.toString());
+      assert.isNull(await provider.connection.getAccountInfo(roomPda));
+    });
+  });
+
+  describe("Donations", () => {
+    const roomId = "donations";
+    const entryFee = 10 * 1_000_000;
+    let roomPda: PublicKey;
+    let roomVaultPda: PublicKey;
+
+    const donationPda = (donor: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("donation"), roomPda.toBuffer(), donor.toBuffer()],
+        program.programId
+      )[0];
+
+    const donateCall = (donor: Keypair, donorTokenAccount: PublicKey, amount: number) =>
+      program.methods
+        .donate(roomId, new anchor.BN(amount))
+        .accounts({
+          room: roomPda,
+          donation: donationPda(donor.publicKey),
+          roomVault: roomVaultPda,
+          donorTokenAccount,
+          donor: donor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([donor]);
+
+    before(async () => {
+      [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+
+      // Room for a single player
+      await program.methods
+        .initPoolRoom(
+          roomId,
+          charityWallet.publicKey,
+          new anchor.BN(entryFee),
+          1,
+          300,
+          2000,
+          [100],
+          "Donation drive",
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null,
+          null
+        )
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          globalConfig: globalConfigPda,
+          host: hostWallet.publicKey,
+          feeTokenMint: tokenMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([hostWallet])
+        .rpc();
+    });
+
+    it("Rejects donations before the room is active", async () => {
+      // An empty room can still be cancelled, returning the vault to the host
+      try {
+        await donateCall(player2Wallet, player2TokenAccount, 1_000_000).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidRoomStatus");
+      }
+    });
+
+    it("Accepts donations to a full room without adding a player", async () => {
+      const [playerEntry] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .joinRoom(roomId, new anchor.BN(0), null, null)
+        .accounts({
+          room: roomPda,
+          roomVault: roomVaultPda,
+          playerEntry,
+          discount: null,
+          player: player1Wallet.publicKey,
+          playerTokenAccount: player1TokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([player1Wallet])
+        .rpc();
+
+      await donateCall(player2Wallet, player2TokenAccount, 5_000_000).rpc();
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.playerCount, 1);
+      assert.equal(room.totalEntryFees.toNumber(), entryFee);
+      assert.equal(room.totalExtrasFees.toNumber(), 5_000_000);
+      assert.equal(room.totalDonations.toNumber(), 5_000_000);
+      assert.equal(room.totalCollected.toNumber(), entryFee + 5_000_000);
+
+      const donation = await program.account.donation.fetch(donationPda(player2Wallet.publicKey));
+      assert.ok(donation.donor.equals(player2Wallet.publicKey));
+      assert.equal(donation.amount.toNumber(), 5_000_000);
+
+      const vault = await getAccount(provider.connection, roomVaultPda);
+      assert.equal(vault.amount.toString(), (entryFee + 5_000_000).toString());
+    });
+
+    it("Adds repeat donations to the donor's receipt", async () => {
+      await donateCall(player2Wallet, player2TokenAccount, 2_000_000).rpc();
+
+      const donation = await program.account.donation.fetch(donationPda(player2Wallet.publicKey));
+      assert.equal(donation.amount.toNumber(), 7_000_000);
+
+      const room = await program.account.room.fetch(roomPda);
+      assert.equal(room.totalDonations.toNumber(), 7_000_000);
+      assert.equal(room.totalExtrasFees.toNumber(), 7_000_000);
+    });
+
+    it("Rejects a zero donation", async () => {
+      try {
+        await donateCall(player3Wallet, player3TokenAccount, 0).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidDonationAmount");
+      }
+    });
+
+    it("Rejects paying from someone else's token account", async () => {
+      try {
+        await donateCall(player3Wallet, player2TokenAccount, 1_000_000).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidTokenOwner");
+      }
+    });
+
+    it("Sends donations to charity, not the players, when the room is recovered", async () => {
+      const [playerEntry] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), roomPda.toBuffer(), player1Wallet.publicKey.toBuffer()],
+        program.programId
+      );
+      const recoverCall = (charityAccount: PublicKey | null) =>
+        program.methods
+          .recoverRoom(roomId, null)
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            globalConfig: globalConfigPda,
+            platformTokenAccount: platformTokenAccount,
+            hostTokenAccount: null,
+            charityTokenAccount: charityAccount,
+            admin: admin.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .remainingAccounts([
+            { pubkey: playerEntry, isSigner: false, isWritable: false },
+            { pubkey: player1TokenAccount, isSigner: false, isWritable: true },
+          ]);
+
+      try {
+        await recoverCall(null).rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("CharityTokenAccountRequired");
+      }
+
+      const balance = async (account: PublicKey) =>
+        (await getAccount(provider.connection, account)).amount;
+      const charityBefore = await balance(charityTokenAccount);
+      const platformBefore = await balance(platformTokenAccount);
+      const playerBefore = await balance(player1TokenAccount);
+
+      await recoverCall(charityTokenAccount).rpc();
+
+      // 7 tokens donated go to charity in full; the 10% fee and refund cover the entry fee only
+      assert.equal(((await balance(charityTokenAccount)) - charityBefore).toString(), "7000000");
+      assert.equal(((await balance(platformTokenAccount)) - platformBefore).toString(), "1000000");
+      assert.equal(((await balance(player1TokenAccount)) - playerBefore).toString(), "9000000");
+      assert.equal((await balance(roomVaultPda)).toString(), "0");
+    });
+  });
+
+  describe("Edge Cases", () => {
+    it("Cannot create room with empty room ID", async () => {
+      const emptyRoomId = "";
+      const [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(emptyRoomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .initPoolRoom(
+            emptyRoomId,
+            new anchor.BN(10 * 1_000_000),
+            300,
+            2000,
+            [100],
+            "Test"
+          )
+          .accounts({
+            room: roomPda,
+            roomVault: roomVaultPda,
+            globalConfig: globalConfigPda,
+            host: hostWallet.publicKey,
+            feeTokenMint: tokenMint,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([hostWallet])
+          .rpc();
+        assert.fail("Should have thrown error");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidRoomId");
+      }
+    });
+
+    it("Cannot create room with zero entry fee", async () => {
+      const roomId = "zero-fee-room";
+      const [roomPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room"), hostWallet.publicKey.toBuffer(), Buffer.from(roomId)],
+        program.programId
+      );
+      const [roomVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("room-vault"), roomPda.toBuffer()],
+        program.programId
+      );
 
       try {
         await program.methods
@@ -808,9 +6564,7 @@ describe("fundraisely", () => {
             new anchor.BN(0), // Zero entry fee
             300,
             2000,
-            100,
-            null,
-            null,
+            [100],
             "Test"
           )
           .accounts({
@@ -40,6 +40,7 @@ export const PDA_SEEDS = {
   ROOM: 'room',
   ROOM_VAULT: 'room-vault',
   PLAYER: 'player',
+  DISCOUNT: 'discount',
 };
 
 // Transaction configuration
@@ -55,3 +56,14 @@ export const FEES = {
   MAX_HOST_BPS: 500,   // 5%
   MAX_COMBINED_BPS: 4000, // 40%
 };
+
+// Compute budget for end_room: a fixed cost plus one token transfer per winner and extra
+// charity (see the Compute Budget section of end_room.rs)
+export const END_ROOM_COMPUTE = {
+  BASE_UNITS: 60_000,
+  UNITS_PER_TRANSFER: 25_000,
+};
+
+export function endRoomComputeUnits(winners: number, extraCharities = 0): number {
+  return END_ROOM_COMPUTE.BASE_UNITS + END_ROOM_COMPUTE.UNITS_PER_TRANSFER * (winners + extraCharities);
+}
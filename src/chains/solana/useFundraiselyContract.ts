@@ -15,6 +15,7 @@ import { useConnection, useWallet } from '@solana/wallet-adapter-react';
 import { useCallback, useMemo } from 'react';
 import { Program, AnchorProvider, BN } from '@coral-xyz/anchor';
 import {
+  ComputeBudgetProgram,
   PublicKey,
   SystemProgram,
   SYSVAR_RENT_PUBKEY,
@@ -26,7 +27,7 @@ import {
   getAssociatedTokenAddress,
   createAssociatedTokenAccountInstruction,
 } from '@solana/spl-token';
-import { PROGRAM_ID, PDA_SEEDS, TX_CONFIG } from './config';
+import { PROGRAM_ID, PDA_SEEDS, TX_CONFIG, endRoomComputeUnits } from './config';
 import {
   simulateTransaction,
   validateTransactionInputs,
@@ -51,8 +52,19 @@ export interface CreatePoolRoomParams {
   firstPlacePct: number; // First place prize percentage: 0-100
   secondPlacePct?: number; // Second place prize percentage: 0-100 (optional)
   thirdPlacePct?: number; // Third place prize percentage: 0-100 (optional)
+  prizeDistribution?: number[]; // Optional: percentages for up to 10 places (overrides the three above)
   charityMemo: string; // Memo for charity transfer (max 28 chars)
-  expirationSlots?: BN; // Optional: slots until room expires (~43200 = 24 hours)
+  expirationSlots?: BN; // Optional: slots until room expires (~43200 = 24 hours); capped by the platform maximum, defaults to the platform default without expirationUnixTs
+  charitySplits?: { wallet: PublicKey; weightBps: number }[]; // Optional: up to 3 charities, weights sum to 10000, first is charityWallet
+  minPlayers?: number; // Optional: quorum; if unmet, recovery refunds players in full
+  requireReady?: boolean; // Optional: turn-based games; winners wait until every player readies up
+  expirationUnixTs?: BN; // Optional: unix time the room expires (e.g. midnight); applies alongside expirationSlots
+  allowlistRoot?: number[]; // Optional: 32-byte merkle root of invited wallets (private room)
+  prizeTiers?: { minPlayers: number; distribution: number[] }[]; // Optional: up to 3 distributions applying from a final player count
+  earlyBirdFee?: BN; // Optional: discounted entry fee (<= entryFee) for the first earlyBirdCount players
+  earlyBirdCount?: number; // Optional: how many players get the early-bird fee
+  discountBps?: number; // Optional: entry fee reduction for wallets registered with add_discount
+  leavePenaltyBps?: number; // Optional: share of a leaver's payment kept for charity (max 2000)
   feeTokenMint: PublicKey; // SPL token mint for entry fees
 }
 
@@ -60,6 +72,8 @@ export interface JoinRoomParams {
   roomId: string; // Room identifier
   hostPubkey: PublicKey; // Room host's pubkey (needed for PDA derivation)
   extrasAmount: BN; // Additional donation beyond entry fee (optional)
+  allowlistProof?: number[][]; // Private rooms: merkle proof of the player's wallet (32-byte hashes, leaf level first)
+  referrer?: PublicKey; // Optional: wallet credited with bringing this player in (reporting only)
   feeTokenMint: PublicKey; // SPL token mint (must match room's mint)
 }
 
@@ -67,6 +81,8 @@ export interface DeclareWinnersParams {
   roomId: string; // Room identifier
   hostPubkey: PublicKey; // Room host's pubkey (must match caller)
   winners: PublicKey[]; // Winner pubkeys (1-3 winners, host cannot be winner)
+  scores?: number[]; // Optional non-zero score per winner, same order as winners
+  placeSizes?: number[]; // Optional winners sharing each place, e.g. [2, 1] = two tied 1st, one 2nd
 }
 
 export interface EndRoomParams {
@@ -87,6 +103,7 @@ export interface RoomInfo {
   status: any; // RoomStatus enum
   ended: boolean;
   expirationSlot: BN;
+  expirationUnixTs: BN;
   hostFeeBps: number;
   prizePoolBps: number;
   charityBps: number;
@@ -185,6 +202,13 @@ export function useFundraiselyContract() {
     );
   }, []);
 
+  const deriveDiscountPDA = useCallback((room: PublicKey, player: PublicKey): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(PDA_SEEDS.DISCOUNT), room.toBuffer(), player.toBuffer()],
+      PROGRAM_ID
+    );
+  }, []);
+
   // ============================================================================
   // Instruction: Create Pool Room
   // ============================================================================
@@ -225,8 +249,23 @@ export function useFundraiselyContract() {
    * @param params.firstPlacePct - First place prize percentage (0-100)
    * @param params.secondPlacePct - Optional second place percentage (0-100)
    * @param params.thirdPlacePct - Optional third place percentage (0-100)
+   * @param params.prizeDistribution - Optional percentages for up to 10 places, summing to 100
    * @param params.charityMemo - Memo attached to charity transfer (max 28 chars)
-   * @param params.expirationSlots - Optional expiration in slots (~43200 = 24 hours)
+   * @param params.expirationSlots - Optional expiration in slots (~43200 = 24 hours, at most the platform maximum)
+   * @param params.charitySplits - Optional weighted charities (first must be charityWallet)
+   * @param params.minPlayers - Optional quorum (at most maxPlayers)
+   * @param params.requireReady - Optional readiness gate for turn-based games
+   * @param params.expirationUnixTs - Optional expiration as a unix timestamp (must be in the future)
+   * @param params.allowlistRoot - Optional merkle root of invited wallets. Leaves are
+   *   sha256(0x00 || wallet), nodes sha256(0x01 || min(a, b) || max(a, b))
+   * @param params.prizeTiers - Optional distributions by increasing minPlayers; the room pays
+   *   out with the tier its final player count reaches (e.g. top-3 split from 10 players)
+   * @param params.earlyBirdFee - Optional discounted entry fee, at most entryFee
+   * @param params.earlyBirdCount - Optional number of first players who pay earlyBirdFee
+   * @param params.discountBps - Optional discount for wallets the host registers; must leave
+   *   charity at least its minimum share of the full entry fee
+   * @param params.leavePenaltyBps - Optional share of a leaving player's payment kept for
+   *   charity (0-2000 bps, default 0)
    * @param params.feeTokenMint - SPL token mint address (USDC, SOL, etc.)
    *
    * @returns Promise resolving to creation result
@@ -338,11 +377,22 @@ export function useFundraiselyContract() {
           params.maxPlayers,
           params.hostFeeBps,
           params.prizePoolBps,
-          params.firstPlacePct,
-          params.secondPlacePct ?? null,
-          params.thirdPlacePct ?? null,
+          params.prizeDistribution ??
+            [params.firstPlacePct, params.secondPlacePct, params.thirdPlacePct].filter(
+              (pct): pct is number => pct !== undefined
+            ),
           params.charityMemo,
-          params.expirationSlots ?? null
+          params.expirationSlots ?? null,
+          params.charitySplits ?? null,
+          params.minPlayers ?? null,
+          params.requireReady ?? null,
+          params.expirationUnixTs ?? null,
+          params.allowlistRoot ?? null,
+          params.prizeTiers ?? null,
+          params.earlyBirdFee ?? null,
+          params.earlyBirdCount ?? null,
+          params.discountBps ?? null,
+          params.leavePenaltyBps ?? null
         )
         .accounts({
           room,
@@ -411,6 +461,8 @@ export function useFundraiselyContract() {
    * @param params.roomId - Room identifier (must match room creation)
    * @param params.hostPubkey - Host's Solana public key (needed for Room PDA derivation)
    * @param params.extrasAmount - Additional donation beyond entry fee (in token base units)
+   * @param params.allowlistProof - Merkle proof of the player's wallet, required by private rooms
+   * @param params.referrer - Wallet to credit with the referral (must not be the player)
    * @param params.feeTokenMint - SPL token mint (must match room's configured token)
    *
    * @returns Promise resolving to join result
@@ -466,6 +518,10 @@ export function useFundraiselyContract() {
       const [roomVault] = deriveRoomVaultPDA(room);
       const [playerEntry] = derivePlayerEntryPDA(room, publicKey);
 
+      // A Discount PDA from the host lets the player join at the reduced fee
+      const [discountPda] = deriveDiscountPDA(room, publicKey);
+      const discount = (await connection.getAccountInfo(discountPda)) ? discountPda : null;
+
       // Get player's Associated Token Account
       const playerTokenAccount = await getAssociatedTokenAddress(
         params.feeTokenMint,
@@ -495,13 +551,19 @@ export function useFundraiselyContract() {
 
       // Build join instruction
       const joinIx = await program.methods
-        .joinRoom(params.roomId, params.extrasAmount)
+        .joinRoom(
+          params.roomId,
+          params.extrasAmount,
+          params.allowlistProof ?? null,
+          params.referrer ?? null
+        )
         .accounts({
           room,
           playerEntry,
           roomVault,
           playerTokenAccount,
           globalConfig,
+          discount,
           player: publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -529,7 +591,7 @@ export function useFundraiselyContract() {
 
       return { signature, playerEntry: playerEntry.toBase58() };
     },
-    [publicKey, program, provider, connection, deriveGlobalConfigPDA, deriveRoomPDA, deriveRoomVaultPDA, derivePlayerEntryPDA]
+    [publicKey, program, provider, connection, deriveGlobalConfigPDA, deriveRoomPDA, deriveRoomVaultPDA, derivePlayerEntryPDA, deriveDiscountPDA]
   );
 
   // ============================================================================
@@ -636,7 +698,12 @@ export function useFundraiselyContract() {
 
       // Build instruction with PlayerEntry PDAs as remaining_accounts
       const ix = await program.methods
-        .declareWinners(params.roomId, params.winners)
+        .declareWinners(
+          params.roomId,
+          params.winners,
+          params.scores ? params.scores.map((score) => new BN(score)) : null,
+          params.placeSizes ? Buffer.from(params.placeSizes) : null
+        )
         .accounts({
           room,
           host: publicKey,
@@ -833,8 +900,11 @@ export function useFundraiselyContract() {
         )
         .instruction();
 
-      // Build transaction and simulate
-      const tx = new Transaction().add(ix);
+      // Build transaction (with room for every winner's transfer) and simulate
+      const tx = new Transaction().add(
+        ComputeBudgetProgram.setComputeUnitLimit({ units: endRoomComputeUnits(params.winners.length) }),
+        ix
+      );
       const simResult = await simulateTransaction(connection, tx);
 
       if (!simResult.success) {
@@ -881,6 +951,7 @@ export function useFundraiselyContract() {
           status: roomAccount.status,
           ended: roomAccount.ended as boolean,
           expirationSlot: roomAccount.expirationSlot as BN,
+          expirationUnixTs: roomAccount.expirationUnixTs as BN,
           hostFeeBps: roomAccount.hostFeeBps as number,
           prizePoolBps: roomAccount.prizePoolBps as number,
           charityBps: roomAccount.charityBps as number,
@@ -939,5 +1010,6 @@ export function useFundraiselyContract() {
     // PDA Helpers
     deriveRoomPDA,
     derivePlayerEntryPDA,
+    deriveDiscountPDA,
   };
 }
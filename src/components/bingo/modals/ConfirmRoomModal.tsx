@@ -91,7 +91,7 @@ const ConfirmRoomModal: React.FC<ConfirmRoomModalProps> = ({
         secondPlacePct: null,
         thirdPlacePct: null,
         charityMemo: `Bingo for ${hostName}`,
-        expirationSlots: null, // No expiration
+        expirationSlots: null, // Platform default expiration
       };
 
       console.log('[NOTE] Creating room with args:', roomArgs);
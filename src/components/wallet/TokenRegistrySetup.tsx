@@ -84,7 +84,7 @@ export function TokenRegistrySetup() {
 
       // Add NATIVE_MINT to approved tokens
       console.log('[NOTE] Adding NATIVE_MINT to approved tokens...');
-      const approveResult = await addApprovedToken(provider, NATIVE_MINT);
+      const approveResult = await addApprovedToken(provider, NATIVE_MINT, 'SOL', 9);
       console.log('[COMPLETE] NATIVE_MINT approved!');
       console.log('Transaction:', approveResult.signature);
       setTxSignatures(prev => [...prev, approveResult.signature]);
@@ -100,13 +100,17 @@ export function TokenRegistrySetup() {
     } catch (err: any) {
       console.error('[ERROR] Setup failed:', err);
 
-      if (err.message?.includes('Unauthorized') || err.message?.includes('unauthorized')) {
+      if (
+        err.message?.includes('Unauthorized') ||
+        err.message?.includes('unauthorized') ||
+        err.message?.includes('AlreadyInitialized')
+      ) {
         setErrorMsg('️ Your wallet is not the program admin. Only the admin can set up the token registry.');
       } else if (err.message?.includes('already in use') || err.message?.includes('AlreadyInUse')) {
         // Token registry exists, try just adding the token
         try {
           const provider = new AnchorProvider(connection, wallet as any, { commitment: 'confirmed' });
-          const approveResult = await addApprovedToken(provider, NATIVE_MINT);
+          const approveResult = await addApprovedToken(provider, NATIVE_MINT, 'SOL', 9);
           setTxSignatures([approveResult.signature]);
           await checkStatus();
           alert('[COMPLETE] NATIVE_MINT approved!');
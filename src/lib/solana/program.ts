@@ -285,7 +285,7 @@ export async function joinRoom(
   );
 
   const tx = await program.methods
-    .joinRoom(args.roomId, args.extrasAmount)
+    .joinRoom(args.roomId, args.extrasAmount, null, args.referrer ?? null)
     .accounts({
       room: roomPDA,
       playerEntry: playerEntryPDA,
@@ -383,20 +383,28 @@ export async function initializeTokenRegistry(provider: AnchorProvider) {
  *
  * @param provider Anchor provider (must be admin)
  * @param tokenMint Token mint address to approve
+ * @param symbol Optional display symbol (max 10 chars)
+ * @param decimals Optional expected decimals; the program reads them from the mint and
+ *   rejects a mismatch
  * @returns Transaction signature
  */
 export async function addApprovedToken(
   provider: AnchorProvider,
-  tokenMint: PublicKey
+  tokenMint: PublicKey,
+  symbol?: string,
+  decimals?: number
 ) {
   const program = getFundraiselyProgram(provider);
   const admin = provider.wallet.publicKey;
   const [tokenRegistryPDA] = getTokenRegistryPDA();
+  const [globalConfigPDA] = getGlobalConfigPDA();
 
   const tx = await program.methods
-    .addApprovedToken(tokenMint)
+    .addApprovedToken(tokenMint, symbol ?? null, decimals ?? null)
     .accounts({
       tokenRegistry: tokenRegistryPDA,
+      globalConfig: globalConfigPDA,
+      mint: tokenMint,
       admin,
     })
     .rpc();
@@ -427,7 +435,7 @@ export async function checkTokenRegistry(
   try {
     const tokenRegistry = await program.account.tokenRegistry.fetch(tokenRegistryPDA);
     const isApproved = tokenMint
-      ? tokenRegistry.approvedTokens.some((t: any) => t.equals(tokenMint))
+      ? tokenRegistry.approvedTokens.some((t: any) => t.mint.equals(tokenMint))
       : false;
 
     return {
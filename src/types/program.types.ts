@@ -171,9 +171,20 @@ export interface GlobalConfig {
   maxPrizePoolBps: number;
   minCharityBps: number;
   emergencyPause: boolean;
+  minPlatformFeeBps: number;
+  lifetimeCharityRaised: bigint;
+  lifetimeRoomsEnded: bigint;
+  lifetimePlayers: bigint;
+  maxExpirationSlots: bigint; // Longest expirationSlots a room may be created with
+  defaultExpirationSlots: bigint; // Expiration of rooms created without a deadline
   bump: number;
 }
 
+export interface PrizeTier {
+  minPlayers: number;
+  distribution: number[];
+}
+
 export interface Room {
   roomId: string;
   host: PublicKey;
@@ -194,10 +205,23 @@ export interface Room {
   ended: boolean;
   creationSlot: bigint;
   expirationSlot: bigint;
+  expirationUnixTs: bigint;
   charityMemo: string;
   winners: (PublicKey | null)[];
   prizeAssets: (PrizeAsset | null)[];
+  platformFeeBps: number;
+  prizeTiers: PrizeTier[];
+  hostFeeWaived: boolean;
+  earlyBirdFee: bigint; // Entry fee for the first earlyBirdCount players
+  earlyBirdCount: number;
+  discountBps: number; // Entry fee reduction for wallets with a Discount PDA (0 = none)
+  totalDiscounts: bigint; // Entry fees waived by discounts
+  placeSizes: number[]; // Winners sharing each prize place (empty = no ties)
   bump: number;
+  roomVersion: number; // Account layout version (older than current = needs migrate_room)
+  leavePenaltyBps: number; // Share of a leaver's payment kept for charity
+  totalLeavePenalties: bigint; // Penalties kept from leavers, paid to charity at end_room
+  totalDonations: bigint; // Donations from non-players (included in totalExtrasFees)
 }
 
 export interface PlayerEntry {
@@ -207,6 +231,22 @@ export interface PlayerEntry {
   extrasPaid: bigint;
   totalPaid: bigint;
   joinSlot: bigint;
+  ready: boolean;
+  referrer: PublicKey | null; // Wallet credited with the referral (no funds move)
+  discountAmount: bigint; // Entry fee waived by the player's Discount PDA
+  bump: number;
+}
+
+export interface Discount {
+  room: PublicKey;
+  player: PublicKey; // Wallet that joins at the room's discounted entry fee
+  bump: number;
+}
+
+export interface Donation {
+  room: PublicKey;
+  donor: PublicKey; // Supporter who gave without playing
+  amount: bigint; // Total this donor has given to the room
   bump: number;
 }
 
@@ -214,11 +254,18 @@ export interface PrizeAsset {
   mint: PublicKey;
   amount: bigint;
   deposited: boolean;
+  isNft: boolean; // 1-of-1 NFT prize (amount 1)
+}
+
+export interface ApprovedTokenInfo {
+  mint: PublicKey;
+  symbol: string; // Empty if not provided
+  decimals: number | null;
 }
 
 export interface TokenRegistry {
   admin: PublicKey;
-  approvedTokens: PublicKey[];
+  approvedTokens: ApprovedTokenInfo[];
   bump: number;
 }
 
@@ -230,6 +277,7 @@ export interface RoomCreated {
   entryFee: bigint;
   maxPlayers: number;
   expirationSlot: bigint;
+  expirationUnixTs: bigint;
   timestamp: bigint;
 }
 
@@ -239,6 +287,16 @@ export interface PlayerJoined {
   amountPaid: bigint;
   extrasPaid: bigint;
   playerCount: number;
+  referrer: PublicKey | null;
+  timestamp: bigint;
+}
+
+export interface DonationReceived {
+  room: PublicKey;
+  donor: PublicKey;
+  amount: bigint;
+  donorTotal: bigint;
+  totalDonations: bigint;
   timestamp: bigint;
 }
 
@@ -251,11 +309,35 @@ export interface WinnersDeclared {
 export interface RoomEnded {
   room: PublicKey;
   winners: PublicKey[];
+  winnerAmounts: bigint[];
   platformAmount: bigint;
   hostAmount: bigint;
+  charityWallet: PublicKey;
   charityAmount: bigint;
+  charityAmounts: bigint[];
   prizeAmount: bigint;
+  prizeDistribution: number[];
   totalPlayers: number;
+  expiredFinalization: boolean;
+  hostFeeWaived: boolean;
+  charityFromEntry: bigint;
+  charityFromExtras: bigint;
+  charityFromLeavePenalties: bigint;
+  timestamp: bigint;
+}
+
+export interface PlatformStats {
+  room: PublicKey;
+  lifetimeCharityRaised: bigint;
+  lifetimeRoomsEnded: bigint;
+  lifetimePlayers: bigint;
+  timestamp: bigint;
+}
+
+export interface RoomCancelled {
+  room: PublicKey;
+  host: PublicKey;
+  refundedEscrow: bigint;
   timestamp: bigint;
 }
 
@@ -277,6 +359,12 @@ export interface InitPoolRoomArgs {
 export interface JoinRoomArgs {
   roomId: string;
   extrasAmount: bigint;
+  referrer?: PublicKey | null;
+}
+
+export interface DonateArgs {
+  roomId: string;
+  amount: bigint;
 }
 
 export interface DeclareWinnersArgs {